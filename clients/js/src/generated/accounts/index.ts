@@ -9,3 +9,5 @@
 export * from './assetDepositReceipt';
 export * from './nftDepositReceipt';
 export * from './pool';
+export * from './priceLadder';
+export * from './traitRequirements';
@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI32Decoder,
@@ -27,6 +29,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -58,10 +62,14 @@ import {
 } from '../../hooked';
 import { PoolSeeds, findPoolPda } from '../pdas';
 import {
+  getCircuitBreakerDecoder,
+  getCircuitBreakerEncoder,
   getPoolConfigDecoder,
   getPoolConfigEncoder,
   getPoolStatsDecoder,
   getPoolStatsEncoder,
+  type CircuitBreaker,
+  type CircuitBreakerArgs,
   type PoolConfig,
   type PoolConfigArgs,
   type PoolStats,
@@ -95,8 +103,12 @@ export type Pool = {
   /** The whitelist of the pool, determining which NFTs can be deposited or sold into the pool. */
   whitelist: Address;
   rentPayer: Address;
+  /**
+   * The currency the pool is priced and settled in.
+   * Default Pubkey is SOL, otherwise SPL token mint.
+   */
   currency: Currency;
-  /** The amount of currency held in the pool. */
+  /** The amount of currency held in the pool, in lamports or token base units. */
   amount: bigint;
   /**
    * The difference between the number of buys and sells
@@ -128,6 +140,46 @@ export type Pool = {
   maxTakerSellCount: number;
   /** Pool configuration values. */
   config: PoolConfig;
+  /** Basis points the price of a GDA curve decays by every `GDA_DECAY_PERIOD_SEC` without a trade. */
+  decayBps: number;
+  /** The price a GDA curve stops decaying at. */
+  floorPrice: bigint;
+  /**
+   * A key the owner delegates pricing to: it can edit the curve and expiry of the pool
+   * but cannot move funds or NFTs. Default pubkey is interpreted as no value.
+   */
+  manager: NullableAddress;
+  /** Whether takers are prevented from buying NFTs from the pool. */
+  buysPaused: boolean;
+  /** Whether takers are prevented from selling NFTs into the pool. */
+  sellsPaused: boolean;
+  /** The highest nonce of the cosigner attestations used to trade with the pool. */
+  cosignerNonce: bigint;
+  /**
+   * Whether NFTs sold or deposited into the pool must have the traits of the pool's
+   * `TraitRequirements` account.
+   */
+  requiresTraits: boolean;
+  /** Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it. */
+  maxTakerBuyCount: number;
+  /** Limit how many NFTs the pool can hold through sells and deposits. */
+  maxNftsHeld: number;
+  /** Limit how much the pool can pay for NFTs sold into it over its lifetime. */
+  maxTotalSpend: bigint;
+  /** Optional limit on the trades the pool can make within a time window. */
+  circuitBreaker: CircuitBreaker;
+  /**
+   * Lamports escrowed in the pool on top of its rent, paid to keepers cleaning up the pool
+   * once it expires.
+   */
+  keeperBounty: bigint;
+  /**
+   * Bitmap of the used nonces in the window below `cosigner_nonce`, bit `i` standing for
+   * nonce `cosigner_nonce - i`, so each attestation can only be used once.
+   */
+  cosignerNoncesUsed: Array<bigint>;
+  /** Total amount the pool has paid for NFTs sold into it, in lamports or currency tokens. */
+  totalSpent: bigint;
   /** Reserved space for future upgrades. */
   reserved: Array<number>;
 };
@@ -150,8 +202,12 @@ export type PoolArgs = {
   /** The whitelist of the pool, determining which NFTs can be deposited or sold into the pool. */
   whitelist: Address;
   rentPayer: Address;
+  /**
+   * The currency the pool is priced and settled in.
+   * Default Pubkey is SOL, otherwise SPL token mint.
+   */
   currency: CurrencyArgs;
-  /** The amount of currency held in the pool. */
+  /** The amount of currency held in the pool, in lamports or token base units. */
   amount: number | bigint;
   /**
    * The difference between the number of buys and sells
@@ -183,6 +239,46 @@ export type PoolArgs = {
   maxTakerSellCount: number;
   /** Pool configuration values. */
   config: PoolConfigArgs;
+  /** Basis points the price of a GDA curve decays by every `GDA_DECAY_PERIOD_SEC` without a trade. */
+  decayBps: number;
+  /** The price a GDA curve stops decaying at. */
+  floorPrice: number | bigint;
+  /**
+   * A key the owner delegates pricing to: it can edit the curve and expiry of the pool
+   * but cannot move funds or NFTs. Default pubkey is interpreted as no value.
+   */
+  manager: NullableAddressArgs;
+  /** Whether takers are prevented from buying NFTs from the pool. */
+  buysPaused: boolean;
+  /** Whether takers are prevented from selling NFTs into the pool. */
+  sellsPaused: boolean;
+  /** The highest nonce of the cosigner attestations used to trade with the pool. */
+  cosignerNonce: number | bigint;
+  /**
+   * Whether NFTs sold or deposited into the pool must have the traits of the pool's
+   * `TraitRequirements` account.
+   */
+  requiresTraits: boolean;
+  /** Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it. */
+  maxTakerBuyCount: number;
+  /** Limit how many NFTs the pool can hold through sells and deposits. */
+  maxNftsHeld: number;
+  /** Limit how much the pool can pay for NFTs sold into it over its lifetime. */
+  maxTotalSpend: number | bigint;
+  /** Optional limit on the trades the pool can make within a time window. */
+  circuitBreaker: CircuitBreakerArgs;
+  /**
+   * Lamports escrowed in the pool on top of its rent, paid to keepers cleaning up the pool
+   * once it expires.
+   */
+  keeperBounty: number | bigint;
+  /**
+   * Bitmap of the used nonces in the window below `cosigner_nonce`, bit `i` standing for
+   * nonce `cosigner_nonce - i`, so each attestation can only be used once.
+   */
+  cosignerNoncesUsed: Array<number | bigint>;
+  /** Total amount the pool has paid for NFTs sold into it, in lamports or currency tokens. */
+  totalSpent: number | bigint;
   /** Reserved space for future upgrades. */
   reserved: Array<number>;
 };
@@ -210,7 +306,21 @@ export function getPoolEncoder(): Encoder<PoolArgs> {
       ['makerBroker', getNullableAddressEncoder()],
       ['maxTakerSellCount', getU32Encoder()],
       ['config', getPoolConfigEncoder()],
-      ['reserved', getArrayEncoder(getU8Encoder(), { size: 100 })],
+      ['decayBps', getU16Encoder()],
+      ['floorPrice', getU64Encoder()],
+      ['manager', getNullableAddressEncoder()],
+      ['buysPaused', getBooleanEncoder()],
+      ['sellsPaused', getBooleanEncoder()],
+      ['cosignerNonce', getU64Encoder()],
+      ['requiresTraits', getBooleanEncoder()],
+      ['maxTakerBuyCount', getU32Encoder()],
+      ['maxNftsHeld', getU32Encoder()],
+      ['maxTotalSpend', getU64Encoder()],
+      ['circuitBreaker', getCircuitBreakerEncoder()],
+      ['keeperBounty', getU64Encoder()],
+      ['cosignerNoncesUsed', getArrayEncoder(getU64Encoder(), { size: 8 })],
+      ['totalSpent', getU64Encoder()],
+      ['reserved', getArrayEncoder(getU8Encoder(), { size: 71 })],
     ]),
     (value) => ({ ...value, discriminator: POOL_DISCRIMINATOR })
   );
@@ -238,7 +348,21 @@ export function getPoolDecoder(): Decoder<Pool> {
     ['makerBroker', getNullableAddressDecoder()],
     ['maxTakerSellCount', getU32Decoder()],
     ['config', getPoolConfigDecoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 100 })],
+    ['decayBps', getU16Decoder()],
+    ['floorPrice', getU64Decoder()],
+    ['manager', getNullableAddressDecoder()],
+    ['buysPaused', getBooleanDecoder()],
+    ['sellsPaused', getBooleanDecoder()],
+    ['cosignerNonce', getU64Decoder()],
+    ['requiresTraits', getBooleanDecoder()],
+    ['maxTakerBuyCount', getU32Decoder()],
+    ['maxNftsHeld', getU32Decoder()],
+    ['maxTotalSpend', getU64Decoder()],
+    ['circuitBreaker', getCircuitBreakerDecoder()],
+    ['keeperBounty', getU64Decoder()],
+    ['cosignerNoncesUsed', getArrayDecoder(getU64Decoder(), { size: 8 })],
+    ['totalSpent', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 71 })],
   ]);
 }
 
@@ -300,7 +424,7 @@ export async function fetchAllMaybePool(
 }
 
 export function getPoolSize(): number {
-  return 447;
+  return 591;
 }
 
 export async function fetchPoolFromSeeds(
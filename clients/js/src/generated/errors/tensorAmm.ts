@@ -72,32 +72,137 @@ export const TENSOR_AMM_ERROR__MISSING_MAKER_BROKER = 0x2efa; // 12026
 export const TENSOR_AMM_ERROR__MISSING_COSIGNER = 0x2efb; // 12027
 /** InvalidEdition: Invalid edition */
 export const TENSOR_AMM_ERROR__INVALID_EDITION = 0x2efc; // 12028
+/** InvalidCurrency: Invalid currency */
+export const TENSOR_AMM_ERROR__INVALID_CURRENCY = 0x2efd; // 12029
+/** MissingCurrencyAccounts: Missing currency accounts */
+export const TENSOR_AMM_ERROR__MISSING_CURRENCY_ACCOUNTS = 0x2efe; // 12030
+/** InvalidCurrencyTokenAccount: Invalid currency token account */
+export const TENSOR_AMM_ERROR__INVALID_CURRENCY_TOKEN_ACCOUNT = 0x2eff; // 12031
+/** InvalidCompressedNft: Invalid compressed NFT */
+export const TENSOR_AMM_ERROR__INVALID_COMPRESSED_NFT = 0x2f00; // 12032
+/** InvalidWnsAccounts: Invalid WNS accounts */
+export const TENSOR_AMM_ERROR__INVALID_WNS_ACCOUNTS = 0x2f01; // 12033
+/** InvalidBatchAccounts: Invalid batch accounts */
+export const TENSOR_AMM_ERROR__INVALID_BATCH_ACCOUNTS = 0x2f02; // 12034
+/** WrongNftReceipt: Wrong NFT deposit receipt */
+export const TENSOR_AMM_ERROR__WRONG_NFT_RECEIPT = 0x2f03; // 12035
+/** InsufficientPoolFunds: Pool cannot fund the sale */
+export const TENSOR_AMM_ERROR__INSUFFICIENT_POOL_FUNDS = 0x2f04; // 12036
+/** WrongCurveType: operation not permitted on this curve type */
+export const TENSOR_AMM_ERROR__WRONG_CURVE_TYPE = 0x2f05; // 12037
+/** InsufficientReserves: XYK pools need both currency and NFTs in reserve */
+export const TENSOR_AMM_ERROR__INSUFFICIENT_RESERVES = 0x2f06; // 12038
+/** InvalidDecay: invalid GDA decay rate or floor price */
+export const TENSOR_AMM_ERROR__INVALID_DECAY = 0x2f07; // 12039
+/** MissingPriceLadder: price ladder account required for custom curves */
+export const TENSOR_AMM_ERROR__MISSING_PRICE_LADDER = 0x2f08; // 12040
+/** InvalidPriceLadder: price ladder must be non-empty and non-decreasing */
+export const TENSOR_AMM_ERROR__INVALID_PRICE_LADDER = 0x2f09; // 12041
+/** PriceLadderExhausted: price offset is outside of the price ladder */
+export const TENSOR_AMM_ERROR__PRICE_LADDER_EXHAUSTED = 0x2f0a; // 12042
+/** WrongManager: Wrong manager */
+export const TENSOR_AMM_ERROR__WRONG_MANAGER = 0x2f0b; // 12043
+/** ManagerNotAllowed: only the owner can make this change */
+export const TENSOR_AMM_ERROR__MANAGER_NOT_ALLOWED = 0x2f0c; // 12044
+/** PoolPaused: pool is paused for this side of the trade */
+export const TENSOR_AMM_ERROR__POOL_PAUSED = 0x2f0d; // 12045
+/** MissingCosignerAttestation: no cosigner attestation found for the asset */
+export const TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION = 0x2f0e; // 12046
+/** ExpiredCosignerAttestation: cosigner attestation has expired */
+export const TENSOR_AMM_ERROR__EXPIRED_COSIGNER_ATTESTATION = 0x2f0f; // 12047
+/** StaleCosignerNonce: cosigner attestation nonce was already used */
+export const TENSOR_AMM_ERROR__STALE_COSIGNER_NONCE = 0x2f10; // 12048
+/** InvalidPriceModifier: price modifier results in an invalid price */
+export const TENSOR_AMM_ERROR__INVALID_PRICE_MODIFIER = 0x2f11; // 12049
+/** InvalidTraitRequirements: trait requirements must be non-empty and fit the account */
+export const TENSOR_AMM_ERROR__INVALID_TRAIT_REQUIREMENTS = 0x2f12; // 12050
+/** MissingTraitRequirements: trait requirements account required for this pool */
+export const TENSOR_AMM_ERROR__MISSING_TRAIT_REQUIREMENTS = 0x2f13; // 12051
+/** TraitRequirementsNotMet: asset does not have the traits required by the pool */
+export const TENSOR_AMM_ERROR__TRAIT_REQUIREMENTS_NOT_MET = 0x2f14; // 12052
+/** PoolHasTraitRequirements: trait requirements must be cleared first */
+export const TENSOR_AMM_ERROR__POOL_HAS_TRAIT_REQUIREMENTS = 0x2f15; // 12053
+/** MaxTakerBuyCountExceeded: max taker buy count exceeded, pool cannot sell anymore NFTs */
+export const TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_EXCEEDED = 0x2f16; // 12054
+/** MaxTakerBuyCountTooSmall: max taker buy count is too small */
+export const TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_TOO_SMALL = 0x2f17; // 12055
+/** MaxNftsHeldExceeded: pool cannot hold anymore NFTs */
+export const TENSOR_AMM_ERROR__MAX_NFTS_HELD_EXCEEDED = 0x2f18; // 12056
+/** MaxNftsHeldTooSmall: max nfts held is lower than the NFTs held */
+export const TENSOR_AMM_ERROR__MAX_NFTS_HELD_TOO_SMALL = 0x2f19; // 12057
+/** MaxTotalSpendExceeded: max total spend exceeded, pool cannot buy anymore NFTs */
+export const TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_EXCEEDED = 0x2f1a; // 12058
+/** MaxTotalSpendTooSmall: max total spend is lower than the amount already spent */
+export const TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_TOO_SMALL = 0x2f1b; // 12059
+/** CircuitBreakerTripped: circuit breaker tripped, pool cannot trade until the window ends */
+export const TENSOR_AMM_ERROR__CIRCUIT_BREAKER_TRIPPED = 0x2f1c; // 12060
+/** InvalidCircuitBreaker: invalid circuit breaker limits */
+export const TENSOR_AMM_ERROR__INVALID_CIRCUIT_BREAKER = 0x2f1d; // 12061
+/** ExistingFunds: cannot convert pool with currency in escrow -- withdraw all before converting */
+export const TENSOR_AMM_ERROR__EXISTING_FUNDS = 0x2f1e; // 12062
+/** NftsNotTransferable: cannot transfer pool holding nfts that are not migrated -- withdraw them before transferring */
+export const TENSOR_AMM_ERROR__NFTS_NOT_TRANSFERABLE = 0x2f1f; // 12063
 
 export type TensorAmmError =
   | typeof TENSOR_AMM_ERROR__ARITHMETIC_ERROR
   | typeof TENSOR_AMM_ERROR__BAD_MINT_PROOF
   | typeof TENSOR_AMM_ERROR__BAD_SHARED_ESCROW
   | typeof TENSOR_AMM_ERROR__CANNOT_USE_SHARED_ESCROW
+  | typeof TENSOR_AMM_ERROR__CIRCUIT_BREAKER_TRIPPED
   | typeof TENSOR_AMM_ERROR__DELTA_TOO_LARGE
   | typeof TENSOR_AMM_ERROR__ESCROW_PROGRAM_NOT_SET
+  | typeof TENSOR_AMM_ERROR__EXISTING_FUNDS
   | typeof TENSOR_AMM_ERROR__EXISTING_NFTS
+  | typeof TENSOR_AMM_ERROR__EXPIRED_COSIGNER_ATTESTATION
   | typeof TENSOR_AMM_ERROR__EXPIRED_POOL
   | typeof TENSOR_AMM_ERROR__EXPIRY_TOO_LARGE
   | typeof TENSOR_AMM_ERROR__FEES_NOT_ALLOWED
   | typeof TENSOR_AMM_ERROR__FEES_TOO_HIGH
+  | typeof TENSOR_AMM_ERROR__INSUFFICIENT_POOL_FUNDS
+  | typeof TENSOR_AMM_ERROR__INSUFFICIENT_RESERVES
+  | typeof TENSOR_AMM_ERROR__INVALID_BATCH_ACCOUNTS
+  | typeof TENSOR_AMM_ERROR__INVALID_CIRCUIT_BREAKER
+  | typeof TENSOR_AMM_ERROR__INVALID_COMPRESSED_NFT
+  | typeof TENSOR_AMM_ERROR__INVALID_CURRENCY
+  | typeof TENSOR_AMM_ERROR__INVALID_CURRENCY_TOKEN_ACCOUNT
+  | typeof TENSOR_AMM_ERROR__INVALID_DECAY
   | typeof TENSOR_AMM_ERROR__INVALID_EDITION
   | typeof TENSOR_AMM_ERROR__INVALID_POOL_AMOUNT
+  | typeof TENSOR_AMM_ERROR__INVALID_PRICE_LADDER
+  | typeof TENSOR_AMM_ERROR__INVALID_PRICE_MODIFIER
+  | typeof TENSOR_AMM_ERROR__INVALID_TRAIT_REQUIREMENTS
+  | typeof TENSOR_AMM_ERROR__INVALID_WNS_ACCOUNTS
+  | typeof TENSOR_AMM_ERROR__MANAGER_NOT_ALLOWED
+  | typeof TENSOR_AMM_ERROR__MAX_NFTS_HELD_EXCEEDED
+  | typeof TENSOR_AMM_ERROR__MAX_NFTS_HELD_TOO_SMALL
+  | typeof TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_EXCEEDED
+  | typeof TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_TOO_SMALL
   | typeof TENSOR_AMM_ERROR__MAX_TAKER_SELL_COUNT_EXCEEDED
   | typeof TENSOR_AMM_ERROR__MAX_TAKER_SELL_COUNT_TOO_SMALL
+  | typeof TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_EXCEEDED
+  | typeof TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_TOO_SMALL
   | typeof TENSOR_AMM_ERROR__MISSING_COSIGNER
+  | typeof TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION
+  | typeof TENSOR_AMM_ERROR__MISSING_CURRENCY_ACCOUNTS
   | typeof TENSOR_AMM_ERROR__MISSING_MAKER_BROKER
+  | typeof TENSOR_AMM_ERROR__MISSING_PRICE_LADDER
+  | typeof TENSOR_AMM_ERROR__MISSING_TRAIT_REQUIREMENTS
+  | typeof TENSOR_AMM_ERROR__NFTS_NOT_TRANSFERABLE
+  | typeof TENSOR_AMM_ERROR__POOL_HAS_TRAIT_REQUIREMENTS
   | typeof TENSOR_AMM_ERROR__POOL_INSUFFICIENT_RENT
   | typeof TENSOR_AMM_ERROR__POOL_NOT_EXPIRED
   | typeof TENSOR_AMM_ERROR__POOL_ON_SHARED_ESCROW
+  | typeof TENSOR_AMM_ERROR__POOL_PAUSED
+  | typeof TENSOR_AMM_ERROR__PRICE_LADDER_EXHAUSTED
   | typeof TENSOR_AMM_ERROR__PRICE_MISMATCH
+  | typeof TENSOR_AMM_ERROR__STALE_COSIGNER_NONCE
   | typeof TENSOR_AMM_ERROR__STARTING_PRICE_TOO_SMALL
+  | typeof TENSOR_AMM_ERROR__TRAIT_REQUIREMENTS_NOT_MET
   | typeof TENSOR_AMM_ERROR__WRONG_COSIGNER
+  | typeof TENSOR_AMM_ERROR__WRONG_CURVE_TYPE
   | typeof TENSOR_AMM_ERROR__WRONG_MAKER_BROKER
+  | typeof TENSOR_AMM_ERROR__WRONG_MANAGER
+  | typeof TENSOR_AMM_ERROR__WRONG_NFT_RECEIPT
   | typeof TENSOR_AMM_ERROR__WRONG_OWNER
   | typeof TENSOR_AMM_ERROR__WRONG_POOL_TYPE
   | typeof TENSOR_AMM_ERROR__WRONG_POOL_VERSION
@@ -111,26 +216,61 @@ if (process.env.NODE_ENV !== 'production') {
     [TENSOR_AMM_ERROR__BAD_MINT_PROOF]: `bad mint proof account`,
     [TENSOR_AMM_ERROR__BAD_SHARED_ESCROW]: `bad shared escrow account`,
     [TENSOR_AMM_ERROR__CANNOT_USE_SHARED_ESCROW]: `cannot use shared escrow in NFT pools`,
+    [TENSOR_AMM_ERROR__CIRCUIT_BREAKER_TRIPPED]: `circuit breaker tripped, pool cannot trade until the window ends`,
     [TENSOR_AMM_ERROR__DELTA_TOO_LARGE]: `delta too large`,
     [TENSOR_AMM_ERROR__ESCROW_PROGRAM_NOT_SET]: `Escrow program not set`,
+    [TENSOR_AMM_ERROR__EXISTING_FUNDS]: `cannot convert pool with currency in escrow -- withdraw all before converting`,
     [TENSOR_AMM_ERROR__EXISTING_NFTS]: `cannot close pool with nfts in escrow -- withdraw all before closing`,
+    [TENSOR_AMM_ERROR__EXPIRED_COSIGNER_ATTESTATION]: `cosigner attestation has expired`,
     [TENSOR_AMM_ERROR__EXPIRED_POOL]: `Expired Pool`,
     [TENSOR_AMM_ERROR__EXPIRY_TOO_LARGE]: `Expiry too large`,
     [TENSOR_AMM_ERROR__FEES_NOT_ALLOWED]: `fees not allowed for non-trade pools`,
     [TENSOR_AMM_ERROR__FEES_TOO_HIGH]: `fees entered above allowed threshold`,
+    [TENSOR_AMM_ERROR__INSUFFICIENT_POOL_FUNDS]: `Pool cannot fund the sale`,
+    [TENSOR_AMM_ERROR__INSUFFICIENT_RESERVES]: `XYK pools need both currency and NFTs in reserve`,
+    [TENSOR_AMM_ERROR__INVALID_BATCH_ACCOUNTS]: `Invalid batch accounts`,
+    [TENSOR_AMM_ERROR__INVALID_CIRCUIT_BREAKER]: `invalid circuit breaker limits`,
+    [TENSOR_AMM_ERROR__INVALID_COMPRESSED_NFT]: `Invalid compressed NFT`,
+    [TENSOR_AMM_ERROR__INVALID_CURRENCY]: `Invalid currency`,
+    [TENSOR_AMM_ERROR__INVALID_CURRENCY_TOKEN_ACCOUNT]: `Invalid currency token account`,
+    [TENSOR_AMM_ERROR__INVALID_DECAY]: `invalid GDA decay rate or floor price`,
     [TENSOR_AMM_ERROR__INVALID_EDITION]: `Invalid edition`,
     [TENSOR_AMM_ERROR__INVALID_POOL_AMOUNT]: `Invalid pool amount`,
+    [TENSOR_AMM_ERROR__INVALID_PRICE_LADDER]: `price ladder must be non-empty and non-decreasing`,
+    [TENSOR_AMM_ERROR__INVALID_PRICE_MODIFIER]: `price modifier results in an invalid price`,
+    [TENSOR_AMM_ERROR__INVALID_TRAIT_REQUIREMENTS]: `trait requirements must be non-empty and fit the account`,
+    [TENSOR_AMM_ERROR__INVALID_WNS_ACCOUNTS]: `Invalid WNS accounts`,
+    [TENSOR_AMM_ERROR__MANAGER_NOT_ALLOWED]: `only the owner can make this change`,
+    [TENSOR_AMM_ERROR__MAX_NFTS_HELD_EXCEEDED]: `pool cannot hold anymore NFTs`,
+    [TENSOR_AMM_ERROR__MAX_NFTS_HELD_TOO_SMALL]: `max nfts held is lower than the NFTs held`,
+    [TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_EXCEEDED]: `max taker buy count exceeded, pool cannot sell anymore NFTs`,
+    [TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_TOO_SMALL]: `max taker buy count is too small`,
     [TENSOR_AMM_ERROR__MAX_TAKER_SELL_COUNT_EXCEEDED]: `max taker sell count exceeded, pool cannot buy anymore NFTs`,
     [TENSOR_AMM_ERROR__MAX_TAKER_SELL_COUNT_TOO_SMALL]: `max taker sell count is too small`,
+    [TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_EXCEEDED]: `max total spend exceeded, pool cannot buy anymore NFTs`,
+    [TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_TOO_SMALL]: `max total spend is lower than the amount already spent`,
     [TENSOR_AMM_ERROR__MISSING_COSIGNER]: `Missing cosigner account`,
+    [TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION]: `no cosigner attestation found for the asset`,
+    [TENSOR_AMM_ERROR__MISSING_CURRENCY_ACCOUNTS]: `Missing currency accounts`,
     [TENSOR_AMM_ERROR__MISSING_MAKER_BROKER]: `Missing maker broker account`,
+    [TENSOR_AMM_ERROR__MISSING_PRICE_LADDER]: `price ladder account required for custom curves`,
+    [TENSOR_AMM_ERROR__MISSING_TRAIT_REQUIREMENTS]: `trait requirements account required for this pool`,
+    [TENSOR_AMM_ERROR__NFTS_NOT_TRANSFERABLE]: `cannot transfer pool holding nfts that are not migrated -- withdraw them before transferring`,
+    [TENSOR_AMM_ERROR__POOL_HAS_TRAIT_REQUIREMENTS]: `trait requirements must be cleared first`,
     [TENSOR_AMM_ERROR__POOL_INSUFFICIENT_RENT]: `Pool must keep minimum rent balance`,
     [TENSOR_AMM_ERROR__POOL_NOT_EXPIRED]: `Pool not expired`,
     [TENSOR_AMM_ERROR__POOL_ON_SHARED_ESCROW]: `expected a non-shared escrow pool to be passed in`,
+    [TENSOR_AMM_ERROR__POOL_PAUSED]: `pool is paused for this side of the trade`,
+    [TENSOR_AMM_ERROR__PRICE_LADDER_EXHAUSTED]: `price offset is outside of the price ladder`,
     [TENSOR_AMM_ERROR__PRICE_MISMATCH]: `specified price not within current price`,
+    [TENSOR_AMM_ERROR__STALE_COSIGNER_NONCE]: `cosigner attestation nonce was already used`,
     [TENSOR_AMM_ERROR__STARTING_PRICE_TOO_SMALL]: `starting price can't be smaller than 1 lamport`,
+    [TENSOR_AMM_ERROR__TRAIT_REQUIREMENTS_NOT_MET]: `asset does not have the traits required by the pool`,
     [TENSOR_AMM_ERROR__WRONG_COSIGNER]: `wrong cosigner`,
+    [TENSOR_AMM_ERROR__WRONG_CURVE_TYPE]: `operation not permitted on this curve type`,
     [TENSOR_AMM_ERROR__WRONG_MAKER_BROKER]: `Wrong maker broker account`,
+    [TENSOR_AMM_ERROR__WRONG_MANAGER]: `Wrong manager`,
+    [TENSOR_AMM_ERROR__WRONG_NFT_RECEIPT]: `Wrong NFT deposit receipt`,
     [TENSOR_AMM_ERROR__WRONG_OWNER]: `Wrong owner`,
     [TENSOR_AMM_ERROR__WRONG_POOL_TYPE]: `operation not permitted on this pool type`,
     [TENSOR_AMM_ERROR__WRONG_POOL_VERSION]: `wrong pool version provided`,
@@ -103,6 +103,20 @@ export type BuyNftInstruction<
   TAccountNativeProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountOwnerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountFeeVaultCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountMakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountCurrencyAssociatedTokenProgram extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAttestationSysvar extends string | IAccountMeta<string> = string,
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountMetadata extends string | IAccountMeta<string> = string,
   TAccountEdition extends string | IAccountMeta<string> = string,
@@ -175,6 +189,42 @@ export type BuyNftInstruction<
       TAccountNativeProgram extends string
         ? ReadonlyAccount<TAccountNativeProgram>
         : TAccountNativeProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountTakerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerCurrencyTa>
+        : TAccountTakerCurrencyTa,
+      TAccountOwnerCurrencyTa extends string
+        ? WritableAccount<TAccountOwnerCurrencyTa>
+        : TAccountOwnerCurrencyTa,
+      TAccountFeeVaultCurrencyTa extends string
+        ? WritableAccount<TAccountFeeVaultCurrencyTa>
+        : TAccountFeeVaultCurrencyTa,
+      TAccountMakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountMakerBrokerCurrencyTa>
+        : TAccountMakerBrokerCurrencyTa,
+      TAccountTakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerBrokerCurrencyTa>
+        : TAccountTakerBrokerCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountCurrencyAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyAssociatedTokenProgram>
+        : TAccountCurrencyAssociatedTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAttestationSysvar extends string
+        ? ReadonlyAccount<TAccountAttestationSysvar>
+        : TAccountAttestationSysvar,
+      TAccountTraitRequirements extends string
+        ? WritableAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -293,6 +343,18 @@ export type BuyNftAsyncInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -340,13 +402,46 @@ export type BuyNftAsyncInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -399,6 +494,18 @@ export async function getBuyNftInstructionAsync<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -431,6 +538,18 @@ export async function getBuyNftInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -465,6 +584,18 @@ export async function getBuyNftInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -501,6 +632,39 @@ export async function getBuyNftInstructionAsync<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -665,6 +829,18 @@ export async function getBuyNftInstructionAsync<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -702,6 +878,18 @@ export async function getBuyNftInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -737,6 +925,18 @@ export type BuyNftInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -784,13 +984,46 @@ export type BuyNftInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -843,6 +1076,18 @@ export function getBuyNftInstruction<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -875,6 +1120,18 @@ export function getBuyNftInstruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -908,6 +1165,18 @@ export function getBuyNftInstruction<
   TAccountAmmProgram,
   TAccountEscrowProgram,
   TAccountNativeProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountTakerCurrencyTa,
+  TAccountOwnerCurrencyTa,
+  TAccountFeeVaultCurrencyTa,
+  TAccountMakerBrokerCurrencyTa,
+  TAccountTakerBrokerCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountCurrencyAssociatedTokenProgram,
+  TAccountPriceLadder,
+  TAccountAttestationSysvar,
+  TAccountTraitRequirements,
   TAccountMint,
   TAccountMetadata,
   TAccountEdition,
@@ -943,6 +1212,39 @@ export function getBuyNftInstruction<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -1059,6 +1361,18 @@ export function getBuyNftInstruction<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -1096,6 +1410,18 @@ export function getBuyNftInstruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -1156,43 +1482,79 @@ export type ParsedBuyNftInstruction<
     makerBroker?: TAccountMetas[8] | undefined;
     /** The account that receives the taker broker fee. */
     takerBroker?: TAccountMetas[9] | undefined;
-    /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+    /**
+     * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+     * trade carries an attestation signed by the cosigner.
+     */
+
     cosigner?: TAccountMetas[10] | undefined;
     /** The AMM program account, used for self-cpi logging. */
     ammProgram: TAccountMetas[11];
     /** The escrow program account for shared liquidity pools. */
     escrowProgram?: TAccountMetas[12] | undefined;
     nativeProgram: TAccountMetas[13];
+    /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+    currency?: TAccountMetas[14] | undefined;
+    /** The pool's currency token account, which escrows the pool's funds. */
+    poolCurrencyTa?: TAccountMetas[15] | undefined;
+    /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+    takerCurrencyTa?: TAccountMetas[16] | undefined;
+    /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+    ownerCurrencyTa?: TAccountMetas[17] | undefined;
+    /** The fee vault's currency token account. */
+    feeVaultCurrencyTa?: TAccountMetas[18] | undefined;
+    /** The maker broker's currency token account. */
+    makerBrokerCurrencyTa?: TAccountMetas[19] | undefined;
+    /** The taker broker's currency token account. */
+    takerBrokerCurrencyTa?: TAccountMetas[20] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[21] | undefined;
+    /** The SPL associated token program, used to create currency token accounts. */
+    currencyAssociatedTokenProgram?: TAccountMetas[22] | undefined;
+    /** The price ladder of the pool; required if the pool uses a custom curve. */
+    priceLadder?: TAccountMetas[23] | undefined;
+    /**
+     * The sysvar instructions account; required to verify cosigner attestations when the
+     * cosigner does not sign the transaction.
+     */
+
+    attestationSysvar?: TAccountMetas[24] | undefined;
+    /**
+     * The traits required by the pool; required for sells if the pool has trait requirements,
+     * and closed along with the pool if it is auto-closed.
+     */
+
+    traitRequirements?: TAccountMetas[25] | undefined;
     /** The mint account of the NFT. */
-    mint: TAccountMetas[14];
+    mint: TAccountMetas[26];
     /** The Token Metadata metadata account of the NFT. */
-    metadata: TAccountMetas[15];
+    metadata: TAccountMetas[27];
     /** The Token Metadata edition account of the NFT. */
-    edition: TAccountMetas[16];
+    edition: TAccountMetas[28];
     /** The Token Metadata source token record account of the NFT. */
-    userTokenRecord?: TAccountMetas[17] | undefined;
+    userTokenRecord?: TAccountMetas[29] | undefined;
     /** The Token Metadata token record for the destination. */
-    poolTokenRecord?: TAccountMetas[18] | undefined;
+    poolTokenRecord?: TAccountMetas[30] | undefined;
     /** The Token Metadata program account. */
-    tokenMetadataProgram?: TAccountMetas[19] | undefined;
+    tokenMetadataProgram?: TAccountMetas[31] | undefined;
     /** The sysvar instructions account. */
-    sysvarInstructions?: TAccountMetas[20] | undefined;
+    sysvarInstructions?: TAccountMetas[32] | undefined;
     /** The Metaplex Token Authority Rules account that stores royalty enforcement rules. */
-    authorizationRules?: TAccountMetas[21] | undefined;
+    authorizationRules?: TAccountMetas[33] | undefined;
     /** The Metaplex Token Authority Rules program account. */
-    authorizationRulesProgram?: TAccountMetas[22] | undefined;
+    authorizationRulesProgram?: TAccountMetas[34] | undefined;
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[23];
+    nftReceipt: TAccountMetas[35];
     /** The TA of the buyer, where the NFT will be transferred. */
-    takerTa: TAccountMetas[24];
+    takerTa: TAccountMetas[36];
     /** The TA of the pool, where the NFT is held. */
-    poolTa: TAccountMetas[25];
+    poolTa: TAccountMetas[37];
     /** Either the legacy token program or token-2022. */
-    tokenProgram: TAccountMetas[26];
+    tokenProgram: TAccountMetas[38];
     /** The SPL associated token program. */
-    associatedTokenProgram: TAccountMetas[27];
+    associatedTokenProgram: TAccountMetas[39];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[28];
+    systemProgram: TAccountMetas[40];
   };
   data: BuyNftInstructionData;
 };
@@ -1205,7 +1567,7 @@ export function parseBuyNftInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBuyNftInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 29) {
+  if (instruction.accounts.length < 41) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -1238,6 +1600,18 @@ export function parseBuyNftInstruction<
       ammProgram: getNextAccount(),
       escrowProgram: getNextOptionalAccount(),
       nativeProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      takerCurrencyTa: getNextOptionalAccount(),
+      ownerCurrencyTa: getNextOptionalAccount(),
+      feeVaultCurrencyTa: getNextOptionalAccount(),
+      makerBrokerCurrencyTa: getNextOptionalAccount(),
+      takerBrokerCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      currencyAssociatedTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      attestationSysvar: getNextOptionalAccount(),
+      traitRequirements: getNextOptionalAccount(),
       mint: getNextAccount(),
       metadata: getNextAccount(),
       edition: getNextAccount(),
@@ -75,6 +75,20 @@ export type BuyNftCoreInstruction<
   TAccountNativeProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountOwnerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountFeeVaultCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountMakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountCurrencyAssociatedTokenProgram extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAttestationSysvar extends string | IAccountMeta<string> = string,
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountAsset extends string | IAccountMeta<string> = string,
   TAccountCollection extends string | IAccountMeta<string> = string,
   TAccountMplCoreProgram extends
@@ -133,6 +147,42 @@ export type BuyNftCoreInstruction<
       TAccountNativeProgram extends string
         ? ReadonlyAccount<TAccountNativeProgram>
         : TAccountNativeProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountTakerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerCurrencyTa>
+        : TAccountTakerCurrencyTa,
+      TAccountOwnerCurrencyTa extends string
+        ? WritableAccount<TAccountOwnerCurrencyTa>
+        : TAccountOwnerCurrencyTa,
+      TAccountFeeVaultCurrencyTa extends string
+        ? WritableAccount<TAccountFeeVaultCurrencyTa>
+        : TAccountFeeVaultCurrencyTa,
+      TAccountMakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountMakerBrokerCurrencyTa>
+        : TAccountMakerBrokerCurrencyTa,
+      TAccountTakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerBrokerCurrencyTa>
+        : TAccountTakerBrokerCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountCurrencyAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyAssociatedTokenProgram>
+        : TAccountCurrencyAssociatedTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAttestationSysvar extends string
+        ? ReadonlyAccount<TAccountAttestationSysvar>
+        : TAccountAttestationSysvar,
+      TAccountTraitRequirements extends string
+        ? WritableAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountAsset extends string
         ? WritableAccount<TAccountAsset>
         : TAccountAsset,
@@ -201,6 +251,18 @@ export type BuyNftCoreAsyncInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -238,13 +300,46 @@ export type BuyNftCoreAsyncInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The MPL core asset account. */
   asset: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
@@ -273,6 +368,18 @@ export async function getBuyNftCoreInstructionAsync<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
@@ -295,6 +402,18 @@ export async function getBuyNftCoreInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -319,6 +438,18 @@ export async function getBuyNftCoreInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -345,6 +476,39 @@ export async function getBuyNftCoreInstructionAsync<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -423,6 +587,18 @@ export async function getBuyNftCoreInstructionAsync<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
@@ -450,6 +626,18 @@ export async function getBuyNftCoreInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -475,6 +663,18 @@ export type BuyNftCoreInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -512,13 +712,46 @@ export type BuyNftCoreInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The MPL core asset account. */
   asset: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
@@ -547,6 +780,18 @@ export function getBuyNftCoreInstruction<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
@@ -569,6 +814,18 @@ export function getBuyNftCoreInstruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -592,6 +849,18 @@ export function getBuyNftCoreInstruction<
   TAccountAmmProgram,
   TAccountEscrowProgram,
   TAccountNativeProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountTakerCurrencyTa,
+  TAccountOwnerCurrencyTa,
+  TAccountFeeVaultCurrencyTa,
+  TAccountMakerBrokerCurrencyTa,
+  TAccountTakerBrokerCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountCurrencyAssociatedTokenProgram,
+  TAccountPriceLadder,
+  TAccountAttestationSysvar,
+  TAccountTraitRequirements,
   TAccountAsset,
   TAccountCollection,
   TAccountMplCoreProgram,
@@ -617,6 +886,39 @@ export function getBuyNftCoreInstruction<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -683,6 +985,18 @@ export function getBuyNftCoreInstruction<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
@@ -710,6 +1024,18 @@ export function getBuyNftCoreInstruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -760,22 +1086,58 @@ export type ParsedBuyNftCoreInstruction<
     makerBroker?: TAccountMetas[8] | undefined;
     /** The account that receives the taker broker fee. */
     takerBroker?: TAccountMetas[9] | undefined;
-    /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+    /**
+     * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+     * trade carries an attestation signed by the cosigner.
+     */
+
     cosigner?: TAccountMetas[10] | undefined;
     /** The AMM program account, used for self-cpi logging. */
     ammProgram: TAccountMetas[11];
     /** The escrow program account for shared liquidity pools. */
     escrowProgram?: TAccountMetas[12] | undefined;
     nativeProgram: TAccountMetas[13];
+    /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+    currency?: TAccountMetas[14] | undefined;
+    /** The pool's currency token account, which escrows the pool's funds. */
+    poolCurrencyTa?: TAccountMetas[15] | undefined;
+    /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+    takerCurrencyTa?: TAccountMetas[16] | undefined;
+    /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+    ownerCurrencyTa?: TAccountMetas[17] | undefined;
+    /** The fee vault's currency token account. */
+    feeVaultCurrencyTa?: TAccountMetas[18] | undefined;
+    /** The maker broker's currency token account. */
+    makerBrokerCurrencyTa?: TAccountMetas[19] | undefined;
+    /** The taker broker's currency token account. */
+    takerBrokerCurrencyTa?: TAccountMetas[20] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[21] | undefined;
+    /** The SPL associated token program, used to create currency token accounts. */
+    currencyAssociatedTokenProgram?: TAccountMetas[22] | undefined;
+    /** The price ladder of the pool; required if the pool uses a custom curve. */
+    priceLadder?: TAccountMetas[23] | undefined;
+    /**
+     * The sysvar instructions account; required to verify cosigner attestations when the
+     * cosigner does not sign the transaction.
+     */
+
+    attestationSysvar?: TAccountMetas[24] | undefined;
+    /**
+     * The traits required by the pool; required for sells if the pool has trait requirements,
+     * and closed along with the pool if it is auto-closed.
+     */
+
+    traitRequirements?: TAccountMetas[25] | undefined;
     /** The MPL core asset account. */
-    asset: TAccountMetas[14];
-    collection?: TAccountMetas[15] | undefined;
+    asset: TAccountMetas[26];
+    collection?: TAccountMetas[27] | undefined;
     /** The MPL Core program. */
-    mplCoreProgram: TAccountMetas[16];
+    mplCoreProgram: TAccountMetas[28];
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[17];
+    nftReceipt: TAccountMetas[29];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[18];
+    systemProgram: TAccountMetas[30];
   };
   data: BuyNftCoreInstructionData;
 };
@@ -788,7 +1150,7 @@ export function parseBuyNftCoreInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBuyNftCoreInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 19) {
+  if (instruction.accounts.length < 31) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -821,6 +1183,18 @@ export function parseBuyNftCoreInstruction<
       ammProgram: getNextAccount(),
       escrowProgram: getNextOptionalAccount(),
       nativeProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      takerCurrencyTa: getNextOptionalAccount(),
+      ownerCurrencyTa: getNextOptionalAccount(),
+      feeVaultCurrencyTa: getNextOptionalAccount(),
+      makerBrokerCurrencyTa: getNextOptionalAccount(),
+      takerBrokerCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      currencyAssociatedTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      attestationSysvar: getNextOptionalAccount(),
+      traitRequirements: getNextOptionalAccount(),
       asset: getNextAccount(),
       collection: getNextOptionalAccount(),
       mplCoreProgram: getNextAccount(),
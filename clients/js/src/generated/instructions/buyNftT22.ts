@@ -76,6 +76,20 @@ export type BuyNftT22Instruction<
   TAccountNativeProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountOwnerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountFeeVaultCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountMakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountCurrencyAssociatedTokenProgram extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAttestationSysvar extends string | IAccountMeta<string> = string,
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountNftReceipt extends string | IAccountMeta<string> = string,
   TAccountTakerTa extends string | IAccountMeta<string> = string,
@@ -138,6 +152,42 @@ export type BuyNftT22Instruction<
       TAccountNativeProgram extends string
         ? ReadonlyAccount<TAccountNativeProgram>
         : TAccountNativeProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountTakerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerCurrencyTa>
+        : TAccountTakerCurrencyTa,
+      TAccountOwnerCurrencyTa extends string
+        ? WritableAccount<TAccountOwnerCurrencyTa>
+        : TAccountOwnerCurrencyTa,
+      TAccountFeeVaultCurrencyTa extends string
+        ? WritableAccount<TAccountFeeVaultCurrencyTa>
+        : TAccountFeeVaultCurrencyTa,
+      TAccountMakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountMakerBrokerCurrencyTa>
+        : TAccountMakerBrokerCurrencyTa,
+      TAccountTakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerBrokerCurrencyTa>
+        : TAccountTakerBrokerCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountCurrencyAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyAssociatedTokenProgram>
+        : TAccountCurrencyAssociatedTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAttestationSysvar extends string
+        ? ReadonlyAccount<TAccountAttestationSysvar>
+        : TAccountAttestationSysvar,
+      TAccountTraitRequirements extends string
+        ? WritableAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -212,6 +262,18 @@ export type BuyNftT22AsyncInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountNftReceipt extends string = string,
   TAccountTakerTa extends string = string,
@@ -251,13 +313,46 @@ export type BuyNftT22AsyncInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
@@ -292,6 +387,18 @@ export async function getBuyNftT22InstructionAsync<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountNftReceipt extends string,
   TAccountTakerTa extends string,
@@ -316,6 +423,18 @@ export async function getBuyNftT22InstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountTakerTa,
@@ -342,6 +461,18 @@ export async function getBuyNftT22InstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountTakerTa,
@@ -370,6 +501,39 @@ export async function getBuyNftT22InstructionAsync<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     nftReceipt: { value: input.nftReceipt ?? null, isWritable: true },
     takerTa: { value: input.takerTa ?? null, isWritable: true },
@@ -476,6 +640,18 @@ export async function getBuyNftT22InstructionAsync<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.nftReceipt),
       getAccountMeta(accounts.takerTa),
@@ -505,6 +681,18 @@ export async function getBuyNftT22InstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountTakerTa,
@@ -532,6 +720,18 @@ export type BuyNftT22Input<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountNftReceipt extends string = string,
   TAccountTakerTa extends string = string,
@@ -571,13 +771,46 @@ export type BuyNftT22Input<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
@@ -612,6 +845,18 @@ export function getBuyNftT22Instruction<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountNftReceipt extends string,
   TAccountTakerTa extends string,
@@ -636,6 +881,18 @@ export function getBuyNftT22Instruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountTakerTa,
@@ -661,6 +918,18 @@ export function getBuyNftT22Instruction<
   TAccountAmmProgram,
   TAccountEscrowProgram,
   TAccountNativeProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountTakerCurrencyTa,
+  TAccountOwnerCurrencyTa,
+  TAccountFeeVaultCurrencyTa,
+  TAccountMakerBrokerCurrencyTa,
+  TAccountTakerBrokerCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountCurrencyAssociatedTokenProgram,
+  TAccountPriceLadder,
+  TAccountAttestationSysvar,
+  TAccountTraitRequirements,
   TAccountMint,
   TAccountNftReceipt,
   TAccountTakerTa,
@@ -688,6 +957,39 @@ export function getBuyNftT22Instruction<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     nftReceipt: { value: input.nftReceipt ?? null, isWritable: true },
     takerTa: { value: input.takerTa ?? null, isWritable: true },
@@ -770,6 +1072,18 @@ export function getBuyNftT22Instruction<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.nftReceipt),
       getAccountMeta(accounts.takerTa),
@@ -799,6 +1113,18 @@ export function getBuyNftT22Instruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountTakerTa,
@@ -851,27 +1177,63 @@ export type ParsedBuyNftT22Instruction<
     makerBroker?: TAccountMetas[8] | undefined;
     /** The account that receives the taker broker fee. */
     takerBroker?: TAccountMetas[9] | undefined;
-    /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+    /**
+     * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+     * trade carries an attestation signed by the cosigner.
+     */
+
     cosigner?: TAccountMetas[10] | undefined;
     /** The AMM program account, used for self-cpi logging. */
     ammProgram: TAccountMetas[11];
     /** The escrow program account for shared liquidity pools. */
     escrowProgram?: TAccountMetas[12] | undefined;
     nativeProgram: TAccountMetas[13];
+    /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+    currency?: TAccountMetas[14] | undefined;
+    /** The pool's currency token account, which escrows the pool's funds. */
+    poolCurrencyTa?: TAccountMetas[15] | undefined;
+    /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+    takerCurrencyTa?: TAccountMetas[16] | undefined;
+    /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+    ownerCurrencyTa?: TAccountMetas[17] | undefined;
+    /** The fee vault's currency token account. */
+    feeVaultCurrencyTa?: TAccountMetas[18] | undefined;
+    /** The maker broker's currency token account. */
+    makerBrokerCurrencyTa?: TAccountMetas[19] | undefined;
+    /** The taker broker's currency token account. */
+    takerBrokerCurrencyTa?: TAccountMetas[20] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[21] | undefined;
+    /** The SPL associated token program, used to create currency token accounts. */
+    currencyAssociatedTokenProgram?: TAccountMetas[22] | undefined;
+    /** The price ladder of the pool; required if the pool uses a custom curve. */
+    priceLadder?: TAccountMetas[23] | undefined;
+    /**
+     * The sysvar instructions account; required to verify cosigner attestations when the
+     * cosigner does not sign the transaction.
+     */
+
+    attestationSysvar?: TAccountMetas[24] | undefined;
+    /**
+     * The traits required by the pool; required for sells if the pool has trait requirements,
+     * and closed along with the pool if it is auto-closed.
+     */
+
+    traitRequirements?: TAccountMetas[25] | undefined;
     /** The mint account of the NFT. */
-    mint: TAccountMetas[14];
+    mint: TAccountMetas[26];
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[15];
+    nftReceipt: TAccountMetas[27];
     /** The TA of the buyer, where the NFT will be transferred. */
-    takerTa: TAccountMetas[16];
+    takerTa: TAccountMetas[28];
     /** The TA of the pool, where the NFT will be escrowed. */
-    poolTa: TAccountMetas[17];
+    poolTa: TAccountMetas[29];
     /** The SPL Token program for the Mint and ATAs. */
-    tokenProgram: TAccountMetas[18];
+    tokenProgram: TAccountMetas[30];
     /** The SPL associated token program. */
-    associatedTokenProgram: TAccountMetas[19];
+    associatedTokenProgram: TAccountMetas[31];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[20];
+    systemProgram: TAccountMetas[32];
   };
   data: BuyNftT22InstructionData;
 };
@@ -884,7 +1246,7 @@ export function parseBuyNftT22Instruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBuyNftT22Instruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 21) {
+  if (instruction.accounts.length < 33) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -917,6 +1279,18 @@ export function parseBuyNftT22Instruction<
       ammProgram: getNextAccount(),
       escrowProgram: getNextOptionalAccount(),
       nativeProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      takerCurrencyTa: getNextOptionalAccount(),
+      ownerCurrencyTa: getNextOptionalAccount(),
+      feeVaultCurrencyTa: getNextOptionalAccount(),
+      makerBrokerCurrencyTa: getNextOptionalAccount(),
+      takerBrokerCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      currencyAssociatedTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      attestationSysvar: getNextOptionalAccount(),
+      traitRequirements: getNextOptionalAccount(),
       mint: getNextAccount(),
       nftReceipt: getNextAccount(),
       takerTa: getNextAccount(),
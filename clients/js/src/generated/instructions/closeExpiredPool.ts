@@ -20,12 +20,15 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { TENSOR_AMM_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -52,6 +55,16 @@ export type CloseExpiredPoolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountOwnerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
+  TAccountKeeper extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -69,6 +82,31 @@ export type CloseExpiredPoolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountOwnerCurrencyTa extends string
+        ? WritableAccount<TAccountOwnerCurrencyTa>
+        : TAccountOwnerCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? WritableAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
+      TAccountKeeper extends string
+        ? WritableSignerAccount<TAccountKeeper> &
+            IAccountSignerMeta<TAccountKeeper>
+        : TAccountKeeper,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,6 +145,14 @@ export type CloseExpiredPoolInput<
   TAccountOwner extends string = string,
   TAccountPool extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
+  TAccountKeeper extends string = string,
 > = {
   /** The rent payer to refund pool rent to. */
   rentPayer?: Address<TAccountRentPayer>;
@@ -119,6 +165,28 @@ export type CloseExpiredPoolInput<
   pool: Address<TAccountPool>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The SPL mint the pool is priced in; required to close SPL currency pools. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, closed along with the pool. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The owner's currency token account, receiving any funds left in the pool. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The price ladder of the pool, closed along with the pool; required for custom curves. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /**
+   * The trait requirements of the pool, closed along with the pool; required if the pool
+   * has trait requirements.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
+  /**
+   * The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+   * bounty is refunded to the rent payer.
+   */
+  keeper?: TransactionSigner<TAccountKeeper>;
 };
 
 export function getCloseExpiredPoolInstruction<
@@ -126,13 +194,29 @@ export function getCloseExpiredPoolInstruction<
   TAccountOwner extends string,
   TAccountPool extends string,
   TAccountSystemProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
+  TAccountKeeper extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
   input: CloseExpiredPoolInput<
     TAccountRentPayer,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
+    TAccountKeeper
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseExpiredPoolInstruction<
@@ -140,7 +224,15 @@ export function getCloseExpiredPoolInstruction<
   TAccountRentPayer,
   TAccountOwner,
   TAccountPool,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountOwnerCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountPriceLadder,
+  TAccountAmmProgram,
+  TAccountTraitRequirements,
+  TAccountKeeper
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TENSOR_AMM_PROGRAM_ADDRESS;
@@ -151,6 +243,20 @@ export function getCloseExpiredPoolInstruction<
     owner: { value: input.owner ?? null, isWritable: true },
     pool: { value: input.pool ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
+    keeper: { value: input.keeper ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -165,6 +271,10 @@ export function getCloseExpiredPoolInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -173,6 +283,14 @@ export function getCloseExpiredPoolInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
+      getAccountMeta(accounts.keeper),
     ],
     programAddress,
     data: getCloseExpiredPoolInstructionDataEncoder().encode({}),
@@ -181,7 +299,15 @@ export function getCloseExpiredPoolInstruction<
     TAccountRentPayer,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
+    TAccountKeeper
   >;
 
   return instruction;
@@ -205,6 +331,30 @@ export type ParsedCloseExpiredPoolInstruction<
     pool: TAccountMetas[2];
     /** The Solana system program. */
     systemProgram: TAccountMetas[3];
+    /** The SPL mint the pool is priced in; required to close SPL currency pools. */
+    currency?: TAccountMetas[4] | undefined;
+    /** The pool's currency token account, closed along with the pool. */
+    poolCurrencyTa?: TAccountMetas[5] | undefined;
+    /** The owner's currency token account, receiving any funds left in the pool. */
+    ownerCurrencyTa?: TAccountMetas[6] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[7] | undefined;
+    /** The price ladder of the pool, closed along with the pool; required for custom curves. */
+    priceLadder?: TAccountMetas[8] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[9];
+    /**
+     * The trait requirements of the pool, closed along with the pool; required if the pool
+     * has trait requirements.
+     */
+
+    traitRequirements?: TAccountMetas[10] | undefined;
+    /**
+     * The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+     * bounty is refunded to the rent payer.
+     */
+
+    keeper?: TAccountMetas[11] | undefined;
   };
   data: CloseExpiredPoolInstructionData;
 };
@@ -217,7 +367,7 @@ export function parseCloseExpiredPoolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseExpiredPoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -227,6 +377,12 @@ export function parseCloseExpiredPoolInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === TENSOR_AMM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -234,6 +390,14 @@ export function parseCloseExpiredPoolInstruction<
       owner: getNextAccount(),
       pool: getNextAccount(),
       systemProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      ownerCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
+      keeper: getNextOptionalAccount(),
     },
     data: getCloseExpiredPoolInstructionDataDecoder().decode(instruction.data),
   };
@@ -53,6 +53,15 @@ export type ClosePoolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountOwnerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -71,6 +80,27 @@ export type ClosePoolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountOwnerCurrencyTa extends string
+        ? WritableAccount<TAccountOwnerCurrencyTa>
+        : TAccountOwnerCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? WritableAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,6 +137,13 @@ export type ClosePoolInput<
   TAccountOwner extends string = string,
   TAccountPool extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
 > = {
   /** The rent payer to refund pool rent to. */
   rentPayer?: Address<TAccountRentPayer>;
@@ -116,6 +153,23 @@ export type ClosePoolInput<
   pool: Address<TAccountPool>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The SPL mint the pool is priced in; required to close SPL currency pools. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, closed along with the pool. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The owner's currency token account, receiving any funds left in the pool. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The price ladder of the pool, closed along with the pool; required for custom curves. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /**
+   * The trait requirements of the pool, closed along with the pool; required if the pool
+   * has trait requirements.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
 };
 
 export function getClosePoolInstruction<
@@ -123,13 +177,27 @@ export function getClosePoolInstruction<
   TAccountOwner extends string,
   TAccountPool extends string,
   TAccountSystemProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
   input: ClosePoolInput<
     TAccountRentPayer,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram,
+    TAccountTraitRequirements
   >,
   config?: { programAddress?: TProgramAddress }
 ): ClosePoolInstruction<
@@ -137,7 +205,14 @@ export function getClosePoolInstruction<
   TAccountRentPayer,
   TAccountOwner,
   TAccountPool,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountOwnerCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountPriceLadder,
+  TAccountAmmProgram,
+  TAccountTraitRequirements
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TENSOR_AMM_PROGRAM_ADDRESS;
@@ -148,6 +223,19 @@ export function getClosePoolInstruction<
     owner: { value: input.owner ?? null, isWritable: true },
     pool: { value: input.pool ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -164,6 +252,10 @@ export function getClosePoolInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -172,6 +264,13 @@ export function getClosePoolInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
     ],
     programAddress,
     data: getClosePoolInstructionDataEncoder().encode({}),
@@ -180,7 +279,14 @@ export function getClosePoolInstruction<
     TAccountRentPayer,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram,
+    TAccountTraitRequirements
   >;
 
   return instruction;
@@ -200,6 +306,24 @@ export type ParsedClosePoolInstruction<
     pool: TAccountMetas[2];
     /** The Solana system program. */
     systemProgram: TAccountMetas[3];
+    /** The SPL mint the pool is priced in; required to close SPL currency pools. */
+    currency?: TAccountMetas[4] | undefined;
+    /** The pool's currency token account, closed along with the pool. */
+    poolCurrencyTa?: TAccountMetas[5] | undefined;
+    /** The owner's currency token account, receiving any funds left in the pool. */
+    ownerCurrencyTa?: TAccountMetas[6] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[7] | undefined;
+    /** The price ladder of the pool, closed along with the pool; required for custom curves. */
+    priceLadder?: TAccountMetas[8] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[9];
+    /**
+     * The trait requirements of the pool, closed along with the pool; required if the pool
+     * has trait requirements.
+     */
+
+    traitRequirements?: TAccountMetas[10] | undefined;
   };
   data: ClosePoolInstructionData;
 };
@@ -212,7 +336,7 @@ export function parseClosePoolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedClosePoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -222,6 +346,12 @@ export function parseClosePoolInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === TENSOR_AMM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -229,6 +359,13 @@ export function parseClosePoolInstruction<
       owner: getNextAccount(),
       pool: getNextAccount(),
       systemProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      ownerCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
     },
     data: getClosePoolInstructionDataDecoder().decode(instruction.data),
   };
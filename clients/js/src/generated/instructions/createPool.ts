@@ -18,6 +18,8 @@ import {
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -52,10 +54,18 @@ import {
   type ResolvedAccount,
 } from '../shared';
 import {
+  getCircuitBreakerArgsDecoder,
+  getCircuitBreakerArgsEncoder,
   getPoolConfigDecoder,
   getPoolConfigEncoder,
+  getPriceLadderArgsDecoder,
+  getPriceLadderArgsEncoder,
+  type CircuitBreakerArgs,
+  type CircuitBreakerArgsArgs,
   type PoolConfig,
   type PoolConfigArgs,
+  type PriceLadderArgs,
+  type PriceLadderArgsArgs,
 } from '../types';
 
 export const CREATE_POOL_DISCRIMINATOR = new Uint8Array([
@@ -76,6 +86,14 @@ export type CreatePoolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -101,6 +119,24 @@ export type CreatePoolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -109,21 +145,61 @@ export type CreatePoolInstructionData = {
   discriminator: ReadonlyUint8Array;
   poolId: ReadonlyUint8Array;
   config: PoolConfig;
-  currency: Option<Address>;
+  /** SPL mint the pool is priced in; `None` (or the default pubkey) for SOL. */
+  currencyMint: Option<Address>;
   cosigner: Option<Address>;
   makerBroker: Option<Address>;
   maxTakerSellCount: Option<number>;
   expireInSec: Option<bigint>;
+  /** Decay rate of GDA curves, in basis points per decay period. */
+  decayBps: Option<number>;
+  /** Price GDA curves stop decaying at. */
+  floorPrice: Option<bigint>;
+  /** Prices of custom curves. */
+  priceLadderArgs: Option<PriceLadderArgs>;
+  /** Key allowed to edit the pricing and expiry of the pool on behalf of the owner. */
+  manager: Option<Address>;
+  /** Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it. */
+  maxTakerBuyCount: Option<number>;
+  /** Limit how many NFTs the pool can hold through sells and deposits. */
+  maxNftsHeld: Option<number>;
+  /** Limit how much the pool can pay for NFTs sold into it over its lifetime. */
+  maxTotalSpend: Option<bigint>;
+  /** Limits on the trades the pool can make within a time window. */
+  circuitBreaker: Option<CircuitBreakerArgs>;
+  /**
+   * Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+   * the pool once it expires.
+   */
+  keeperBounty: Option<bigint>;
 };
 
 export type CreatePoolInstructionDataArgs = {
   poolId: ReadonlyUint8Array;
-  config: PoolConfigArgs;
-  currency?: OptionOrNullable<Address>;
+  config: PoolConfigArgs /** SPL mint the pool is priced in; `None` (or the default pubkey) for SOL. */;
+  currencyMint?: OptionOrNullable<Address>;
   cosigner?: OptionOrNullable<Address>;
   makerBroker?: OptionOrNullable<Address>;
   maxTakerSellCount?: OptionOrNullable<number>;
-  expireInSec?: OptionOrNullable<number | bigint>;
+  expireInSec?: OptionOrNullable<
+    number | bigint
+  > /** Decay rate of GDA curves, in basis points per decay period. */;
+  decayBps?: OptionOrNullable<number> /** Price GDA curves stop decaying at. */;
+  floorPrice?: OptionOrNullable<
+    number | bigint
+  > /** Prices of custom curves. */;
+  priceLadderArgs?: OptionOrNullable<PriceLadderArgsArgs> /** Key allowed to edit the pricing and expiry of the pool on behalf of the owner. */;
+  manager?: OptionOrNullable<Address> /** Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it. */;
+  maxTakerBuyCount?: OptionOrNullable<number> /** Limit how many NFTs the pool can hold through sells and deposits. */;
+  maxNftsHeld?: OptionOrNullable<number> /** Limit how much the pool can pay for NFTs sold into it over its lifetime. */;
+  maxTotalSpend?: OptionOrNullable<
+    number | bigint
+  > /** Limits on the trades the pool can make within a time window. */;
+  circuitBreaker?: OptionOrNullable<CircuitBreakerArgsArgs> /**
+   * Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+   * the pool once it expires.
+   */;
+  keeperBounty?: OptionOrNullable<number | bigint>;
 };
 
 export function getCreatePoolInstructionDataEncoder(): Encoder<CreatePoolInstructionDataArgs> {
@@ -132,20 +208,38 @@ export function getCreatePoolInstructionDataEncoder(): Encoder<CreatePoolInstruc
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['poolId', fixEncoderSize(getBytesEncoder(), 32)],
       ['config', getPoolConfigEncoder()],
-      ['currency', getOptionEncoder(getAddressEncoder())],
+      ['currencyMint', getOptionEncoder(getAddressEncoder())],
       ['cosigner', getOptionEncoder(getAddressEncoder())],
       ['makerBroker', getOptionEncoder(getAddressEncoder())],
       ['maxTakerSellCount', getOptionEncoder(getU32Encoder())],
       ['expireInSec', getOptionEncoder(getU64Encoder())],
+      ['decayBps', getOptionEncoder(getU16Encoder())],
+      ['floorPrice', getOptionEncoder(getU64Encoder())],
+      ['priceLadderArgs', getOptionEncoder(getPriceLadderArgsEncoder())],
+      ['manager', getOptionEncoder(getAddressEncoder())],
+      ['maxTakerBuyCount', getOptionEncoder(getU32Encoder())],
+      ['maxNftsHeld', getOptionEncoder(getU32Encoder())],
+      ['maxTotalSpend', getOptionEncoder(getU64Encoder())],
+      ['circuitBreaker', getOptionEncoder(getCircuitBreakerArgsEncoder())],
+      ['keeperBounty', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: CREATE_POOL_DISCRIMINATOR,
-      currency: value.currency ?? none(),
+      currencyMint: value.currencyMint ?? none(),
       cosigner: value.cosigner ?? none(),
       makerBroker: value.makerBroker ?? none(),
       maxTakerSellCount: value.maxTakerSellCount ?? none(),
       expireInSec: value.expireInSec ?? none(),
+      decayBps: value.decayBps ?? none(),
+      floorPrice: value.floorPrice ?? none(),
+      priceLadderArgs: value.priceLadderArgs ?? none(),
+      manager: value.manager ?? none(),
+      maxTakerBuyCount: value.maxTakerBuyCount ?? none(),
+      maxNftsHeld: value.maxNftsHeld ?? none(),
+      maxTotalSpend: value.maxTotalSpend ?? none(),
+      circuitBreaker: value.circuitBreaker ?? none(),
+      keeperBounty: value.keeperBounty ?? none(),
     })
   );
 }
@@ -155,11 +249,20 @@ export function getCreatePoolInstructionDataDecoder(): Decoder<CreatePoolInstruc
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['poolId', fixDecoderSize(getBytesDecoder(), 32)],
     ['config', getPoolConfigDecoder()],
-    ['currency', getOptionDecoder(getAddressDecoder())],
+    ['currencyMint', getOptionDecoder(getAddressDecoder())],
     ['cosigner', getOptionDecoder(getAddressDecoder())],
     ['makerBroker', getOptionDecoder(getAddressDecoder())],
     ['maxTakerSellCount', getOptionDecoder(getU32Decoder())],
     ['expireInSec', getOptionDecoder(getU64Decoder())],
+    ['decayBps', getOptionDecoder(getU16Decoder())],
+    ['floorPrice', getOptionDecoder(getU64Decoder())],
+    ['priceLadderArgs', getOptionDecoder(getPriceLadderArgsDecoder())],
+    ['manager', getOptionDecoder(getAddressDecoder())],
+    ['maxTakerBuyCount', getOptionDecoder(getU32Decoder())],
+    ['maxNftsHeld', getOptionDecoder(getU32Decoder())],
+    ['maxTotalSpend', getOptionDecoder(getU64Decoder())],
+    ['circuitBreaker', getOptionDecoder(getCircuitBreakerArgsDecoder())],
+    ['keeperBounty', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -180,6 +283,12 @@ export type CreatePoolAsyncInput<
   TAccountWhitelist extends string = string,
   TAccountSharedEscrow extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAmmProgram extends string = string,
 > = {
   /**
    * The account that pays for the rent to open the pool. This will be stored on the pool
@@ -195,13 +304,47 @@ export type CreatePoolAsyncInput<
   sharedEscrow?: Address<TAccountSharedEscrow>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The SPL mint the pool is priced in; required if `args.currency` is set. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds for SPL currency pools. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program. */
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
   poolId?: CreatePoolInstructionDataArgs['poolId'];
   config: CreatePoolInstructionDataArgs['config'];
-  currency?: CreatePoolInstructionDataArgs['currency'];
+  /** SPL mint the pool is priced in; `None` (or the default pubkey) for SOL. */
+  currencyMint?: CreatePoolInstructionDataArgs['currencyMint'];
   cosigner?: CreatePoolInstructionDataArgs['cosigner'];
   makerBroker?: CreatePoolInstructionDataArgs['makerBroker'];
   maxTakerSellCount?: CreatePoolInstructionDataArgs['maxTakerSellCount'];
   expireInSec?: CreatePoolInstructionDataArgs['expireInSec'];
+  /** Decay rate of GDA curves, in basis points per decay period. */
+  decayBps?: CreatePoolInstructionDataArgs['decayBps'];
+  /** Price GDA curves stop decaying at. */
+  floorPrice?: CreatePoolInstructionDataArgs['floorPrice'];
+  /** Prices of custom curves. */
+  priceLadderArgs?: CreatePoolInstructionDataArgs['priceLadderArgs'];
+  /** Key allowed to edit the pricing and expiry of the pool on behalf of the owner. */
+  manager?: CreatePoolInstructionDataArgs['manager'];
+  /** Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it. */
+  maxTakerBuyCount?: CreatePoolInstructionDataArgs['maxTakerBuyCount'];
+  /** Limit how many NFTs the pool can hold through sells and deposits. */
+  maxNftsHeld?: CreatePoolInstructionDataArgs['maxNftsHeld'];
+  /** Limit how much the pool can pay for NFTs sold into it over its lifetime. */
+  maxTotalSpend?: CreatePoolInstructionDataArgs['maxTotalSpend'];
+  /** Limits on the trades the pool can make within a time window. */
+  circuitBreaker?: CreatePoolInstructionDataArgs['circuitBreaker'];
+  /**
+   * Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+   * the pool once it expires.
+   */
+  keeperBounty?: CreatePoolInstructionDataArgs['keeperBounty'];
 };
 
 export async function getCreatePoolInstructionAsync<
@@ -211,6 +354,12 @@ export async function getCreatePoolInstructionAsync<
   TAccountWhitelist extends string,
   TAccountSharedEscrow extends string,
   TAccountSystemProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAmmProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
   input: CreatePoolAsyncInput<
@@ -219,7 +368,13 @@ export async function getCreatePoolInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountSharedEscrow,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -230,7 +385,13 @@ export async function getCreatePoolInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountSharedEscrow,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram
   >
 > {
   // Program address.
@@ -244,6 +405,18 @@ export async function getCreatePoolInstructionAsync<
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     sharedEscrow: { value: input.sharedEscrow ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -273,6 +446,10 @@ export async function getCreatePoolInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -283,6 +460,12 @@ export async function getCreatePoolInstructionAsync<
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.sharedEscrow),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.ammProgram),
     ],
     programAddress,
     data: getCreatePoolInstructionDataEncoder().encode(
@@ -295,7 +478,13 @@ export async function getCreatePoolInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountSharedEscrow,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram
   >;
 
   return instruction;
@@ -308,6 +497,12 @@ export type CreatePoolInput<
   TAccountWhitelist extends string = string,
   TAccountSharedEscrow extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAmmProgram extends string = string,
 > = {
   /**
    * The account that pays for the rent to open the pool. This will be stored on the pool
@@ -323,13 +518,47 @@ export type CreatePoolInput<
   sharedEscrow?: Address<TAccountSharedEscrow>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The SPL mint the pool is priced in; required if `args.currency` is set. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds for SPL currency pools. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program. */
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
   poolId?: CreatePoolInstructionDataArgs['poolId'];
   config: CreatePoolInstructionDataArgs['config'];
-  currency?: CreatePoolInstructionDataArgs['currency'];
+  /** SPL mint the pool is priced in; `None` (or the default pubkey) for SOL. */
+  currencyMint?: CreatePoolInstructionDataArgs['currencyMint'];
   cosigner?: CreatePoolInstructionDataArgs['cosigner'];
   makerBroker?: CreatePoolInstructionDataArgs['makerBroker'];
   maxTakerSellCount?: CreatePoolInstructionDataArgs['maxTakerSellCount'];
   expireInSec?: CreatePoolInstructionDataArgs['expireInSec'];
+  /** Decay rate of GDA curves, in basis points per decay period. */
+  decayBps?: CreatePoolInstructionDataArgs['decayBps'];
+  /** Price GDA curves stop decaying at. */
+  floorPrice?: CreatePoolInstructionDataArgs['floorPrice'];
+  /** Prices of custom curves. */
+  priceLadderArgs?: CreatePoolInstructionDataArgs['priceLadderArgs'];
+  /** Key allowed to edit the pricing and expiry of the pool on behalf of the owner. */
+  manager?: CreatePoolInstructionDataArgs['manager'];
+  /** Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it. */
+  maxTakerBuyCount?: CreatePoolInstructionDataArgs['maxTakerBuyCount'];
+  /** Limit how many NFTs the pool can hold through sells and deposits. */
+  maxNftsHeld?: CreatePoolInstructionDataArgs['maxNftsHeld'];
+  /** Limit how much the pool can pay for NFTs sold into it over its lifetime. */
+  maxTotalSpend?: CreatePoolInstructionDataArgs['maxTotalSpend'];
+  /** Limits on the trades the pool can make within a time window. */
+  circuitBreaker?: CreatePoolInstructionDataArgs['circuitBreaker'];
+  /**
+   * Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+   * the pool once it expires.
+   */
+  keeperBounty?: CreatePoolInstructionDataArgs['keeperBounty'];
 };
 
 export function getCreatePoolInstruction<
@@ -339,6 +568,12 @@ export function getCreatePoolInstruction<
   TAccountWhitelist extends string,
   TAccountSharedEscrow extends string,
   TAccountSystemProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAmmProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
   input: CreatePoolInput<
@@ -347,7 +582,13 @@ export function getCreatePoolInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountSharedEscrow,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreatePoolInstruction<
@@ -357,7 +598,13 @@ export function getCreatePoolInstruction<
   TAccountPool,
   TAccountWhitelist,
   TAccountSharedEscrow,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountAssociatedTokenProgram,
+  TAccountPriceLadder,
+  TAccountAmmProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TENSOR_AMM_PROGRAM_ADDRESS;
@@ -370,6 +617,18 @@ export function getCreatePoolInstruction<
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     sharedEscrow: { value: input.sharedEscrow ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -393,6 +652,10 @@ export function getCreatePoolInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -403,6 +666,12 @@ export function getCreatePoolInstruction<
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.sharedEscrow),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.ammProgram),
     ],
     programAddress,
     data: getCreatePoolInstructionDataEncoder().encode(
@@ -415,7 +684,13 @@ export function getCreatePoolInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountSharedEscrow,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAmmProgram
   >;
 
   return instruction;
@@ -442,6 +717,18 @@ export type ParsedCreatePoolInstruction<
     sharedEscrow?: TAccountMetas[4] | undefined;
     /** The Solana system program. */
     systemProgram: TAccountMetas[5];
+    /** The SPL mint the pool is priced in; required if `args.currency` is set. */
+    currency?: TAccountMetas[6] | undefined;
+    /** The pool's currency token account, which escrows the pool's funds for SPL currency pools. */
+    poolCurrencyTa?: TAccountMetas[7] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[8] | undefined;
+    /** The SPL associated token program. */
+    associatedTokenProgram?: TAccountMetas[9] | undefined;
+    /** The price ladder of the pool; required if the pool uses a custom curve. */
+    priceLadder?: TAccountMetas[10] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[11];
   };
   data: CreatePoolInstructionData;
 };
@@ -454,7 +741,7 @@ export function parseCreatePoolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCreatePoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -479,6 +766,12 @@ export function parseCreatePoolInstruction<
       whitelist: getNextAccount(),
       sharedEscrow: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
     },
     data: getCreatePoolInstructionDataDecoder().decode(instruction.data),
   };
@@ -80,6 +80,10 @@ export type DepositNftInstruction<
   TAccountPool extends string | IAccountMeta<string> = string,
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountMintProof extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountMetadata extends string | IAccountMeta<string> = string,
   TAccountEdition extends string | IAccountMeta<string> = string,
@@ -121,6 +125,12 @@ export type DepositNftInstruction<
       TAccountMintProof extends string
         ? ReadonlyAccount<TAccountMintProof>
         : TAccountMintProof,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? ReadonlyAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -222,6 +232,8 @@ export type DepositNftAsyncInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -252,6 +264,10 @@ export type DepositNftAsyncInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -291,6 +307,8 @@ export async function getDepositNftInstructionAsync<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -313,6 +331,8 @@ export async function getDepositNftInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -337,6 +357,8 @@ export async function getDepositNftInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -363,6 +385,11 @@ export async function getDepositNftInstructionAsync<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -406,6 +433,10 @@ export async function getDepositNftInstructionAsync<
   const resolverScope = { programAddress, accounts, args };
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.metadata.value) {
     accounts.metadata = {
       ...accounts.metadata,
@@ -489,6 +520,8 @@ export async function getDepositNftInstructionAsync<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -515,6 +548,8 @@ export async function getDepositNftInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -540,6 +575,8 @@ export type DepositNftInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -570,6 +607,10 @@ export type DepositNftInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -609,6 +650,8 @@ export function getDepositNftInstruction<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -631,6 +674,8 @@ export function getDepositNftInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -654,6 +699,8 @@ export function getDepositNftInstruction<
   TAccountPool,
   TAccountWhitelist,
   TAccountMintProof,
+  TAccountAmmProgram,
+  TAccountTraitRequirements,
   TAccountMint,
   TAccountMetadata,
   TAccountEdition,
@@ -679,6 +726,11 @@ export function getDepositNftInstruction<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -722,6 +774,10 @@ export function getDepositNftInstruction<
   const resolverScope = { programAddress, accounts, args };
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -763,6 +819,8 @@ export function getDepositNftInstruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -789,6 +847,8 @@ export function getDepositNftInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -831,36 +891,40 @@ export type ParsedDepositNftInstruction<
      */
 
     mintProof?: TAccountMetas[3] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[4];
+    /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+    traitRequirements?: TAccountMetas[5] | undefined;
     /** The mint account of the NFT. */
-    mint: TAccountMetas[4];
+    mint: TAccountMetas[6];
     /** The Token Metadata metadata account of the NFT. */
-    metadata: TAccountMetas[5];
+    metadata: TAccountMetas[7];
     /** The Token Metadata edition account of the NFT. */
-    edition: TAccountMetas[6];
+    edition: TAccountMetas[8];
     /** The Token Metadata source token record account of the NFT. */
-    userTokenRecord?: TAccountMetas[7] | undefined;
+    userTokenRecord?: TAccountMetas[9] | undefined;
     /** The Token Metadata token record for the destination. */
-    poolTokenRecord?: TAccountMetas[8] | undefined;
+    poolTokenRecord?: TAccountMetas[10] | undefined;
     /** The Token Metadata program account. */
-    tokenMetadataProgram?: TAccountMetas[9] | undefined;
+    tokenMetadataProgram?: TAccountMetas[11] | undefined;
     /** The sysvar instructions account. */
-    sysvarInstructions?: TAccountMetas[10] | undefined;
+    sysvarInstructions?: TAccountMetas[12] | undefined;
     /** The Metaplex Token Authority Rules account that stores royalty enforcement rules. */
-    authorizationRules?: TAccountMetas[11] | undefined;
+    authorizationRules?: TAccountMetas[13] | undefined;
     /** The Metaplex Token Authority Rules program account. */
-    authorizationRulesProgram?: TAccountMetas[12] | undefined;
+    authorizationRulesProgram?: TAccountMetas[14] | undefined;
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[13];
+    nftReceipt: TAccountMetas[15];
     /** The token account of the owner, where the NFT will be transferred from. */
-    ownerTa: TAccountMetas[14];
+    ownerTa: TAccountMetas[16];
     /** The token account of the pool, where the NFT will be escrowed. */
-    poolTa: TAccountMetas[15];
+    poolTa: TAccountMetas[17];
     /** The SPL Token program for the Mint and ATAs. */
-    tokenProgram: TAccountMetas[16];
+    tokenProgram: TAccountMetas[18];
     /** The SPL associated token program. */
-    associatedTokenProgram: TAccountMetas[17];
+    associatedTokenProgram: TAccountMetas[19];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[18];
+    systemProgram: TAccountMetas[20];
   };
   data: DepositNftInstructionData;
 };
@@ -873,7 +937,7 @@ export function parseDepositNftInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDepositNftInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 19) {
+  if (instruction.accounts.length < 21) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -896,6 +960,8 @@ export function parseDepositNftInstruction<
       pool: getNextAccount(),
       whitelist: getNextAccount(),
       mintProof: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
       mint: getNextAccount(),
       metadata: getNextAccount(),
       edition: getNextAccount(),
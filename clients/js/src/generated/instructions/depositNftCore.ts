@@ -54,6 +54,10 @@ export type DepositNftCoreInstruction<
   TAccountPool extends string | IAccountMeta<string> = string,
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountMintProof extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountAsset extends string | IAccountMeta<string> = string,
   TAccountCollection extends string | IAccountMeta<string> = string,
   TAccountMplCoreProgram extends
@@ -81,6 +85,12 @@ export type DepositNftCoreInstruction<
       TAccountMintProof extends string
         ? ReadonlyAccount<TAccountMintProof>
         : TAccountMintProof,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? ReadonlyAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountAsset extends string
         ? WritableAccount<TAccountAsset>
         : TAccountAsset,
@@ -134,6 +144,8 @@ export type DepositNftCoreAsyncInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -154,6 +166,10 @@ export type DepositNftCoreAsyncInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The MPL core asset account. */
   asset: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
@@ -170,6 +186,8 @@ export async function getDepositNftCoreInstructionAsync<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
@@ -182,6 +200,8 @@ export async function getDepositNftCoreInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -196,6 +216,8 @@ export async function getDepositNftCoreInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -212,6 +234,11 @@ export async function getDepositNftCoreInstructionAsync<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -224,6 +251,10 @@ export async function getDepositNftCoreInstructionAsync<
   >;
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -246,6 +277,8 @@ export async function getDepositNftCoreInstructionAsync<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
@@ -260,6 +293,8 @@ export async function getDepositNftCoreInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -275,6 +310,8 @@ export type DepositNftCoreInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -295,6 +332,10 @@ export type DepositNftCoreInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The MPL core asset account. */
   asset: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
@@ -311,6 +352,8 @@ export function getDepositNftCoreInstruction<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
@@ -323,6 +366,8 @@ export function getDepositNftCoreInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -336,6 +381,8 @@ export function getDepositNftCoreInstruction<
   TAccountPool,
   TAccountWhitelist,
   TAccountMintProof,
+  TAccountAmmProgram,
+  TAccountTraitRequirements,
   TAccountAsset,
   TAccountCollection,
   TAccountMplCoreProgram,
@@ -351,6 +398,11 @@ export function getDepositNftCoreInstruction<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -363,6 +415,10 @@ export function getDepositNftCoreInstruction<
   >;
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -379,6 +435,8 @@ export function getDepositNftCoreInstruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
@@ -393,6 +451,8 @@ export function getDepositNftCoreInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -425,15 +485,19 @@ export type ParsedDepositNftCoreInstruction<
      */
 
     mintProof?: TAccountMetas[3] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[4];
+    /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+    traitRequirements?: TAccountMetas[5] | undefined;
     /** The MPL core asset account. */
-    asset: TAccountMetas[4];
-    collection?: TAccountMetas[5] | undefined;
+    asset: TAccountMetas[6];
+    collection?: TAccountMetas[7] | undefined;
     /** The MPL Core program. */
-    mplCoreProgram: TAccountMetas[6];
+    mplCoreProgram: TAccountMetas[8];
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[7];
+    nftReceipt: TAccountMetas[9];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[10];
   };
   data: DepositNftCoreInstructionData;
 };
@@ -446,7 +510,7 @@ export function parseDepositNftCoreInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDepositNftCoreInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -469,6 +533,8 @@ export function parseDepositNftCoreInstruction<
       pool: getNextAccount(),
       whitelist: getNextAccount(),
       mintProof: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
       asset: getNextAccount(),
       collection: getNextOptionalAccount(),
       mplCoreProgram: getNextAccount(),
@@ -56,6 +56,10 @@ export type DepositNftT22Instruction<
   TAccountPool extends string | IAccountMeta<string> = string,
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountMintProof extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountNftReceipt extends string | IAccountMeta<string> = string,
   TAccountOwnerTa extends string | IAccountMeta<string> = string,
@@ -87,6 +91,12 @@ export type DepositNftT22Instruction<
       TAccountMintProof extends string
         ? ReadonlyAccount<TAccountMintProof>
         : TAccountMintProof,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? ReadonlyAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -146,6 +156,8 @@ export type DepositNftT22AsyncInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountNftReceipt extends string = string,
   TAccountOwnerTa extends string = string,
@@ -168,6 +180,10 @@ export type DepositNftT22AsyncInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
@@ -190,6 +206,8 @@ export async function getDepositNftT22InstructionAsync<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountNftReceipt extends string,
   TAccountOwnerTa extends string,
@@ -204,6 +222,8 @@ export async function getDepositNftT22InstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountOwnerTa,
@@ -220,6 +240,8 @@ export async function getDepositNftT22InstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountOwnerTa,
@@ -238,6 +260,11 @@ export async function getDepositNftT22InstructionAsync<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     nftReceipt: { value: input.nftReceipt ?? null, isWritable: true },
     ownerTa: { value: input.ownerTa ?? null, isWritable: true },
@@ -261,6 +288,10 @@ export async function getDepositNftT22InstructionAsync<
   const resolverScope = { programAddress, accounts, args };
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.nftReceipt.value) {
     accounts.nftReceipt.value = await findNftDepositReceiptPda({
       mint: expectAddress(accounts.mint.value),
@@ -304,6 +335,8 @@ export async function getDepositNftT22InstructionAsync<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.nftReceipt),
       getAccountMeta(accounts.ownerTa),
@@ -321,6 +354,8 @@ export async function getDepositNftT22InstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountOwnerTa,
@@ -338,6 +373,8 @@ export type DepositNftT22Input<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountNftReceipt extends string = string,
   TAccountOwnerTa extends string = string,
@@ -360,6 +397,10 @@ export type DepositNftT22Input<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
@@ -382,6 +423,8 @@ export function getDepositNftT22Instruction<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountNftReceipt extends string,
   TAccountOwnerTa extends string,
@@ -396,6 +439,8 @@ export function getDepositNftT22Instruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountOwnerTa,
@@ -411,6 +456,8 @@ export function getDepositNftT22Instruction<
   TAccountPool,
   TAccountWhitelist,
   TAccountMintProof,
+  TAccountAmmProgram,
+  TAccountTraitRequirements,
   TAccountMint,
   TAccountNftReceipt,
   TAccountOwnerTa,
@@ -428,6 +475,11 @@ export function getDepositNftT22Instruction<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     nftReceipt: { value: input.nftReceipt ?? null, isWritable: true },
     ownerTa: { value: input.ownerTa ?? null, isWritable: true },
@@ -448,6 +500,10 @@ export function getDepositNftT22Instruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
@@ -473,6 +529,8 @@ export function getDepositNftT22Instruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.nftReceipt),
       getAccountMeta(accounts.ownerTa),
@@ -490,6 +548,8 @@ export function getDepositNftT22Instruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountNftReceipt,
     TAccountOwnerTa,
@@ -524,20 +584,24 @@ export type ParsedDepositNftT22Instruction<
      */
 
     mintProof?: TAccountMetas[3] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[4];
+    /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+    traitRequirements?: TAccountMetas[5] | undefined;
     /** The mint account of the NFT. */
-    mint: TAccountMetas[4];
+    mint: TAccountMetas[6];
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[5];
+    nftReceipt: TAccountMetas[7];
     /** The TA of the owner, where the NFT will be transferred from. */
-    ownerTa: TAccountMetas[6];
+    ownerTa: TAccountMetas[8];
     /** The TA of the pool, where the NFT will be escrowed. */
-    poolTa: TAccountMetas[7];
+    poolTa: TAccountMetas[9];
     /** The SPL Token program for the Mint and ATAs. */
-    tokenProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[10];
     /** The SPL associated token program. */
-    associatedTokenProgram: TAccountMetas[9];
+    associatedTokenProgram: TAccountMetas[11];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[12];
   };
   data: DepositNftT22InstructionData;
 };
@@ -550,7 +614,7 @@ export function parseDepositNftT22Instruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDepositNftT22Instruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -573,6 +637,8 @@ export function parseDepositNftT22Instruction<
       pool: getNextAccount(),
       whitelist: getNextAccount(),
       mintProof: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
       mint: getNextAccount(),
       nftReceipt: getNextAccount(),
       ownerTa: getNextAccount(),
@@ -50,6 +50,9 @@ export type DepositSolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -65,6 +68,9 @@ export type DepositSolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,6 +113,7 @@ export type DepositSolInput<
   TAccountOwner extends string = string,
   TAccountPool extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAmmProgram extends string = string,
 > = {
   /** The owner of the pool--must sign to deposit SOL. */
   owner: TransactionSigner<TAccountOwner>;
@@ -114,6 +121,8 @@ export type DepositSolInput<
   pool: Address<TAccountPool>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
   lamports: DepositSolInstructionDataArgs['lamports'];
 };
 
@@ -121,15 +130,22 @@ export function getDepositSolInstruction<
   TAccountOwner extends string,
   TAccountPool extends string,
   TAccountSystemProgram extends string,
+  TAccountAmmProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
-  input: DepositSolInput<TAccountOwner, TAccountPool, TAccountSystemProgram>,
+  input: DepositSolInput<
+    TAccountOwner,
+    TAccountPool,
+    TAccountSystemProgram,
+    TAccountAmmProgram
+  >,
   config?: { programAddress?: TProgramAddress }
 ): DepositSolInstruction<
   TProgramAddress,
   TAccountOwner,
   TAccountPool,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountAmmProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TENSOR_AMM_PROGRAM_ADDRESS;
@@ -139,6 +155,7 @@ export function getDepositSolInstruction<
     owner: { value: input.owner ?? null, isWritable: true },
     pool: { value: input.pool ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -153,6 +170,10 @@ export function getDepositSolInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -160,6 +181,7 @@ export function getDepositSolInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.ammProgram),
     ],
     programAddress,
     data: getDepositSolInstructionDataEncoder().encode(
@@ -169,7 +191,8 @@ export function getDepositSolInstruction<
     TProgramAddress,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAmmProgram
   >;
 
   return instruction;
@@ -187,6 +210,8 @@ export type ParsedDepositSolInstruction<
     pool: TAccountMetas[1];
     /** The Solana system program. */
     systemProgram: TAccountMetas[2];
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[3];
   };
   data: DepositSolInstructionData;
 };
@@ -199,7 +224,7 @@ export function parseDepositSolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDepositSolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -215,6 +240,7 @@ export function parseDepositSolInstruction<
       owner: getNextAccount(),
       pool: getNextAccount(),
       systemProgram: getNextAccount(),
+      ammProgram: getNextAccount(),
     },
     data: getDepositSolInstructionDataDecoder().decode(instruction.data),
   };
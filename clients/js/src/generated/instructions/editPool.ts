@@ -20,6 +20,8 @@ import {
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -42,14 +44,23 @@ import {
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { TENSOR_AMM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
+  getCircuitBreakerArgsDecoder,
+  getCircuitBreakerArgsEncoder,
   getEditPoolConfigDecoder,
   getEditPoolConfigEncoder,
+  getPriceLadderArgsDecoder,
+  getPriceLadderArgsEncoder,
+  type CircuitBreakerArgs,
+  type CircuitBreakerArgsArgs,
   type EditPoolConfig,
   type EditPoolConfigArgs,
+  type PriceLadderArgs,
+  type PriceLadderArgsArgs,
 } from '../types';
 
 export const EDIT_POOL_DISCRIMINATOR = new Uint8Array([
@@ -67,14 +78,20 @@ export type EditPoolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountRentPayer extends string | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountNewWhitelist extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
   IInstructionWithAccounts<
     [
       TAccountOwner extends string
-        ? ReadonlySignerAccount<TAccountOwner> &
-            IAccountSignerMeta<TAccountOwner>
+        ? ReadonlyAccount<TAccountOwner>
         : TAccountOwner,
       TAccountPool extends string
         ? WritableAccount<TAccountPool>
@@ -82,6 +99,23 @@ export type EditPoolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountRentPayer extends string
+        ? WritableSignerAccount<TAccountRentPayer> &
+            IAccountSignerMeta<TAccountRentPayer>
+        : TAccountRentPayer,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountManager extends string
+        ? ReadonlySignerAccount<TAccountManager> &
+            IAccountSignerMeta<TAccountManager>
+        : TAccountManager,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountNewWhitelist extends string
+        ? ReadonlyAccount<TAccountNewWhitelist>
+        : TAccountNewWhitelist,
       ...TRemainingAccounts,
     ]
   >;
@@ -94,6 +128,17 @@ export type EditPoolInstructionData = {
   expireInSec: Option<bigint>;
   maxTakerSellCount: Option<number>;
   resetPriceOffset: boolean;
+  decayBps: Option<number>;
+  floorPrice: Option<bigint>;
+  /** New prices of custom curves. */
+  priceLadderArgs: Option<PriceLadderArgs>;
+  /** New manager of the pool; the default pubkey removes the manager. */
+  newManager: Option<Address>;
+  maxTakerBuyCount: Option<number>;
+  maxNftsHeld: Option<number>;
+  maxTotalSpend: Option<bigint>;
+  /** New limits of the circuit breaker; a zero window disables it. */
+  circuitBreaker: Option<CircuitBreakerArgs>;
 };
 
 export type EditPoolInstructionDataArgs = {
@@ -103,6 +148,18 @@ export type EditPoolInstructionDataArgs = {
   expireInSec?: OptionOrNullable<number | bigint>;
   maxTakerSellCount?: OptionOrNullable<number>;
   resetPriceOffset: boolean;
+  decayBps?: OptionOrNullable<number>;
+  floorPrice?: OptionOrNullable<
+    number | bigint
+  > /** New prices of custom curves. */;
+  priceLadderArgs?: OptionOrNullable<PriceLadderArgsArgs> /** New manager of the pool; the default pubkey removes the manager. */;
+  newManager?: OptionOrNullable<Address>;
+  maxTakerBuyCount?: OptionOrNullable<number>;
+  maxNftsHeld?: OptionOrNullable<number>;
+  maxTotalSpend?: OptionOrNullable<
+    number | bigint
+  > /** New limits of the circuit breaker; a zero window disables it. */;
+  circuitBreaker?: OptionOrNullable<CircuitBreakerArgsArgs>;
 };
 
 export function getEditPoolInstructionDataEncoder(): Encoder<EditPoolInstructionDataArgs> {
@@ -115,6 +172,14 @@ export function getEditPoolInstructionDataEncoder(): Encoder<EditPoolInstruction
       ['expireInSec', getOptionEncoder(getU64Encoder())],
       ['maxTakerSellCount', getOptionEncoder(getU32Encoder())],
       ['resetPriceOffset', getBooleanEncoder()],
+      ['decayBps', getOptionEncoder(getU16Encoder())],
+      ['floorPrice', getOptionEncoder(getU64Encoder())],
+      ['priceLadderArgs', getOptionEncoder(getPriceLadderArgsEncoder())],
+      ['newManager', getOptionEncoder(getAddressEncoder())],
+      ['maxTakerBuyCount', getOptionEncoder(getU32Encoder())],
+      ['maxNftsHeld', getOptionEncoder(getU32Encoder())],
+      ['maxTotalSpend', getOptionEncoder(getU64Encoder())],
+      ['circuitBreaker', getOptionEncoder(getCircuitBreakerArgsEncoder())],
     ]),
     (value) => ({
       ...value,
@@ -123,6 +188,14 @@ export function getEditPoolInstructionDataEncoder(): Encoder<EditPoolInstruction
       makerBroker: value.makerBroker ?? none(),
       expireInSec: value.expireInSec ?? none(),
       maxTakerSellCount: value.maxTakerSellCount ?? none(),
+      decayBps: value.decayBps ?? none(),
+      floorPrice: value.floorPrice ?? none(),
+      priceLadderArgs: value.priceLadderArgs ?? none(),
+      newManager: value.newManager ?? none(),
+      maxTakerBuyCount: value.maxTakerBuyCount ?? none(),
+      maxNftsHeld: value.maxNftsHeld ?? none(),
+      maxTotalSpend: value.maxTotalSpend ?? none(),
+      circuitBreaker: value.circuitBreaker ?? none(),
     })
   );
 }
@@ -136,6 +209,14 @@ export function getEditPoolInstructionDataDecoder(): Decoder<EditPoolInstruction
     ['expireInSec', getOptionDecoder(getU64Decoder())],
     ['maxTakerSellCount', getOptionDecoder(getU32Decoder())],
     ['resetPriceOffset', getBooleanDecoder()],
+    ['decayBps', getOptionDecoder(getU16Decoder())],
+    ['floorPrice', getOptionDecoder(getU64Decoder())],
+    ['priceLadderArgs', getOptionDecoder(getPriceLadderArgsDecoder())],
+    ['newManager', getOptionDecoder(getAddressDecoder())],
+    ['maxTakerBuyCount', getOptionDecoder(getU32Decoder())],
+    ['maxNftsHeld', getOptionDecoder(getU32Decoder())],
+    ['maxTotalSpend', getOptionDecoder(getU64Decoder())],
+    ['circuitBreaker', getOptionDecoder(getCircuitBreakerArgsDecoder())],
   ]);
 }
 
@@ -153,34 +234,85 @@ export type EditPoolInput<
   TAccountOwner extends string = string,
   TAccountPool extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountRentPayer extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountManager extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountNewWhitelist extends string = string,
 > = {
-  /** The owner of the pool--must sign to edit the pool. */
-  owner: TransactionSigner<TAccountOwner>;
+  /** The owner of the pool--must sign to edit the pool, unless the pool manager signs instead. */
+  owner: Address<TAccountOwner> | TransactionSigner<TAccountOwner>;
   /** The pool to edit. */
   pool: Address<TAccountPool>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /**
+   * The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+   * refunded when it is closed.
+   */
+  rentPayer?: TransactionSigner<TAccountRentPayer>;
+  /** The price ladder of the pool; required to set prices or switch to or from a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /** The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner. */
+  manager?: TransactionSigner<TAccountManager>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /**
+   * The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+   * were verified against the current whitelist.
+   */
+  newWhitelist?: Address<TAccountNewWhitelist>;
   newConfig: EditPoolInstructionDataArgs['newConfig'];
   cosigner?: EditPoolInstructionDataArgs['cosigner'];
   makerBroker?: EditPoolInstructionDataArgs['makerBroker'];
   expireInSec?: EditPoolInstructionDataArgs['expireInSec'];
   maxTakerSellCount?: EditPoolInstructionDataArgs['maxTakerSellCount'];
   resetPriceOffset: EditPoolInstructionDataArgs['resetPriceOffset'];
+  decayBps?: EditPoolInstructionDataArgs['decayBps'];
+  floorPrice?: EditPoolInstructionDataArgs['floorPrice'];
+  /** New prices of custom curves. */
+  priceLadderArgs?: EditPoolInstructionDataArgs['priceLadderArgs'];
+  /** New manager of the pool; the default pubkey removes the manager. */
+  newManager?: EditPoolInstructionDataArgs['newManager'];
+  maxTakerBuyCount?: EditPoolInstructionDataArgs['maxTakerBuyCount'];
+  maxNftsHeld?: EditPoolInstructionDataArgs['maxNftsHeld'];
+  maxTotalSpend?: EditPoolInstructionDataArgs['maxTotalSpend'];
+  /** New limits of the circuit breaker; a zero window disables it. */
+  circuitBreaker?: EditPoolInstructionDataArgs['circuitBreaker'];
 };
 
 export function getEditPoolInstruction<
   TAccountOwner extends string,
   TAccountPool extends string,
   TAccountSystemProgram extends string,
+  TAccountRentPayer extends string,
+  TAccountPriceLadder extends string,
+  TAccountManager extends string,
+  TAccountAmmProgram extends string,
+  TAccountNewWhitelist extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
-  input: EditPoolInput<TAccountOwner, TAccountPool, TAccountSystemProgram>,
+  input: EditPoolInput<
+    TAccountOwner,
+    TAccountPool,
+    TAccountSystemProgram,
+    TAccountRentPayer,
+    TAccountPriceLadder,
+    TAccountManager,
+    TAccountAmmProgram,
+    TAccountNewWhitelist
+  >,
   config?: { programAddress?: TProgramAddress }
 ): EditPoolInstruction<
   TProgramAddress,
   TAccountOwner,
   TAccountPool,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountRentPayer,
+  TAccountPriceLadder,
+  TAccountManager,
+  TAccountAmmProgram,
+  TAccountNewWhitelist
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TENSOR_AMM_PROGRAM_ADDRESS;
@@ -190,6 +322,11 @@ export function getEditPoolInstruction<
     owner: { value: input.owner ?? null, isWritable: false },
     pool: { value: input.pool ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    rentPayer: { value: input.rentPayer ?? null, isWritable: true },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    newWhitelist: { value: input.newWhitelist ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -204,6 +341,10 @@ export function getEditPoolInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -211,6 +352,11 @@ export function getEditPoolInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.rentPayer),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.newWhitelist),
     ],
     programAddress,
     data: getEditPoolInstructionDataEncoder().encode(
@@ -220,7 +366,12 @@ export function getEditPoolInstruction<
     TProgramAddress,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountRentPayer,
+    TAccountPriceLadder,
+    TAccountManager,
+    TAccountAmmProgram,
+    TAccountNewWhitelist
   >;
 
   return instruction;
@@ -232,12 +383,30 @@ export type ParsedEditPoolInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The owner of the pool--must sign to edit the pool. */
+    /** The owner of the pool--must sign to edit the pool, unless the pool manager signs instead. */
     owner: TAccountMetas[0];
     /** The pool to edit. */
     pool: TAccountMetas[1];
     /** The Solana system program. */
     systemProgram: TAccountMetas[2];
+    /**
+     * The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+     * refunded when it is closed.
+     */
+
+    rentPayer?: TAccountMetas[3] | undefined;
+    /** The price ladder of the pool; required to set prices or switch to or from a custom curve. */
+    priceLadder?: TAccountMetas[4] | undefined;
+    /** The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner. */
+    manager?: TAccountMetas[5] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[6];
+    /**
+     * The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+     * were verified against the current whitelist.
+     */
+
+    newWhitelist?: TAccountMetas[7] | undefined;
   };
   data: EditPoolInstructionData;
 };
@@ -250,7 +419,7 @@ export function parseEditPoolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedEditPoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -260,12 +429,23 @@ export function parseEditPoolInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === TENSOR_AMM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
       pool: getNextAccount(),
       systemProgram: getNextAccount(),
+      rentPayer: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      manager: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      newWhitelist: getNextOptionalAccount(),
     },
     data: getEditPoolInstructionDataDecoder().decode(instruction.data),
   };
@@ -6,25 +6,61 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './attachSharedEscrow';
 export * from './buyNft';
+export * from './buyNftCompressed';
 export * from './buyNftCore';
+export * from './buyNfts';
+export * from './buyNftsCore';
+export * from './buyNftsT22';
+export * from './buyNftsWns';
 export * from './buyNftT22';
+export * from './buyNftWns';
 export * from './closeExpiredPool';
 export * from './closePool';
+export * from './convertPool';
 export * from './createPool';
 export * from './depositNft';
+export * from './depositNftCompressed';
 export * from './depositNftCore';
 export * from './depositNftT22';
+export * from './depositNftWns';
 export * from './depositSol';
+export * from './depositToken';
+export * from './detachSharedEscrow';
 export * from './editPool';
+export * from './sellNftsTokenPool';
+export * from './sellNftsTokenPoolCore';
+export * from './sellNftsTokenPoolT22';
+export * from './sellNftsTokenPoolWns';
+export * from './sellNftsTradePool';
+export * from './sellNftsTradePoolCore';
+export * from './sellNftsTradePoolT22';
+export * from './sellNftsTradePoolWns';
 export * from './sellNftTokenPool';
+export * from './sellNftTokenPoolCompressed';
 export * from './sellNftTokenPoolCore';
 export * from './sellNftTokenPoolT22';
+export * from './sellNftTokenPoolWns';
 export * from './sellNftTradePool';
+export * from './sellNftTradePoolCompressed';
 export * from './sellNftTradePoolCore';
 export * from './sellNftTradePoolT22';
+export * from './sellNftTradePoolWns';
+export * from './setPoolStatus';
+export * from './setTraitRequirements';
 export * from './tammNoop';
+export * from './transferPool';
+export * from './upgradePool';
+export * from './withdrawExpiredNft';
+export * from './withdrawExpiredNftCompressed';
+export * from './withdrawExpiredNftCore';
+export * from './withdrawExpiredNftT22';
+export * from './withdrawExpiredNftWns';
 export * from './withdrawNft';
+export * from './withdrawNftCompressed';
 export * from './withdrawNftCore';
 export * from './withdrawNftT22';
+export * from './withdrawNftWns';
 export * from './withdrawSol';
+export * from './withdrawToken';
@@ -105,6 +105,20 @@ export type SellNftTokenPoolInstruction<
   TAccountNativeProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCurrency extends string | IAccountMeta<string> = string,
+  TAccountPoolCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountOwnerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountFeeVaultCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountMakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountTakerBrokerCurrencyTa extends string | IAccountMeta<string> = string,
+  TAccountCurrencyTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountCurrencyAssociatedTokenProgram extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountPriceLadder extends string | IAccountMeta<string> = string,
+  TAccountAttestationSysvar extends string | IAccountMeta<string> = string,
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountMetadata extends string | IAccountMeta<string> = string,
   TAccountEdition extends string | IAccountMeta<string> = string,
@@ -178,6 +192,42 @@ export type SellNftTokenPoolInstruction<
       TAccountNativeProgram extends string
         ? ReadonlyAccount<TAccountNativeProgram>
         : TAccountNativeProgram,
+      TAccountCurrency extends string
+        ? ReadonlyAccount<TAccountCurrency>
+        : TAccountCurrency,
+      TAccountPoolCurrencyTa extends string
+        ? WritableAccount<TAccountPoolCurrencyTa>
+        : TAccountPoolCurrencyTa,
+      TAccountTakerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerCurrencyTa>
+        : TAccountTakerCurrencyTa,
+      TAccountOwnerCurrencyTa extends string
+        ? WritableAccount<TAccountOwnerCurrencyTa>
+        : TAccountOwnerCurrencyTa,
+      TAccountFeeVaultCurrencyTa extends string
+        ? WritableAccount<TAccountFeeVaultCurrencyTa>
+        : TAccountFeeVaultCurrencyTa,
+      TAccountMakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountMakerBrokerCurrencyTa>
+        : TAccountMakerBrokerCurrencyTa,
+      TAccountTakerBrokerCurrencyTa extends string
+        ? WritableAccount<TAccountTakerBrokerCurrencyTa>
+        : TAccountTakerBrokerCurrencyTa,
+      TAccountCurrencyTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyTokenProgram>
+        : TAccountCurrencyTokenProgram,
+      TAccountCurrencyAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountCurrencyAssociatedTokenProgram>
+        : TAccountCurrencyAssociatedTokenProgram,
+      TAccountPriceLadder extends string
+        ? WritableAccount<TAccountPriceLadder>
+        : TAccountPriceLadder,
+      TAccountAttestationSysvar extends string
+        ? ReadonlyAccount<TAccountAttestationSysvar>
+        : TAccountAttestationSysvar,
+      TAccountTraitRequirements extends string
+        ? WritableAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -301,6 +351,18 @@ export type SellNftTokenPoolAsyncInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -349,13 +411,46 @@ export type SellNftTokenPoolAsyncInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -410,6 +505,18 @@ export async function getSellNftTokenPoolInstructionAsync<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -443,6 +550,18 @@ export async function getSellNftTokenPoolInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -478,6 +597,18 @@ export async function getSellNftTokenPoolInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -515,6 +646,39 @@ export async function getSellNftTokenPoolInstructionAsync<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -689,6 +853,18 @@ export async function getSellNftTokenPoolInstructionAsync<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -727,6 +903,18 @@ export async function getSellNftTokenPoolInstructionAsync<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -763,6 +951,18 @@ export type SellNftTokenPoolInput<
   TAccountAmmProgram extends string = string,
   TAccountEscrowProgram extends string = string,
   TAccountNativeProgram extends string = string,
+  TAccountCurrency extends string = string,
+  TAccountPoolCurrencyTa extends string = string,
+  TAccountTakerCurrencyTa extends string = string,
+  TAccountOwnerCurrencyTa extends string = string,
+  TAccountFeeVaultCurrencyTa extends string = string,
+  TAccountMakerBrokerCurrencyTa extends string = string,
+  TAccountTakerBrokerCurrencyTa extends string = string,
+  TAccountCurrencyTokenProgram extends string = string,
+  TAccountCurrencyAssociatedTokenProgram extends string = string,
+  TAccountPriceLadder extends string = string,
+  TAccountAttestationSysvar extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -811,13 +1011,46 @@ export type SellNftTokenPoolInput<
   makerBroker?: Address<TAccountMakerBroker>;
   /** The account that receives the taker broker fee. */
   takerBroker?: Address<TAccountTakerBroker>;
-  /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+  /**
+   * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+   * trade carries an attestation signed by the cosigner.
+   */
   cosigner?: TransactionSigner<TAccountCosigner>;
   /** The AMM program account, used for self-cpi logging. */
   ammProgram?: Address<TAccountAmmProgram>;
   /** The escrow program account for shared liquidity pools. */
   escrowProgram?: Address<TAccountEscrowProgram>;
   nativeProgram?: Address<TAccountNativeProgram>;
+  /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+  currency?: Address<TAccountCurrency>;
+  /** The pool's currency token account, which escrows the pool's funds. */
+  poolCurrencyTa?: Address<TAccountPoolCurrencyTa>;
+  /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+  takerCurrencyTa?: Address<TAccountTakerCurrencyTa>;
+  /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+  ownerCurrencyTa?: Address<TAccountOwnerCurrencyTa>;
+  /** The fee vault's currency token account. */
+  feeVaultCurrencyTa?: Address<TAccountFeeVaultCurrencyTa>;
+  /** The maker broker's currency token account. */
+  makerBrokerCurrencyTa?: Address<TAccountMakerBrokerCurrencyTa>;
+  /** The taker broker's currency token account. */
+  takerBrokerCurrencyTa?: Address<TAccountTakerBrokerCurrencyTa>;
+  /** The token program of the currency mint. */
+  currencyTokenProgram?: Address<TAccountCurrencyTokenProgram>;
+  /** The SPL associated token program, used to create currency token accounts. */
+  currencyAssociatedTokenProgram?: Address<TAccountCurrencyAssociatedTokenProgram>;
+  /** The price ladder of the pool; required if the pool uses a custom curve. */
+  priceLadder?: Address<TAccountPriceLadder>;
+  /**
+   * The sysvar instructions account; required to verify cosigner attestations when the
+   * cosigner does not sign the transaction.
+   */
+  attestationSysvar?: Address<TAccountAttestationSysvar>;
+  /**
+   * The traits required by the pool; required for sells if the pool has trait requirements,
+   * and closed along with the pool if it is auto-closed.
+   */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -872,6 +1105,18 @@ export function getSellNftTokenPoolInstruction<
   TAccountAmmProgram extends string,
   TAccountEscrowProgram extends string,
   TAccountNativeProgram extends string,
+  TAccountCurrency extends string,
+  TAccountPoolCurrencyTa extends string,
+  TAccountTakerCurrencyTa extends string,
+  TAccountOwnerCurrencyTa extends string,
+  TAccountFeeVaultCurrencyTa extends string,
+  TAccountMakerBrokerCurrencyTa extends string,
+  TAccountTakerBrokerCurrencyTa extends string,
+  TAccountCurrencyTokenProgram extends string,
+  TAccountCurrencyAssociatedTokenProgram extends string,
+  TAccountPriceLadder extends string,
+  TAccountAttestationSysvar extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -905,6 +1150,18 @@ export function getSellNftTokenPoolInstruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -939,6 +1196,18 @@ export function getSellNftTokenPoolInstruction<
   TAccountAmmProgram,
   TAccountEscrowProgram,
   TAccountNativeProgram,
+  TAccountCurrency,
+  TAccountPoolCurrencyTa,
+  TAccountTakerCurrencyTa,
+  TAccountOwnerCurrencyTa,
+  TAccountFeeVaultCurrencyTa,
+  TAccountMakerBrokerCurrencyTa,
+  TAccountTakerBrokerCurrencyTa,
+  TAccountCurrencyTokenProgram,
+  TAccountCurrencyAssociatedTokenProgram,
+  TAccountPriceLadder,
+  TAccountAttestationSysvar,
+  TAccountTraitRequirements,
   TAccountMint,
   TAccountMetadata,
   TAccountEdition,
@@ -975,6 +1244,39 @@ export function getSellNftTokenPoolInstruction<
     ammProgram: { value: input.ammProgram ?? null, isWritable: false },
     escrowProgram: { value: input.escrowProgram ?? null, isWritable: false },
     nativeProgram: { value: input.nativeProgram ?? null, isWritable: false },
+    currency: { value: input.currency ?? null, isWritable: false },
+    poolCurrencyTa: { value: input.poolCurrencyTa ?? null, isWritable: true },
+    takerCurrencyTa: { value: input.takerCurrencyTa ?? null, isWritable: true },
+    ownerCurrencyTa: { value: input.ownerCurrencyTa ?? null, isWritable: true },
+    feeVaultCurrencyTa: {
+      value: input.feeVaultCurrencyTa ?? null,
+      isWritable: true,
+    },
+    makerBrokerCurrencyTa: {
+      value: input.makerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    takerBrokerCurrencyTa: {
+      value: input.takerBrokerCurrencyTa ?? null,
+      isWritable: true,
+    },
+    currencyTokenProgram: {
+      value: input.currencyTokenProgram ?? null,
+      isWritable: false,
+    },
+    currencyAssociatedTokenProgram: {
+      value: input.currencyAssociatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    priceLadder: { value: input.priceLadder ?? null, isWritable: true },
+    attestationSysvar: {
+      value: input.attestationSysvar ?? null,
+      isWritable: false,
+    },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: true,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -1095,6 +1397,18 @@ export function getSellNftTokenPoolInstruction<
       getAccountMeta(accounts.ammProgram),
       getAccountMeta(accounts.escrowProgram),
       getAccountMeta(accounts.nativeProgram),
+      getAccountMeta(accounts.currency),
+      getAccountMeta(accounts.poolCurrencyTa),
+      getAccountMeta(accounts.takerCurrencyTa),
+      getAccountMeta(accounts.ownerCurrencyTa),
+      getAccountMeta(accounts.feeVaultCurrencyTa),
+      getAccountMeta(accounts.makerBrokerCurrencyTa),
+      getAccountMeta(accounts.takerBrokerCurrencyTa),
+      getAccountMeta(accounts.currencyTokenProgram),
+      getAccountMeta(accounts.currencyAssociatedTokenProgram),
+      getAccountMeta(accounts.priceLadder),
+      getAccountMeta(accounts.attestationSysvar),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -1133,6 +1447,18 @@ export function getSellNftTokenPoolInstruction<
     TAccountAmmProgram,
     TAccountEscrowProgram,
     TAccountNativeProgram,
+    TAccountCurrency,
+    TAccountPoolCurrencyTa,
+    TAccountTakerCurrencyTa,
+    TAccountOwnerCurrencyTa,
+    TAccountFeeVaultCurrencyTa,
+    TAccountMakerBrokerCurrencyTa,
+    TAccountTakerBrokerCurrencyTa,
+    TAccountCurrencyTokenProgram,
+    TAccountCurrencyAssociatedTokenProgram,
+    TAccountPriceLadder,
+    TAccountAttestationSysvar,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -1194,45 +1520,81 @@ export type ParsedSellNftTokenPoolInstruction<
     makerBroker?: TAccountMetas[8] | undefined;
     /** The account that receives the taker broker fee. */
     takerBroker?: TAccountMetas[9] | undefined;
-    /** The optional cosigner account that must be passed in if the pool has a cosigner. */
+    /**
+     * The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+     * trade carries an attestation signed by the cosigner.
+     */
+
     cosigner?: TAccountMetas[10] | undefined;
     /** The AMM program account, used for self-cpi logging. */
     ammProgram: TAccountMetas[11];
     /** The escrow program account for shared liquidity pools. */
     escrowProgram?: TAccountMetas[12] | undefined;
     nativeProgram: TAccountMetas[13];
+    /** The SPL mint the pool is priced in; required if the pool currency is not SOL. */
+    currency?: TAccountMetas[14] | undefined;
+    /** The pool's currency token account, which escrows the pool's funds. */
+    poolCurrencyTa?: TAccountMetas[15] | undefined;
+    /** The taker's currency token account, paying for buys and receiving sale proceeds. */
+    takerCurrencyTa?: TAccountMetas[16] | undefined;
+    /** The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees. */
+    ownerCurrencyTa?: TAccountMetas[17] | undefined;
+    /** The fee vault's currency token account. */
+    feeVaultCurrencyTa?: TAccountMetas[18] | undefined;
+    /** The maker broker's currency token account. */
+    makerBrokerCurrencyTa?: TAccountMetas[19] | undefined;
+    /** The taker broker's currency token account. */
+    takerBrokerCurrencyTa?: TAccountMetas[20] | undefined;
+    /** The token program of the currency mint. */
+    currencyTokenProgram?: TAccountMetas[21] | undefined;
+    /** The SPL associated token program, used to create currency token accounts. */
+    currencyAssociatedTokenProgram?: TAccountMetas[22] | undefined;
+    /** The price ladder of the pool; required if the pool uses a custom curve. */
+    priceLadder?: TAccountMetas[23] | undefined;
+    /**
+     * The sysvar instructions account; required to verify cosigner attestations when the
+     * cosigner does not sign the transaction.
+     */
+
+    attestationSysvar?: TAccountMetas[24] | undefined;
+    /**
+     * The traits required by the pool; required for sells if the pool has trait requirements,
+     * and closed along with the pool if it is auto-closed.
+     */
+
+    traitRequirements?: TAccountMetas[25] | undefined;
     /** The mint account of the NFT. */
-    mint: TAccountMetas[14];
+    mint: TAccountMetas[26];
     /** The Token Metadata metadata account of the NFT. */
-    metadata: TAccountMetas[15];
+    metadata: TAccountMetas[27];
     /** The Token Metadata edition account of the NFT. */
-    edition: TAccountMetas[16];
+    edition: TAccountMetas[28];
     /** The Token Metadata source token record account of the NFT. */
-    userTokenRecord?: TAccountMetas[17] | undefined;
+    userTokenRecord?: TAccountMetas[29] | undefined;
     /** The Token Metadata token record for the destination. */
-    poolTokenRecord?: TAccountMetas[18] | undefined;
+    poolTokenRecord?: TAccountMetas[30] | undefined;
     /** The Token Metadata program account. */
-    tokenMetadataProgram?: TAccountMetas[19] | undefined;
+    tokenMetadataProgram?: TAccountMetas[31] | undefined;
     /** The sysvar instructions account. */
-    sysvarInstructions?: TAccountMetas[20] | undefined;
+    sysvarInstructions?: TAccountMetas[32] | undefined;
     /** The Metaplex Token Authority Rules account that stores royalty enforcement rules. */
-    authorizationRules?: TAccountMetas[21] | undefined;
+    authorizationRules?: TAccountMetas[33] | undefined;
     /** The Metaplex Token Authority Rules program account. */
-    authorizationRulesProgram?: TAccountMetas[22] | undefined;
+    authorizationRulesProgram?: TAccountMetas[34] | undefined;
     /** The token account of the NFT for the seller's wallet. */
-    takerTa: TAccountMetas[23];
+    takerTa: TAccountMetas[35];
     /** The TA of the owner, where the NFT will be transferred to as a result of this sale. */
-    ownerTa: TAccountMetas[24];
+    ownerTa: TAccountMetas[36];
     /** The TA of the pool, where the NFT token is temporarily escrowed as a result of this sale. */
-    poolTa: TAccountMetas[25];
+    poolTa: TAccountMetas[37];
     /** The Token Metadata owner/buyer token record account of the NFT. */
-    ownerTokenRecord?: TAccountMetas[26] | undefined;
+    ownerTokenRecord?: TAccountMetas[38] | undefined;
     /** Either the legacy token program or token-2022. */
-    tokenProgram: TAccountMetas[27];
+    tokenProgram: TAccountMetas[39];
     /** The SPL associated token program. */
-    associatedTokenProgram: TAccountMetas[28];
+    associatedTokenProgram: TAccountMetas[40];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[29];
+    systemProgram: TAccountMetas[41];
   };
   data: SellNftTokenPoolInstructionData;
 };
@@ -1245,7 +1607,7 @@ export function parseSellNftTokenPoolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedSellNftTokenPoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 30) {
+  if (instruction.accounts.length < 42) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -1278,6 +1640,18 @@ export function parseSellNftTokenPoolInstruction<
       ammProgram: getNextAccount(),
       escrowProgram: getNextOptionalAccount(),
       nativeProgram: getNextAccount(),
+      currency: getNextOptionalAccount(),
+      poolCurrencyTa: getNextOptionalAccount(),
+      takerCurrencyTa: getNextOptionalAccount(),
+      ownerCurrencyTa: getNextOptionalAccount(),
+      feeVaultCurrencyTa: getNextOptionalAccount(),
+      makerBrokerCurrencyTa: getNextOptionalAccount(),
+      takerBrokerCurrencyTa: getNextOptionalAccount(),
+      currencyTokenProgram: getNextOptionalAccount(),
+      currencyAssociatedTokenProgram: getNextOptionalAccount(),
+      priceLadder: getNextOptionalAccount(),
+      attestationSysvar: getNextOptionalAccount(),
+      traitRequirements: getNextOptionalAccount(),
       mint: getNextAccount(),
       metadata: getNextAccount(),
       edition: getNextAccount(),
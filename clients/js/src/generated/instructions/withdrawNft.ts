@@ -82,6 +82,10 @@ export type WithdrawNftInstruction<
   TAccountPool extends string | IAccountMeta<string> = string,
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountMintProof extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountMetadata extends string | IAccountMeta<string> = string,
   TAccountEdition extends string | IAccountMeta<string> = string,
@@ -123,6 +127,12 @@ export type WithdrawNftInstruction<
       TAccountMintProof extends string
         ? ReadonlyAccount<TAccountMintProof>
         : TAccountMintProof,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? ReadonlyAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
@@ -224,6 +234,8 @@ export type WithdrawNftAsyncInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -254,6 +266,10 @@ export type WithdrawNftAsyncInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -293,6 +309,8 @@ export async function getWithdrawNftInstructionAsync<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -315,6 +333,8 @@ export async function getWithdrawNftInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -339,6 +359,8 @@ export async function getWithdrawNftInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -365,6 +387,11 @@ export async function getWithdrawNftInstructionAsync<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -408,6 +435,10 @@ export async function getWithdrawNftInstructionAsync<
   const resolverScope = { programAddress, accounts, args };
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.metadata.value) {
     accounts.metadata = {
       ...accounts.metadata,
@@ -491,6 +522,8 @@ export async function getWithdrawNftInstructionAsync<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -517,6 +550,8 @@ export async function getWithdrawNftInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -542,6 +577,8 @@ export type WithdrawNftInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountMint extends string = string,
   TAccountMetadata extends string = string,
   TAccountEdition extends string = string,
@@ -572,6 +609,10 @@ export type WithdrawNftInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The mint account of the NFT. */
   mint: Address<TAccountMint>;
   /** The Token Metadata metadata account of the NFT. */
@@ -611,6 +652,8 @@ export function getWithdrawNftInstruction<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountMint extends string,
   TAccountMetadata extends string,
   TAccountEdition extends string,
@@ -633,6 +676,8 @@ export function getWithdrawNftInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -656,6 +701,8 @@ export function getWithdrawNftInstruction<
   TAccountPool,
   TAccountWhitelist,
   TAccountMintProof,
+  TAccountAmmProgram,
+  TAccountTraitRequirements,
   TAccountMint,
   TAccountMetadata,
   TAccountEdition,
@@ -681,6 +728,11 @@ export function getWithdrawNftInstruction<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     mint: { value: input.mint ?? null, isWritable: false },
     metadata: { value: input.metadata ?? null, isWritable: true },
     edition: { value: input.edition ?? null, isWritable: false },
@@ -724,6 +776,10 @@ export function getWithdrawNftInstruction<
   const resolverScope = { programAddress, accounts, args };
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -765,6 +821,8 @@ export function getWithdrawNftInstruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.metadata),
       getAccountMeta(accounts.edition),
@@ -791,6 +849,8 @@ export function getWithdrawNftInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountMint,
     TAccountMetadata,
     TAccountEdition,
@@ -833,36 +893,40 @@ export type ParsedWithdrawNftInstruction<
      */
 
     mintProof?: TAccountMetas[3] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[4];
+    /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+    traitRequirements?: TAccountMetas[5] | undefined;
     /** The mint account of the NFT. */
-    mint: TAccountMetas[4];
+    mint: TAccountMetas[6];
     /** The Token Metadata metadata account of the NFT. */
-    metadata: TAccountMetas[5];
+    metadata: TAccountMetas[7];
     /** The Token Metadata edition account of the NFT. */
-    edition: TAccountMetas[6];
+    edition: TAccountMetas[8];
     /** The Token Metadata source token record account of the NFT. */
-    userTokenRecord?: TAccountMetas[7] | undefined;
+    userTokenRecord?: TAccountMetas[9] | undefined;
     /** The Token Metadata token record for the destination. */
-    poolTokenRecord?: TAccountMetas[8] | undefined;
+    poolTokenRecord?: TAccountMetas[10] | undefined;
     /** The Token Metadata program account. */
-    tokenMetadataProgram?: TAccountMetas[9] | undefined;
+    tokenMetadataProgram?: TAccountMetas[11] | undefined;
     /** The sysvar instructions account. */
-    sysvarInstructions?: TAccountMetas[10] | undefined;
+    sysvarInstructions?: TAccountMetas[12] | undefined;
     /** The Metaplex Token Authority Rules account that stores royalty enforcement rules. */
-    authorizationRules?: TAccountMetas[11] | undefined;
+    authorizationRules?: TAccountMetas[13] | undefined;
     /** The Metaplex Token Authority Rules program account. */
-    authorizationRulesProgram?: TAccountMetas[12] | undefined;
+    authorizationRulesProgram?: TAccountMetas[14] | undefined;
     /** The NFT deposit receipt, which ties an NFT to the pool it was deposited to. */
-    nftReceipt: TAccountMetas[13];
+    nftReceipt: TAccountMetas[15];
     /** The TA of the owner, where the NFT will be transferred to as a result of this action. */
-    ownerTa: TAccountMetas[14];
+    ownerTa: TAccountMetas[16];
     /** The TA of the pool, where the NFT token is escrowed. */
-    poolTa: TAccountMetas[15];
+    poolTa: TAccountMetas[17];
     /** The SPL Token program for the Mint and ATAs. */
-    tokenProgram: TAccountMetas[16];
+    tokenProgram: TAccountMetas[18];
     /** The SPL associated token program. */
-    associatedTokenProgram: TAccountMetas[17];
+    associatedTokenProgram: TAccountMetas[19];
     /** The Solana system program. */
-    systemProgram: TAccountMetas[18];
+    systemProgram: TAccountMetas[20];
   };
   data: WithdrawNftInstructionData;
 };
@@ -875,7 +939,7 @@ export function parseWithdrawNftInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawNftInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 19) {
+  if (instruction.accounts.length < 21) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -898,6 +962,8 @@ export function parseWithdrawNftInstruction<
       pool: getNextAccount(),
       whitelist: getNextOptionalAccount(),
       mintProof: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
       mint: getNextAccount(),
       metadata: getNextAccount(),
       edition: getNextAccount(),
@@ -54,6 +54,10 @@ export type WithdrawNftCoreInstruction<
   TAccountPool extends string | IAccountMeta<string> = string,
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountMintProof extends string | IAccountMeta<string> = string,
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
+  TAccountTraitRequirements extends string | IAccountMeta<string> = string,
   TAccountAsset extends string | IAccountMeta<string> = string,
   TAccountCollection extends string | IAccountMeta<string> = string,
   TAccountMplCoreProgram extends
@@ -78,6 +82,12 @@ export type WithdrawNftCoreInstruction<
       TAccountMintProof extends string
         ? ReadonlyAccount<TAccountMintProof>
         : TAccountMintProof,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
+      TAccountTraitRequirements extends string
+        ? ReadonlyAccount<TAccountTraitRequirements>
+        : TAccountTraitRequirements,
       TAccountAsset extends string
         ? WritableAccount<TAccountAsset>
         : TAccountAsset,
@@ -128,6 +138,8 @@ export type WithdrawNftCoreAsyncInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -147,6 +159,10 @@ export type WithdrawNftCoreAsyncInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The MPL core asset account. */
   asset: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
@@ -161,6 +177,8 @@ export async function getWithdrawNftCoreInstructionAsync<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
@@ -172,6 +190,8 @@ export async function getWithdrawNftCoreInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -185,6 +205,8 @@ export async function getWithdrawNftCoreInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -200,6 +222,11 @@ export async function getWithdrawNftCoreInstructionAsync<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -211,6 +238,10 @@ export async function getWithdrawNftCoreInstructionAsync<
   >;
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -229,6 +260,8 @@ export async function getWithdrawNftCoreInstructionAsync<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
@@ -242,6 +275,8 @@ export async function getWithdrawNftCoreInstructionAsync<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -256,6 +291,8 @@ export type WithdrawNftCoreInput<
   TAccountPool extends string = string,
   TAccountWhitelist extends string = string,
   TAccountMintProof extends string = string,
+  TAccountAmmProgram extends string = string,
+  TAccountTraitRequirements extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -275,6 +312,10 @@ export type WithdrawNftCoreInput<
    * merkle proof condition in the whitelist.
    */
   mintProof?: Address<TAccountMintProof>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
+  /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+  traitRequirements?: Address<TAccountTraitRequirements>;
   /** The MPL core asset account. */
   asset: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
@@ -289,6 +330,8 @@ export function getWithdrawNftCoreInstruction<
   TAccountPool extends string,
   TAccountWhitelist extends string,
   TAccountMintProof extends string,
+  TAccountAmmProgram extends string,
+  TAccountTraitRequirements extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
@@ -300,6 +343,8 @@ export function getWithdrawNftCoreInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -312,6 +357,8 @@ export function getWithdrawNftCoreInstruction<
   TAccountPool,
   TAccountWhitelist,
   TAccountMintProof,
+  TAccountAmmProgram,
+  TAccountTraitRequirements,
   TAccountAsset,
   TAccountCollection,
   TAccountMplCoreProgram,
@@ -326,6 +373,11 @@ export function getWithdrawNftCoreInstruction<
     pool: { value: input.pool ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: false },
     mintProof: { value: input.mintProof ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
+    traitRequirements: {
+      value: input.traitRequirements ?? null,
+      isWritable: false,
+    },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
@@ -337,6 +389,10 @@ export function getWithdrawNftCoreInstruction<
   >;
 
   // Resolve default values.
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -349,6 +405,8 @@ export function getWithdrawNftCoreInstruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.mintProof),
+      getAccountMeta(accounts.ammProgram),
+      getAccountMeta(accounts.traitRequirements),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
@@ -362,6 +420,8 @@ export function getWithdrawNftCoreInstruction<
     TAccountPool,
     TAccountWhitelist,
     TAccountMintProof,
+    TAccountAmmProgram,
+    TAccountTraitRequirements,
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
@@ -393,13 +453,17 @@ export type ParsedWithdrawNftCoreInstruction<
      */
 
     mintProof?: TAccountMetas[3] | undefined;
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[4];
+    /** The traits required by the pool; required for deposits if the pool has trait requirements. */
+    traitRequirements?: TAccountMetas[5] | undefined;
     /** The MPL core asset account. */
-    asset: TAccountMetas[4];
-    collection?: TAccountMetas[5] | undefined;
+    asset: TAccountMetas[6];
+    collection?: TAccountMetas[7] | undefined;
     /** The MPL Core program. */
-    mplCoreProgram: TAccountMetas[6];
+    mplCoreProgram: TAccountMetas[8];
     /** The NFT receipt account denoting that an NFT has been deposited into this pool. */
-    nftReceipt: TAccountMetas[7];
+    nftReceipt: TAccountMetas[9];
   };
   data: WithdrawNftCoreInstructionData;
 };
@@ -412,7 +476,7 @@ export function parseWithdrawNftCoreInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawNftCoreInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -435,6 +499,8 @@ export function parseWithdrawNftCoreInstruction<
       pool: getNextAccount(),
       whitelist: getNextOptionalAccount(),
       mintProof: getNextOptionalAccount(),
+      ammProgram: getNextAccount(),
+      traitRequirements: getNextOptionalAccount(),
       asset: getNextAccount(),
       collection: getNextOptionalAccount(),
       mplCoreProgram: getNextAccount(),
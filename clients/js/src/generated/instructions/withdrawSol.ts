@@ -52,6 +52,9 @@ export type WithdrawSolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountAmmProgram extends
+    | string
+    | IAccountMeta<string> = 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -67,6 +70,9 @@ export type WithdrawSolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountAmmProgram extends string
+        ? ReadonlyAccount<TAccountAmmProgram>
+        : TAccountAmmProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -109,6 +115,7 @@ export type WithdrawSolInput<
   TAccountOwner extends string = string,
   TAccountPool extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAmmProgram extends string = string,
 > = {
   /** The owner of the pool and will receive the SOL. */
   owner: TransactionSigner<TAccountOwner>;
@@ -116,6 +123,8 @@ export type WithdrawSolInput<
   pool: Address<TAccountPool>;
   /** The Solana system program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** The AMM program account, used for self-cpi logging. */
+  ammProgram?: Address<TAccountAmmProgram>;
   lamports: WithdrawSolInstructionDataArgs['lamports'];
 };
 
@@ -123,15 +132,22 @@ export function getWithdrawSolInstruction<
   TAccountOwner extends string,
   TAccountPool extends string,
   TAccountSystemProgram extends string,
+  TAccountAmmProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_AMM_PROGRAM_ADDRESS,
 >(
-  input: WithdrawSolInput<TAccountOwner, TAccountPool, TAccountSystemProgram>,
+  input: WithdrawSolInput<
+    TAccountOwner,
+    TAccountPool,
+    TAccountSystemProgram,
+    TAccountAmmProgram
+  >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawSolInstruction<
   TProgramAddress,
   TAccountOwner,
   TAccountPool,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountAmmProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? TENSOR_AMM_PROGRAM_ADDRESS;
@@ -141,6 +157,7 @@ export function getWithdrawSolInstruction<
     owner: { value: input.owner ?? null, isWritable: true },
     pool: { value: input.pool ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    ammProgram: { value: input.ammProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -155,6 +172,10 @@ export function getWithdrawSolInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.ammProgram.value) {
+    accounts.ammProgram.value =
+      'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg' as Address<'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -162,6 +183,7 @@ export function getWithdrawSolInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.ammProgram),
     ],
     programAddress,
     data: getWithdrawSolInstructionDataEncoder().encode(
@@ -171,7 +193,8 @@ export function getWithdrawSolInstruction<
     TProgramAddress,
     TAccountOwner,
     TAccountPool,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAmmProgram
   >;
 
   return instruction;
@@ -189,6 +212,8 @@ export type ParsedWithdrawSolInstruction<
     pool: TAccountMetas[1];
     /** The Solana system program. */
     systemProgram: TAccountMetas[2];
+    /** The AMM program account, used for self-cpi logging. */
+    ammProgram: TAccountMetas[3];
   };
   data: WithdrawSolInstructionData;
 };
@@ -201,7 +226,7 @@ export function parseWithdrawSolInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawSolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -217,6 +242,7 @@ export function parseWithdrawSolInstruction<
       owner: getNextAccount(),
       pool: getNextAccount(),
       systemProgram: getNextAccount(),
+      ammProgram: getNextAccount(),
     },
     data: getWithdrawSolInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,28 +14,64 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedAttachSharedEscrowInstruction,
+  type ParsedBuyNftCompressedInstruction,
   type ParsedBuyNftCoreInstruction,
   type ParsedBuyNftInstruction,
   type ParsedBuyNftT22Instruction,
+  type ParsedBuyNftWnsInstruction,
+  type ParsedBuyNftsCoreInstruction,
+  type ParsedBuyNftsInstruction,
+  type ParsedBuyNftsT22Instruction,
+  type ParsedBuyNftsWnsInstruction,
   type ParsedCloseExpiredPoolInstruction,
   type ParsedClosePoolInstruction,
+  type ParsedConvertPoolInstruction,
   type ParsedCreatePoolInstruction,
+  type ParsedDepositNftCompressedInstruction,
   type ParsedDepositNftCoreInstruction,
   type ParsedDepositNftInstruction,
   type ParsedDepositNftT22Instruction,
+  type ParsedDepositNftWnsInstruction,
   type ParsedDepositSolInstruction,
+  type ParsedDepositTokenInstruction,
+  type ParsedDetachSharedEscrowInstruction,
   type ParsedEditPoolInstruction,
+  type ParsedSellNftTokenPoolCompressedInstruction,
   type ParsedSellNftTokenPoolCoreInstruction,
   type ParsedSellNftTokenPoolInstruction,
   type ParsedSellNftTokenPoolT22Instruction,
+  type ParsedSellNftTokenPoolWnsInstruction,
+  type ParsedSellNftTradePoolCompressedInstruction,
   type ParsedSellNftTradePoolCoreInstruction,
   type ParsedSellNftTradePoolInstruction,
   type ParsedSellNftTradePoolT22Instruction,
+  type ParsedSellNftTradePoolWnsInstruction,
+  type ParsedSellNftsTokenPoolCoreInstruction,
+  type ParsedSellNftsTokenPoolInstruction,
+  type ParsedSellNftsTokenPoolT22Instruction,
+  type ParsedSellNftsTokenPoolWnsInstruction,
+  type ParsedSellNftsTradePoolCoreInstruction,
+  type ParsedSellNftsTradePoolInstruction,
+  type ParsedSellNftsTradePoolT22Instruction,
+  type ParsedSellNftsTradePoolWnsInstruction,
+  type ParsedSetPoolStatusInstruction,
+  type ParsedSetTraitRequirementsInstruction,
   type ParsedTammNoopInstruction,
+  type ParsedTransferPoolInstruction,
+  type ParsedUpgradePoolInstruction,
+  type ParsedWithdrawExpiredNftCompressedInstruction,
+  type ParsedWithdrawExpiredNftCoreInstruction,
+  type ParsedWithdrawExpiredNftInstruction,
+  type ParsedWithdrawExpiredNftT22Instruction,
+  type ParsedWithdrawExpiredNftWnsInstruction,
+  type ParsedWithdrawNftCompressedInstruction,
   type ParsedWithdrawNftCoreInstruction,
   type ParsedWithdrawNftInstruction,
   type ParsedWithdrawNftT22Instruction,
+  type ParsedWithdrawNftWnsInstruction,
   type ParsedWithdrawSolInstruction,
+  type ParsedWithdrawTokenInstruction,
 } from '../instructions';
 
 export const TENSOR_AMM_PROGRAM_ADDRESS =
@@ -45,6 +81,8 @@ export enum TensorAmmAccount {
   AssetDepositReceipt,
   NftDepositReceipt,
   Pool,
+  PriceLadder,
+  TraitRequirements,
 }
 
 export function identifyTensorAmmAccount(
@@ -84,6 +122,28 @@ export function identifyTensorAmmAccount(
   ) {
     return TensorAmmAccount.Pool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([115, 197, 53, 144, 146, 104, 45, 38])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmAccount.PriceLadder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([25, 255, 4, 178, 58, 107, 107, 251])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmAccount.TraitRequirements;
+  }
   throw new Error(
     'The provided account could not be identified as a tensorAmm account.'
   );
@@ -93,25 +153,61 @@ export enum TensorAmmInstruction {
   TammNoop,
   CreatePool,
   EditPool,
+  ConvertPool,
+  UpgradePool,
+  SetPoolStatus,
+  SetTraitRequirements,
+  TransferPool,
   ClosePool,
   CloseExpiredPool,
   DepositSol,
   WithdrawSol,
+  DepositToken,
+  WithdrawToken,
+  AttachSharedEscrow,
+  DetachSharedEscrow,
   DepositNft,
   WithdrawNft,
+  WithdrawExpiredNft,
   BuyNft,
+  BuyNfts,
   SellNftTokenPool,
   SellNftTradePool,
+  SellNftsTokenPool,
+  SellNftsTradePool,
   DepositNftCore,
   WithdrawNftCore,
+  WithdrawExpiredNftCore,
   BuyNftCore,
+  BuyNftsCore,
   SellNftTokenPoolCore,
   SellNftTradePoolCore,
+  SellNftsTokenPoolCore,
+  SellNftsTradePoolCore,
   DepositNftT22,
   WithdrawNftT22,
+  WithdrawExpiredNftT22,
   BuyNftT22,
+  BuyNftsT22,
   SellNftTokenPoolT22,
   SellNftTradePoolT22,
+  SellNftsTokenPoolT22,
+  SellNftsTradePoolT22,
+  DepositNftWns,
+  WithdrawNftWns,
+  WithdrawExpiredNftWns,
+  BuyNftWns,
+  BuyNftsWns,
+  SellNftTokenPoolWns,
+  SellNftTradePoolWns,
+  SellNftsTokenPoolWns,
+  SellNftsTradePoolWns,
+  DepositNftCompressed,
+  WithdrawNftCompressed,
+  WithdrawExpiredNftCompressed,
+  BuyNftCompressed,
+  SellNftTokenPoolCompressed,
+  SellNftTradePoolCompressed,
 }
 
 export function identifyTensorAmmInstruction(
@@ -151,6 +247,61 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.EditPool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([151, 57, 212, 222, 106, 41, 95, 254])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.ConvertPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([108, 204, 192, 255, 183, 148, 118, 248])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.UpgradePool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 87, 135, 223, 83, 204, 132, 53])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SetPoolStatus;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([117, 187, 207, 230, 234, 178, 68, 78])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SetTraitRequirements;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 141, 158, 204, 75, 171, 90, 135])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.TransferPool;
+  }
   if (
     containsBytes(
       data,
@@ -195,6 +346,50 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.WithdrawSol;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 156, 96, 218, 39, 163, 180, 19])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.DepositToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([136, 235, 181, 5, 101, 109, 57, 81])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([12, 116, 145, 18, 246, 88, 252, 230])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.AttachSharedEscrow;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([115, 216, 236, 9, 96, 185, 210, 168])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.DetachSharedEscrow;
+  }
   if (
     containsBytes(
       data,
@@ -217,6 +412,17 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.WithdrawNft;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([189, 11, 24, 32, 178, 35, 135, 76])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawExpiredNft;
+  }
   if (
     containsBytes(
       data,
@@ -228,6 +434,17 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.BuyNft;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 55, 200, 94, 215, 89, 37, 105])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.BuyNfts;
+  }
   if (
     containsBytes(
       data,
@@ -250,6 +467,28 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.SellNftTradePool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([224, 250, 240, 197, 58, 157, 20, 98])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTokenPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([179, 201, 106, 233, 56, 195, 144, 221])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTradePool;
+  }
   if (
     containsBytes(
       data,
@@ -272,6 +511,17 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.WithdrawNftCore;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([180, 89, 215, 79, 96, 168, 1, 63])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawExpiredNftCore;
+  }
   if (
     containsBytes(
       data,
@@ -283,6 +533,17 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.BuyNftCore;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([202, 32, 106, 251, 96, 32, 55, 197])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.BuyNftsCore;
+  }
   if (
     containsBytes(
       data,
@@ -305,6 +566,28 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.SellNftTradePoolCore;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([212, 190, 200, 157, 93, 121, 9, 190])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTokenPoolCore;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([36, 70, 215, 90, 46, 31, 67, 88])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTradePoolCore;
+  }
   if (
     containsBytes(
       data,
@@ -327,6 +610,17 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.WithdrawNftT22;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 66, 178, 42, 20, 150, 166, 135])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawExpiredNftT22;
+  }
   if (
     containsBytes(
       data,
@@ -338,6 +632,17 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.BuyNftT22;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 60, 164, 174, 82, 204, 253, 188])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.BuyNftsT22;
+  }
   if (
     containsBytes(
       data,
@@ -360,6 +665,193 @@ export function identifyTensorAmmInstruction(
   ) {
     return TensorAmmInstruction.SellNftTradePoolT22;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([123, 31, 51, 206, 249, 78, 236, 37])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTokenPoolT22;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([178, 217, 232, 215, 231, 17, 74, 53])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTradePoolT22;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([215, 239, 219, 97, 69, 243, 135, 204])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.DepositNftWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 189, 77, 227, 118, 39, 250, 89])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawNftWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 133, 116, 63, 222, 177, 83, 74])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawExpiredNftWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([223, 199, 65, 82, 203, 241, 24, 153])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.BuyNftWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([233, 52, 136, 240, 3, 40, 73, 197])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.BuyNftsWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 81, 81, 119, 219, 152, 148, 82])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftTokenPoolWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 87, 205, 245, 40, 176, 167, 138])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftTradePoolWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([193, 35, 8, 135, 89, 207, 247, 68])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTokenPoolWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([198, 220, 99, 234, 105, 120, 202, 248])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftsTradePoolWns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 92, 133, 62, 44, 65, 152, 75])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.DepositNftCompressed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 131, 171, 201, 141, 109, 40, 51])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawNftCompressed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([7, 125, 24, 40, 88, 168, 71, 209])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.WithdrawExpiredNftCompressed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([182, 189, 166, 174, 26, 182, 7, 115])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.BuyNftCompressed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 3, 223, 117, 207, 222, 241, 24])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftTokenPoolCompressed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([143, 64, 200, 37, 75, 182, 4, 54])
+      ),
+      0
+    )
+  ) {
+    return TensorAmmInstruction.SellNftTradePoolCompressed;
+  }
   throw new Error(
     'The provided instruction could not be identified as a tensorAmm instruction.'
   );
@@ -377,6 +869,21 @@ export type ParsedTensorAmmInstruction<
   | ({
       instructionType: TensorAmmInstruction.EditPool;
     } & ParsedEditPoolInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.ConvertPool;
+    } & ParsedConvertPoolInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.UpgradePool;
+    } & ParsedUpgradePoolInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SetPoolStatus;
+    } & ParsedSetPoolStatusInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SetTraitRequirements;
+    } & ParsedSetTraitRequirementsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.TransferPool;
+    } & ParsedTransferPoolInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.ClosePool;
     } & ParsedClosePoolInstruction<TProgram>)
@@ -389,48 +896,141 @@ export type ParsedTensorAmmInstruction<
   | ({
       instructionType: TensorAmmInstruction.WithdrawSol;
     } & ParsedWithdrawSolInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.DepositToken;
+    } & ParsedDepositTokenInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawToken;
+    } & ParsedWithdrawTokenInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.AttachSharedEscrow;
+    } & ParsedAttachSharedEscrowInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.DetachSharedEscrow;
+    } & ParsedDetachSharedEscrowInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.DepositNft;
     } & ParsedDepositNftInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.WithdrawNft;
     } & ParsedWithdrawNftInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawExpiredNft;
+    } & ParsedWithdrawExpiredNftInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.BuyNft;
     } & ParsedBuyNftInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.BuyNfts;
+    } & ParsedBuyNftsInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.SellNftTokenPool;
     } & ParsedSellNftTokenPoolInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.SellNftTradePool;
     } & ParsedSellNftTradePoolInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTokenPool;
+    } & ParsedSellNftsTokenPoolInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTradePool;
+    } & ParsedSellNftsTradePoolInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.DepositNftCore;
     } & ParsedDepositNftCoreInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.WithdrawNftCore;
     } & ParsedWithdrawNftCoreInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawExpiredNftCore;
+    } & ParsedWithdrawExpiredNftCoreInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.BuyNftCore;
     } & ParsedBuyNftCoreInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.BuyNftsCore;
+    } & ParsedBuyNftsCoreInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.SellNftTokenPoolCore;
     } & ParsedSellNftTokenPoolCoreInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.SellNftTradePoolCore;
     } & ParsedSellNftTradePoolCoreInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTokenPoolCore;
+    } & ParsedSellNftsTokenPoolCoreInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTradePoolCore;
+    } & ParsedSellNftsTradePoolCoreInstruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.DepositNftT22;
     } & ParsedDepositNftT22Instruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.WithdrawNftT22;
     } & ParsedWithdrawNftT22Instruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawExpiredNftT22;
+    } & ParsedWithdrawExpiredNftT22Instruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.BuyNftT22;
     } & ParsedBuyNftT22Instruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.BuyNftsT22;
+    } & ParsedBuyNftsT22Instruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.SellNftTokenPoolT22;
     } & ParsedSellNftTokenPoolT22Instruction<TProgram>)
   | ({
       instructionType: TensorAmmInstruction.SellNftTradePoolT22;
-    } & ParsedSellNftTradePoolT22Instruction<TProgram>);
+    } & ParsedSellNftTradePoolT22Instruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTokenPoolT22;
+    } & ParsedSellNftsTokenPoolT22Instruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTradePoolT22;
+    } & ParsedSellNftsTradePoolT22Instruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.DepositNftWns;
+    } & ParsedDepositNftWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawNftWns;
+    } & ParsedWithdrawNftWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawExpiredNftWns;
+    } & ParsedWithdrawExpiredNftWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.BuyNftWns;
+    } & ParsedBuyNftWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.BuyNftsWns;
+    } & ParsedBuyNftsWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftTokenPoolWns;
+    } & ParsedSellNftTokenPoolWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftTradePoolWns;
+    } & ParsedSellNftTradePoolWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTokenPoolWns;
+    } & ParsedSellNftsTokenPoolWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftsTradePoolWns;
+    } & ParsedSellNftsTradePoolWnsInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.DepositNftCompressed;
+    } & ParsedDepositNftCompressedInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawNftCompressed;
+    } & ParsedWithdrawNftCompressedInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.WithdrawExpiredNftCompressed;
+    } & ParsedWithdrawExpiredNftCompressedInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.BuyNftCompressed;
+    } & ParsedBuyNftCompressedInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftTokenPoolCompressed;
+    } & ParsedSellNftTokenPoolCompressedInstruction<TProgram>)
+  | ({
+      instructionType: TensorAmmInstruction.SellNftTradePoolCompressed;
+    } & ParsedSellNftTradePoolCompressedInstruction<TProgram>);
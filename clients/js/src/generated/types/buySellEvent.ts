@@ -17,7 +17,13 @@ import {
   type Encoder,
 } from '@solana/web3.js';
 
-/** Event emitted when a user buys or sells tokens. */
+/**
+ * Event emitted when a user buys or sells tokens.
+ *
+ * No longer emitted: superseded by `BuySellEventV2`, which is only emitted once the trade has
+ * settled. Kept so the indices of the other events don't change.
+ */
+
 export type BuySellEvent = {
   currentPrice: bigint;
   takerFee: bigint;
@@ -21,11 +21,22 @@ import {
  * Linear curves have price offsets that increase or decrease linearly.
  *
  * Exponential curves have a price offset that increases or decreases exponentially.
+ *
+ * XYK curves price NFTs from the pool's reserves (`amount` and `nfts_held`) so that their
+ * product stays constant, and are only available to Trade pools.
+ *
+ * GDA curves are gradual Dutch auctions: the price decays towards a floor while the pool doesn't
+ * trade, and bumps back up by `delta` basis points after each fill. Only available to NFT pools.
+ *
+ * Custom curves read their prices from the pool's `PriceLadder` account, indexed by the price offset.
  */
 
 export enum CurveType {
   Linear,
   Exponential,
+  Xyk,
+  Gda,
+  Custom,
 }
 
 export type CurveTypeArgs = CurveType;
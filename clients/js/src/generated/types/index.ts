@@ -6,18 +6,47 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './attachSharedEscrowEvent';
 export * from './authorizationDataLocal';
 export * from './buySellEvent';
+export * from './buySellEventV2';
+export * from './circuitBreaker';
+export * from './circuitBreakerArgs';
+export * from './closePoolEvent';
+export * from './closePoolReason';
+export * from './collectionLocal';
+export * from './compressedArgs';
+export * from './cosignerAttestation';
+export * from './createPoolEvent';
+export * from './creatorLocal';
 export * from './curveType';
+export * from './depositCurrencyEvent';
+export * from './depositNftEvent';
+export * from './detachSharedEscrowEvent';
 export * from './direction';
 export * from './editPoolConfig';
+export * from './editPoolEvent';
+export * from './editPoolWhitelistEvent';
+export * from './metadataArgsLocal';
+export * from './migratedNft';
 export * from './payloadTypeLocal';
 export * from './poolConfig';
 export * from './poolStats';
 export * from './poolType';
+export * from './poolV1';
+export * from './priceLadderArgs';
+export * from './priceModifier';
 export * from './proofInfoLocal';
 export * from './seedsVecLocal';
 export * from './taggedPayload';
 export * from './takerSide';
 export * from './tAmmEvent';
+export * from './tokenProgramVersionLocal';
+export * from './tokenStandardLocal';
+export * from './traitRequirement';
 export * from './transferDirection';
+export * from './transferPoolEvent';
+export * from './useMethodLocal';
+export * from './usesLocal';
+export * from './withdrawCurrencyEvent';
+export * from './withdrawNftEvent';
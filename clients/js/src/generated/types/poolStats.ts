@@ -19,7 +19,7 @@ import {
   type Encoder,
 } from '@solana/web3.js';
 
-/** Stats for a pool include the number of buys and sells, and the accumulated MM profit. */
+/** Stats for a pool include the number of buys and sells and the accumulated MM profit. */
 export type PoolStats = {
   takerSellCount: number;
   takerBuyCount: number;
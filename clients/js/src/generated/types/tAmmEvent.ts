@@ -21,22 +21,117 @@ import {
   type GetDiscriminatedUnionVariantContent,
 } from '@solana/web3.js';
 import {
+  getAttachSharedEscrowEventDecoder,
+  getAttachSharedEscrowEventEncoder,
   getBuySellEventDecoder,
   getBuySellEventEncoder,
+  getBuySellEventV2Decoder,
+  getBuySellEventV2Encoder,
+  getClosePoolEventDecoder,
+  getClosePoolEventEncoder,
+  getCreatePoolEventDecoder,
+  getCreatePoolEventEncoder,
+  getDepositCurrencyEventDecoder,
+  getDepositCurrencyEventEncoder,
+  getDepositNftEventDecoder,
+  getDepositNftEventEncoder,
+  getDetachSharedEscrowEventDecoder,
+  getDetachSharedEscrowEventEncoder,
+  getEditPoolEventDecoder,
+  getEditPoolEventEncoder,
+  getEditPoolWhitelistEventDecoder,
+  getEditPoolWhitelistEventEncoder,
+  getTransferPoolEventDecoder,
+  getTransferPoolEventEncoder,
+  getWithdrawCurrencyEventDecoder,
+  getWithdrawCurrencyEventEncoder,
+  getWithdrawNftEventDecoder,
+  getWithdrawNftEventEncoder,
+  type AttachSharedEscrowEvent,
+  type AttachSharedEscrowEventArgs,
   type BuySellEvent,
   type BuySellEventArgs,
+  type BuySellEventV2,
+  type BuySellEventV2Args,
+  type ClosePoolEvent,
+  type ClosePoolEventArgs,
+  type CreatePoolEvent,
+  type CreatePoolEventArgs,
+  type DepositCurrencyEvent,
+  type DepositCurrencyEventArgs,
+  type DepositNftEvent,
+  type DepositNftEventArgs,
+  type DetachSharedEscrowEvent,
+  type DetachSharedEscrowEventArgs,
+  type EditPoolEvent,
+  type EditPoolEventArgs,
+  type EditPoolWhitelistEvent,
+  type EditPoolWhitelistEventArgs,
+  type TransferPoolEvent,
+  type TransferPoolEventArgs,
+  type WithdrawCurrencyEvent,
+  type WithdrawCurrencyEventArgs,
+  type WithdrawNftEvent,
+  type WithdrawNftEventArgs,
 } from '.';
 
 /** Enum for events emitted by the AMM program. */
-export type TAmmEvent = {
-  __kind: 'BuySellEvent';
-  fields: readonly [BuySellEvent];
-};
+export type TAmmEvent =
+  | { __kind: 'BuySellEvent'; fields: readonly [BuySellEvent] }
+  | { __kind: 'CreatePoolEvent'; fields: readonly [CreatePoolEvent] }
+  | { __kind: 'EditPoolEvent'; fields: readonly [EditPoolEvent] }
+  | { __kind: 'DepositNftEvent'; fields: readonly [DepositNftEvent] }
+  | { __kind: 'WithdrawNftEvent'; fields: readonly [WithdrawNftEvent] }
+  | { __kind: 'DepositCurrencyEvent'; fields: readonly [DepositCurrencyEvent] }
+  | {
+      __kind: 'WithdrawCurrencyEvent';
+      fields: readonly [WithdrawCurrencyEvent];
+    }
+  | { __kind: 'ClosePoolEvent'; fields: readonly [ClosePoolEvent] }
+  | { __kind: 'BuySellEventV2'; fields: readonly [BuySellEventV2] }
+  | {
+      __kind: 'EditPoolWhitelistEvent';
+      fields: readonly [EditPoolWhitelistEvent];
+    }
+  | { __kind: 'TransferPoolEvent'; fields: readonly [TransferPoolEvent] }
+  | {
+      __kind: 'AttachSharedEscrowEvent';
+      fields: readonly [AttachSharedEscrowEvent];
+    }
+  | {
+      __kind: 'DetachSharedEscrowEvent';
+      fields: readonly [DetachSharedEscrowEvent];
+    };
 
-export type TAmmEventArgs = {
-  __kind: 'BuySellEvent';
-  fields: readonly [BuySellEventArgs];
-};
+export type TAmmEventArgs =
+  | { __kind: 'BuySellEvent'; fields: readonly [BuySellEventArgs] }
+  | { __kind: 'CreatePoolEvent'; fields: readonly [CreatePoolEventArgs] }
+  | { __kind: 'EditPoolEvent'; fields: readonly [EditPoolEventArgs] }
+  | { __kind: 'DepositNftEvent'; fields: readonly [DepositNftEventArgs] }
+  | { __kind: 'WithdrawNftEvent'; fields: readonly [WithdrawNftEventArgs] }
+  | {
+      __kind: 'DepositCurrencyEvent';
+      fields: readonly [DepositCurrencyEventArgs];
+    }
+  | {
+      __kind: 'WithdrawCurrencyEvent';
+      fields: readonly [WithdrawCurrencyEventArgs];
+    }
+  | { __kind: 'ClosePoolEvent'; fields: readonly [ClosePoolEventArgs] }
+  | { __kind: 'BuySellEventV2'; fields: readonly [BuySellEventV2Args] }
+  | {
+      __kind: 'EditPoolWhitelistEvent';
+      fields: readonly [EditPoolWhitelistEventArgs];
+    }
+  | { __kind: 'TransferPoolEvent'; fields: readonly [TransferPoolEventArgs] }
+  | {
+      __kind: 'AttachSharedEscrowEvent';
+      fields: readonly [AttachSharedEscrowEventArgs];
+    }
+  | {
+      __kind: 'DetachSharedEscrowEvent';
+      fields: readonly [DetachSharedEscrowEventArgs];
+    };
 
 export function getTAmmEventEncoder(): Encoder<TAmmEventArgs> {
   return getDiscriminatedUnionEncoder([
@@ -46,6 +141,78 @@ export function getTAmmEventEncoder(): Encoder<TAmmEventArgs> {
         ['fields', getTupleEncoder([getBuySellEventEncoder()])],
       ]),
     ],
+    [
+      'CreatePoolEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getCreatePoolEventEncoder()])],
+      ]),
+    ],
+    [
+      'EditPoolEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getEditPoolEventEncoder()])],
+      ]),
+    ],
+    [
+      'DepositNftEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getDepositNftEventEncoder()])],
+      ]),
+    ],
+    [
+      'WithdrawNftEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getWithdrawNftEventEncoder()])],
+      ]),
+    ],
+    [
+      'DepositCurrencyEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getDepositCurrencyEventEncoder()])],
+      ]),
+    ],
+    [
+      'WithdrawCurrencyEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getWithdrawCurrencyEventEncoder()])],
+      ]),
+    ],
+    [
+      'ClosePoolEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getClosePoolEventEncoder()])],
+      ]),
+    ],
+    [
+      'BuySellEventV2',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getBuySellEventV2Encoder()])],
+      ]),
+    ],
+    [
+      'EditPoolWhitelistEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getEditPoolWhitelistEventEncoder()])],
+      ]),
+    ],
+    [
+      'TransferPoolEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getTransferPoolEventEncoder()])],
+      ]),
+    ],
+    [
+      'AttachSharedEscrowEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getAttachSharedEscrowEventEncoder()])],
+      ]),
+    ],
+    [
+      'DetachSharedEscrowEvent',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getDetachSharedEscrowEventEncoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -57,6 +224,78 @@ export function getTAmmEventDecoder(): Decoder<TAmmEvent> {
         ['fields', getTupleDecoder([getBuySellEventDecoder()])],
       ]),
     ],
+    [
+      'CreatePoolEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getCreatePoolEventDecoder()])],
+      ]),
+    ],
+    [
+      'EditPoolEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getEditPoolEventDecoder()])],
+      ]),
+    ],
+    [
+      'DepositNftEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getDepositNftEventDecoder()])],
+      ]),
+    ],
+    [
+      'WithdrawNftEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getWithdrawNftEventDecoder()])],
+      ]),
+    ],
+    [
+      'DepositCurrencyEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getDepositCurrencyEventDecoder()])],
+      ]),
+    ],
+    [
+      'WithdrawCurrencyEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getWithdrawCurrencyEventDecoder()])],
+      ]),
+    ],
+    [
+      'ClosePoolEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getClosePoolEventDecoder()])],
+      ]),
+    ],
+    [
+      'BuySellEventV2',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getBuySellEventV2Decoder()])],
+      ]),
+    ],
+    [
+      'EditPoolWhitelistEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getEditPoolWhitelistEventDecoder()])],
+      ]),
+    ],
+    [
+      'TransferPoolEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getTransferPoolEventDecoder()])],
+      ]),
+    ],
+    [
+      'AttachSharedEscrowEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getAttachSharedEscrowEventDecoder()])],
+      ]),
+    ],
+    [
+      'DetachSharedEscrowEvent',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getDetachSharedEscrowEventDecoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -73,6 +312,122 @@ export function tAmmEvent(
     'BuySellEvent'
   >['fields']
 ): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'BuySellEvent'>;
+export function tAmmEvent(
+  kind: 'CreatePoolEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'CreatePoolEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'CreatePoolEvent'>;
+export function tAmmEvent(
+  kind: 'EditPoolEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'EditPoolEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'EditPoolEvent'>;
+export function tAmmEvent(
+  kind: 'DepositNftEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'DepositNftEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'DepositNftEvent'>;
+export function tAmmEvent(
+  kind: 'WithdrawNftEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'WithdrawNftEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'WithdrawNftEvent'>;
+export function tAmmEvent(
+  kind: 'DepositCurrencyEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'DepositCurrencyEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TAmmEventArgs,
+  '__kind',
+  'DepositCurrencyEvent'
+>;
+export function tAmmEvent(
+  kind: 'WithdrawCurrencyEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'WithdrawCurrencyEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TAmmEventArgs,
+  '__kind',
+  'WithdrawCurrencyEvent'
+>;
+export function tAmmEvent(
+  kind: 'ClosePoolEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'ClosePoolEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'ClosePoolEvent'>;
+export function tAmmEvent(
+  kind: 'BuySellEventV2',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'BuySellEventV2'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'BuySellEventV2'>;
+export function tAmmEvent(
+  kind: 'EditPoolWhitelistEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'EditPoolWhitelistEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TAmmEventArgs,
+  '__kind',
+  'EditPoolWhitelistEvent'
+>;
+export function tAmmEvent(
+  kind: 'TransferPoolEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'TransferPoolEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<TAmmEventArgs, '__kind', 'TransferPoolEvent'>;
+export function tAmmEvent(
+  kind: 'AttachSharedEscrowEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'AttachSharedEscrowEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TAmmEventArgs,
+  '__kind',
+  'AttachSharedEscrowEvent'
+>;
+export function tAmmEvent(
+  kind: 'DetachSharedEscrowEvent',
+  data: GetDiscriminatedUnionVariantContent<
+    TAmmEventArgs,
+    '__kind',
+    'DetachSharedEscrowEvent'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TAmmEventArgs,
+  '__kind',
+  'DetachSharedEscrowEvent'
+>;
 export function tAmmEvent<K extends TAmmEventArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -3,6 +3,7 @@ import {
   Account,
   Address,
   Base64EncodedDataResponse,
+  IInstruction,
   KeyPairSigner,
   ProgramDerivedAddress,
   SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM,
@@ -19,6 +20,7 @@ import {
   lamports,
   none,
   pipe,
+  signBytes,
   some,
 } from '@solana/web3.js';
 import {
@@ -55,6 +57,8 @@ import { ExecutionContext } from 'ava';
 import bs58 from 'bs58';
 import { v4 } from 'uuid';
 import {
+  CosignerAttestationArgs,
+  CreatePoolInput,
   CurveType,
   NftDepositReceipt,
   PoolConfig,
@@ -64,6 +68,7 @@ import {
   fetchNftDepositReceipt,
   findNftDepositReceiptPda,
   findPoolPda,
+  getCosignerAttestationEncoder,
   getCreatePoolInstruction,
   getDepositSolInstruction,
 } from '../src/index.js';
@@ -119,9 +124,9 @@ export const ONE_YEAR = 60 * 60 * 24 * 365;
 export const ZERO_ACCOUNT_RENT_LAMPORTS = 890880n;
 export const ONE_SOL = 1_000_000_000n;
 
-export const POOL_SIZE = 447n;
+export const POOL_SIZE = 591n;
 
-export const CURRENT_POOL_VERSION = 1;
+export const CURRENT_POOL_VERSION = 2;
 
 export const TAKER_FEE_BPS = 200n;
 export const BROKER_FEE_PCT = 50n;
@@ -321,7 +326,20 @@ export const nameToUint8Array = (name: string) => {
   return paddedName;
 };
 
-export interface CreatePoolParams {
+// Optional create_pool args the helpers pass through as is.
+export type CreatePoolOptions = Pick<
+  CreatePoolInput,
+  | 'decayBps'
+  | 'floorPrice'
+  | 'manager'
+  | 'maxTakerBuyCount'
+  | 'maxNftsHeld'
+  | 'maxTotalSpend'
+  | 'circuitBreaker'
+  | 'keeperBounty'
+>;
+
+export interface CreatePoolParams extends CreatePoolOptions {
   client: Client;
   whitelist: Address;
   owner: KeyPairSigner;
@@ -358,6 +376,7 @@ export async function createPool({
   poolId,
   config,
   expireInSec,
+  ...options
 }: CreatePoolParams): Promise<CreatePoolReturns> {
   // Pool values
 
@@ -394,6 +413,7 @@ export async function createPool({
     sharedEscrow: sharedEscrow ?? undefined,
     makerBroker: makerBroker ? some(makerBroker) : none(),
     expireInSec: expireInSec ?? null,
+    ...options,
   });
 
   await pipe(
@@ -553,6 +573,8 @@ export async function createPoolAndWhitelist({
   depositAmount = ONE_SOL,
   conditions,
   funded,
+  expireInSec,
+  ...options
 }: CreatePoolAndWhitelistParams) {
   const updateAuthority = await generateKeyPairSignerWithSol(client);
   const namespace = await generateKeyPairSigner();
@@ -586,6 +608,8 @@ export async function createPoolAndWhitelist({
     makerBroker,
     poolId,
     config,
+    expireInSec,
+    ...options,
   });
 
   if (funded && config?.poolType !== PoolType.NFT) {
@@ -779,6 +803,47 @@ export const expectCustomError = async (
   }
 };
 
+export const ED25519_PROGRAM_ID: Address = address(
+  'Ed25519SigVerify111111111111111111111111111'
+);
+export const SYSVAR_INSTRUCTIONS_ID: Address = address(
+  'Sysvar1nstructions1111111111111111111111111'
+);
+
+// Ed25519 signature verification instruction carrying a cosigner attestation, to be
+// placed in the same transaction before the trade it approves.
+export async function getCosignerAttestationInstruction(
+  cosigner: KeyPairSigner,
+  attestation: CosignerAttestationArgs
+): Promise<IInstruction> {
+  const message = getCosignerAttestationEncoder().encode(attestation);
+  const signature = await signBytes(cosigner.keyPair.privateKey, message);
+
+  // One signature: 2 header bytes followed by the 14-byte offsets entry.
+  const publicKeyOffset = 16;
+  const signatureOffset = publicKeyOffset + 32;
+  const messageOffset = signatureOffset + 64;
+
+  const data = new Uint8Array(messageOffset + message.length);
+  const view = new DataView(data.buffer);
+  data[0] = 1;
+  // u16::MAX instruction indices point at the Ed25519 instruction itself.
+  [
+    signatureOffset,
+    0xffff,
+    publicKeyOffset,
+    0xffff,
+    messageOffset,
+    message.length,
+    0xffff,
+  ].forEach((value, i) => view.setUint16(2 + i * 2, value, true));
+  data.set(getAddressEncoder().encode(cosigner.address), publicKeyOffset);
+  data.set(signature, signatureOffset);
+  data.set(message, messageOffset);
+
+  return { programAddress: ED25519_PROGRAM_ID, data };
+}
+
 export const errorLogsContain = async (
   t: ExecutionContext,
   promise: Promise<unknown>,
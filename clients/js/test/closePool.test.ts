@@ -6,6 +6,7 @@ import {
   createDefaultSolanaClient,
   createDefaultTransaction,
   generateKeyPairSignerWithSol,
+  getBalance,
   signAndSendTransaction,
 } from '@tensor-foundation/test-helpers';
 import { Mode } from '@tensor-foundation/whitelist';
@@ -18,6 +19,7 @@ import {
   TENSOR_AMM_ERROR__EXISTING_NFTS,
   fetchMaybePool,
   fetchPool,
+  getCloseExpiredPoolInstruction,
   getClosePoolInstruction,
   getDepositNftInstructionAsync,
   getDepositSolInstruction,
@@ -31,6 +33,7 @@ import {
   createPool,
   createPoolAndWhitelist,
   createWhitelistV2,
+  delay,
   expectCustomError,
   getAndFundFeeVault,
   getPoolStateBond,
@@ -426,3 +429,106 @@ test('closing a pool returns excess funds to the owner', async (t) => {
   // The original rent payer should have received the pool rent back.
   t.assert(rentPayerEndingBalance === rentPayerStartingBalance + poolStateBond);
 });
+
+test('keeper closing an expired pool gets the keeper bounty', async (t) => {
+  const client = createDefaultSolanaClient();
+
+  const txPayer = await generateKeyPairSignerWithSol(client);
+  const rentPayer = await generateKeyPairSignerWithSol(client);
+  const keeper = await generateKeyPairSignerWithSol(client);
+
+  const keeperBounty = ONE_SOL / 10n;
+  const poolStateBond = await getPoolStateBond(client);
+
+  // Create a pool with a keeper bounty that expires right away.
+  const { pool, owner } = await createPoolAndWhitelist({
+    client,
+    payer: rentPayer,
+    expireInSec: 1,
+    keeperBounty,
+    funded: false,
+  });
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      keeperBounty,
+    },
+  });
+
+  // Wait for the pool to expire.
+  await delay(3_000);
+
+  const keeperStartingBalance = await getBalance(client, keeper.address);
+  const rentPayerStartingBalance = await getBalance(client, rentPayer.address);
+
+  const closeExpiredPoolIx = getCloseExpiredPoolInstruction({
+    rentPayer: rentPayer.address,
+    owner: owner.address,
+    pool,
+    keeper,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, txPayer),
+    (tx) => appendTransactionMessageInstruction(closeExpiredPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then the pool is closed.
+  const maybePoolAccount = await fetchMaybePool(client.rpc, pool);
+  t.assert(!maybePoolAccount.exists);
+
+  // The keeper got the bounty and the rent payer the pool rent.
+  t.assert(
+    (await getBalance(client, keeper.address)) ===
+      keeperStartingBalance + keeperBounty
+  );
+  t.assert(
+    (await getBalance(client, rentPayer.address)) ===
+      rentPayerStartingBalance + poolStateBond
+  );
+});
+
+test('closing an expired pool without a keeper refunds the keeper bounty', async (t) => {
+  const client = createDefaultSolanaClient();
+
+  const txPayer = await generateKeyPairSignerWithSol(client);
+  const rentPayer = await generateKeyPairSignerWithSol(client);
+
+  const keeperBounty = ONE_SOL / 10n;
+  const poolStateBond = await getPoolStateBond(client);
+
+  const { pool, owner } = await createPoolAndWhitelist({
+    client,
+    payer: rentPayer,
+    expireInSec: 1,
+    keeperBounty,
+    funded: false,
+  });
+
+  // Wait for the pool to expire.
+  await delay(3_000);
+
+  const rentPayerStartingBalance = await getBalance(client, rentPayer.address);
+
+  const closeExpiredPoolIx = getCloseExpiredPoolInstruction({
+    rentPayer: rentPayer.address,
+    owner: owner.address,
+    pool,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, txPayer),
+    (tx) => appendTransactionMessageInstruction(closeExpiredPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const maybePoolAccount = await fetchMaybePool(client.rpc, pool);
+  t.assert(!maybePoolAccount.exists);
+
+  // The bounty goes back to the rent payer along with the pool rent.
+  t.assert(
+    (await getBalance(client, rentPayer.address)) ===
+      rentPayerStartingBalance + poolStateBond + keeperBounty
+  );
+});
@@ -27,9 +27,13 @@ import {
   CurveType,
   PoolType,
   TENSOR_AMM_ERROR__DELTA_TOO_LARGE,
+  TENSOR_AMM_ERROR__EXISTING_NFTS,
   TENSOR_AMM_ERROR__FEES_NOT_ALLOWED,
   TENSOR_AMM_ERROR__FEES_TOO_HIGH,
+  TENSOR_AMM_ERROR__INVALID_DECAY,
+  TENSOR_AMM_ERROR__MANAGER_NOT_ALLOWED,
   TENSOR_AMM_ERROR__MAX_TAKER_SELL_COUNT_TOO_SMALL,
+  TENSOR_AMM_ERROR__WRONG_MANAGER,
   fetchPool,
   findNftDepositReceiptPda,
   getCurrentBidPriceSync,
@@ -37,17 +41,20 @@ import {
   getSellNftTradePoolInstructionAsync,
 } from '../src/index.js';
 import {
+  DEFAULT_PUBKEY,
   MAX_DELTA_BPS,
   MAX_MM_FEES_BPS,
   ONE_WEEK,
   TestAction,
   createPool,
+  createPoolAndWhitelist,
   createWhitelistV2,
   nftPoolConfig,
   tokenPoolConfig,
   tradePoolConfig,
 } from './_common.js';
 import { setupLegacyTest, testSell } from './legacy/_common.js';
+import { setupCoreTest } from './mpl_core/_common.js';
 
 test('can edit pool with new config', async (t) => {
   const { client, pool, signers } = await setupLegacyTest({
@@ -654,3 +661,293 @@ test('invalid owner cannot edit pool', async (t) => {
 
   await expectCustomError(t, promise, ANCHOR_ERROR__CONSTRAINT_SEEDS);
 });
+
+test('manager can edit pool pricing and expiry', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(client);
+  const manager = await generateKeyPairSignerWithSol(client);
+
+  const { pool } = await createPoolAndWhitelist({
+    client,
+    owner,
+    config: tradePoolConfig,
+    manager: manager.address,
+    funded: false,
+  });
+
+  const newConfig = {
+    ...tradePoolConfig,
+    startingPrice: 2n * tradePoolConfig.startingPrice,
+  };
+
+  // The owner doesn't sign: the manager edits on their behalf.
+  const editPoolIx = getEditPoolInstruction({
+    owner: owner.address,
+    manager,
+    pool,
+    expireInSec: 2 * ONE_WEEK,
+    newConfig,
+    resetPriceOffset: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, manager),
+    (tx) => appendTransactionMessageInstruction(editPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      config: newConfig,
+      manager: manager.address,
+    },
+  });
+});
+
+test('manager cannot make owner-only changes', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(client);
+  const manager = await generateKeyPairSignerWithSol(client);
+
+  const { pool } = await createPoolAndWhitelist({
+    client,
+    owner,
+    config: tradePoolConfig,
+    manager: manager.address,
+    funded: false,
+  });
+
+  // Setting a cosigner is reserved to the owner.
+  const cosignerIx = getEditPoolInstruction({
+    owner: owner.address,
+    manager,
+    pool,
+    cosigner: (await generateKeyPairSigner()).address,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  let promise = pipe(
+    await createDefaultTransaction(client, manager),
+    (tx) => appendTransactionMessageInstruction(cosignerIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__MANAGER_NOT_ALLOWED);
+
+  // So are the MM fees, which are the owner's revenue.
+  const mmFeeIx = getEditPoolInstruction({
+    owner: owner.address,
+    manager,
+    pool,
+    newConfig: { ...tradePoolConfig, mmFeeBps: 100 },
+    resetPriceOffset: false,
+  });
+
+  promise = pipe(
+    await createDefaultTransaction(client, manager),
+    (tx) => appendTransactionMessageInstruction(mmFeeIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__MANAGER_NOT_ALLOWED);
+
+  // Another signer can't act as the manager.
+  const notManager = await generateKeyPairSignerWithSol(client);
+
+  const notManagerIx = getEditPoolInstruction({
+    owner: owner.address,
+    manager: notManager,
+    pool,
+    expireInSec: 2 * ONE_WEEK,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  promise = pipe(
+    await createDefaultTransaction(client, notManager),
+    (tx) => appendTransactionMessageInstruction(notManagerIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__WRONG_MANAGER);
+
+  // Once the owner removes the manager, it can't edit the pool anymore.
+  const removeManagerIx = getEditPoolInstruction({
+    owner,
+    pool,
+    newManager: DEFAULT_PUBKEY,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(removeManagerIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      manager: null,
+    },
+  });
+
+  const removedManagerIx = getEditPoolInstruction({
+    owner: owner.address,
+    manager,
+    pool,
+    expireInSec: 3 * ONE_WEEK,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  promise = pipe(
+    await createDefaultTransaction(client, manager),
+    (tx) => appendTransactionMessageInstruction(removedManagerIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__WRONG_MANAGER);
+});
+
+test('GDA pool edits must keep a valid decay', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(client);
+
+  // GDA pools only list NFTs and bump their price by `delta` bps after each fill.
+  const config = { ...nftPoolConfig, curveType: CurveType.Gda, delta: 500n };
+
+  const { pool } = await createPoolAndWhitelist({
+    client,
+    owner,
+    config,
+    decayBps: 100,
+    floorPrice: config.startingPrice / 2n,
+    funded: false,
+  });
+
+  // A GDA curve needs a non-zero decay.
+  const zeroDecayIx = getEditPoolInstruction({
+    owner,
+    pool,
+    decayBps: 0,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  let promise = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(zeroDecayIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__INVALID_DECAY);
+
+  // The floor price can't be above the starting price.
+  const floorIx = getEditPoolInstruction({
+    owner,
+    pool,
+    floorPrice: config.startingPrice + 1n,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  promise = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(floorIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__INVALID_DECAY);
+
+  const editPoolIx = getEditPoolInstruction({
+    owner,
+    pool,
+    decayBps: 200,
+    floorPrice: config.startingPrice / 4n,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(editPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      decayBps: 200,
+      floorPrice: config.startingPrice / 4n,
+    },
+  });
+});
+
+test('can edit the whitelist of an empty pool', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(client);
+
+  const { pool } = await createPoolAndWhitelist({
+    client,
+    owner,
+    config: tokenPoolConfig,
+    funded: false,
+  });
+
+  const { whitelist: newWhitelist } = await createWhitelistV2({
+    client,
+    updateAuthority: await generateKeyPairSignerWithSol(client),
+  });
+
+  const editPoolIx = getEditPoolInstruction({
+    owner,
+    pool,
+    newWhitelist,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(editPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      whitelist: newWhitelist,
+    },
+  });
+});
+
+test('cannot edit the whitelist of a pool holding NFTs', async (t) => {
+  // The NFT is deposited into the pool.
+  const { client, pool, signers } = await setupCoreTest({
+    t,
+    poolType: PoolType.NFT,
+    action: TestAction.Buy,
+    fundPool: false,
+  });
+
+  const { whitelist: newWhitelist } = await createWhitelistV2({
+    client,
+    updateAuthority: signers.nftUpdateAuthority,
+  });
+
+  const editPoolIx = getEditPoolInstruction({
+    owner: signers.poolOwner,
+    pool,
+    newWhitelist,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  const promise = pipe(
+    await createDefaultTransaction(client, signers.poolOwner),
+    (tx) => appendTransactionMessageInstruction(editPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__EXISTING_NFTS);
+});
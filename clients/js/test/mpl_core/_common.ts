@@ -3,6 +3,7 @@ import {
   Account,
   Address,
   IInstruction,
+  KeyPairSigner,
   appendTransactionMessageInstruction,
   pipe,
 } from '@solana/web3.js';
@@ -10,7 +11,9 @@ import {
   AssetV1,
   CollectionV1,
   Creator,
+  createDefaultAsset,
   createDefaultAssetWithCollection,
+  createDefaultCollection,
   fetchAssetV1,
 } from '@tensor-foundation/mpl-core';
 import {
@@ -39,6 +42,7 @@ import {
   BASIS_POINTS,
   BROKER_FEE_PCT,
   COMPUTE_300K_IX,
+  CreatePoolOptions,
   HUNDRED_PERCENT,
   MAKER_BROKER_FEE_PCT,
   SetupTestParams,
@@ -77,6 +81,7 @@ export async function setupCoreTest(
   params: SetupTestParams & {
     creators?: Creator[];
     poolConfig?: PoolConfig | null;
+    poolOptions?: CreatePoolOptions;
   }
 ): Promise<MplCoreTest> {
   const {
@@ -212,6 +217,7 @@ export async function setupCoreTest(
     depositAmount,
     conditions,
     funded: sharedEscrow ? false : fundPool, // Shared Escrow pools can't be funded directly.
+    ...params.poolOptions,
   });
 
   const poolAccount = await fetchPool(client.rpc, pool);
@@ -707,3 +713,93 @@ export async function testSell(
   // Fees can have a race-condition when many tests are run so this just needs to be higher or equal to the expected balance.
   t.assert(feeVaultEndingBalance >= expectedFeeVaultBalance);
 }
+
+export interface CorePoolWithAssetsParams extends CreatePoolOptions {
+  client: Client;
+  poolOwner: KeyPairSigner;
+  assetOwner: KeyPairSigner;
+  config: PoolConfig;
+  count: number;
+  depositAmount?: bigint;
+  cosigner?: KeyPairSigner;
+}
+
+export interface CorePoolWithAssets {
+  pool: Address;
+  whitelist: Address;
+  collection: Address;
+  assets: Address[];
+}
+
+// Creates `count` assets of a new collection, with the asset owner as royalty creator, and a
+// pool whitelisting the collection. The assets are deposited into the pool when the pool owner
+// owns them, otherwise the pool is funded so they can be sold into it.
+export async function setupCorePoolWithAssets({
+  client,
+  poolOwner,
+  assetOwner,
+  config,
+  count,
+  depositAmount,
+  cosigner,
+  ...options
+}: CorePoolWithAssetsParams): Promise<CorePoolWithAssets> {
+  const collection = await createDefaultCollection({
+    client,
+    payer: assetOwner,
+    updateAuthority: assetOwner.address,
+    royalties: {
+      creators: [{ address: assetOwner.address, percentage: 100 }],
+      basisPoints: 500,
+    },
+  });
+
+  const assets = await Promise.all(
+    Array(count)
+      .fill(null)
+      .map(async () => {
+        const asset = await createDefaultAsset({
+          client,
+          payer: assetOwner,
+          authority: assetOwner,
+          owner: assetOwner.address,
+          collection: collection.address,
+        });
+        return asset.address;
+      })
+  );
+
+  const deposit = assetOwner.address === poolOwner.address;
+
+  const { pool, whitelist } = await createPoolAndWhitelist({
+    client,
+    owner: poolOwner,
+    config,
+    cosigner,
+    conditions: [{ mode: Mode.VOC, value: collection.address }],
+    depositAmount,
+    funded: !deposit,
+    ...options,
+  });
+
+  if (deposit) {
+    for (const asset of assets) {
+      const depositNftIx = await getDepositNftCoreInstructionAsync({
+        owner: poolOwner,
+        pool,
+        whitelist,
+        asset,
+        collection: collection.address,
+      });
+
+      await pipe(
+        await createDefaultTransaction(client, poolOwner),
+        (tx) => appendTransactionMessageInstruction(COMPUTE_300K_IX, tx),
+        (tx) => appendTransactionMessageInstruction(depositNftIx, tx),
+        (tx) => signAndSendTransaction(client, tx)
+      );
+    }
+  }
+
+  return { pool, whitelist, collection: collection.address, assets };
+}
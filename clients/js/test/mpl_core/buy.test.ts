@@ -1,6 +1,7 @@
 import { getSetComputeUnitLimitInstruction } from '@solana-program/compute-budget';
 import {
   Account,
+  AccountRole,
   Address,
   appendTransactionMessageInstruction,
   appendTransactionMessageInstructions,
@@ -33,13 +34,21 @@ import {
   fetchPool,
   findNftDepositReceiptPda,
   getBuyNftCoreInstructionAsync,
+  getBuyNftsCoreInstructionAsync,
   getCurrentAskPrice,
   getDepositNftCoreInstructionAsync,
   getEditPoolInstruction,
   isSol,
   Pool,
   PoolType,
+  priceModifier,
+  TakerSide,
+  TENSOR_AMM_ERROR__CIRCUIT_BREAKER_TRIPPED,
+  TENSOR_AMM_ERROR__EXPIRED_COSIGNER_ATTESTATION,
+  TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_EXCEEDED,
+  TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION,
   TENSOR_AMM_ERROR__PRICE_MISMATCH,
+  TENSOR_AMM_ERROR__STALE_COSIGNER_NONCE,
   TENSOR_AMM_ERROR__WRONG_COSIGNER,
   TENSOR_AMM_ERROR__WRONG_MAKER_BROKER,
 } from '../../src/index.js';
@@ -47,18 +56,25 @@ import {
   assertNftReceiptClosed,
   assertTammNoop,
   BASIS_POINTS,
+  COMPUTE_500K_IX,
   createPool,
   createProofWhitelist,
   createWhitelistV2,
   expectCustomError,
   getAndFundFeeVault,
+  getCosignerAttestationInstruction,
   MAX_MM_FEES_BPS,
   nftPoolConfig,
+  SYSVAR_INSTRUCTIONS_ID,
   TestAction,
   tradePoolConfig,
   upsertMintProof,
 } from '../_common.js';
-import { setupCoreTest, testBuyNft } from './_common.js';
+import {
+  setupCorePoolWithAssets,
+  setupCoreTest,
+  testBuyNft,
+} from './_common.js';
 
 test('buy from NFT pool', async (t) => {
   const coreTest = await setupCoreTest({
@@ -1699,3 +1715,304 @@ test('buy a ton with default exponential curve + tolerance', async (t) => {
     });
   }
 });
+
+test('buy several NFTs from a NFT pool in one instruction', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const buyer = await generateKeyPairSignerWithSol(client);
+
+  const { pool, collection, assets } = await setupCorePoolWithAssets({
+    client,
+    poolOwner,
+    assetOwner: poolOwner,
+    config: nftPoolConfig,
+    count: 2,
+  });
+
+  const buyNftsIx = await getBuyNftsCoreInstructionAsync({
+    owner: poolOwner.address,
+    taker: buyer,
+    pool,
+    collection,
+    maxTotalAmount: LAMPORTS_PER_SOL,
+  });
+
+  // Each asset is followed by its receipt and royalty creator.
+  const remainingAccounts = (
+    await Promise.all(
+      assets.map(async (asset) => [
+        asset,
+        (await findNftDepositReceiptPda({ mint: asset, pool }))[0],
+        poolOwner.address,
+      ])
+    )
+  )
+    .flat()
+    .map((address) => ({ address, role: AccountRole.WRITABLE }));
+
+  await pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) => appendTransactionMessageInstruction(COMPUTE_500K_IX, tx),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        {
+          ...buyNftsIx,
+          accounts: [...buyNftsIx.accounts, ...remainingAccounts],
+        },
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // NFTs are now owned by the buyer.
+  for (const asset of assets) {
+    t.like(await fetchAssetV1(client.rpc, asset), <Account<AssetV1, Address>>{
+      address: asset,
+      data: {
+        owner: buyer.address,
+      },
+    });
+  }
+
+  // The empty NFT pool is closed.
+  const maybePool = await fetchMaybePool(client.rpc, pool);
+  t.assert(maybePool.exists === false);
+});
+
+test('cannot buy more NFTs than the max taker buy count', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const buyer = await generateKeyPairSignerWithSol(client);
+
+  const { pool, collection, assets } = await setupCorePoolWithAssets({
+    client,
+    poolOwner,
+    assetOwner: poolOwner,
+    config: nftPoolConfig,
+    count: 2,
+    maxTakerBuyCount: 1,
+  });
+
+  const [first, second] = await Promise.all(
+    assets.map((asset) =>
+      getBuyNftCoreInstructionAsync({
+        owner: poolOwner.address,
+        taker: buyer,
+        pool,
+        asset,
+        collection,
+        maxAmount: LAMPORTS_PER_SOL,
+        creators: [poolOwner.address],
+      })
+    )
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) => appendTransactionMessageInstruction(first, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const promise = pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) => appendTransactionMessageInstruction(second, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__MAX_TAKER_BUY_COUNT_EXCEEDED
+  );
+});
+
+test('circuit breaker stops buys once the pool traded too much', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const buyer = await generateKeyPairSignerWithSol(client);
+
+  // At most one trade per hour.
+  const { pool, collection, assets } = await setupCorePoolWithAssets({
+    client,
+    poolOwner,
+    assetOwner: poolOwner,
+    config: nftPoolConfig,
+    count: 2,
+    circuitBreaker: {
+      windowSec: 3600,
+      maxTrades: 1,
+      maxPriceOffsetChange: 0,
+    },
+  });
+
+  const [first, second] = await Promise.all(
+    assets.map((asset) =>
+      getBuyNftCoreInstructionAsync({
+        owner: poolOwner.address,
+        taker: buyer,
+        pool,
+        asset,
+        collection,
+        maxAmount: LAMPORTS_PER_SOL,
+        creators: [poolOwner.address],
+      })
+    )
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) => appendTransactionMessageInstruction(first, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const promise = pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) => appendTransactionMessageInstruction(second, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__CIRCUIT_BREAKER_TRIPPED
+  );
+});
+
+test('it can buy an NFT from a pool w/ a cosigner attestation', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const buyer = await generateKeyPairSignerWithSol(client);
+  const cosigner = await generateKeyPairSigner();
+
+  const { pool, collection, assets } = await setupCorePoolWithAssets({
+    client,
+    poolOwner,
+    assetOwner: poolOwner,
+    config: tradePoolConfig,
+    count: 2,
+    cosigner,
+  });
+
+  const expiry = BigInt(Math.floor(Date.now() / 1000)) + 600n;
+
+  // The cosigner doesn't sign: the trade reads its attestation from the instructions sysvar.
+  const [firstBuyIx, secondBuyIx] = await Promise.all(
+    assets.map((asset) =>
+      getBuyNftCoreInstructionAsync({
+        owner: poolOwner.address,
+        taker: buyer,
+        pool,
+        asset,
+        collection,
+        maxAmount: LAMPORTS_PER_SOL,
+        creators: [poolOwner.address],
+        attestationSysvar: SYSVAR_INSTRUCTIONS_ID,
+      })
+    )
+  );
+
+  const attestation = (asset: Address, nonce: bigint, expiry: bigint) =>
+    getCosignerAttestationInstruction(cosigner, {
+      pool,
+      asset,
+      taker: buyer.address,
+      takerSide: TakerSide.Buy,
+      expiry,
+      nonce,
+      priceModifier: priceModifier('None'),
+    });
+
+  const firstAttestationIx = await attestation(assets[0], 1n, expiry);
+
+  await pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [firstAttestationIx, firstBuyIx],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchAssetV1(client.rpc, assets[0]), <
+    Account<AssetV1, Address>
+  >{
+    address: assets[0],
+    data: {
+      owner: buyer.address,
+    },
+  });
+
+  // Without an attestation, the buy fails.
+  let promise = pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) => appendTransactionMessageInstruction(secondBuyIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION
+  );
+
+  // An attestation can't reuse the nonce of one already used.
+  const replayedAttestationIx = await attestation(assets[1], 1n, expiry);
+
+  promise = pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [replayedAttestationIx, secondBuyIx],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__STALE_COSIGNER_NONCE);
+
+  // Nor be used once it expired.
+  const expiredAttestationIx = await attestation(
+    assets[1],
+    2n,
+    expiry - 3600n
+  );
+
+  promise = pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [expiredAttestationIx, secondBuyIx],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__EXPIRED_COSIGNER_ATTESTATION
+  );
+
+  // A fresh attestation goes through.
+  const secondAttestationIx = await attestation(assets[1], 2n, expiry);
+
+  await pipe(
+    await createDefaultTransaction(client, buyer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [secondAttestationIx, secondBuyIx],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchAssetV1(client.rpc, assets[1]), <
+    Account<AssetV1, Address>
+  >{
+    address: assets[1],
+    data: {
+      owner: buyer.address,
+    },
+  });
+});
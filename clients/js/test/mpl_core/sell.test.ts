@@ -1,6 +1,7 @@
 import { getSetComputeUnitLimitInstruction } from '@solana-program/compute-budget';
 import {
   Account,
+  AccountRole,
   Address,
   appendTransactionMessageInstruction,
   appendTransactionMessageInstructions,
@@ -39,34 +40,51 @@ import {
   fetchNftDepositReceipt,
   fetchPool,
   findNftDepositReceiptPda,
+  findTraitRequirementsPda,
   getDepositSolInstruction,
   getEditPoolInstruction,
+  getSellNftsTokenPoolCoreInstructionAsync,
   getSellNftTokenPoolCoreInstructionAsync,
   getSellNftTradePoolCoreInstructionAsync,
+  getSetTraitRequirementsInstructionAsync,
   isSol,
   NftDepositReceipt,
   PoolType,
+  priceModifier,
+  TakerSide,
+  TENSOR_AMM_ERROR__MAX_NFTS_HELD_EXCEEDED,
+  TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_EXCEEDED,
+  TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION,
   TENSOR_AMM_ERROR__PRICE_MISMATCH,
+  TENSOR_AMM_ERROR__TRAIT_REQUIREMENTS_NOT_MET,
   TENSOR_AMM_ERROR__WRONG_COSIGNER,
   TENSOR_AMM_ERROR__WRONG_MAKER_BROKER,
   TENSOR_AMM_ERROR__WRONG_POOL_TYPE,
   TENSOR_AMM_ERROR__WRONG_WHITELIST,
+  TENSOR_AMM_PROGRAM_ADDRESS,
 } from '../../src/index.js';
 import {
   assertTammNoop,
+  COMPUTE_500K_IX,
   createPool,
   createWhitelistV2,
   expectCustomError,
   getAndFundFeeVault,
+  getCosignerAttestationInstruction,
   getTestSigners,
   MAX_MM_FEES_BPS,
+  SYSVAR_INSTRUCTIONS_ID,
   TestAction,
   tokenPoolConfig,
   tradePoolConfig,
   upsertMintProof,
 } from '../_common.js';
 import { generateTreeOfSize } from '../_merkle.js';
-import { setupCoreTest, testSell } from './_common.js';
+import {
+  setupCorePoolWithAssets,
+  setupCoreTest,
+  testSell,
+} from './_common.js';
 
 test('it can sell an NFT into a Token pool', async (t) => {
   const {
@@ -1863,3 +1881,393 @@ test('token pool with makerBroker set requires passing the account in & fails w/
   // Should fail with a missing makerBroker error.
   await expectCustomError(t, promise, TENSOR_AMM_ERROR__WRONG_MAKER_BROKER);
 });
+
+test('sell several NFTs into a Token pool in one instruction', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const seller = await generateKeyPairSignerWithSol(client);
+
+  const { pool, whitelist, collection, assets } =
+    await setupCorePoolWithAssets({
+      client,
+      poolOwner,
+      assetOwner: seller,
+      config: tokenPoolConfig,
+      count: 2,
+      depositAmount: ONE_SOL,
+    });
+
+  const sellNftsIx = await getSellNftsTokenPoolCoreInstructionAsync({
+    owner: poolOwner.address,
+    taker: seller,
+    pool,
+    whitelist,
+    collection,
+    escrowProgram: TSWAP_PROGRAM_ID,
+    minTotalPrice: 0n,
+  });
+
+  // Each asset is followed by its mint proof, unset here, and royalty creator.
+  const remainingAccounts = assets.flatMap((asset) => [
+    { address: asset, role: AccountRole.WRITABLE },
+    { address: TENSOR_AMM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    { address: seller.address, role: AccountRole.WRITABLE },
+  ]);
+
+  await pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(COMPUTE_500K_IX, tx),
+    (tx) =>
+      appendTransactionMessageInstruction(
+        {
+          ...sellNftsIx,
+          accounts: [...sellNftsIx.accounts, ...remainingAccounts],
+        },
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Token pools pass the NFTs through to the pool owner.
+  for (const asset of assets) {
+    t.like(await fetchAssetV1(client.rpc, asset), <Account<AssetV1, Address>>{
+      address: asset,
+      data: {
+        owner: poolOwner.address,
+      },
+    });
+  }
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      stats: {
+        takerSellCount: 2,
+      },
+    },
+  });
+});
+
+test('cannot sell into a Trade pool holding the max NFTs held', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const seller = await generateKeyPairSignerWithSol(client);
+
+  const { pool, whitelist, collection, assets } =
+    await setupCorePoolWithAssets({
+      client,
+      poolOwner,
+      assetOwner: seller,
+      config: tradePoolConfig,
+      count: 2,
+      depositAmount: ONE_SOL,
+      maxNftsHeld: 1,
+    });
+
+  const [first, second] = await Promise.all(
+    assets.map((asset) =>
+      getSellNftTradePoolCoreInstructionAsync({
+        owner: poolOwner.address,
+        taker: seller,
+        pool,
+        whitelist,
+        asset,
+        collection,
+        minPrice: 0n,
+        escrowProgram: TSWAP_PROGRAM_ID,
+        creators: [seller.address],
+      })
+    )
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(first, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const promise = pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(second, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__MAX_NFTS_HELD_EXCEEDED
+  );
+});
+
+test('cannot sell into a pool beyond its max total spend', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const seller = await generateKeyPairSignerWithSol(client);
+
+  // The pool can only pay for the first NFT.
+  const { pool, whitelist, collection, assets } =
+    await setupCorePoolWithAssets({
+      client,
+      poolOwner,
+      assetOwner: seller,
+      config: tokenPoolConfig,
+      count: 2,
+      depositAmount: ONE_SOL,
+      maxTotalSpend: tokenPoolConfig.startingPrice,
+    });
+
+  const [first, second] = await Promise.all(
+    assets.map((asset) =>
+      getSellNftTokenPoolCoreInstructionAsync({
+        owner: poolOwner.address,
+        taker: seller,
+        pool,
+        whitelist,
+        asset,
+        collection,
+        minPrice: 0n,
+        escrowProgram: TSWAP_PROGRAM_ID,
+        creators: [seller.address],
+      })
+    )
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(first, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      totalSpent: tokenPoolConfig.startingPrice,
+    },
+  });
+
+  const promise = pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(second, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__MAX_TOTAL_SPEND_EXCEEDED
+  );
+});
+
+test('it can sell an NFT into a pool w/ a cosigner attestation', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const seller = await generateKeyPairSignerWithSol(client);
+  const cosigner = await generateKeyPairSigner();
+
+  const { pool, whitelist, collection, assets } =
+    await setupCorePoolWithAssets({
+      client,
+      poolOwner,
+      assetOwner: seller,
+      config: tokenPoolConfig,
+      count: 1,
+      depositAmount: ONE_SOL,
+      cosigner,
+    });
+  const [asset] = assets;
+
+  const sellNftIx = await getSellNftTokenPoolCoreInstructionAsync({
+    owner: poolOwner.address,
+    taker: seller,
+    pool,
+    whitelist,
+    asset,
+    collection,
+    minPrice: 0n,
+    escrowProgram: TSWAP_PROGRAM_ID,
+    creators: [seller.address],
+    attestationSysvar: SYSVAR_INSTRUCTIONS_ID,
+  });
+
+  // An attestation for the other side of the trade doesn't approve the sell.
+  const attestation = (takerSide: TakerSide) =>
+    getCosignerAttestationInstruction(cosigner, {
+      pool,
+      asset,
+      taker: seller.address,
+      takerSide,
+      expiry: BigInt(Math.floor(Date.now() / 1000)) + 600n,
+      nonce: 1n,
+      priceModifier: priceModifier('None'),
+    });
+
+  const buyAttestationIx = await attestation(TakerSide.Buy);
+
+  const promise = pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) =>
+      appendTransactionMessageInstructions([buyAttestationIx, sellNftIx], tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__MISSING_COSIGNER_ATTESTATION
+  );
+
+  const sellAttestationIx = await attestation(TakerSide.Sell);
+
+  await pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) =>
+      appendTransactionMessageInstructions([sellAttestationIx, sellNftIx], tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchAssetV1(client.rpc, asset), <Account<AssetV1, Address>>{
+    address: asset,
+    data: {
+      owner: poolOwner.address,
+    },
+  });
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      cosignerNonce: 1n,
+    },
+  });
+});
+
+test('pool with trait requirements only buys assets with the required traits', async (t) => {
+  const client = createDefaultSolanaClient();
+
+  const nftUpdateAuthority = await generateKeyPairSignerWithSol(client);
+  const owner = await generateKeyPairSignerWithSol(client, 5n * ONE_SOL);
+  const nftOwner = await generateKeyPairSignerWithSol(client);
+
+  // Mint assets w/ verified creators and attributes plugins.
+  const mintAsset = async (rarity: string) =>
+    await createAsset({
+      client,
+      payer: owner,
+      authority: nftUpdateAuthority,
+      owner: nftOwner.address,
+      plugins: [
+        {
+          plugin: {
+            __kind: 'VerifiedCreators',
+            fields: [
+              {
+                signatures: [
+                  { address: nftUpdateAuthority.address, verified: true },
+                ],
+              },
+            ],
+          },
+          authority: { __kind: 'UpdateAuthority' },
+        },
+        {
+          plugin: {
+            __kind: 'Attributes',
+            fields: [{ attributeList: [{ key: 'rarity', value: rarity }] }],
+          },
+          authority: { __kind: 'UpdateAuthority' },
+        },
+      ],
+      name: 'Test',
+      uri: 'https://test.com',
+    });
+
+  const rareAsset = await mintAsset('rare');
+  const commonAsset = await mintAsset('common');
+
+  const { whitelist } = await createWhitelistV2({
+    client,
+    updateAuthority: owner,
+    conditions: [{ mode: Mode.FVC, value: nftUpdateAuthority.address }],
+  });
+
+  const { pool } = await createPool({
+    client,
+    whitelist,
+    owner,
+    config: tokenPoolConfig,
+  });
+
+  const depositSolIx = getDepositSolInstruction({
+    pool,
+    owner,
+    lamports: ONE_SOL,
+  });
+
+  const setTraitRequirementsIx = await getSetTraitRequirementsInstructionAsync(
+    {
+      owner,
+      pool,
+      traits: [{ key: 'rarity', value: 'rare' }],
+    }
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [depositSolIx, setTraitRequirementsIx],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      requiresTraits: true,
+    },
+  });
+
+  const [traitRequirements] = await findTraitRequirementsPda({ pool });
+
+  const [sellCommonIx, sellRareIx] = await Promise.all(
+    [commonAsset, rareAsset].map((asset) =>
+      getSellNftTokenPoolCoreInstructionAsync({
+        owner: owner.address,
+        taker: nftOwner,
+        pool,
+        whitelist,
+        asset: asset.address,
+        minPrice: 0n,
+        escrowProgram: TSWAP_PROGRAM_ID,
+        traitRequirements,
+        creators: [],
+      })
+    )
+  );
+
+  const promise = pipe(
+    await createDefaultTransaction(client, nftOwner),
+    (tx) => appendTransactionMessageInstruction(COMPUTE_500K_IX, tx),
+    (tx) => appendTransactionMessageInstruction(sellCommonIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    promise,
+    TENSOR_AMM_ERROR__TRAIT_REQUIREMENTS_NOT_MET
+  );
+
+  await pipe(
+    await createDefaultTransaction(client, nftOwner),
+    (tx) => appendTransactionMessageInstruction(COMPUTE_500K_IX, tx),
+    (tx) => appendTransactionMessageInstruction(sellRareIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Token pools pass the NFT through to the pool owner.
+  t.like(await fetchAssetV1(client.rpc, rareAsset.address), <
+    Account<AssetV1, Address>
+  >{
+    address: rareAsset.address,
+    data: {
+      owner: owner.address,
+    },
+  });
+});
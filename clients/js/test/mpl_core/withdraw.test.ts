@@ -18,6 +18,7 @@ import {
   createDefaultSolanaClient,
   createDefaultTransaction,
   generateKeyPairSignerWithSol,
+  getBalance,
   signAndSendTransaction,
 } from '@tensor-foundation/test-helpers';
 import { Mode } from '@tensor-foundation/whitelist';
@@ -27,9 +28,12 @@ import {
   fetchMaybeNftDepositReceipt,
   fetchPool,
   findNftDepositReceiptPda,
+  getCloseExpiredPoolInstruction,
   getDepositNftCoreInstructionAsync,
   getDepositSolInstruction,
+  getEditPoolInstruction,
   getSellNftTradePoolCoreInstructionAsync,
+  getWithdrawExpiredNftCoreInstructionAsync,
   getWithdrawNftCoreInstructionAsync,
 } from '../../src/index.js';
 import {
@@ -38,11 +42,13 @@ import {
   assertNftReceiptClosed,
   createPool,
   createWhitelistV2,
+  delay,
   expectCustomError,
   getAndFundFeeVault,
+  nftPoolConfig,
   tradePoolConfig,
 } from '../_common.js';
-import { setupCoreTest } from './_common.js';
+import { setupCorePoolWithAssets, setupCoreTest } from './_common.js';
 
 test('it can withdraw an NFT from a Trade pool', async (t) => {
   const client = createDefaultSolanaClient();
@@ -366,3 +372,95 @@ test('it cannot withdraw an NFT from a NFT pool with wrong owner', async (t) =>
   // Throws constraint seeds error
   await expectCustomError(t, promise, ANCHOR_ERROR__CONSTRAINT_SEEDS);
 });
+
+test('keeper returning the NFTs of an expired pool gets a share of the keeper bounty', async (t) => {
+  const client = createDefaultSolanaClient();
+  const poolOwner = await generateKeyPairSignerWithSol(client);
+  const keeper = await generateKeyPairSignerWithSol(client);
+  const txPayer = await generateKeyPairSignerWithSol(client);
+
+  const keeperBounty = ONE_SOL / 10n;
+
+  const { pool, collection, assets } = await setupCorePoolWithAssets({
+    client,
+    poolOwner,
+    assetOwner: poolOwner,
+    config: nftPoolConfig,
+    count: 1,
+    keeperBounty,
+  });
+  const [asset] = assets;
+
+  // Expire the pool now that the NFT is deposited.
+  const editPoolIx = getEditPoolInstruction({
+    owner: poolOwner,
+    pool,
+    expireInSec: 0,
+    newConfig: null,
+    resetPriceOffset: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, poolOwner),
+    (tx) => appendTransactionMessageInstruction(editPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await delay(2_000);
+
+  const keeperStartingBalance = await getBalance(client, keeper.address);
+
+  const withdrawIx = await getWithdrawExpiredNftCoreInstructionAsync({
+    payer: keeper,
+    owner: poolOwner.address,
+    pool,
+    asset,
+    collection,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, txPayer),
+    (tx) => appendTransactionMessageInstruction(withdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The NFT went back to the owner.
+  t.like(await fetchAssetV1(client.rpc, asset), <Account<AssetV1, Address>>{
+    address: asset,
+    data: {
+      owner: poolOwner.address,
+    },
+  });
+
+  // The bounty is split between the NFT returned and closing the pool.
+  const share = keeperBounty / 2n;
+
+  t.assert(
+    (await getBalance(client, keeper.address)) === keeperStartingBalance + share
+  );
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      nftsHeld: 0,
+      keeperBounty: keeperBounty - share,
+    },
+  });
+
+  // Closing the empty pool pays the rest of the bounty.
+  const closeExpiredPoolIx = getCloseExpiredPoolInstruction({
+    rentPayer: poolOwner.address,
+    owner: poolOwner.address,
+    pool,
+    keeper,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, txPayer),
+    (tx) => appendTransactionMessageInstruction(closeExpiredPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.assert(
+    (await getBalance(client, keeper.address)) ===
+      keeperStartingBalance + keeperBounty
+  );
+});
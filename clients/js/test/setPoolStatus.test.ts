@@ -0,0 +1,194 @@
+import { appendTransactionMessageInstruction, pipe } from '@solana/web3.js';
+import {
+  ANCHOR_ERROR__CONSTRAINT_SEEDS,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  expectCustomError,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  PoolType,
+  TENSOR_AMM_ERROR__POOL_PAUSED,
+  fetchPool,
+  getSetPoolStatusInstruction,
+} from '../src/index.js';
+import { TestAction, createPoolAndWhitelist } from './_common.js';
+import { setupCoreTest, testBuyNft, testSell } from './mpl_core/_common.js';
+
+test('owner can pause and resume a pool', async (t) => {
+  const client = createDefaultSolanaClient();
+
+  const { pool, owner } = await createPoolAndWhitelist({
+    client,
+    funded: false,
+  });
+
+  const pauseIx = getSetPoolStatusInstruction({
+    owner,
+    pool,
+    buysPaused: true,
+    sellsPaused: true,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(pauseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      buysPaused: true,
+      sellsPaused: true,
+    },
+  });
+
+  const resumeIx = getSetPoolStatusInstruction({
+    owner,
+    pool,
+    buysPaused: false,
+    sellsPaused: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(resumeIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.like(await fetchPool(client.rpc, pool), {
+    data: {
+      buysPaused: false,
+      sellsPaused: false,
+    },
+  });
+});
+
+test('invalid owner cannot pause a pool', async (t) => {
+  const client = createDefaultSolanaClient();
+
+  const { pool } = await createPoolAndWhitelist({
+    client,
+    funded: false,
+  });
+
+  const notOwner = await generateKeyPairSignerWithSol(client);
+
+  const pauseIx = getSetPoolStatusInstruction({
+    owner: notOwner,
+    pool,
+    buysPaused: true,
+    sellsPaused: true,
+  });
+
+  const promise = pipe(
+    await createDefaultTransaction(client, notOwner),
+    (tx) => appendTransactionMessageInstruction(pauseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, ANCHOR_ERROR__CONSTRAINT_SEEDS);
+});
+
+test('cannot buy from a pool with buys paused', async (t) => {
+  const coreTest = await setupCoreTest({
+    t,
+    poolType: PoolType.NFT,
+    action: TestAction.Buy,
+    fundPool: false,
+  });
+  const { client, pool, signers } = coreTest;
+
+  const pauseIx = getSetPoolStatusInstruction({
+    owner: signers.poolOwner,
+    pool,
+    buysPaused: true,
+    sellsPaused: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, signers.poolOwner),
+    (tx) => appendTransactionMessageInstruction(pauseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await testBuyNft(t, coreTest, {
+    brokerPayments: false,
+    expectError: TENSOR_AMM_ERROR__POOL_PAUSED,
+  });
+
+  // Buys work again once the pool is resumed.
+  const resumeIx = getSetPoolStatusInstruction({
+    owner: signers.poolOwner,
+    pool,
+    buysPaused: false,
+    sellsPaused: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, signers.poolOwner),
+    (tx) => appendTransactionMessageInstruction(resumeIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await testBuyNft(t, coreTest, {
+    brokerPayments: false,
+  });
+});
+
+test('cannot sell into a pool with sells paused', async (t) => {
+  const coreTest = await setupCoreTest({
+    t,
+    poolType: PoolType.Token,
+    action: TestAction.Sell,
+  });
+  const { client, pool, signers } = coreTest;
+
+  const pauseIx = getSetPoolStatusInstruction({
+    owner: signers.poolOwner,
+    pool,
+    buysPaused: false,
+    sellsPaused: true,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, signers.poolOwner),
+    (tx) => appendTransactionMessageInstruction(pauseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await testSell(t, coreTest, {
+    brokerPayments: false,
+    cosigner: false,
+    expectError: TENSOR_AMM_ERROR__POOL_PAUSED,
+  });
+});
+
+test('pausing buys does not pause sells', async (t) => {
+  const coreTest = await setupCoreTest({
+    t,
+    poolType: PoolType.Token,
+    action: TestAction.Sell,
+  });
+  const { client, pool, signers } = coreTest;
+
+  const pauseIx = getSetPoolStatusInstruction({
+    owner: signers.poolOwner,
+    pool,
+    buysPaused: true,
+    sellsPaused: false,
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, signers.poolOwner),
+    (tx) => appendTransactionMessageInstruction(pauseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await testSell(t, coreTest, {
+    brokerPayments: false,
+    cosigner: false,
+  });
+});
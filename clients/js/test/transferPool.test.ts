@@ -0,0 +1,190 @@
+import {
+  Account,
+  AccountRole,
+  Address,
+  appendTransactionMessageInstruction,
+  pipe,
+} from '@solana/web3.js';
+import { AssetV1, fetchAssetV1 } from '@tensor-foundation/mpl-core';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  PoolType,
+  TENSOR_AMM_ERROR__NFTS_NOT_TRANSFERABLE,
+  fetchMaybeNftDepositReceipt,
+  fetchMaybePool,
+  fetchPool,
+  findNftDepositReceiptPda,
+  findPoolPda,
+  getTransferPoolInstruction,
+  migratedNft,
+} from '../src/index.js';
+import {
+  COMPUTE_300K_IX,
+  ONE_SOL,
+  TestAction,
+  createPoolAndWhitelist,
+  expectCustomError,
+  tokenPoolConfig,
+} from './_common.js';
+import { setupCoreTest } from './mpl_core/_common.js';
+
+test('owner can transfer a pool to a new owner', async (t) => {
+  const client = createDefaultSolanaClient();
+
+  const { pool, owner, poolId, whitelist } = await createPoolAndWhitelist({
+    client,
+    config: tokenPoolConfig,
+    depositAmount: ONE_SOL,
+    funded: true,
+  });
+
+  const newOwner = await generateKeyPairSignerWithSol(client);
+  const [newPool] = await findPoolPda({ owner: newOwner.address, poolId });
+
+  const transferPoolIx = getTransferPoolInstruction({
+    rentPayer: newOwner,
+    oldRentPayer: owner.address,
+    owner,
+    newOwner,
+    pool,
+    newPool,
+    nfts: [],
+  });
+
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(transferPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The old pool is closed.
+  const maybePool = await fetchMaybePool(client.rpc, pool);
+  t.assert(!maybePool.exists);
+
+  // The new pool has the same settings and funds, under the new owner.
+  t.like(await fetchPool(client.rpc, newPool), {
+    data: {
+      owner: newOwner.address,
+      rentPayer: newOwner.address,
+      whitelist,
+      config: tokenPoolConfig,
+      amount: ONE_SOL,
+    },
+  });
+});
+
+test('transferring a pool must migrate all of its NFTs', async (t) => {
+  // The NFT is deposited into the pool.
+  const { client, pool, signers } = await setupCoreTest({
+    t,
+    poolType: PoolType.NFT,
+    action: TestAction.Buy,
+    fundPool: false,
+  });
+
+  const { poolOwner } = signers;
+  const { poolId } = (await fetchPool(client.rpc, pool)).data;
+
+  const newOwner = await generateKeyPairSignerWithSol(client);
+  const [newPool] = await findPoolPda({ owner: newOwner.address, poolId });
+
+  const transferPoolIx = getTransferPoolInstruction({
+    rentPayer: newOwner,
+    oldRentPayer: poolOwner.address,
+    owner: poolOwner,
+    newOwner,
+    pool,
+    newPool,
+    nfts: [],
+  });
+
+  const promise = pipe(
+    await createDefaultTransaction(client, poolOwner),
+    (tx) => appendTransactionMessageInstruction(transferPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, promise, TENSOR_AMM_ERROR__NFTS_NOT_TRANSFERABLE);
+});
+
+test('transferring a pool migrates its Core assets', async (t) => {
+  const { client, pool, signers, asset, collection } = await setupCoreTest({
+    t,
+    poolType: PoolType.NFT,
+    action: TestAction.Buy,
+    fundPool: false,
+  });
+
+  const { poolOwner } = signers;
+  const { poolId } = (await fetchPool(client.rpc, pool)).data;
+
+  const newOwner = await generateKeyPairSignerWithSol(client);
+  const [newPool] = await findPoolPda({ owner: newOwner.address, poolId });
+
+  const [nftReceipt] = await findNftDepositReceiptPda({
+    mint: asset.address,
+    pool,
+  });
+  const [newNftReceipt] = await findNftDepositReceiptPda({
+    mint: asset.address,
+    pool: newPool,
+  });
+
+  const transferPoolIx = getTransferPoolInstruction({
+    rentPayer: newOwner,
+    oldRentPayer: poolOwner.address,
+    owner: poolOwner,
+    newOwner,
+    pool,
+    newPool,
+    nfts: [migratedNft('Core')],
+  });
+
+  // Core assets are migrated with [asset, collection, receipt, new receipt].
+  const ix = {
+    ...transferPoolIx,
+    accounts: [
+      ...transferPoolIx.accounts,
+      { address: asset.address, role: AccountRole.WRITABLE },
+      { address: collection.address, role: AccountRole.WRITABLE },
+      { address: nftReceipt, role: AccountRole.WRITABLE },
+      { address: newNftReceipt, role: AccountRole.WRITABLE },
+    ],
+  };
+
+  await pipe(
+    await createDefaultTransaction(client, poolOwner),
+    (tx) => appendTransactionMessageInstruction(COMPUTE_300K_IX, tx),
+    (tx) => appendTransactionMessageInstruction(ix, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The asset is now owned by the new pool.
+  t.like(await fetchAssetV1(client.rpc, asset.address), <
+    Account<AssetV1, Address>
+  >{
+    address: asset.address,
+    data: {
+      owner: newPool,
+    },
+  });
+
+  // The deposit receipt moved to the new pool.
+  t.false((await fetchMaybeNftDepositReceipt(client.rpc, nftReceipt)).exists);
+  t.true(
+    (await fetchMaybeNftDepositReceipt(client.rpc, newNftReceipt)).exists
+  );
+
+  t.like(await fetchPool(client.rpc, newPool), {
+    data: {
+      owner: newOwner.address,
+      nftsHeld: 1,
+    },
+  });
+});
@@ -8,7 +8,11 @@
 pub(crate) mod r#asset_deposit_receipt;
 pub(crate) mod r#nft_deposit_receipt;
 pub(crate) mod r#pool;
+pub(crate) mod r#price_ladder;
+pub(crate) mod r#trait_requirements;
 
 pub use self::r#asset_deposit_receipt::*;
 pub use self::r#nft_deposit_receipt::*;
 pub use self::r#pool::*;
+pub use self::r#price_ladder::*;
+pub use self::r#trait_requirements::*;
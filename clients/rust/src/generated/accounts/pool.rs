@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::CircuitBreaker;
 use crate::generated::types::PoolConfig;
 use crate::generated::types::PoolStats;
 use crate::hooked::Currency;
@@ -49,8 +50,10 @@ pub struct Pool {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub rent_payer: Pubkey,
+    /// The currency the pool is priced and settled in.
+    /// Default Pubkey is SOL, otherwise SPL token mint.
     pub currency: Currency,
-    /// The amount of currency held in the pool.
+    /// The amount of currency held in the pool, in lamports or token base units.
     pub amount: u64,
     /// The difference between the number of buys and sells
     /// where a postive number indicates the taker has BOUGHT more NFTs than sold
@@ -74,13 +77,45 @@ pub struct Pool {
     pub max_taker_sell_count: u32,
     /// Pool configuration values.
     pub config: PoolConfig,
+    /// Basis points the price of a GDA curve decays by every `GDA_DECAY_PERIOD_SEC` without a trade.
+    pub decay_bps: u16,
+    /// The price a GDA curve stops decaying at.
+    pub floor_price: u64,
+    /// A key the owner delegates pricing to: it can edit the curve and expiry of the pool
+    /// but cannot move funds or NFTs. Default pubkey is interpreted as no value.
+    pub manager: NullableAddress,
+    /// Whether takers are prevented from buying NFTs from the pool.
+    pub buys_paused: bool,
+    /// Whether takers are prevented from selling NFTs into the pool.
+    pub sells_paused: bool,
+    /// The highest nonce of the cosigner attestations used to trade with the pool.
+    pub cosigner_nonce: u64,
+    /// Whether NFTs sold or deposited into the pool must have the traits of the pool's
+    /// `TraitRequirements` account.
+    pub requires_traits: bool,
+    /// Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it.
+    pub max_taker_buy_count: u32,
+    /// Limit how many NFTs the pool can hold through sells and deposits.
+    pub max_nfts_held: u32,
+    /// Limit how much the pool can pay for NFTs sold into it over its lifetime.
+    pub max_total_spend: u64,
+    /// Optional limit on the trades the pool can make within a time window.
+    pub circuit_breaker: CircuitBreaker,
+    /// Lamports escrowed in the pool on top of its rent, paid to keepers cleaning up the pool
+    /// once it expires.
+    pub keeper_bounty: u64,
+    /// Bitmap of the used nonces in the window below `cosigner_nonce`, bit `i` standing for
+    /// nonce `cosigner_nonce - i`, so each attestation can only be used once.
+    pub cosigner_nonces_used: [u64; 8],
+    /// Total amount the pool has paid for NFTs sold into it, in lamports or currency tokens.
+    pub total_spent: u64,
     /// Reserved space for future upgrades.
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 100],
+    pub reserved: [u8; 71],
 }
 
 impl Pool {
-    pub const LEN: usize = 447;
+    pub const LEN: usize = 591;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -97,6 +97,111 @@ pub enum TensorAmmError {
     /// 12028 - Invalid edition
     #[error("Invalid edition")]
     InvalidEdition = 0x2EFC,
+    /// 12029 - Invalid currency
+    #[error("Invalid currency")]
+    InvalidCurrency = 0x2EFD,
+    /// 12030 - Missing currency accounts
+    #[error("Missing currency accounts")]
+    MissingCurrencyAccounts = 0x2EFE,
+    /// 12031 - Invalid currency token account
+    #[error("Invalid currency token account")]
+    InvalidCurrencyTokenAccount = 0x2EFF,
+    /// 12032 - Invalid compressed NFT
+    #[error("Invalid compressed NFT")]
+    InvalidCompressedNft = 0x2F00,
+    /// 12033 - Invalid WNS accounts
+    #[error("Invalid WNS accounts")]
+    InvalidWnsAccounts = 0x2F01,
+    /// 12034 - Invalid batch accounts
+    #[error("Invalid batch accounts")]
+    InvalidBatchAccounts = 0x2F02,
+    /// 12035 - Wrong NFT deposit receipt
+    #[error("Wrong NFT deposit receipt")]
+    WrongNftReceipt = 0x2F03,
+    /// 12036 - Pool cannot fund the sale
+    #[error("Pool cannot fund the sale")]
+    InsufficientPoolFunds = 0x2F04,
+    /// 12037 - operation not permitted on this curve type
+    #[error("operation not permitted on this curve type")]
+    WrongCurveType = 0x2F05,
+    /// 12038 - XYK pools need both currency and NFTs in reserve
+    #[error("XYK pools need both currency and NFTs in reserve")]
+    InsufficientReserves = 0x2F06,
+    /// 12039 - invalid GDA decay rate or floor price
+    #[error("invalid GDA decay rate or floor price")]
+    InvalidDecay = 0x2F07,
+    /// 12040 - price ladder account required for custom curves
+    #[error("price ladder account required for custom curves")]
+    MissingPriceLadder = 0x2F08,
+    /// 12041 - price ladder must be non-empty and non-decreasing
+    #[error("price ladder must be non-empty and non-decreasing")]
+    InvalidPriceLadder = 0x2F09,
+    /// 12042 - price offset is outside of the price ladder
+    #[error("price offset is outside of the price ladder")]
+    PriceLadderExhausted = 0x2F0A,
+    /// 12043 - Wrong manager
+    #[error("Wrong manager")]
+    WrongManager = 0x2F0B,
+    /// 12044 - only the owner can make this change
+    #[error("only the owner can make this change")]
+    ManagerNotAllowed = 0x2F0C,
+    /// 12045 - pool is paused for this side of the trade
+    #[error("pool is paused for this side of the trade")]
+    PoolPaused = 0x2F0D,
+    /// 12046 - no cosigner attestation found for the asset
+    #[error("no cosigner attestation found for the asset")]
+    MissingCosignerAttestation = 0x2F0E,
+    /// 12047 - cosigner attestation has expired
+    #[error("cosigner attestation has expired")]
+    ExpiredCosignerAttestation = 0x2F0F,
+    /// 12048 - cosigner attestation nonce was already used
+    #[error("cosigner attestation nonce was already used")]
+    StaleCosignerNonce = 0x2F10,
+    /// 12049 - price modifier results in an invalid price
+    #[error("price modifier results in an invalid price")]
+    InvalidPriceModifier = 0x2F11,
+    /// 12050 - trait requirements must be non-empty and fit the account
+    #[error("trait requirements must be non-empty and fit the account")]
+    InvalidTraitRequirements = 0x2F12,
+    /// 12051 - trait requirements account required for this pool
+    #[error("trait requirements account required for this pool")]
+    MissingTraitRequirements = 0x2F13,
+    /// 12052 - asset does not have the traits required by the pool
+    #[error("asset does not have the traits required by the pool")]
+    TraitRequirementsNotMet = 0x2F14,
+    /// 12053 - trait requirements must be cleared first
+    #[error("trait requirements must be cleared first")]
+    PoolHasTraitRequirements = 0x2F15,
+    /// 12054 - max taker buy count exceeded, pool cannot sell anymore NFTs
+    #[error("max taker buy count exceeded, pool cannot sell anymore NFTs")]
+    MaxTakerBuyCountExceeded = 0x2F16,
+    /// 12055 - max taker buy count is too small
+    #[error("max taker buy count is too small")]
+    MaxTakerBuyCountTooSmall = 0x2F17,
+    /// 12056 - pool cannot hold anymore NFTs
+    #[error("pool cannot hold anymore NFTs")]
+    MaxNftsHeldExceeded = 0x2F18,
+    /// 12057 - max nfts held is lower than the NFTs held
+    #[error("max nfts held is lower than the NFTs held")]
+    MaxNftsHeldTooSmall = 0x2F19,
+    /// 12058 - max total spend exceeded, pool cannot buy anymore NFTs
+    #[error("max total spend exceeded, pool cannot buy anymore NFTs")]
+    MaxTotalSpendExceeded = 0x2F1A,
+    /// 12059 - max total spend is lower than the amount already spent
+    #[error("max total spend is lower than the amount already spent")]
+    MaxTotalSpendTooSmall = 0x2F1B,
+    /// 12060 - circuit breaker tripped, pool cannot trade until the window ends
+    #[error("circuit breaker tripped, pool cannot trade until the window ends")]
+    CircuitBreakerTripped = 0x2F1C,
+    /// 12061 - invalid circuit breaker limits
+    #[error("invalid circuit breaker limits")]
+    InvalidCircuitBreaker = 0x2F1D,
+    /// 12062 - cannot convert pool with currency in escrow -- withdraw all before converting
+    #[error("cannot convert pool with currency in escrow -- withdraw all before converting")]
+    ExistingFunds = 0x2F1E,
+    /// 12063 - cannot transfer pool holding nfts that are not migrated -- withdraw them before transferring
+    #[error("cannot transfer pool holding nfts that are not migrated -- withdraw them before transferring")]
+    NftsNotTransferable = 0x2F1F,
 }
 
 impl solana_program::program_error::PrintProgramError for TensorAmmError {
@@ -35,7 +35,8 @@ pub struct BuyNftCore {
     pub maker_broker: Option<solana_program::pubkey::Pubkey>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<solana_program::pubkey::Pubkey>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<solana_program::pubkey::Pubkey>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: solana_program::pubkey::Pubkey,
@@ -43,6 +44,32 @@ pub struct BuyNftCore {
     pub escrow_program: Option<solana_program::pubkey::Pubkey>,
 
     pub native_program: solana_program::pubkey::Pubkey,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<solana_program::pubkey::Pubkey>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<solana_program::pubkey::Pubkey>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
     /// The MPL core asset account.
     pub asset: solana_program::pubkey::Pubkey,
 
@@ -68,7 +95,7 @@ impl BuyNftCore {
         args: BuyNftCoreInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(31 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, false,
         ));
@@ -168,6 +195,137 @@ impl BuyNftCore {
             self.native_program,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                pool_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                taker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                owner_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_vault_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                maker_broker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                taker_broker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_associated_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                price_ladder,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                attestation_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.asset, false,
         ));
@@ -249,11 +407,23 @@ pub struct BuyNftCoreInstructionArgs {
 ///   11. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
 ///   12. `[optional]` escrow_program
 ///   13. `[optional]` native_program (default to `11111111111111111111111111111111`)
-///   14. `[writable]` asset
-///   15. `[optional]` collection
-///   16. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
-///   17. `[writable]` nft_receipt
-///   18. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   14. `[optional]` currency
+///   15. `[writable, optional]` pool_currency_ta
+///   16. `[writable, optional]` taker_currency_ta
+///   17. `[writable, optional]` owner_currency_ta
+///   18. `[writable, optional]` fee_vault_currency_ta
+///   19. `[writable, optional]` maker_broker_currency_ta
+///   20. `[writable, optional]` taker_broker_currency_ta
+///   21. `[optional]` currency_token_program
+///   22. `[optional]` currency_associated_token_program
+///   23. `[writable, optional]` price_ladder
+///   24. `[optional]` attestation_sysvar
+///   25. `[writable, optional]` trait_requirements
+///   26. `[writable]` asset
+///   27. `[optional]` collection
+///   28. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   29. `[writable]` nft_receipt
+///   30. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct BuyNftCoreBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -270,6 +440,18 @@ pub struct BuyNftCoreBuilder {
     amm_program: Option<solana_program::pubkey::Pubkey>,
     escrow_program: Option<solana_program::pubkey::Pubkey>,
     native_program: Option<solana_program::pubkey::Pubkey>,
+    currency: Option<solana_program::pubkey::Pubkey>,
+    pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    price_ladder: Option<solana_program::pubkey::Pubkey>,
+    attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
     asset: Option<solana_program::pubkey::Pubkey>,
     collection: Option<solana_program::pubkey::Pubkey>,
     mpl_core_program: Option<solana_program::pubkey::Pubkey>,
@@ -363,7 +545,8 @@ impl BuyNftCoreBuilder {
         self
     }
     /// `[optional account]`
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     #[inline(always)]
     pub fn cosigner(&mut self, cosigner: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.cosigner = cosigner;
@@ -392,6 +575,125 @@ impl BuyNftCoreBuilder {
         self.native_program = Some(native_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[inline(always)]
+    pub fn taker_currency_ta(
+        &mut self,
+        taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.taker_currency_ta = taker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The fee vault's currency token account.
+    #[inline(always)]
+    pub fn fee_vault_currency_ta(
+        &mut self,
+        fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_vault_currency_ta = fee_vault_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The maker broker's currency token account.
+    #[inline(always)]
+    pub fn maker_broker_currency_ta(
+        &mut self,
+        maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.maker_broker_currency_ta = maker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker broker's currency token account.
+    #[inline(always)]
+    pub fn taker_broker_currency_ta(
+        &mut self,
+        taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.taker_broker_currency_ta = taker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program, used to create currency token accounts.
+    #[inline(always)]
+    pub fn currency_associated_token_program(
+        &mut self,
+        currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_associated_token_program = currency_associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    #[inline(always)]
+    pub fn attestation_sysvar(
+        &mut self,
+        attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attestation_sysvar = attestation_sysvar;
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
     /// The MPL core asset account.
     #[inline(always)]
     pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -471,6 +773,18 @@ impl BuyNftCoreBuilder {
             native_program: self
                 .native_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            currency: self.currency,
+            pool_currency_ta: self.pool_currency_ta,
+            taker_currency_ta: self.taker_currency_ta,
+            owner_currency_ta: self.owner_currency_ta,
+            fee_vault_currency_ta: self.fee_vault_currency_ta,
+            maker_broker_currency_ta: self.maker_broker_currency_ta,
+            taker_broker_currency_ta: self.taker_broker_currency_ta,
+            currency_token_program: self.currency_token_program,
+            currency_associated_token_program: self.currency_associated_token_program,
+            price_ladder: self.price_ladder,
+            attestation_sysvar: self.attestation_sysvar,
+            trait_requirements: self.trait_requirements,
             asset: self.asset.expect("asset is not set"),
             collection: self.collection,
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
@@ -516,7 +830,8 @@ pub struct BuyNftCoreCpiAccounts<'a, 'b> {
     pub maker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -524,6 +839,33 @@ pub struct BuyNftCoreCpiAccounts<'a, 'b> {
     pub escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub native_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The MPL core asset account.
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -565,7 +907,8 @@ pub struct BuyNftCoreCpi<'a, 'b> {
     pub maker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -573,6 +916,33 @@ pub struct BuyNftCoreCpi<'a, 'b> {
     pub escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub native_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The MPL core asset account.
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -609,6 +979,18 @@ impl<'a, 'b> BuyNftCoreCpi<'a, 'b> {
             amm_program: accounts.amm_program,
             escrow_program: accounts.escrow_program,
             native_program: accounts.native_program,
+            currency: accounts.currency,
+            pool_currency_ta: accounts.pool_currency_ta,
+            taker_currency_ta: accounts.taker_currency_ta,
+            owner_currency_ta: accounts.owner_currency_ta,
+            fee_vault_currency_ta: accounts.fee_vault_currency_ta,
+            maker_broker_currency_ta: accounts.maker_broker_currency_ta,
+            taker_broker_currency_ta: accounts.taker_broker_currency_ta,
+            currency_token_program: accounts.currency_token_program,
+            currency_associated_token_program: accounts.currency_associated_token_program,
+            price_ladder: accounts.price_ladder,
+            attestation_sysvar: accounts.attestation_sysvar,
+            trait_requirements: accounts.trait_requirements,
             asset: accounts.asset,
             collection: accounts.collection,
             mpl_core_program: accounts.mpl_core_program,
@@ -650,7 +1032,7 @@ impl<'a, 'b> BuyNftCoreCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(31 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             false,
@@ -756,6 +1138,138 @@ impl<'a, 'b> BuyNftCoreCpi<'a, 'b> {
             *self.native_program.key,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *pool_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *taker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *owner_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_vault_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *maker_broker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *taker_broker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_associated_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *price_ladder.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *attestation_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.asset.key,
             false,
@@ -799,7 +1313,7 @@ impl<'a, 'b> BuyNftCoreCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(32 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.taker.clone());
@@ -829,6 +1343,42 @@ impl<'a, 'b> BuyNftCoreCpi<'a, 'b> {
             account_infos.push(escrow_program.clone());
         }
         account_infos.push(self.native_program.clone());
+        if let Some(currency) = self.currency {
+            account_infos.push(currency.clone());
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            account_infos.push(pool_currency_ta.clone());
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            account_infos.push(taker_currency_ta.clone());
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            account_infos.push(owner_currency_ta.clone());
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            account_infos.push(fee_vault_currency_ta.clone());
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            account_infos.push(maker_broker_currency_ta.clone());
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            account_infos.push(taker_broker_currency_ta.clone());
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            account_infos.push(currency_token_program.clone());
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            account_infos.push(currency_associated_token_program.clone());
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            account_infos.push(price_ladder.clone());
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            account_infos.push(attestation_sysvar.clone());
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
         account_infos.push(self.asset.clone());
         if let Some(collection) = self.collection {
             account_infos.push(collection.clone());
@@ -866,11 +1416,23 @@ impl<'a, 'b> BuyNftCoreCpi<'a, 'b> {
 ///   11. `[]` amm_program
 ///   12. `[optional]` escrow_program
 ///   13. `[]` native_program
-///   14. `[writable]` asset
-///   15. `[optional]` collection
-///   16. `[]` mpl_core_program
-///   17. `[writable]` nft_receipt
-///   18. `[]` system_program
+///   14. `[optional]` currency
+///   15. `[writable, optional]` pool_currency_ta
+///   16. `[writable, optional]` taker_currency_ta
+///   17. `[writable, optional]` owner_currency_ta
+///   18. `[writable, optional]` fee_vault_currency_ta
+///   19. `[writable, optional]` maker_broker_currency_ta
+///   20. `[writable, optional]` taker_broker_currency_ta
+///   21. `[optional]` currency_token_program
+///   22. `[optional]` currency_associated_token_program
+///   23. `[writable, optional]` price_ladder
+///   24. `[optional]` attestation_sysvar
+///   25. `[writable, optional]` trait_requirements
+///   26. `[writable]` asset
+///   27. `[optional]` collection
+///   28. `[]` mpl_core_program
+///   29. `[writable]` nft_receipt
+///   30. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct BuyNftCoreCpiBuilder<'a, 'b> {
     instruction: Box<BuyNftCoreCpiBuilderInstruction<'a, 'b>>,
@@ -894,6 +1456,18 @@ impl<'a, 'b> BuyNftCoreCpiBuilder<'a, 'b> {
             amm_program: None,
             escrow_program: None,
             native_program: None,
+            currency: None,
+            pool_currency_ta: None,
+            taker_currency_ta: None,
+            owner_currency_ta: None,
+            fee_vault_currency_ta: None,
+            maker_broker_currency_ta: None,
+            taker_broker_currency_ta: None,
+            currency_token_program: None,
+            currency_associated_token_program: None,
+            price_ladder: None,
+            attestation_sysvar: None,
+            trait_requirements: None,
             asset: None,
             collection: None,
             mpl_core_program: None,
@@ -996,7 +1570,8 @@ impl<'a, 'b> BuyNftCoreCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     #[inline(always)]
     pub fn cosigner(
         &mut self,
@@ -1032,6 +1607,130 @@ impl<'a, 'b> BuyNftCoreCpiBuilder<'a, 'b> {
         self.instruction.native_program = Some(native_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[inline(always)]
+    pub fn currency(
+        &mut self,
+        currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[inline(always)]
+    pub fn taker_currency_ta(
+        &mut self,
+        taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.taker_currency_ta = taker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The fee vault's currency token account.
+    #[inline(always)]
+    pub fn fee_vault_currency_ta(
+        &mut self,
+        fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_vault_currency_ta = fee_vault_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The maker broker's currency token account.
+    #[inline(always)]
+    pub fn maker_broker_currency_ta(
+        &mut self,
+        maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.maker_broker_currency_ta = maker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker broker's currency token account.
+    #[inline(always)]
+    pub fn taker_broker_currency_ta(
+        &mut self,
+        taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.taker_broker_currency_ta = taker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program, used to create currency token accounts.
+    #[inline(always)]
+    pub fn currency_associated_token_program(
+        &mut self,
+        currency_associated_token_program: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.currency_associated_token_program = currency_associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    #[inline(always)]
+    pub fn attestation_sysvar(
+        &mut self,
+        attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attestation_sysvar = attestation_sysvar;
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
     /// The MPL core asset account.
     #[inline(always)]
     pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
@@ -1164,6 +1863,30 @@ impl<'a, 'b> BuyNftCoreCpiBuilder<'a, 'b> {
                 .native_program
                 .expect("native_program is not set"),
 
+            currency: self.instruction.currency,
+
+            pool_currency_ta: self.instruction.pool_currency_ta,
+
+            taker_currency_ta: self.instruction.taker_currency_ta,
+
+            owner_currency_ta: self.instruction.owner_currency_ta,
+
+            fee_vault_currency_ta: self.instruction.fee_vault_currency_ta,
+
+            maker_broker_currency_ta: self.instruction.maker_broker_currency_ta,
+
+            taker_broker_currency_ta: self.instruction.taker_broker_currency_ta,
+
+            currency_token_program: self.instruction.currency_token_program,
+
+            currency_associated_token_program: self.instruction.currency_associated_token_program,
+
+            price_ladder: self.instruction.price_ladder,
+
+            attestation_sysvar: self.instruction.attestation_sysvar,
+
+            trait_requirements: self.instruction.trait_requirements,
+
             asset: self.instruction.asset.expect("asset is not set"),
 
             collection: self.instruction.collection,
@@ -1208,6 +1931,18 @@ struct BuyNftCoreCpiBuilderInstruction<'a, 'b> {
     amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     native_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -35,7 +35,8 @@ pub struct BuyNftT22 {
     pub maker_broker: Option<solana_program::pubkey::Pubkey>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<solana_program::pubkey::Pubkey>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<solana_program::pubkey::Pubkey>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: solana_program::pubkey::Pubkey,
@@ -43,6 +44,32 @@ pub struct BuyNftT22 {
     pub escrow_program: Option<solana_program::pubkey::Pubkey>,
 
     pub native_program: solana_program::pubkey::Pubkey,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<solana_program::pubkey::Pubkey>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<solana_program::pubkey::Pubkey>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
     /// The mint account of the NFT.
     pub mint: solana_program::pubkey::Pubkey,
     /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
@@ -72,7 +99,7 @@ impl BuyNftT22 {
         args: BuyNftT22InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(33 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, false,
         ));
@@ -172,6 +199,137 @@ impl BuyNftT22 {
             self.native_program,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                pool_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                taker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                owner_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_vault_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                maker_broker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                taker_broker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_associated_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                price_ladder,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                attestation_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -255,13 +413,25 @@ pub struct BuyNftT22InstructionArgs {
 ///   11. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
 ///   12. `[optional]` escrow_program
 ///   13. `[optional]` native_program (default to `11111111111111111111111111111111`)
-///   14. `[]` mint
-///   15. `[writable]` nft_receipt
-///   16. `[writable]` taker_ta
-///   17. `[writable]` pool_ta
-///   18. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
-///   19. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-///   20. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   14. `[optional]` currency
+///   15. `[writable, optional]` pool_currency_ta
+///   16. `[writable, optional]` taker_currency_ta
+///   17. `[writable, optional]` owner_currency_ta
+///   18. `[writable, optional]` fee_vault_currency_ta
+///   19. `[writable, optional]` maker_broker_currency_ta
+///   20. `[writable, optional]` taker_broker_currency_ta
+///   21. `[optional]` currency_token_program
+///   22. `[optional]` currency_associated_token_program
+///   23. `[writable, optional]` price_ladder
+///   24. `[optional]` attestation_sysvar
+///   25. `[writable, optional]` trait_requirements
+///   26. `[]` mint
+///   27. `[writable]` nft_receipt
+///   28. `[writable]` taker_ta
+///   29. `[writable]` pool_ta
+///   30. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   31. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   32. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct BuyNftT22Builder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -278,6 +448,18 @@ pub struct BuyNftT22Builder {
     amm_program: Option<solana_program::pubkey::Pubkey>,
     escrow_program: Option<solana_program::pubkey::Pubkey>,
     native_program: Option<solana_program::pubkey::Pubkey>,
+    currency: Option<solana_program::pubkey::Pubkey>,
+    pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    price_ladder: Option<solana_program::pubkey::Pubkey>,
+    attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     nft_receipt: Option<solana_program::pubkey::Pubkey>,
     taker_ta: Option<solana_program::pubkey::Pubkey>,
@@ -373,7 +555,8 @@ impl BuyNftT22Builder {
         self
     }
     /// `[optional account]`
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     #[inline(always)]
     pub fn cosigner(&mut self, cosigner: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.cosigner = cosigner;
@@ -402,6 +585,125 @@ impl BuyNftT22Builder {
         self.native_program = Some(native_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[inline(always)]
+    pub fn taker_currency_ta(
+        &mut self,
+        taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.taker_currency_ta = taker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The fee vault's currency token account.
+    #[inline(always)]
+    pub fn fee_vault_currency_ta(
+        &mut self,
+        fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_vault_currency_ta = fee_vault_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The maker broker's currency token account.
+    #[inline(always)]
+    pub fn maker_broker_currency_ta(
+        &mut self,
+        maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.maker_broker_currency_ta = maker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker broker's currency token account.
+    #[inline(always)]
+    pub fn taker_broker_currency_ta(
+        &mut self,
+        taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.taker_broker_currency_ta = taker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program, used to create currency token accounts.
+    #[inline(always)]
+    pub fn currency_associated_token_program(
+        &mut self,
+        currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_associated_token_program = currency_associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    #[inline(always)]
+    pub fn attestation_sysvar(
+        &mut self,
+        attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attestation_sysvar = attestation_sysvar;
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -494,6 +796,18 @@ impl BuyNftT22Builder {
             native_program: self
                 .native_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            currency: self.currency,
+            pool_currency_ta: self.pool_currency_ta,
+            taker_currency_ta: self.taker_currency_ta,
+            owner_currency_ta: self.owner_currency_ta,
+            fee_vault_currency_ta: self.fee_vault_currency_ta,
+            maker_broker_currency_ta: self.maker_broker_currency_ta,
+            taker_broker_currency_ta: self.taker_broker_currency_ta,
+            currency_token_program: self.currency_token_program,
+            currency_associated_token_program: self.currency_associated_token_program,
+            price_ladder: self.price_ladder,
+            attestation_sysvar: self.attestation_sysvar,
+            trait_requirements: self.trait_requirements,
             mint: self.mint.expect("mint is not set"),
             nft_receipt: self.nft_receipt.expect("nft_receipt is not set"),
             taker_ta: self.taker_ta.expect("taker_ta is not set"),
@@ -543,7 +857,8 @@ pub struct BuyNftT22CpiAccounts<'a, 'b> {
     pub maker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -551,6 +866,33 @@ pub struct BuyNftT22CpiAccounts<'a, 'b> {
     pub escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub native_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
@@ -596,7 +938,8 @@ pub struct BuyNftT22Cpi<'a, 'b> {
     pub maker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -604,6 +947,33 @@ pub struct BuyNftT22Cpi<'a, 'b> {
     pub escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub native_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
@@ -644,6 +1014,18 @@ impl<'a, 'b> BuyNftT22Cpi<'a, 'b> {
             amm_program: accounts.amm_program,
             escrow_program: accounts.escrow_program,
             native_program: accounts.native_program,
+            currency: accounts.currency,
+            pool_currency_ta: accounts.pool_currency_ta,
+            taker_currency_ta: accounts.taker_currency_ta,
+            owner_currency_ta: accounts.owner_currency_ta,
+            fee_vault_currency_ta: accounts.fee_vault_currency_ta,
+            maker_broker_currency_ta: accounts.maker_broker_currency_ta,
+            taker_broker_currency_ta: accounts.taker_broker_currency_ta,
+            currency_token_program: accounts.currency_token_program,
+            currency_associated_token_program: accounts.currency_associated_token_program,
+            price_ladder: accounts.price_ladder,
+            attestation_sysvar: accounts.attestation_sysvar,
+            trait_requirements: accounts.trait_requirements,
             mint: accounts.mint,
             nft_receipt: accounts.nft_receipt,
             taker_ta: accounts.taker_ta,
@@ -687,7 +1069,7 @@ impl<'a, 'b> BuyNftT22Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(33 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             false,
@@ -793,6 +1175,138 @@ impl<'a, 'b> BuyNftT22Cpi<'a, 'b> {
             *self.native_program.key,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *pool_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *taker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *owner_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_vault_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *maker_broker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *taker_broker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_associated_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *price_ladder.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *attestation_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -837,7 +1351,7 @@ impl<'a, 'b> BuyNftT22Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(22 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(34 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.taker.clone());
@@ -867,6 +1381,42 @@ impl<'a, 'b> BuyNftT22Cpi<'a, 'b> {
             account_infos.push(escrow_program.clone());
         }
         account_infos.push(self.native_program.clone());
+        if let Some(currency) = self.currency {
+            account_infos.push(currency.clone());
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            account_infos.push(pool_currency_ta.clone());
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            account_infos.push(taker_currency_ta.clone());
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            account_infos.push(owner_currency_ta.clone());
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            account_infos.push(fee_vault_currency_ta.clone());
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            account_infos.push(maker_broker_currency_ta.clone());
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            account_infos.push(taker_broker_currency_ta.clone());
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            account_infos.push(currency_token_program.clone());
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            account_infos.push(currency_associated_token_program.clone());
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            account_infos.push(price_ladder.clone());
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            account_infos.push(attestation_sysvar.clone());
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
         account_infos.push(self.mint.clone());
         account_infos.push(self.nft_receipt.clone());
         account_infos.push(self.taker_ta.clone());
@@ -904,13 +1454,25 @@ impl<'a, 'b> BuyNftT22Cpi<'a, 'b> {
 ///   11. `[]` amm_program
 ///   12. `[optional]` escrow_program
 ///   13. `[]` native_program
-///   14. `[]` mint
-///   15. `[writable]` nft_receipt
-///   16. `[writable]` taker_ta
-///   17. `[writable]` pool_ta
-///   18. `[]` token_program
-///   19. `[]` associated_token_program
-///   20. `[]` system_program
+///   14. `[optional]` currency
+///   15. `[writable, optional]` pool_currency_ta
+///   16. `[writable, optional]` taker_currency_ta
+///   17. `[writable, optional]` owner_currency_ta
+///   18. `[writable, optional]` fee_vault_currency_ta
+///   19. `[writable, optional]` maker_broker_currency_ta
+///   20. `[writable, optional]` taker_broker_currency_ta
+///   21. `[optional]` currency_token_program
+///   22. `[optional]` currency_associated_token_program
+///   23. `[writable, optional]` price_ladder
+///   24. `[optional]` attestation_sysvar
+///   25. `[writable, optional]` trait_requirements
+///   26. `[]` mint
+///   27. `[writable]` nft_receipt
+///   28. `[writable]` taker_ta
+///   29. `[writable]` pool_ta
+///   30. `[]` token_program
+///   31. `[]` associated_token_program
+///   32. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct BuyNftT22CpiBuilder<'a, 'b> {
     instruction: Box<BuyNftT22CpiBuilderInstruction<'a, 'b>>,
@@ -934,6 +1496,18 @@ impl<'a, 'b> BuyNftT22CpiBuilder<'a, 'b> {
             amm_program: None,
             escrow_program: None,
             native_program: None,
+            currency: None,
+            pool_currency_ta: None,
+            taker_currency_ta: None,
+            owner_currency_ta: None,
+            fee_vault_currency_ta: None,
+            maker_broker_currency_ta: None,
+            taker_broker_currency_ta: None,
+            currency_token_program: None,
+            currency_associated_token_program: None,
+            price_ladder: None,
+            attestation_sysvar: None,
+            trait_requirements: None,
             mint: None,
             nft_receipt: None,
             taker_ta: None,
@@ -1038,7 +1612,8 @@ impl<'a, 'b> BuyNftT22CpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     #[inline(always)]
     pub fn cosigner(
         &mut self,
@@ -1074,6 +1649,130 @@ impl<'a, 'b> BuyNftT22CpiBuilder<'a, 'b> {
         self.instruction.native_program = Some(native_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[inline(always)]
+    pub fn currency(
+        &mut self,
+        currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[inline(always)]
+    pub fn taker_currency_ta(
+        &mut self,
+        taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.taker_currency_ta = taker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The fee vault's currency token account.
+    #[inline(always)]
+    pub fn fee_vault_currency_ta(
+        &mut self,
+        fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_vault_currency_ta = fee_vault_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The maker broker's currency token account.
+    #[inline(always)]
+    pub fn maker_broker_currency_ta(
+        &mut self,
+        maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.maker_broker_currency_ta = maker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker broker's currency token account.
+    #[inline(always)]
+    pub fn taker_broker_currency_ta(
+        &mut self,
+        taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.taker_broker_currency_ta = taker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program, used to create currency token accounts.
+    #[inline(always)]
+    pub fn currency_associated_token_program(
+        &mut self,
+        currency_associated_token_program: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.currency_associated_token_program = currency_associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    #[inline(always)]
+    pub fn attestation_sysvar(
+        &mut self,
+        attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attestation_sysvar = attestation_sysvar;
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
@@ -1224,6 +1923,30 @@ impl<'a, 'b> BuyNftT22CpiBuilder<'a, 'b> {
                 .native_program
                 .expect("native_program is not set"),
 
+            currency: self.instruction.currency,
+
+            pool_currency_ta: self.instruction.pool_currency_ta,
+
+            taker_currency_ta: self.instruction.taker_currency_ta,
+
+            owner_currency_ta: self.instruction.owner_currency_ta,
+
+            fee_vault_currency_ta: self.instruction.fee_vault_currency_ta,
+
+            maker_broker_currency_ta: self.instruction.maker_broker_currency_ta,
+
+            taker_broker_currency_ta: self.instruction.taker_broker_currency_ta,
+
+            currency_token_program: self.instruction.currency_token_program,
+
+            currency_associated_token_program: self.instruction.currency_associated_token_program,
+
+            price_ladder: self.instruction.price_ladder,
+
+            attestation_sysvar: self.instruction.attestation_sysvar,
+
+            trait_requirements: self.instruction.trait_requirements,
+
             mint: self.instruction.mint.expect("mint is not set"),
 
             nft_receipt: self
@@ -1275,6 +1998,18 @@ struct BuyNftT22CpiBuilderInstruction<'a, 'b> {
     amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     native_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     nft_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     taker_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -19,6 +19,24 @@ pub struct CloseExpiredPool {
     pub pool: solana_program::pubkey::Pubkey,
     /// The Solana system program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The SPL mint the pool is priced in; required to close SPL currency pools.
+    pub currency: Option<solana_program::pubkey::Pubkey>,
+    /// The pool's currency token account, closed along with the pool.
+    pub pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The owner's currency token account, receiving any funds left in the pool.
+    pub owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The price ladder of the pool, closed along with the pool; required for custom curves.
+    pub price_ladder: Option<solana_program::pubkey::Pubkey>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
+    /// The trait requirements of the pool, closed along with the pool; required if the pool
+    /// has trait requirements.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    /// The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+    /// bounty is refunded to the rent payer.
+    pub keeper: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CloseExpiredPool {
@@ -30,7 +48,7 @@ impl CloseExpiredPool {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.rent_payer,
             false,
@@ -45,6 +63,83 @@ impl CloseExpiredPool {
             self.system_program,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                pool_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                owner_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                price_ladder,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(keeper) = self.keeper {
+            accounts.push(solana_program::instruction::AccountMeta::new(keeper, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = CloseExpiredPoolInstructionData::new().try_to_vec().unwrap();
 
@@ -83,12 +178,28 @@ impl Default for CloseExpiredPoolInstructionData {
 ///   1. `[writable]` owner
 ///   2. `[writable]` pool
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` currency
+///   5. `[writable, optional]` pool_currency_ta
+///   6. `[writable, optional]` owner_currency_ta
+///   7. `[optional]` currency_token_program
+///   8. `[writable, optional]` price_ladder
+///   9. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
+///   10. `[writable, optional]` trait_requirements
+///   11. `[writable, signer, optional]` keeper
 #[derive(Clone, Debug, Default)]
 pub struct CloseExpiredPoolBuilder {
     rent_payer: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     pool: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    currency: Option<solana_program::pubkey::Pubkey>,
+    pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    price_ladder: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    keeper: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -122,6 +233,79 @@ impl CloseExpiredPoolBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required to close SPL currency pools.
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, closed along with the pool.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving any funds left in the pool.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool, closed along with the pool; required for custom curves.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The trait requirements of the pool, closed along with the pool; required if the pool
+    /// has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
+    /// `[optional account]`
+    /// The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+    /// bounty is refunded to the rent payer.
+    #[inline(always)]
+    pub fn keeper(&mut self, keeper: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.keeper = keeper;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -149,6 +333,16 @@ impl CloseExpiredPoolBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            currency: self.currency,
+            pool_currency_ta: self.pool_currency_ta,
+            owner_currency_ta: self.owner_currency_ta,
+            currency_token_program: self.currency_token_program,
+            price_ladder: self.price_ladder,
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
+            trait_requirements: self.trait_requirements,
+            keeper: self.keeper,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -166,6 +360,24 @@ pub struct CloseExpiredPoolCpiAccounts<'a, 'b> {
     pub pool: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required to close SPL currency pools.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, closed along with the pool.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving any funds left in the pool.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool, closed along with the pool; required for custom curves.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The trait requirements of the pool, closed along with the pool; required if the pool
+    /// has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+    /// bounty is refunded to the rent payer.
+    pub keeper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `close_expired_pool` CPI instruction.
@@ -181,6 +393,24 @@ pub struct CloseExpiredPoolCpi<'a, 'b> {
     pub pool: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required to close SPL currency pools.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, closed along with the pool.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving any funds left in the pool.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool, closed along with the pool; required for custom curves.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The trait requirements of the pool, closed along with the pool; required if the pool
+    /// has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+    /// bounty is refunded to the rent payer.
+    pub keeper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> CloseExpiredPoolCpi<'a, 'b> {
@@ -194,6 +424,14 @@ impl<'a, 'b> CloseExpiredPoolCpi<'a, 'b> {
             owner: accounts.owner,
             pool: accounts.pool,
             system_program: accounts.system_program,
+            currency: accounts.currency,
+            pool_currency_ta: accounts.pool_currency_ta,
+            owner_currency_ta: accounts.owner_currency_ta,
+            currency_token_program: accounts.currency_token_program,
+            price_ladder: accounts.price_ladder,
+            amm_program: accounts.amm_program,
+            trait_requirements: accounts.trait_requirements,
+            keeper: accounts.keeper,
         }
     }
     #[inline(always)]
@@ -229,7 +467,7 @@ impl<'a, 'b> CloseExpiredPoolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.rent_payer.key,
             false,
@@ -246,6 +484,87 @@ impl<'a, 'b> CloseExpiredPoolCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *pool_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *owner_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *price_ladder.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(keeper) = self.keeper {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *keeper.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -260,12 +579,34 @@ impl<'a, 'b> CloseExpiredPoolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.rent_payer.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.pool.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(currency) = self.currency {
+            account_infos.push(currency.clone());
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            account_infos.push(pool_currency_ta.clone());
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            account_infos.push(owner_currency_ta.clone());
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            account_infos.push(currency_token_program.clone());
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            account_infos.push(price_ladder.clone());
+        }
+        account_infos.push(self.amm_program.clone());
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
+        if let Some(keeper) = self.keeper {
+            account_infos.push(keeper.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -286,6 +627,14 @@ impl<'a, 'b> CloseExpiredPoolCpi<'a, 'b> {
 ///   1. `[writable]` owner
 ///   2. `[writable]` pool
 ///   3. `[]` system_program
+///   4. `[optional]` currency
+///   5. `[writable, optional]` pool_currency_ta
+///   6. `[writable, optional]` owner_currency_ta
+///   7. `[optional]` currency_token_program
+///   8. `[writable, optional]` price_ladder
+///   9. `[]` amm_program
+///   10. `[writable, optional]` trait_requirements
+///   11. `[writable, signer, optional]` keeper
 #[derive(Clone, Debug)]
 pub struct CloseExpiredPoolCpiBuilder<'a, 'b> {
     instruction: Box<CloseExpiredPoolCpiBuilderInstruction<'a, 'b>>,
@@ -299,6 +648,14 @@ impl<'a, 'b> CloseExpiredPoolCpiBuilder<'a, 'b> {
             owner: None,
             pool: None,
             system_program: None,
+            currency: None,
+            pool_currency_ta: None,
+            owner_currency_ta: None,
+            currency_token_program: None,
+            price_ladder: None,
+            amm_program: None,
+            trait_requirements: None,
+            keeper: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -334,6 +691,87 @@ impl<'a, 'b> CloseExpiredPoolCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required to close SPL currency pools.
+    #[inline(always)]
+    pub fn currency(
+        &mut self,
+        currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, closed along with the pool.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving any funds left in the pool.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool, closed along with the pool; required for custom curves.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.price_ladder = price_ladder;
+        self
+    }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The trait requirements of the pool, closed along with the pool; required if the pool
+    /// has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
+    /// `[optional account]`
+    /// The keeper closing the pool, receiving the rest of the keeper bounty. Without it, the
+    /// bounty is refunded to the rent payer.
+    #[inline(always)]
+    pub fn keeper(
+        &mut self,
+        keeper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.keeper = keeper;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -388,6 +826,25 @@ impl<'a, 'b> CloseExpiredPoolCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            currency: self.instruction.currency,
+
+            pool_currency_ta: self.instruction.pool_currency_ta,
+
+            owner_currency_ta: self.instruction.owner_currency_ta,
+
+            currency_token_program: self.instruction.currency_token_program,
+
+            price_ladder: self.instruction.price_ladder,
+
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
+
+            trait_requirements: self.instruction.trait_requirements,
+
+            keeper: self.instruction.keeper,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -403,6 +860,14 @@ struct CloseExpiredPoolCpiBuilderInstruction<'a, 'b> {
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    keeper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,7 +5,9 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::CircuitBreakerArgs;
 use crate::generated::types::PoolConfig;
+use crate::generated::types::PriceLadderArgs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -25,6 +27,18 @@ pub struct CreatePool {
     pub shared_escrow: Option<solana_program::pubkey::Pubkey>,
     /// The Solana system program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The SPL mint the pool is priced in; required if `args.currency` is set.
+    pub currency: Option<solana_program::pubkey::Pubkey>,
+    /// The pool's currency token account, which escrows the pool's funds for SPL currency pools.
+    pub pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The SPL associated token program.
+    pub associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<solana_program::pubkey::Pubkey>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
 }
 
 impl CreatePool {
@@ -40,7 +54,7 @@ impl CreatePool {
         args: CreatePoolInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.rent_payer,
             true,
@@ -70,6 +84,64 @@ impl CreatePool {
             self.system_program,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                pool_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(associated_token_program) = self.associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                associated_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                price_ladder,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreatePoolInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -107,11 +179,31 @@ impl Default for CreatePoolInstructionData {
 pub struct CreatePoolInstructionArgs {
     pub pool_id: [u8; 32],
     pub config: PoolConfig,
-    pub currency: Option<Pubkey>,
+    /// SPL mint the pool is priced in; `None` (or the default pubkey) for SOL.
+    pub currency_mint: Option<Pubkey>,
     pub cosigner: Option<Pubkey>,
     pub maker_broker: Option<Pubkey>,
     pub max_taker_sell_count: Option<u32>,
     pub expire_in_sec: Option<u64>,
+    /// Decay rate of GDA curves, in basis points per decay period.
+    pub decay_bps: Option<u16>,
+    /// Price GDA curves stop decaying at.
+    pub floor_price: Option<u64>,
+    /// Prices of custom curves.
+    pub price_ladder_args: Option<PriceLadderArgs>,
+    /// Key allowed to edit the pricing and expiry of the pool on behalf of the owner.
+    pub manager: Option<Pubkey>,
+    /// Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it.
+    pub max_taker_buy_count: Option<u32>,
+    /// Limit how many NFTs the pool can hold through sells and deposits.
+    pub max_nfts_held: Option<u32>,
+    /// Limit how much the pool can pay for NFTs sold into it over its lifetime.
+    pub max_total_spend: Option<u64>,
+    /// Limits on the trades the pool can make within a time window.
+    pub circuit_breaker: Option<CircuitBreakerArgs>,
+    /// Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+    /// the pool once it expires.
+    pub keeper_bounty: Option<u64>,
 }
 
 /// Instruction builder for `CreatePool`.
@@ -124,6 +216,12 @@ pub struct CreatePoolInstructionArgs {
 ///   3. `[]` whitelist
 ///   4. `[optional]` shared_escrow
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` currency
+///   7. `[writable, optional]` pool_currency_ta
+///   8. `[optional]` currency_token_program
+///   9. `[optional]` associated_token_program
+///   10. `[writable, optional]` price_ladder
+///   11. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
 #[derive(Clone, Debug, Default)]
 pub struct CreatePoolBuilder {
     rent_payer: Option<solana_program::pubkey::Pubkey>,
@@ -132,13 +230,28 @@ pub struct CreatePoolBuilder {
     whitelist: Option<solana_program::pubkey::Pubkey>,
     shared_escrow: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    currency: Option<solana_program::pubkey::Pubkey>,
+    pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    price_ladder: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
     pool_id: Option<[u8; 32]>,
     config: Option<PoolConfig>,
-    currency: Option<Pubkey>,
+    currency_mint: Option<Pubkey>,
     cosigner: Option<Pubkey>,
     maker_broker: Option<Pubkey>,
     max_taker_sell_count: Option<u32>,
     expire_in_sec: Option<u64>,
+    decay_bps: Option<u16>,
+    floor_price: Option<u64>,
+    price_ladder_args: Option<PriceLadderArgs>,
+    manager: Option<Pubkey>,
+    max_taker_buy_count: Option<u32>,
+    max_nfts_held: Option<u32>,
+    max_total_spend: Option<u64>,
+    circuit_breaker: Option<CircuitBreakerArgs>,
+    keeper_bounty: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -187,6 +300,60 @@ impl CreatePoolBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if `args.currency` is set.
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds for SPL currency pools.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program.
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.associated_token_program = associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
     #[inline(always)]
     pub fn pool_id(&mut self, pool_id: [u8; 32]) -> &mut Self {
         self.pool_id = Some(pool_id);
@@ -198,9 +365,10 @@ impl CreatePoolBuilder {
         self
     }
     /// `[optional argument]`
+    /// SPL mint the pool is priced in; `None` (or the default pubkey) for SOL.
     #[inline(always)]
-    pub fn currency(&mut self, currency: Pubkey) -> &mut Self {
-        self.currency = Some(currency);
+    pub fn currency_mint(&mut self, currency_mint: Pubkey) -> &mut Self {
+        self.currency_mint = Some(currency_mint);
         self
     }
     /// `[optional argument]`
@@ -227,6 +395,70 @@ impl CreatePoolBuilder {
         self.expire_in_sec = Some(expire_in_sec);
         self
     }
+    /// `[optional argument]`
+    /// Decay rate of GDA curves, in basis points per decay period.
+    #[inline(always)]
+    pub fn decay_bps(&mut self, decay_bps: u16) -> &mut Self {
+        self.decay_bps = Some(decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    /// Price GDA curves stop decaying at.
+    #[inline(always)]
+    pub fn floor_price(&mut self, floor_price: u64) -> &mut Self {
+        self.floor_price = Some(floor_price);
+        self
+    }
+    /// `[optional argument]`
+    /// Prices of custom curves.
+    #[inline(always)]
+    pub fn price_ladder_args(&mut self, price_ladder_args: PriceLadderArgs) -> &mut Self {
+        self.price_ladder_args = Some(price_ladder_args);
+        self
+    }
+    /// `[optional argument]`
+    /// Key allowed to edit the pricing and expiry of the pool on behalf of the owner.
+    #[inline(always)]
+    pub fn manager(&mut self, manager: Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
+    /// `[optional argument]`
+    /// Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it.
+    #[inline(always)]
+    pub fn max_taker_buy_count(&mut self, max_taker_buy_count: u32) -> &mut Self {
+        self.max_taker_buy_count = Some(max_taker_buy_count);
+        self
+    }
+    /// `[optional argument]`
+    /// Limit how many NFTs the pool can hold through sells and deposits.
+    #[inline(always)]
+    pub fn max_nfts_held(&mut self, max_nfts_held: u32) -> &mut Self {
+        self.max_nfts_held = Some(max_nfts_held);
+        self
+    }
+    /// `[optional argument]`
+    /// Limit how much the pool can pay for NFTs sold into it over its lifetime.
+    #[inline(always)]
+    pub fn max_total_spend(&mut self, max_total_spend: u64) -> &mut Self {
+        self.max_total_spend = Some(max_total_spend);
+        self
+    }
+    /// `[optional argument]`
+    /// Limits on the trades the pool can make within a time window.
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreakerArgs) -> &mut Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+    /// `[optional argument]`
+    /// Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+    /// the pool once it expires.
+    #[inline(always)]
+    pub fn keeper_bounty(&mut self, keeper_bounty: u64) -> &mut Self {
+        self.keeper_bounty = Some(keeper_bounty);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -256,15 +488,32 @@ impl CreatePoolBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            currency: self.currency,
+            pool_currency_ta: self.pool_currency_ta,
+            currency_token_program: self.currency_token_program,
+            associated_token_program: self.associated_token_program,
+            price_ladder: self.price_ladder,
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
         };
         let args = CreatePoolInstructionArgs {
             pool_id: self.pool_id.clone().expect("pool_id is not set"),
             config: self.config.clone().expect("config is not set"),
-            currency: self.currency.clone(),
+            currency_mint: self.currency_mint.clone(),
             cosigner: self.cosigner.clone(),
             maker_broker: self.maker_broker.clone(),
             max_taker_sell_count: self.max_taker_sell_count.clone(),
             expire_in_sec: self.expire_in_sec.clone(),
+            decay_bps: self.decay_bps.clone(),
+            floor_price: self.floor_price.clone(),
+            price_ladder_args: self.price_ladder_args.clone(),
+            manager: self.manager.clone(),
+            max_taker_buy_count: self.max_taker_buy_count.clone(),
+            max_nfts_held: self.max_nfts_held.clone(),
+            max_total_spend: self.max_total_spend.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            keeper_bounty: self.keeper_bounty.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -286,6 +535,18 @@ pub struct CreatePoolCpiAccounts<'a, 'b> {
     pub shared_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if `args.currency` is set.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds for SPL currency pools.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program.
+    pub associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `create_pool` CPI instruction.
@@ -305,6 +566,18 @@ pub struct CreatePoolCpi<'a, 'b> {
     pub shared_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if `args.currency` is set.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds for SPL currency pools.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program.
+    pub associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreatePoolInstructionArgs,
 }
@@ -323,6 +596,12 @@ impl<'a, 'b> CreatePoolCpi<'a, 'b> {
             whitelist: accounts.whitelist,
             shared_escrow: accounts.shared_escrow,
             system_program: accounts.system_program,
+            currency: accounts.currency,
+            pool_currency_ta: accounts.pool_currency_ta,
+            currency_token_program: accounts.currency_token_program,
+            associated_token_program: accounts.associated_token_program,
+            price_ladder: accounts.price_ladder,
+            amm_program: accounts.amm_program,
             __args: args,
         }
     }
@@ -359,7 +638,7 @@ impl<'a, 'b> CreatePoolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.rent_payer.key,
             true,
@@ -391,6 +670,65 @@ impl<'a, 'b> CreatePoolCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *pool_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(associated_token_program) = self.associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *associated_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *price_ladder.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -407,7 +745,7 @@ impl<'a, 'b> CreatePoolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.rent_payer.clone());
         account_infos.push(self.owner.clone());
@@ -417,6 +755,22 @@ impl<'a, 'b> CreatePoolCpi<'a, 'b> {
             account_infos.push(shared_escrow.clone());
         }
         account_infos.push(self.system_program.clone());
+        if let Some(currency) = self.currency {
+            account_infos.push(currency.clone());
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            account_infos.push(pool_currency_ta.clone());
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            account_infos.push(currency_token_program.clone());
+        }
+        if let Some(associated_token_program) = self.associated_token_program {
+            account_infos.push(associated_token_program.clone());
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            account_infos.push(price_ladder.clone());
+        }
+        account_infos.push(self.amm_program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -439,6 +793,12 @@ impl<'a, 'b> CreatePoolCpi<'a, 'b> {
 ///   3. `[]` whitelist
 ///   4. `[optional]` shared_escrow
 ///   5. `[]` system_program
+///   6. `[optional]` currency
+///   7. `[writable, optional]` pool_currency_ta
+///   8. `[optional]` currency_token_program
+///   9. `[optional]` associated_token_program
+///   10. `[writable, optional]` price_ladder
+///   11. `[]` amm_program
 #[derive(Clone, Debug)]
 pub struct CreatePoolCpiBuilder<'a, 'b> {
     instruction: Box<CreatePoolCpiBuilderInstruction<'a, 'b>>,
@@ -454,13 +814,28 @@ impl<'a, 'b> CreatePoolCpiBuilder<'a, 'b> {
             whitelist: None,
             shared_escrow: None,
             system_program: None,
+            currency: None,
+            pool_currency_ta: None,
+            currency_token_program: None,
+            associated_token_program: None,
+            price_ladder: None,
+            amm_program: None,
             pool_id: None,
             config: None,
-            currency: None,
+            currency_mint: None,
             cosigner: None,
             maker_broker: None,
             max_taker_sell_count: None,
             expire_in_sec: None,
+            decay_bps: None,
+            floor_price: None,
+            price_ladder_args: None,
+            manager: None,
+            max_taker_buy_count: None,
+            max_nfts_held: None,
+            max_total_spend: None,
+            circuit_breaker: None,
+            keeper_bounty: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -514,6 +889,65 @@ impl<'a, 'b> CreatePoolCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if `args.currency` is set.
+    #[inline(always)]
+    pub fn currency(
+        &mut self,
+        currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds for SPL currency pools.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program.
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.price_ladder = price_ladder;
+        self
+    }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
     #[inline(always)]
     pub fn pool_id(&mut self, pool_id: [u8; 32]) -> &mut Self {
         self.instruction.pool_id = Some(pool_id);
@@ -525,9 +959,10 @@ impl<'a, 'b> CreatePoolCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional argument]`
+    /// SPL mint the pool is priced in; `None` (or the default pubkey) for SOL.
     #[inline(always)]
-    pub fn currency(&mut self, currency: Pubkey) -> &mut Self {
-        self.instruction.currency = Some(currency);
+    pub fn currency_mint(&mut self, currency_mint: Pubkey) -> &mut Self {
+        self.instruction.currency_mint = Some(currency_mint);
         self
     }
     /// `[optional argument]`
@@ -554,6 +989,70 @@ impl<'a, 'b> CreatePoolCpiBuilder<'a, 'b> {
         self.instruction.expire_in_sec = Some(expire_in_sec);
         self
     }
+    /// `[optional argument]`
+    /// Decay rate of GDA curves, in basis points per decay period.
+    #[inline(always)]
+    pub fn decay_bps(&mut self, decay_bps: u16) -> &mut Self {
+        self.instruction.decay_bps = Some(decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    /// Price GDA curves stop decaying at.
+    #[inline(always)]
+    pub fn floor_price(&mut self, floor_price: u64) -> &mut Self {
+        self.instruction.floor_price = Some(floor_price);
+        self
+    }
+    /// `[optional argument]`
+    /// Prices of custom curves.
+    #[inline(always)]
+    pub fn price_ladder_args(&mut self, price_ladder_args: PriceLadderArgs) -> &mut Self {
+        self.instruction.price_ladder_args = Some(price_ladder_args);
+        self
+    }
+    /// `[optional argument]`
+    /// Key allowed to edit the pricing and expiry of the pool on behalf of the owner.
+    #[inline(always)]
+    pub fn manager(&mut self, manager: Pubkey) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    /// `[optional argument]`
+    /// Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it.
+    #[inline(always)]
+    pub fn max_taker_buy_count(&mut self, max_taker_buy_count: u32) -> &mut Self {
+        self.instruction.max_taker_buy_count = Some(max_taker_buy_count);
+        self
+    }
+    /// `[optional argument]`
+    /// Limit how many NFTs the pool can hold through sells and deposits.
+    #[inline(always)]
+    pub fn max_nfts_held(&mut self, max_nfts_held: u32) -> &mut Self {
+        self.instruction.max_nfts_held = Some(max_nfts_held);
+        self
+    }
+    /// `[optional argument]`
+    /// Limit how much the pool can pay for NFTs sold into it over its lifetime.
+    #[inline(always)]
+    pub fn max_total_spend(&mut self, max_total_spend: u64) -> &mut Self {
+        self.instruction.max_total_spend = Some(max_total_spend);
+        self
+    }
+    /// `[optional argument]`
+    /// Limits on the trades the pool can make within a time window.
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreakerArgs) -> &mut Self {
+        self.instruction.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+    /// `[optional argument]`
+    /// Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+    /// the pool once it expires.
+    #[inline(always)]
+    pub fn keeper_bounty(&mut self, keeper_bounty: u64) -> &mut Self {
+        self.instruction.keeper_bounty = Some(keeper_bounty);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -602,11 +1101,20 @@ impl<'a, 'b> CreatePoolCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("pool_id is not set"),
             config: self.instruction.config.clone().expect("config is not set"),
-            currency: self.instruction.currency.clone(),
+            currency_mint: self.instruction.currency_mint.clone(),
             cosigner: self.instruction.cosigner.clone(),
             maker_broker: self.instruction.maker_broker.clone(),
             max_taker_sell_count: self.instruction.max_taker_sell_count.clone(),
             expire_in_sec: self.instruction.expire_in_sec.clone(),
+            decay_bps: self.instruction.decay_bps.clone(),
+            floor_price: self.instruction.floor_price.clone(),
+            price_ladder_args: self.instruction.price_ladder_args.clone(),
+            manager: self.instruction.manager.clone(),
+            max_taker_buy_count: self.instruction.max_taker_buy_count.clone(),
+            max_nfts_held: self.instruction.max_nfts_held.clone(),
+            max_total_spend: self.instruction.max_total_spend.clone(),
+            circuit_breaker: self.instruction.circuit_breaker.clone(),
+            keeper_bounty: self.instruction.keeper_bounty.clone(),
         };
         let instruction = CreatePoolCpi {
             __program: self.instruction.__program,
@@ -625,6 +1133,21 @@ impl<'a, 'b> CreatePoolCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            currency: self.instruction.currency,
+
+            pool_currency_ta: self.instruction.pool_currency_ta,
+
+            currency_token_program: self.instruction.currency_token_program,
+
+            associated_token_program: self.instruction.associated_token_program,
+
+            price_ladder: self.instruction.price_ladder,
+
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -643,13 +1166,28 @@ struct CreatePoolCpiBuilderInstruction<'a, 'b> {
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     shared_escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     pool_id: Option<[u8; 32]>,
     config: Option<PoolConfig>,
-    currency: Option<Pubkey>,
+    currency_mint: Option<Pubkey>,
     cosigner: Option<Pubkey>,
     maker_broker: Option<Pubkey>,
     max_taker_sell_count: Option<u32>,
     expire_in_sec: Option<u64>,
+    decay_bps: Option<u16>,
+    floor_price: Option<u64>,
+    price_ladder_args: Option<PriceLadderArgs>,
+    manager: Option<Pubkey>,
+    max_taker_buy_count: Option<u32>,
+    max_nfts_held: Option<u32>,
+    max_total_spend: Option<u64>,
+    circuit_breaker: Option<CircuitBreakerArgs>,
+    keeper_bounty: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
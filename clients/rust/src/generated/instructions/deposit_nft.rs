@@ -21,6 +21,10 @@ pub struct DepositNft {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<solana_program::pubkey::Pubkey>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
     /// The mint account of the NFT.
     pub mint: solana_program::pubkey::Pubkey,
     /// The Token Metadata metadata account of the NFT.
@@ -66,7 +70,7 @@ impl DepositNft {
         args: DepositNftInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -87,6 +91,21 @@ impl DepositNft {
                 false,
             ));
         }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -234,27 +253,31 @@ pub struct DepositNftInstructionArgs {
 ///   1. `[writable]` pool
 ///   2. `[]` whitelist
 ///   3. `[optional]` mint_proof
-///   4. `[]` mint
-///   5. `[writable]` metadata
-///   6. `[]` edition
-///   7. `[writable, optional]` user_token_record
-///   8. `[writable, optional]` pool_token_record
-///   9. `[optional]` token_metadata_program
-///   10. `[optional]` sysvar_instructions
-///   11. `[optional]` authorization_rules
-///   12. `[optional]` authorization_rules_program
-///   13. `[writable]` nft_receipt
-///   14. `[writable]` owner_ta
-///   15. `[writable]` pool_ta
-///   16. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   17. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-///   18. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
+///   5. `[optional]` trait_requirements
+///   6. `[]` mint
+///   7. `[writable]` metadata
+///   8. `[]` edition
+///   9. `[writable, optional]` user_token_record
+///   10. `[writable, optional]` pool_token_record
+///   11. `[optional]` token_metadata_program
+///   12. `[optional]` sysvar_instructions
+///   13. `[optional]` authorization_rules
+///   14. `[optional]` authorization_rules_program
+///   15. `[writable]` nft_receipt
+///   16. `[writable]` owner_ta
+///   17. `[writable]` pool_ta
+///   18. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   19. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   20. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct DepositNftBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     pool: Option<solana_program::pubkey::Pubkey>,
     whitelist: Option<solana_program::pubkey::Pubkey>,
     mint_proof: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     metadata: Option<solana_program::pubkey::Pubkey>,
     edition: Option<solana_program::pubkey::Pubkey>,
@@ -305,6 +328,23 @@ impl DepositNftBuilder {
         self.mint_proof = mint_proof;
         self
     }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -456,6 +496,10 @@ impl DepositNftBuilder {
             pool: self.pool.expect("pool is not set"),
             whitelist: self.whitelist.expect("whitelist is not set"),
             mint_proof: self.mint_proof,
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
+            trait_requirements: self.trait_requirements,
             mint: self.mint.expect("mint is not set"),
             metadata: self.metadata.expect("metadata is not set"),
             edition: self.edition.expect("edition is not set"),
@@ -498,6 +542,10 @@ pub struct DepositNftCpiAccounts<'a, 'b> {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Token Metadata metadata account of the NFT.
@@ -544,6 +592,10 @@ pub struct DepositNftCpi<'a, 'b> {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Token Metadata metadata account of the NFT.
@@ -590,6 +642,8 @@ impl<'a, 'b> DepositNftCpi<'a, 'b> {
             pool: accounts.pool,
             whitelist: accounts.whitelist,
             mint_proof: accounts.mint_proof,
+            amm_program: accounts.amm_program,
+            trait_requirements: accounts.trait_requirements,
             mint: accounts.mint,
             metadata: accounts.metadata,
             edition: accounts.edition,
@@ -641,7 +695,7 @@ impl<'a, 'b> DepositNftCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -665,6 +719,21 @@ impl<'a, 'b> DepositNftCpi<'a, 'b> {
                 false,
             ));
         }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -783,7 +852,7 @@ impl<'a, 'b> DepositNftCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(22 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.pool.clone());
@@ -791,6 +860,10 @@ impl<'a, 'b> DepositNftCpi<'a, 'b> {
         if let Some(mint_proof) = self.mint_proof {
             account_infos.push(mint_proof.clone());
         }
+        account_infos.push(self.amm_program.clone());
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
         account_infos.push(self.mint.clone());
         account_infos.push(self.metadata.clone());
         account_infos.push(self.edition.clone());
@@ -838,21 +911,23 @@ impl<'a, 'b> DepositNftCpi<'a, 'b> {
 ///   1. `[writable]` pool
 ///   2. `[]` whitelist
 ///   3. `[optional]` mint_proof
-///   4. `[]` mint
-///   5. `[writable]` metadata
-///   6. `[]` edition
-///   7. `[writable, optional]` user_token_record
-///   8. `[writable, optional]` pool_token_record
-///   9. `[optional]` token_metadata_program
-///   10. `[optional]` sysvar_instructions
-///   11. `[optional]` authorization_rules
-///   12. `[optional]` authorization_rules_program
-///   13. `[writable]` nft_receipt
-///   14. `[writable]` owner_ta
-///   15. `[writable]` pool_ta
-///   16. `[]` token_program
-///   17. `[]` associated_token_program
-///   18. `[]` system_program
+///   4. `[]` amm_program
+///   5. `[optional]` trait_requirements
+///   6. `[]` mint
+///   7. `[writable]` metadata
+///   8. `[]` edition
+///   9. `[writable, optional]` user_token_record
+///   10. `[writable, optional]` pool_token_record
+///   11. `[optional]` token_metadata_program
+///   12. `[optional]` sysvar_instructions
+///   13. `[optional]` authorization_rules
+///   14. `[optional]` authorization_rules_program
+///   15. `[writable]` nft_receipt
+///   16. `[writable]` owner_ta
+///   17. `[writable]` pool_ta
+///   18. `[]` token_program
+///   19. `[]` associated_token_program
+///   20. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct DepositNftCpiBuilder<'a, 'b> {
     instruction: Box<DepositNftCpiBuilderInstruction<'a, 'b>>,
@@ -866,6 +941,8 @@ impl<'a, 'b> DepositNftCpiBuilder<'a, 'b> {
             pool: None,
             whitelist: None,
             mint_proof: None,
+            amm_program: None,
+            trait_requirements: None,
             mint: None,
             metadata: None,
             edition: None,
@@ -919,6 +996,25 @@ impl<'a, 'b> DepositNftCpiBuilder<'a, 'b> {
         self.instruction.mint_proof = mint_proof;
         self
     }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
@@ -1118,6 +1214,13 @@ impl<'a, 'b> DepositNftCpiBuilder<'a, 'b> {
 
             mint_proof: self.instruction.mint_proof,
 
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
+
+            trait_requirements: self.instruction.trait_requirements,
+
             mint: self.instruction.mint.expect("mint is not set"),
 
             metadata: self.instruction.metadata.expect("metadata is not set"),
@@ -1175,6 +1278,8 @@ struct DepositNftCpiBuilderInstruction<'a, 'b> {
     pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -20,6 +20,10 @@ pub struct DepositNftCore {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<solana_program::pubkey::Pubkey>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
     /// The MPL core asset account.
     pub asset: solana_program::pubkey::Pubkey,
 
@@ -41,7 +45,7 @@ impl DepositNftCore {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -62,6 +66,21 @@ impl DepositNftCore {
                 false,
             ));
         }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.asset, false,
         ));
@@ -125,17 +144,21 @@ impl Default for DepositNftCoreInstructionData {
 ///   1. `[writable]` pool
 ///   2. `[]` whitelist
 ///   3. `[optional]` mint_proof
-///   4. `[writable]` asset
-///   5. `[optional]` collection
-///   6. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
-///   7. `[writable]` nft_receipt
-///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
+///   5. `[optional]` trait_requirements
+///   6. `[writable]` asset
+///   7. `[optional]` collection
+///   8. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   9. `[writable]` nft_receipt
+///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct DepositNftCoreBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     pool: Option<solana_program::pubkey::Pubkey>,
     whitelist: Option<solana_program::pubkey::Pubkey>,
     mint_proof: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
     asset: Option<solana_program::pubkey::Pubkey>,
     collection: Option<solana_program::pubkey::Pubkey>,
     mpl_core_program: Option<solana_program::pubkey::Pubkey>,
@@ -175,6 +198,23 @@ impl DepositNftCoreBuilder {
         self.mint_proof = mint_proof;
         self
     }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
     /// The MPL core asset account.
     #[inline(always)]
     pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -235,6 +275,10 @@ impl DepositNftCoreBuilder {
             pool: self.pool.expect("pool is not set"),
             whitelist: self.whitelist.expect("whitelist is not set"),
             mint_proof: self.mint_proof,
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
+            trait_requirements: self.trait_requirements,
             asset: self.asset.expect("asset is not set"),
             collection: self.collection,
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
@@ -262,6 +306,10 @@ pub struct DepositNftCoreCpiAccounts<'a, 'b> {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The MPL core asset account.
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -288,6 +336,10 @@ pub struct DepositNftCoreCpi<'a, 'b> {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The MPL core asset account.
     pub asset: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -311,6 +363,8 @@ impl<'a, 'b> DepositNftCoreCpi<'a, 'b> {
             pool: accounts.pool,
             whitelist: accounts.whitelist,
             mint_proof: accounts.mint_proof,
+            amm_program: accounts.amm_program,
+            trait_requirements: accounts.trait_requirements,
             asset: accounts.asset,
             collection: accounts.collection,
             mpl_core_program: accounts.mpl_core_program,
@@ -351,7 +405,7 @@ impl<'a, 'b> DepositNftCoreCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -375,6 +429,21 @@ impl<'a, 'b> DepositNftCoreCpi<'a, 'b> {
                 false,
             ));
         }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.asset.key,
             false,
@@ -416,7 +485,7 @@ impl<'a, 'b> DepositNftCoreCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.pool.clone());
@@ -424,6 +493,10 @@ impl<'a, 'b> DepositNftCoreCpi<'a, 'b> {
         if let Some(mint_proof) = self.mint_proof {
             account_infos.push(mint_proof.clone());
         }
+        account_infos.push(self.amm_program.clone());
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
         account_infos.push(self.asset.clone());
         if let Some(collection) = self.collection {
             account_infos.push(collection.clone());
@@ -451,11 +524,13 @@ impl<'a, 'b> DepositNftCoreCpi<'a, 'b> {
 ///   1. `[writable]` pool
 ///   2. `[]` whitelist
 ///   3. `[optional]` mint_proof
-///   4. `[writable]` asset
-///   5. `[optional]` collection
-///   6. `[]` mpl_core_program
-///   7. `[writable]` nft_receipt
-///   8. `[]` system_program
+///   4. `[]` amm_program
+///   5. `[optional]` trait_requirements
+///   6. `[writable]` asset
+///   7. `[optional]` collection
+///   8. `[]` mpl_core_program
+///   9. `[writable]` nft_receipt
+///   10. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct DepositNftCoreCpiBuilder<'a, 'b> {
     instruction: Box<DepositNftCoreCpiBuilderInstruction<'a, 'b>>,
@@ -469,6 +544,8 @@ impl<'a, 'b> DepositNftCoreCpiBuilder<'a, 'b> {
             pool: None,
             whitelist: None,
             mint_proof: None,
+            amm_program: None,
+            trait_requirements: None,
             asset: None,
             collection: None,
             mpl_core_program: None,
@@ -511,6 +588,25 @@ impl<'a, 'b> DepositNftCoreCpiBuilder<'a, 'b> {
         self.instruction.mint_proof = mint_proof;
         self
     }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
     /// The MPL core asset account.
     #[inline(always)]
     pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
@@ -605,6 +701,13 @@ impl<'a, 'b> DepositNftCoreCpiBuilder<'a, 'b> {
 
             mint_proof: self.instruction.mint_proof,
 
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
+
+            trait_requirements: self.instruction.trait_requirements,
+
             asset: self.instruction.asset.expect("asset is not set"),
 
             collection: self.instruction.collection,
@@ -638,6 +741,8 @@ struct DepositNftCoreCpiBuilderInstruction<'a, 'b> {
     pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -20,6 +20,10 @@ pub struct DepositNftT22 {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<solana_program::pubkey::Pubkey>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
     /// The mint account of the NFT.
     pub mint: solana_program::pubkey::Pubkey,
     /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
@@ -45,7 +49,7 @@ impl DepositNftT22 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -66,6 +70,21 @@ impl DepositNftT22 {
                 false,
             ));
         }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -131,19 +150,23 @@ impl Default for DepositNftT22InstructionData {
 ///   1. `[writable]` pool
 ///   2. `[]` whitelist
 ///   3. `[optional]` mint_proof
-///   4. `[]` mint
-///   5. `[writable]` nft_receipt
-///   6. `[writable]` owner_ta
-///   7. `[writable]` pool_ta
-///   8. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
-///   9. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
+///   5. `[optional]` trait_requirements
+///   6. `[]` mint
+///   7. `[writable]` nft_receipt
+///   8. `[writable]` owner_ta
+///   9. `[writable]` pool_ta
+///   10. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct DepositNftT22Builder {
     owner: Option<solana_program::pubkey::Pubkey>,
     pool: Option<solana_program::pubkey::Pubkey>,
     whitelist: Option<solana_program::pubkey::Pubkey>,
     mint_proof: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     nft_receipt: Option<solana_program::pubkey::Pubkey>,
     owner_ta: Option<solana_program::pubkey::Pubkey>,
@@ -185,6 +208,23 @@ impl DepositNftT22Builder {
         self.mint_proof = mint_proof;
         self
     }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -258,6 +298,10 @@ impl DepositNftT22Builder {
             pool: self.pool.expect("pool is not set"),
             whitelist: self.whitelist.expect("whitelist is not set"),
             mint_proof: self.mint_proof,
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
+            trait_requirements: self.trait_requirements,
             mint: self.mint.expect("mint is not set"),
             nft_receipt: self.nft_receipt.expect("nft_receipt is not set"),
             owner_ta: self.owner_ta.expect("owner_ta is not set"),
@@ -289,6 +333,10 @@ pub struct DepositNftT22CpiAccounts<'a, 'b> {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
@@ -319,6 +367,10 @@ pub struct DepositNftT22Cpi<'a, 'b> {
     /// Optional account which must be passed in if the NFT must be verified against a
     /// merkle proof condition in the whitelist.
     pub mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
@@ -346,6 +398,8 @@ impl<'a, 'b> DepositNftT22Cpi<'a, 'b> {
             pool: accounts.pool,
             whitelist: accounts.whitelist,
             mint_proof: accounts.mint_proof,
+            amm_program: accounts.amm_program,
+            trait_requirements: accounts.trait_requirements,
             mint: accounts.mint,
             nft_receipt: accounts.nft_receipt,
             owner_ta: accounts.owner_ta,
@@ -388,7 +442,7 @@ impl<'a, 'b> DepositNftT22Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -412,6 +466,21 @@ impl<'a, 'b> DepositNftT22Cpi<'a, 'b> {
                 false,
             ));
         }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -454,7 +523,7 @@ impl<'a, 'b> DepositNftT22Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.pool.clone());
@@ -462,6 +531,10 @@ impl<'a, 'b> DepositNftT22Cpi<'a, 'b> {
         if let Some(mint_proof) = self.mint_proof {
             account_infos.push(mint_proof.clone());
         }
+        account_infos.push(self.amm_program.clone());
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
         account_infos.push(self.mint.clone());
         account_infos.push(self.nft_receipt.clone());
         account_infos.push(self.owner_ta.clone());
@@ -489,13 +562,15 @@ impl<'a, 'b> DepositNftT22Cpi<'a, 'b> {
 ///   1. `[writable]` pool
 ///   2. `[]` whitelist
 ///   3. `[optional]` mint_proof
-///   4. `[]` mint
-///   5. `[writable]` nft_receipt
-///   6. `[writable]` owner_ta
-///   7. `[writable]` pool_ta
-///   8. `[]` token_program
-///   9. `[]` associated_token_program
-///   10. `[]` system_program
+///   4. `[]` amm_program
+///   5. `[optional]` trait_requirements
+///   6. `[]` mint
+///   7. `[writable]` nft_receipt
+///   8. `[writable]` owner_ta
+///   9. `[writable]` pool_ta
+///   10. `[]` token_program
+///   11. `[]` associated_token_program
+///   12. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct DepositNftT22CpiBuilder<'a, 'b> {
     instruction: Box<DepositNftT22CpiBuilderInstruction<'a, 'b>>,
@@ -509,6 +584,8 @@ impl<'a, 'b> DepositNftT22CpiBuilder<'a, 'b> {
             pool: None,
             whitelist: None,
             mint_proof: None,
+            amm_program: None,
+            trait_requirements: None,
             mint: None,
             nft_receipt: None,
             owner_ta: None,
@@ -553,6 +630,25 @@ impl<'a, 'b> DepositNftT22CpiBuilder<'a, 'b> {
         self.instruction.mint_proof = mint_proof;
         self
     }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
@@ -665,6 +761,13 @@ impl<'a, 'b> DepositNftT22CpiBuilder<'a, 'b> {
 
             mint_proof: self.instruction.mint_proof,
 
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
+
+            trait_requirements: self.instruction.trait_requirements,
+
             mint: self.instruction.mint.expect("mint is not set"),
 
             nft_receipt: self
@@ -705,6 +808,8 @@ struct DepositNftT22CpiBuilderInstruction<'a, 'b> {
     pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     nft_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -5,19 +5,33 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::CircuitBreakerArgs;
 use crate::generated::types::EditPoolConfig;
+use crate::generated::types::PriceLadderArgs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct EditPool {
-    /// The owner of the pool--must sign to edit the pool.
-    pub owner: solana_program::pubkey::Pubkey,
+    /// The owner of the pool--must sign to edit the pool, unless the pool manager signs instead.
+    pub owner: (solana_program::pubkey::Pubkey, bool),
     /// The pool to edit.
     pub pool: solana_program::pubkey::Pubkey,
     /// The Solana system program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+    /// refunded when it is closed.
+    pub rent_payer: Option<solana_program::pubkey::Pubkey>,
+    /// The price ladder of the pool; required to set prices or switch to or from a custom curve.
+    pub price_ladder: Option<solana_program::pubkey::Pubkey>,
+    /// The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner.
+    pub manager: Option<solana_program::pubkey::Pubkey>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
+    /// The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+    /// were verified against the current whitelist.
+    pub new_whitelist: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl EditPool {
@@ -33,9 +47,10 @@ impl EditPool {
         args: EditPoolInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.owner, true,
+            self.owner.0,
+            self.owner.1,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.pool, false,
@@ -44,6 +59,52 @@ impl EditPool {
             self.system_program,
             false,
         ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rent_payer, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                price_ladder,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(manager) = self.manager {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                manager, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
+        if let Some(new_whitelist) = self.new_whitelist {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                new_whitelist,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = EditPoolInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -85,26 +146,55 @@ pub struct EditPoolInstructionArgs {
     pub expire_in_sec: Option<u64>,
     pub max_taker_sell_count: Option<u32>,
     pub reset_price_offset: bool,
+    pub decay_bps: Option<u16>,
+    pub floor_price: Option<u64>,
+    /// New prices of custom curves.
+    pub price_ladder_args: Option<PriceLadderArgs>,
+    /// New manager of the pool; the default pubkey removes the manager.
+    pub new_manager: Option<Pubkey>,
+    pub max_taker_buy_count: Option<u32>,
+    pub max_nfts_held: Option<u32>,
+    pub max_total_spend: Option<u64>,
+    /// New limits of the circuit breaker; a zero window disables it.
+    pub circuit_breaker: Option<CircuitBreakerArgs>,
 }
 
 /// Instruction builder for `EditPool`.
 ///
 /// ### Accounts:
 ///
-///   0. `[signer]` owner
+///   0. `[optional_signer]` owner
 ///   1. `[writable]` pool
 ///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[writable, signer, optional]` rent_payer
+///   4. `[writable, optional]` price_ladder
+///   5. `[signer, optional]` manager
+///   6. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
+///   7. `[optional]` new_whitelist
 #[derive(Clone, Debug, Default)]
 pub struct EditPoolBuilder {
-    owner: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<(solana_program::pubkey::Pubkey, bool)>,
     pool: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    rent_payer: Option<solana_program::pubkey::Pubkey>,
+    price_ladder: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
+    new_whitelist: Option<solana_program::pubkey::Pubkey>,
     new_config: Option<EditPoolConfig>,
     cosigner: Option<Pubkey>,
     maker_broker: Option<Pubkey>,
     expire_in_sec: Option<u64>,
     max_taker_sell_count: Option<u32>,
     reset_price_offset: Option<bool>,
+    decay_bps: Option<u16>,
+    floor_price: Option<u64>,
+    price_ladder_args: Option<PriceLadderArgs>,
+    new_manager: Option<Pubkey>,
+    max_taker_buy_count: Option<u32>,
+    max_nfts_held: Option<u32>,
+    max_total_spend: Option<u64>,
+    circuit_breaker: Option<CircuitBreakerArgs>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -112,10 +202,10 @@ impl EditPoolBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    /// The owner of the pool--must sign to edit the pool.
+    /// The owner of the pool--must sign to edit the pool, unless the pool manager signs instead.
     #[inline(always)]
-    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.owner = Some(owner);
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey, as_signer: bool) -> &mut Self {
+        self.owner = Some((owner, as_signer));
         self
     }
     /// The pool to edit.
@@ -131,6 +221,49 @@ impl EditPoolBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+    /// refunded when it is closed.
+    #[inline(always)]
+    pub fn rent_payer(&mut self, rent_payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.rent_payer = rent_payer;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required to set prices or switch to or from a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner.
+    #[inline(always)]
+    pub fn manager(&mut self, manager: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.manager = manager;
+        self
+    }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+    /// were verified against the current whitelist.
+    #[inline(always)]
+    pub fn new_whitelist(
+        &mut self,
+        new_whitelist: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_whitelist = new_whitelist;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn new_config(&mut self, new_config: EditPoolConfig) -> &mut Self {
@@ -166,6 +299,57 @@ impl EditPoolBuilder {
         self.reset_price_offset = Some(reset_price_offset);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn decay_bps(&mut self, decay_bps: u16) -> &mut Self {
+        self.decay_bps = Some(decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn floor_price(&mut self, floor_price: u64) -> &mut Self {
+        self.floor_price = Some(floor_price);
+        self
+    }
+    /// `[optional argument]`
+    /// New prices of custom curves.
+    #[inline(always)]
+    pub fn price_ladder_args(&mut self, price_ladder_args: PriceLadderArgs) -> &mut Self {
+        self.price_ladder_args = Some(price_ladder_args);
+        self
+    }
+    /// `[optional argument]`
+    /// New manager of the pool; the default pubkey removes the manager.
+    #[inline(always)]
+    pub fn new_manager(&mut self, new_manager: Pubkey) -> &mut Self {
+        self.new_manager = Some(new_manager);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_taker_buy_count(&mut self, max_taker_buy_count: u32) -> &mut Self {
+        self.max_taker_buy_count = Some(max_taker_buy_count);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_nfts_held(&mut self, max_nfts_held: u32) -> &mut Self {
+        self.max_nfts_held = Some(max_nfts_held);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_total_spend(&mut self, max_total_spend: u64) -> &mut Self {
+        self.max_total_spend = Some(max_total_spend);
+        self
+    }
+    /// `[optional argument]`
+    /// New limits of the circuit breaker; a zero window disables it.
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreakerArgs) -> &mut Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -192,6 +376,13 @@ impl EditPoolBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent_payer: self.rent_payer,
+            price_ladder: self.price_ladder,
+            manager: self.manager,
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
+            new_whitelist: self.new_whitelist,
         };
         let args = EditPoolInstructionArgs {
             new_config: self.new_config.clone(),
@@ -203,6 +394,14 @@ impl EditPoolBuilder {
                 .reset_price_offset
                 .clone()
                 .expect("reset_price_offset is not set"),
+            decay_bps: self.decay_bps.clone(),
+            floor_price: self.floor_price.clone(),
+            price_ladder_args: self.price_ladder_args.clone(),
+            new_manager: self.new_manager.clone(),
+            max_taker_buy_count: self.max_taker_buy_count.clone(),
+            max_nfts_held: self.max_nfts_held.clone(),
+            max_total_spend: self.max_total_spend.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -211,24 +410,48 @@ impl EditPoolBuilder {
 
 /// `edit_pool` CPI accounts.
 pub struct EditPoolCpiAccounts<'a, 'b> {
-    /// The owner of the pool--must sign to edit the pool.
-    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the pool--must sign to edit the pool, unless the pool manager signs instead.
+    pub owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
     /// The pool to edit.
     pub pool: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+    /// refunded when it is closed.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required to set prices or switch to or from a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner.
+    pub manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+    /// were verified against the current whitelist.
+    pub new_whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `edit_pool` CPI instruction.
 pub struct EditPoolCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The owner of the pool--must sign to edit the pool.
-    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the pool--must sign to edit the pool, unless the pool manager signs instead.
+    pub owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
     /// The pool to edit.
     pub pool: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+    /// refunded when it is closed.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required to set prices or switch to or from a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner.
+    pub manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+    /// were verified against the current whitelist.
+    pub new_whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: EditPoolInstructionArgs,
 }
@@ -244,6 +467,11 @@ impl<'a, 'b> EditPoolCpi<'a, 'b> {
             owner: accounts.owner,
             pool: accounts.pool,
             system_program: accounts.system_program,
+            rent_payer: accounts.rent_payer,
+            price_ladder: accounts.price_ladder,
+            manager: accounts.manager,
+            amm_program: accounts.amm_program,
+            new_whitelist: accounts.new_whitelist,
             __args: args,
         }
     }
@@ -280,10 +508,10 @@ impl<'a, 'b> EditPoolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.owner.key,
-            true,
+            *self.owner.0.key,
+            self.owner.1,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.pool.key,
@@ -293,6 +521,54 @@ impl<'a, 'b> EditPoolCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rent_payer.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *price_ladder.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(manager) = self.manager {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *manager.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
+        if let Some(new_whitelist) = self.new_whitelist {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *new_whitelist.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -309,11 +585,24 @@ impl<'a, 'b> EditPoolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
-        account_infos.push(self.owner.clone());
+        account_infos.push(self.owner.0.clone());
         account_infos.push(self.pool.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(rent_payer) = self.rent_payer {
+            account_infos.push(rent_payer.clone());
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            account_infos.push(price_ladder.clone());
+        }
+        if let Some(manager) = self.manager {
+            account_infos.push(manager.clone());
+        }
+        account_infos.push(self.amm_program.clone());
+        if let Some(new_whitelist) = self.new_whitelist {
+            account_infos.push(new_whitelist.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -330,9 +619,14 @@ impl<'a, 'b> EditPoolCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[signer]` owner
+///   0. `[optional_signer]` owner
 ///   1. `[writable]` pool
 ///   2. `[]` system_program
+///   3. `[writable, signer, optional]` rent_payer
+///   4. `[writable, optional]` price_ladder
+///   5. `[signer, optional]` manager
+///   6. `[]` amm_program
+///   7. `[optional]` new_whitelist
 #[derive(Clone, Debug)]
 pub struct EditPoolCpiBuilder<'a, 'b> {
     instruction: Box<EditPoolCpiBuilderInstruction<'a, 'b>>,
@@ -345,20 +639,37 @@ impl<'a, 'b> EditPoolCpiBuilder<'a, 'b> {
             owner: None,
             pool: None,
             system_program: None,
+            rent_payer: None,
+            price_ladder: None,
+            manager: None,
+            amm_program: None,
+            new_whitelist: None,
             new_config: None,
             cosigner: None,
             maker_broker: None,
             expire_in_sec: None,
             max_taker_sell_count: None,
             reset_price_offset: None,
+            decay_bps: None,
+            floor_price: None,
+            price_ladder_args: None,
+            new_manager: None,
+            max_taker_buy_count: None,
+            max_nfts_held: None,
+            max_total_spend: None,
+            circuit_breaker: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
-    /// The owner of the pool--must sign to edit the pool.
+    /// The owner of the pool--must sign to edit the pool, unless the pool manager signs instead.
     #[inline(always)]
-    pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.owner = Some(owner);
+    pub fn owner(
+        &mut self,
+        owner: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.owner = Some((owner, as_signer));
         self
     }
     /// The pool to edit.
@@ -376,6 +687,57 @@ impl<'a, 'b> EditPoolCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+    /// refunded when it is closed.
+    #[inline(always)]
+    pub fn rent_payer(
+        &mut self,
+        rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_payer = rent_payer;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required to set prices or switch to or from a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner.
+    #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.manager = manager;
+        self
+    }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
+    /// `[optional account]`
+    /// The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+    /// were verified against the current whitelist.
+    #[inline(always)]
+    pub fn new_whitelist(
+        &mut self,
+        new_whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_whitelist = new_whitelist;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn new_config(&mut self, new_config: EditPoolConfig) -> &mut Self {
@@ -411,6 +773,57 @@ impl<'a, 'b> EditPoolCpiBuilder<'a, 'b> {
         self.instruction.reset_price_offset = Some(reset_price_offset);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn decay_bps(&mut self, decay_bps: u16) -> &mut Self {
+        self.instruction.decay_bps = Some(decay_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn floor_price(&mut self, floor_price: u64) -> &mut Self {
+        self.instruction.floor_price = Some(floor_price);
+        self
+    }
+    /// `[optional argument]`
+    /// New prices of custom curves.
+    #[inline(always)]
+    pub fn price_ladder_args(&mut self, price_ladder_args: PriceLadderArgs) -> &mut Self {
+        self.instruction.price_ladder_args = Some(price_ladder_args);
+        self
+    }
+    /// `[optional argument]`
+    /// New manager of the pool; the default pubkey removes the manager.
+    #[inline(always)]
+    pub fn new_manager(&mut self, new_manager: Pubkey) -> &mut Self {
+        self.instruction.new_manager = Some(new_manager);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_taker_buy_count(&mut self, max_taker_buy_count: u32) -> &mut Self {
+        self.instruction.max_taker_buy_count = Some(max_taker_buy_count);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_nfts_held(&mut self, max_nfts_held: u32) -> &mut Self {
+        self.instruction.max_nfts_held = Some(max_nfts_held);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_total_spend(&mut self, max_total_spend: u64) -> &mut Self {
+        self.instruction.max_total_spend = Some(max_total_spend);
+        self
+    }
+    /// `[optional argument]`
+    /// New limits of the circuit breaker; a zero window disables it.
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreakerArgs) -> &mut Self {
+        self.instruction.circuit_breaker = Some(circuit_breaker);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -463,6 +876,14 @@ impl<'a, 'b> EditPoolCpiBuilder<'a, 'b> {
                 .reset_price_offset
                 .clone()
                 .expect("reset_price_offset is not set"),
+            decay_bps: self.instruction.decay_bps.clone(),
+            floor_price: self.instruction.floor_price.clone(),
+            price_ladder_args: self.instruction.price_ladder_args.clone(),
+            new_manager: self.instruction.new_manager.clone(),
+            max_taker_buy_count: self.instruction.max_taker_buy_count.clone(),
+            max_nfts_held: self.instruction.max_nfts_held.clone(),
+            max_total_spend: self.instruction.max_total_spend.clone(),
+            circuit_breaker: self.instruction.circuit_breaker.clone(),
         };
         let instruction = EditPoolCpi {
             __program: self.instruction.__program,
@@ -475,6 +896,19 @@ impl<'a, 'b> EditPoolCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            rent_payer: self.instruction.rent_payer,
+
+            price_ladder: self.instruction.price_ladder,
+
+            manager: self.instruction.manager,
+
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
+
+            new_whitelist: self.instruction.new_whitelist,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -487,15 +921,28 @@ impl<'a, 'b> EditPoolCpiBuilder<'a, 'b> {
 #[derive(Clone, Debug)]
 struct EditPoolCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
-    owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
     pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_config: Option<EditPoolConfig>,
     cosigner: Option<Pubkey>,
     maker_broker: Option<Pubkey>,
     expire_in_sec: Option<u64>,
     max_taker_sell_count: Option<u32>,
     reset_price_offset: Option<bool>,
+    decay_bps: Option<u16>,
+    floor_price: Option<u64>,
+    price_ladder_args: Option<PriceLadderArgs>,
+    new_manager: Option<Pubkey>,
+    max_taker_buy_count: Option<u32>,
+    max_nfts_held: Option<u32>,
+    max_total_spend: Option<u64>,
+    circuit_breaker: Option<CircuitBreakerArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,48 +5,120 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#attach_shared_escrow;
 pub(crate) mod r#buy_nft;
+pub(crate) mod r#buy_nft_compressed;
 pub(crate) mod r#buy_nft_core;
 pub(crate) mod r#buy_nft_t22;
+pub(crate) mod r#buy_nft_wns;
+pub(crate) mod r#buy_nfts;
+pub(crate) mod r#buy_nfts_core;
+pub(crate) mod r#buy_nfts_t22;
+pub(crate) mod r#buy_nfts_wns;
 pub(crate) mod r#close_expired_pool;
 pub(crate) mod r#close_pool;
+pub(crate) mod r#convert_pool;
 pub(crate) mod r#create_pool;
 pub(crate) mod r#deposit_nft;
+pub(crate) mod r#deposit_nft_compressed;
 pub(crate) mod r#deposit_nft_core;
 pub(crate) mod r#deposit_nft_t22;
+pub(crate) mod r#deposit_nft_wns;
 pub(crate) mod r#deposit_sol;
+pub(crate) mod r#deposit_token;
+pub(crate) mod r#detach_shared_escrow;
 pub(crate) mod r#edit_pool;
 pub(crate) mod r#sell_nft_token_pool;
+pub(crate) mod r#sell_nft_token_pool_compressed;
 pub(crate) mod r#sell_nft_token_pool_core;
 pub(crate) mod r#sell_nft_token_pool_t22;
+pub(crate) mod r#sell_nft_token_pool_wns;
 pub(crate) mod r#sell_nft_trade_pool;
+pub(crate) mod r#sell_nft_trade_pool_compressed;
 pub(crate) mod r#sell_nft_trade_pool_core;
 pub(crate) mod r#sell_nft_trade_pool_t22;
+pub(crate) mod r#sell_nft_trade_pool_wns;
+pub(crate) mod r#sell_nfts_token_pool;
+pub(crate) mod r#sell_nfts_token_pool_core;
+pub(crate) mod r#sell_nfts_token_pool_t22;
+pub(crate) mod r#sell_nfts_token_pool_wns;
+pub(crate) mod r#sell_nfts_trade_pool;
+pub(crate) mod r#sell_nfts_trade_pool_core;
+pub(crate) mod r#sell_nfts_trade_pool_t22;
+pub(crate) mod r#sell_nfts_trade_pool_wns;
+pub(crate) mod r#set_pool_status;
+pub(crate) mod r#set_trait_requirements;
 pub(crate) mod r#tamm_noop;
+pub(crate) mod r#transfer_pool;
+pub(crate) mod r#upgrade_pool;
+pub(crate) mod r#withdraw_expired_nft;
+pub(crate) mod r#withdraw_expired_nft_compressed;
+pub(crate) mod r#withdraw_expired_nft_core;
+pub(crate) mod r#withdraw_expired_nft_t22;
+pub(crate) mod r#withdraw_expired_nft_wns;
 pub(crate) mod r#withdraw_nft;
+pub(crate) mod r#withdraw_nft_compressed;
 pub(crate) mod r#withdraw_nft_core;
 pub(crate) mod r#withdraw_nft_t22;
+pub(crate) mod r#withdraw_nft_wns;
 pub(crate) mod r#withdraw_sol;
+pub(crate) mod r#withdraw_token;
 
+pub use self::r#attach_shared_escrow::*;
 pub use self::r#buy_nft::*;
+pub use self::r#buy_nft_compressed::*;
 pub use self::r#buy_nft_core::*;
 pub use self::r#buy_nft_t22::*;
+pub use self::r#buy_nft_wns::*;
+pub use self::r#buy_nfts::*;
+pub use self::r#buy_nfts_core::*;
+pub use self::r#buy_nfts_t22::*;
+pub use self::r#buy_nfts_wns::*;
 pub use self::r#close_expired_pool::*;
 pub use self::r#close_pool::*;
+pub use self::r#convert_pool::*;
 pub use self::r#create_pool::*;
 pub use self::r#deposit_nft::*;
+pub use self::r#deposit_nft_compressed::*;
 pub use self::r#deposit_nft_core::*;
 pub use self::r#deposit_nft_t22::*;
+pub use self::r#deposit_nft_wns::*;
 pub use self::r#deposit_sol::*;
+pub use self::r#deposit_token::*;
+pub use self::r#detach_shared_escrow::*;
 pub use self::r#edit_pool::*;
 pub use self::r#sell_nft_token_pool::*;
+pub use self::r#sell_nft_token_pool_compressed::*;
 pub use self::r#sell_nft_token_pool_core::*;
 pub use self::r#sell_nft_token_pool_t22::*;
+pub use self::r#sell_nft_token_pool_wns::*;
 pub use self::r#sell_nft_trade_pool::*;
+pub use self::r#sell_nft_trade_pool_compressed::*;
 pub use self::r#sell_nft_trade_pool_core::*;
 pub use self::r#sell_nft_trade_pool_t22::*;
+pub use self::r#sell_nft_trade_pool_wns::*;
+pub use self::r#sell_nfts_token_pool::*;
+pub use self::r#sell_nfts_token_pool_core::*;
+pub use self::r#sell_nfts_token_pool_t22::*;
+pub use self::r#sell_nfts_token_pool_wns::*;
+pub use self::r#sell_nfts_trade_pool::*;
+pub use self::r#sell_nfts_trade_pool_core::*;
+pub use self::r#sell_nfts_trade_pool_t22::*;
+pub use self::r#sell_nfts_trade_pool_wns::*;
+pub use self::r#set_pool_status::*;
+pub use self::r#set_trait_requirements::*;
 pub use self::r#tamm_noop::*;
+pub use self::r#transfer_pool::*;
+pub use self::r#upgrade_pool::*;
+pub use self::r#withdraw_expired_nft::*;
+pub use self::r#withdraw_expired_nft_compressed::*;
+pub use self::r#withdraw_expired_nft_core::*;
+pub use self::r#withdraw_expired_nft_t22::*;
+pub use self::r#withdraw_expired_nft_wns::*;
 pub use self::r#withdraw_nft::*;
+pub use self::r#withdraw_nft_compressed::*;
 pub use self::r#withdraw_nft_core::*;
 pub use self::r#withdraw_nft_t22::*;
+pub use self::r#withdraw_nft_wns::*;
 pub use self::r#withdraw_sol::*;
+pub use self::r#withdraw_token::*;
@@ -36,7 +36,8 @@ pub struct SellNftTokenPool {
     pub maker_broker: Option<solana_program::pubkey::Pubkey>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<solana_program::pubkey::Pubkey>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<solana_program::pubkey::Pubkey>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: solana_program::pubkey::Pubkey,
@@ -44,6 +45,32 @@ pub struct SellNftTokenPool {
     pub escrow_program: Option<solana_program::pubkey::Pubkey>,
 
     pub native_program: solana_program::pubkey::Pubkey,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<solana_program::pubkey::Pubkey>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<solana_program::pubkey::Pubkey>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<solana_program::pubkey::Pubkey>,
     /// The mint account of the NFT.
     pub mint: solana_program::pubkey::Pubkey,
     /// The Token Metadata metadata account of the NFT.
@@ -91,7 +118,7 @@ impl SellNftTokenPool {
         args: SellNftTokenPoolInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(30 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(42 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, false,
         ));
@@ -185,6 +212,137 @@ impl SellNftTokenPool {
             self.native_program,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                pool_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                taker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                owner_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_vault_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                maker_broker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                taker_broker_currency_ta,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                currency_associated_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                price_ladder,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                attestation_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                trait_requirements,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
@@ -355,22 +513,34 @@ pub struct SellNftTokenPoolInstructionArgs {
 ///   11. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
 ///   12. `[optional]` escrow_program
 ///   13. `[optional]` native_program (default to `11111111111111111111111111111111`)
-///   14. `[]` mint
-///   15. `[writable]` metadata
-///   16. `[]` edition
-///   17. `[writable, optional]` user_token_record
-///   18. `[writable, optional]` pool_token_record
-///   19. `[optional]` token_metadata_program
-///   20. `[optional]` sysvar_instructions
-///   21. `[optional]` authorization_rules
-///   22. `[optional]` authorization_rules_program
-///   23. `[writable]` taker_ta
-///   24. `[writable]` owner_ta
-///   25. `[writable]` pool_ta
-///   26. `[writable, optional]` owner_token_record
-///   27. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   28. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-///   29. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   14. `[optional]` currency
+///   15. `[writable, optional]` pool_currency_ta
+///   16. `[writable, optional]` taker_currency_ta
+///   17. `[writable, optional]` owner_currency_ta
+///   18. `[writable, optional]` fee_vault_currency_ta
+///   19. `[writable, optional]` maker_broker_currency_ta
+///   20. `[writable, optional]` taker_broker_currency_ta
+///   21. `[optional]` currency_token_program
+///   22. `[optional]` currency_associated_token_program
+///   23. `[writable, optional]` price_ladder
+///   24. `[optional]` attestation_sysvar
+///   25. `[writable, optional]` trait_requirements
+///   26. `[]` mint
+///   27. `[writable]` metadata
+///   28. `[]` edition
+///   29. `[writable, optional]` user_token_record
+///   30. `[writable, optional]` pool_token_record
+///   31. `[optional]` token_metadata_program
+///   32. `[optional]` sysvar_instructions
+///   33. `[optional]` authorization_rules
+///   34. `[optional]` authorization_rules_program
+///   35. `[writable]` taker_ta
+///   36. `[writable]` owner_ta
+///   37. `[writable]` pool_ta
+///   38. `[writable, optional]` owner_token_record
+///   39. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   40. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   41. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct SellNftTokenPoolBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -387,6 +557,18 @@ pub struct SellNftTokenPoolBuilder {
     amm_program: Option<solana_program::pubkey::Pubkey>,
     escrow_program: Option<solana_program::pubkey::Pubkey>,
     native_program: Option<solana_program::pubkey::Pubkey>,
+    currency: Option<solana_program::pubkey::Pubkey>,
+    pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    price_ladder: Option<solana_program::pubkey::Pubkey>,
+    attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    trait_requirements: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     metadata: Option<solana_program::pubkey::Pubkey>,
     edition: Option<solana_program::pubkey::Pubkey>,
@@ -492,7 +674,8 @@ impl SellNftTokenPoolBuilder {
         self
     }
     /// `[optional account]`
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     #[inline(always)]
     pub fn cosigner(&mut self, cosigner: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.cosigner = cosigner;
@@ -521,6 +704,125 @@ impl SellNftTokenPoolBuilder {
         self.native_program = Some(native_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[inline(always)]
+    pub fn taker_currency_ta(
+        &mut self,
+        taker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.taker_currency_ta = taker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The fee vault's currency token account.
+    #[inline(always)]
+    pub fn fee_vault_currency_ta(
+        &mut self,
+        fee_vault_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_vault_currency_ta = fee_vault_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The maker broker's currency token account.
+    #[inline(always)]
+    pub fn maker_broker_currency_ta(
+        &mut self,
+        maker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.maker_broker_currency_ta = maker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker broker's currency token account.
+    #[inline(always)]
+    pub fn taker_broker_currency_ta(
+        &mut self,
+        taker_broker_currency_ta: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.taker_broker_currency_ta = taker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program, used to create currency token accounts.
+    #[inline(always)]
+    pub fn currency_associated_token_program(
+        &mut self,
+        currency_associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.currency_associated_token_program = currency_associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    #[inline(always)]
+    pub fn attestation_sysvar(
+        &mut self,
+        attestation_sysvar: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attestation_sysvar = attestation_sysvar;
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -707,6 +1009,18 @@ impl SellNftTokenPoolBuilder {
             native_program: self
                 .native_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            currency: self.currency,
+            pool_currency_ta: self.pool_currency_ta,
+            taker_currency_ta: self.taker_currency_ta,
+            owner_currency_ta: self.owner_currency_ta,
+            fee_vault_currency_ta: self.fee_vault_currency_ta,
+            maker_broker_currency_ta: self.maker_broker_currency_ta,
+            taker_broker_currency_ta: self.taker_broker_currency_ta,
+            currency_token_program: self.currency_token_program,
+            currency_associated_token_program: self.currency_associated_token_program,
+            price_ladder: self.price_ladder,
+            attestation_sysvar: self.attestation_sysvar,
+            trait_requirements: self.trait_requirements,
             mint: self.mint.expect("mint is not set"),
             metadata: self.metadata.expect("metadata is not set"),
             edition: self.edition.expect("edition is not set"),
@@ -767,7 +1081,8 @@ pub struct SellNftTokenPoolCpiAccounts<'a, 'b> {
     pub maker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -775,6 +1090,33 @@ pub struct SellNftTokenPoolCpiAccounts<'a, 'b> {
     pub escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub native_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Token Metadata metadata account of the NFT.
@@ -838,7 +1180,8 @@ pub struct SellNftTokenPoolCpi<'a, 'b> {
     pub maker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The account that receives the taker broker fee.
     pub taker_broker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The AMM program account, used for self-cpi logging.
     pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -846,6 +1189,33 @@ pub struct SellNftTokenPoolCpi<'a, 'b> {
     pub escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub native_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    pub currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The pool's currency token account, which escrows the pool's funds.
+    pub pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    pub taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    pub owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The fee vault's currency token account.
+    pub fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The maker broker's currency token account.
+    pub maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The taker broker's currency token account.
+    pub taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    pub price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    pub attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    pub trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The mint account of the NFT.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Token Metadata metadata account of the NFT.
@@ -904,6 +1274,18 @@ impl<'a, 'b> SellNftTokenPoolCpi<'a, 'b> {
             amm_program: accounts.amm_program,
             escrow_program: accounts.escrow_program,
             native_program: accounts.native_program,
+            currency: accounts.currency,
+            pool_currency_ta: accounts.pool_currency_ta,
+            taker_currency_ta: accounts.taker_currency_ta,
+            owner_currency_ta: accounts.owner_currency_ta,
+            fee_vault_currency_ta: accounts.fee_vault_currency_ta,
+            maker_broker_currency_ta: accounts.maker_broker_currency_ta,
+            taker_broker_currency_ta: accounts.taker_broker_currency_ta,
+            currency_token_program: accounts.currency_token_program,
+            currency_associated_token_program: accounts.currency_associated_token_program,
+            price_ladder: accounts.price_ladder,
+            attestation_sysvar: accounts.attestation_sysvar,
+            trait_requirements: accounts.trait_requirements,
             mint: accounts.mint,
             metadata: accounts.metadata,
             edition: accounts.edition,
@@ -956,7 +1338,7 @@ impl<'a, 'b> SellNftTokenPoolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(30 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(42 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             false,
@@ -1055,6 +1437,138 @@ impl<'a, 'b> SellNftTokenPoolCpi<'a, 'b> {
             *self.native_program.key,
             false,
         ));
+        if let Some(currency) = self.currency {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *pool_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *taker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *owner_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_vault_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *maker_broker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *taker_broker_currency_ta.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *currency_associated_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *price_ladder.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *attestation_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *trait_requirements.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_AMM_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mint.key,
             false,
@@ -1184,7 +1698,7 @@ impl<'a, 'b> SellNftTokenPoolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(31 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(43 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.taker.clone());
@@ -1212,6 +1726,42 @@ impl<'a, 'b> SellNftTokenPoolCpi<'a, 'b> {
             account_infos.push(escrow_program.clone());
         }
         account_infos.push(self.native_program.clone());
+        if let Some(currency) = self.currency {
+            account_infos.push(currency.clone());
+        }
+        if let Some(pool_currency_ta) = self.pool_currency_ta {
+            account_infos.push(pool_currency_ta.clone());
+        }
+        if let Some(taker_currency_ta) = self.taker_currency_ta {
+            account_infos.push(taker_currency_ta.clone());
+        }
+        if let Some(owner_currency_ta) = self.owner_currency_ta {
+            account_infos.push(owner_currency_ta.clone());
+        }
+        if let Some(fee_vault_currency_ta) = self.fee_vault_currency_ta {
+            account_infos.push(fee_vault_currency_ta.clone());
+        }
+        if let Some(maker_broker_currency_ta) = self.maker_broker_currency_ta {
+            account_infos.push(maker_broker_currency_ta.clone());
+        }
+        if let Some(taker_broker_currency_ta) = self.taker_broker_currency_ta {
+            account_infos.push(taker_broker_currency_ta.clone());
+        }
+        if let Some(currency_token_program) = self.currency_token_program {
+            account_infos.push(currency_token_program.clone());
+        }
+        if let Some(currency_associated_token_program) = self.currency_associated_token_program {
+            account_infos.push(currency_associated_token_program.clone());
+        }
+        if let Some(price_ladder) = self.price_ladder {
+            account_infos.push(price_ladder.clone());
+        }
+        if let Some(attestation_sysvar) = self.attestation_sysvar {
+            account_infos.push(attestation_sysvar.clone());
+        }
+        if let Some(trait_requirements) = self.trait_requirements {
+            account_infos.push(trait_requirements.clone());
+        }
         account_infos.push(self.mint.clone());
         account_infos.push(self.metadata.clone());
         account_infos.push(self.edition.clone());
@@ -1272,22 +1822,34 @@ impl<'a, 'b> SellNftTokenPoolCpi<'a, 'b> {
 ///   11. `[]` amm_program
 ///   12. `[optional]` escrow_program
 ///   13. `[]` native_program
-///   14. `[]` mint
-///   15. `[writable]` metadata
-///   16. `[]` edition
-///   17. `[writable, optional]` user_token_record
-///   18. `[writable, optional]` pool_token_record
-///   19. `[optional]` token_metadata_program
-///   20. `[optional]` sysvar_instructions
-///   21. `[optional]` authorization_rules
-///   22. `[optional]` authorization_rules_program
-///   23. `[writable]` taker_ta
-///   24. `[writable]` owner_ta
-///   25. `[writable]` pool_ta
-///   26. `[writable, optional]` owner_token_record
-///   27. `[]` token_program
-///   28. `[]` associated_token_program
-///   29. `[]` system_program
+///   14. `[optional]` currency
+///   15. `[writable, optional]` pool_currency_ta
+///   16. `[writable, optional]` taker_currency_ta
+///   17. `[writable, optional]` owner_currency_ta
+///   18. `[writable, optional]` fee_vault_currency_ta
+///   19. `[writable, optional]` maker_broker_currency_ta
+///   20. `[writable, optional]` taker_broker_currency_ta
+///   21. `[optional]` currency_token_program
+///   22. `[optional]` currency_associated_token_program
+///   23. `[writable, optional]` price_ladder
+///   24. `[optional]` attestation_sysvar
+///   25. `[writable, optional]` trait_requirements
+///   26. `[]` mint
+///   27. `[writable]` metadata
+///   28. `[]` edition
+///   29. `[writable, optional]` user_token_record
+///   30. `[writable, optional]` pool_token_record
+///   31. `[optional]` token_metadata_program
+///   32. `[optional]` sysvar_instructions
+///   33. `[optional]` authorization_rules
+///   34. `[optional]` authorization_rules_program
+///   35. `[writable]` taker_ta
+///   36. `[writable]` owner_ta
+///   37. `[writable]` pool_ta
+///   38. `[writable, optional]` owner_token_record
+///   39. `[]` token_program
+///   40. `[]` associated_token_program
+///   41. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct SellNftTokenPoolCpiBuilder<'a, 'b> {
     instruction: Box<SellNftTokenPoolCpiBuilderInstruction<'a, 'b>>,
@@ -1311,6 +1873,18 @@ impl<'a, 'b> SellNftTokenPoolCpiBuilder<'a, 'b> {
             amm_program: None,
             escrow_program: None,
             native_program: None,
+            currency: None,
+            pool_currency_ta: None,
+            taker_currency_ta: None,
+            owner_currency_ta: None,
+            fee_vault_currency_ta: None,
+            maker_broker_currency_ta: None,
+            taker_broker_currency_ta: None,
+            currency_token_program: None,
+            currency_associated_token_program: None,
+            price_ladder: None,
+            attestation_sysvar: None,
+            trait_requirements: None,
             mint: None,
             metadata: None,
             edition: None,
@@ -1425,7 +1999,8 @@ impl<'a, 'b> SellNftTokenPoolCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     #[inline(always)]
     pub fn cosigner(
         &mut self,
@@ -1461,6 +2036,130 @@ impl<'a, 'b> SellNftTokenPoolCpiBuilder<'a, 'b> {
         self.instruction.native_program = Some(native_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[inline(always)]
+    pub fn currency(
+        &mut self,
+        currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency = currency;
+        self
+    }
+    /// `[optional account]`
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[inline(always)]
+    pub fn pool_currency_ta(
+        &mut self,
+        pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pool_currency_ta = pool_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[inline(always)]
+    pub fn taker_currency_ta(
+        &mut self,
+        taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.taker_currency_ta = taker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    #[inline(always)]
+    pub fn owner_currency_ta(
+        &mut self,
+        owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.owner_currency_ta = owner_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The fee vault's currency token account.
+    #[inline(always)]
+    pub fn fee_vault_currency_ta(
+        &mut self,
+        fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_vault_currency_ta = fee_vault_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The maker broker's currency token account.
+    #[inline(always)]
+    pub fn maker_broker_currency_ta(
+        &mut self,
+        maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.maker_broker_currency_ta = maker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The taker broker's currency token account.
+    #[inline(always)]
+    pub fn taker_broker_currency_ta(
+        &mut self,
+        taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.taker_broker_currency_ta = taker_broker_currency_ta;
+        self
+    }
+    /// `[optional account]`
+    /// The token program of the currency mint.
+    #[inline(always)]
+    pub fn currency_token_program(
+        &mut self,
+        currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.currency_token_program = currency_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The SPL associated token program, used to create currency token accounts.
+    #[inline(always)]
+    pub fn currency_associated_token_program(
+        &mut self,
+        currency_associated_token_program: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.currency_associated_token_program = currency_associated_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[inline(always)]
+    pub fn price_ladder(
+        &mut self,
+        price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.price_ladder = price_ladder;
+        self
+    }
+    /// `[optional account]`
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    #[inline(always)]
+    pub fn attestation_sysvar(
+        &mut self,
+        attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attestation_sysvar = attestation_sysvar;
+        self
+    }
+    /// `[optional account]`
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[inline(always)]
+    pub fn trait_requirements(
+        &mut self,
+        trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.trait_requirements = trait_requirements;
+        self
+    }
     /// The mint account of the NFT.
     #[inline(always)]
     pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
@@ -1713,6 +2412,30 @@ impl<'a, 'b> SellNftTokenPoolCpiBuilder<'a, 'b> {
                 .native_program
                 .expect("native_program is not set"),
 
+            currency: self.instruction.currency,
+
+            pool_currency_ta: self.instruction.pool_currency_ta,
+
+            taker_currency_ta: self.instruction.taker_currency_ta,
+
+            owner_currency_ta: self.instruction.owner_currency_ta,
+
+            fee_vault_currency_ta: self.instruction.fee_vault_currency_ta,
+
+            maker_broker_currency_ta: self.instruction.maker_broker_currency_ta,
+
+            taker_broker_currency_ta: self.instruction.taker_broker_currency_ta,
+
+            currency_token_program: self.instruction.currency_token_program,
+
+            currency_associated_token_program: self.instruction.currency_associated_token_program,
+
+            price_ladder: self.instruction.price_ladder,
+
+            attestation_sysvar: self.instruction.attestation_sysvar,
+
+            trait_requirements: self.instruction.trait_requirements,
+
             mint: self.instruction.mint.expect("mint is not set"),
 
             metadata: self.instruction.metadata.expect("metadata is not set"),
@@ -1779,6 +2502,18 @@ struct SellNftTokenPoolCpiBuilderInstruction<'a, 'b> {
     amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     escrow_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     native_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    taker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_vault_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    maker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    taker_broker_currency_ta: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    currency_associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    price_ladder: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attestation_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    trait_requirements: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
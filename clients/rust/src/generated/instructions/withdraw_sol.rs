@@ -16,6 +16,8 @@ pub struct WithdrawSol {
     pub pool: solana_program::pubkey::Pubkey,
     /// The Solana system program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: solana_program::pubkey::Pubkey,
 }
 
 impl WithdrawSol {
@@ -31,7 +33,7 @@ impl WithdrawSol {
         args: WithdrawSolInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -42,6 +44,10 @@ impl WithdrawSol {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.amm_program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = WithdrawSolInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -87,11 +93,13 @@ pub struct WithdrawSolInstructionArgs {
 ///   0. `[writable, signer]` owner
 ///   1. `[writable]` pool
 ///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[optional]` amm_program (default to `TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg`)
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawSolBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     pool: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    amm_program: Option<solana_program::pubkey::Pubkey>,
     lamports: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -119,6 +127,13 @@ impl WithdrawSolBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg']`
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(&mut self, amm_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.amm_program = Some(amm_program);
+        self
+    }
     #[inline(always)]
     pub fn lamports(&mut self, lamports: u64) -> &mut Self {
         self.lamports = Some(lamports);
@@ -150,6 +165,9 @@ impl WithdrawSolBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            amm_program: self.amm_program.unwrap_or(solana_program::pubkey!(
+                "TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg"
+            )),
         };
         let args = WithdrawSolInstructionArgs {
             lamports: self.lamports.clone().expect("lamports is not set"),
@@ -167,6 +185,8 @@ pub struct WithdrawSolCpiAccounts<'a, 'b> {
     pub pool: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `withdraw_sol` CPI instruction.
@@ -179,6 +199,8 @@ pub struct WithdrawSolCpi<'a, 'b> {
     pub pool: &'b solana_program::account_info::AccountInfo<'a>,
     /// The Solana system program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: WithdrawSolInstructionArgs,
 }
@@ -194,6 +216,7 @@ impl<'a, 'b> WithdrawSolCpi<'a, 'b> {
             owner: accounts.owner,
             pool: accounts.pool,
             system_program: accounts.system_program,
+            amm_program: accounts.amm_program,
             __args: args,
         }
     }
@@ -230,7 +253,7 @@ impl<'a, 'b> WithdrawSolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -243,6 +266,10 @@ impl<'a, 'b> WithdrawSolCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.amm_program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -259,11 +286,12 @@ impl<'a, 'b> WithdrawSolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.pool.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.amm_program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -283,6 +311,7 @@ impl<'a, 'b> WithdrawSolCpi<'a, 'b> {
 ///   0. `[writable, signer]` owner
 ///   1. `[writable]` pool
 ///   2. `[]` system_program
+///   3. `[]` amm_program
 #[derive(Clone, Debug)]
 pub struct WithdrawSolCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawSolCpiBuilderInstruction<'a, 'b>>,
@@ -295,6 +324,7 @@ impl<'a, 'b> WithdrawSolCpiBuilder<'a, 'b> {
             owner: None,
             pool: None,
             system_program: None,
+            amm_program: None,
             lamports: None,
             __remaining_accounts: Vec::new(),
         });
@@ -321,6 +351,15 @@ impl<'a, 'b> WithdrawSolCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// The AMM program account, used for self-cpi logging.
+    #[inline(always)]
+    pub fn amm_program(
+        &mut self,
+        amm_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.amm_program = Some(amm_program);
+        self
+    }
     #[inline(always)]
     pub fn lamports(&mut self, lamports: u64) -> &mut Self {
         self.instruction.lamports = Some(lamports);
@@ -385,6 +424,11 @@ impl<'a, 'b> WithdrawSolCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            amm_program: self
+                .instruction
+                .amm_program
+                .expect("amm_program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -400,6 +444,7 @@ struct WithdrawSolCpiBuilderInstruction<'a, 'b> {
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amm_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     lamports: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
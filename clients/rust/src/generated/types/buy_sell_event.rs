@@ -9,6 +9,9 @@ use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
 /// Event emitted when a user buys or sells tokens.
+///
+/// No longer emitted: superseded by `BuySellEventV2`, which is only emitted once the trade has
+/// settled. Kept so the indices of the other events don't change.
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuySellEvent {
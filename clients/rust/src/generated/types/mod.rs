@@ -5,34 +5,92 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#attach_shared_escrow_event;
 pub(crate) mod r#authorization_data_local;
 pub(crate) mod r#buy_sell_event;
+pub(crate) mod r#buy_sell_event_v2;
+pub(crate) mod r#circuit_breaker;
+pub(crate) mod r#circuit_breaker_args;
+pub(crate) mod r#close_pool_event;
+pub(crate) mod r#close_pool_reason;
+pub(crate) mod r#collection_local;
+pub(crate) mod r#compressed_args;
+pub(crate) mod r#cosigner_attestation;
+pub(crate) mod r#create_pool_event;
+pub(crate) mod r#creator_local;
 pub(crate) mod r#curve_type;
+pub(crate) mod r#deposit_currency_event;
+pub(crate) mod r#deposit_nft_event;
+pub(crate) mod r#detach_shared_escrow_event;
 pub(crate) mod r#direction;
 pub(crate) mod r#edit_pool_config;
+pub(crate) mod r#edit_pool_event;
+pub(crate) mod r#edit_pool_whitelist_event;
+pub(crate) mod r#metadata_args_local;
+pub(crate) mod r#migrated_nft;
 pub(crate) mod r#payload_type_local;
 pub(crate) mod r#pool_config;
 pub(crate) mod r#pool_stats;
 pub(crate) mod r#pool_type;
+pub(crate) mod r#pool_v1;
+pub(crate) mod r#price_ladder_args;
+pub(crate) mod r#price_modifier;
 pub(crate) mod r#proof_info_local;
 pub(crate) mod r#seeds_vec_local;
 pub(crate) mod r#t_amm_event;
 pub(crate) mod r#tagged_payload;
 pub(crate) mod r#taker_side;
+pub(crate) mod r#token_program_version_local;
+pub(crate) mod r#token_standard_local;
+pub(crate) mod r#trait_requirement;
 pub(crate) mod r#transfer_direction;
+pub(crate) mod r#transfer_pool_event;
+pub(crate) mod r#use_method_local;
+pub(crate) mod r#uses_local;
+pub(crate) mod r#withdraw_currency_event;
+pub(crate) mod r#withdraw_nft_event;
 
+pub use self::r#attach_shared_escrow_event::*;
 pub use self::r#authorization_data_local::*;
 pub use self::r#buy_sell_event::*;
+pub use self::r#buy_sell_event_v2::*;
+pub use self::r#circuit_breaker::*;
+pub use self::r#circuit_breaker_args::*;
+pub use self::r#close_pool_event::*;
+pub use self::r#close_pool_reason::*;
+pub use self::r#collection_local::*;
+pub use self::r#compressed_args::*;
+pub use self::r#cosigner_attestation::*;
+pub use self::r#create_pool_event::*;
+pub use self::r#creator_local::*;
 pub use self::r#curve_type::*;
+pub use self::r#deposit_currency_event::*;
+pub use self::r#deposit_nft_event::*;
+pub use self::r#detach_shared_escrow_event::*;
 pub use self::r#direction::*;
 pub use self::r#edit_pool_config::*;
+pub use self::r#edit_pool_event::*;
+pub use self::r#edit_pool_whitelist_event::*;
+pub use self::r#metadata_args_local::*;
+pub use self::r#migrated_nft::*;
 pub use self::r#payload_type_local::*;
 pub use self::r#pool_config::*;
 pub use self::r#pool_stats::*;
 pub use self::r#pool_type::*;
+pub use self::r#pool_v1::*;
+pub use self::r#price_ladder_args::*;
+pub use self::r#price_modifier::*;
 pub use self::r#proof_info_local::*;
 pub use self::r#seeds_vec_local::*;
 pub use self::r#t_amm_event::*;
 pub use self::r#tagged_payload::*;
 pub use self::r#taker_side::*;
+pub use self::r#token_program_version_local::*;
+pub use self::r#token_standard_local::*;
+pub use self::r#trait_requirement::*;
 pub use self::r#transfer_direction::*;
+pub use self::r#transfer_pool_event::*;
+pub use self::r#use_method_local::*;
+pub use self::r#uses_local::*;
+pub use self::r#withdraw_currency_event::*;
+pub use self::r#withdraw_nft_event::*;
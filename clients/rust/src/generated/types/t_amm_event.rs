@@ -5,7 +5,19 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AttachSharedEscrowEvent;
 use crate::generated::types::BuySellEvent;
+use crate::generated::types::BuySellEventV2;
+use crate::generated::types::ClosePoolEvent;
+use crate::generated::types::CreatePoolEvent;
+use crate::generated::types::DepositCurrencyEvent;
+use crate::generated::types::DepositNftEvent;
+use crate::generated::types::DetachSharedEscrowEvent;
+use crate::generated::types::EditPoolEvent;
+use crate::generated::types::EditPoolWhitelistEvent;
+use crate::generated::types::TransferPoolEvent;
+use crate::generated::types::WithdrawCurrencyEvent;
+use crate::generated::types::WithdrawNftEvent;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -14,4 +26,16 @@ use borsh::BorshSerialize;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TAmmEvent {
     BuySellEvent(BuySellEvent),
+    CreatePoolEvent(CreatePoolEvent),
+    EditPoolEvent(EditPoolEvent),
+    DepositNftEvent(DepositNftEvent),
+    WithdrawNftEvent(WithdrawNftEvent),
+    DepositCurrencyEvent(DepositCurrencyEvent),
+    WithdrawCurrencyEvent(WithdrawCurrencyEvent),
+    ClosePoolEvent(ClosePoolEvent),
+    BuySellEventV2(BuySellEventV2),
+    EditPoolWhitelistEvent(EditPoolWhitelistEvent),
+    TransferPoolEvent(TransferPoolEvent),
+    AttachSharedEscrowEvent(AttachSharedEscrowEvent),
+    DetachSharedEscrowEvent(DetachSharedEscrowEvent),
 }
@@ -0,0 +1,15 @@
+use borsh::BorshSerialize;
+
+use crate::types::CosignerAttestation;
+
+impl CosignerAttestation {
+    /// The message the cosigner signs.
+    ///
+    /// The taker verifies the signature with an Ed25519 program instruction placed before the
+    /// trade instruction, and passes the sysvar instructions account to the trade instead of the
+    /// cosigner.
+    pub fn to_message(&self) -> Vec<u8> {
+        // Serializing into a vector can't fail.
+        self.try_to_vec().unwrap()
+    }
+}
@@ -1,7 +1,10 @@
+pub mod cosigner_attestation;
 pub mod currency;
 pub mod fees;
 pub mod nullable;
 pub mod pool;
+pub mod pool_v1;
+pub mod price_ladder;
 
 pub use currency::*;
 pub use fees::*;
@@ -12,3 +15,6 @@ pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 pub const BROKER_FEE_PCT: u64 = 50;
 pub const MAKER_BROKER_PCT: u8 = 80;
 pub const TAKER_FEE_BPS: u64 = 200;
+pub const GDA_DECAY_PERIOD_SEC: i64 = 3600;
+pub const CURRENT_POOL_VERSION: u8 = 2;
+pub const COSIGNER_NONCE_WINDOW: u64 = 512;
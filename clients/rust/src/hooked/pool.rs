@@ -1,11 +1,13 @@
 use std::fmt::{self, Display, Formatter};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::accounts::Pool;
+use crate::accounts::{Pool, PriceLadder};
 use crate::errors::TensorAmmError;
 use crate::types::{
-    CurveType, Direction, EditPoolConfig, PoolConfig, PoolStats, PoolType, TakerSide,
+    CircuitBreaker, CurveType, Direction, EditPoolConfig, PoolConfig, PoolStats, PoolType,
+    TakerSide,
 };
-use crate::HUNDRED_PCT_BPS;
+use crate::{GDA_DECAY_PERIOD_SEC, HUNDRED_PCT_BPS};
 
 use spl_math::precise_number::PreciseNumber;
 
@@ -20,9 +22,39 @@ impl Default for PoolStats {
     }
 }
 
+#[allow(clippy::derivable_impls)]
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            window_sec: 0,
+            max_trades: 0,
+            max_price_offset_change: 0,
+            window_start: 0,
+            window_trades: 0,
+            window_start_offset: 0,
+        }
+    }
+}
+
 impl Pool {
     /// Shifts the price of a pool by a certain offset.
+    ///
+    /// XYK curves are priced from the pool's reserves, so the offset is relative to the
+    /// current `price_offset`: each step is one NFT bought from the pool.
+    ///
+    /// GDA curves are priced at the current system time, use `shift_price_at` to price them
+    /// at a given time instead.
     pub fn shift_price(&self, price_offset: i32, side: TakerSide) -> Result<u64, TensorAmmError> {
+        self.shift_price_at(price_offset, side, self.pricing_timestamp())
+    }
+
+    /// Shifts the price of a pool by a certain offset, at the given unix timestamp.
+    pub fn shift_price_at(
+        &self,
+        price_offset: i32,
+        side: TakerSide,
+        unix_timestamp: i64,
+    ) -> Result<u64, TensorAmmError> {
         let direction = if price_offset > 0 {
             Direction::Up
         } else {
@@ -53,7 +85,7 @@ impl Pool {
                         .ok_or(TensorAmmError::ArithmeticError)?,
                 }
             }
-            CurveType::Exponential => {
+            CurveType::Exponential | CurveType::Gda => {
                 let hundred_pct = PreciseNumber::new(HUNDRED_PCT_BPS.into())
                     .ok_or(TensorAmmError::ArithmeticError)?;
 
@@ -72,13 +104,33 @@ impl Pool {
                 .checked_pow(offset.into())
                 .ok_or(TensorAmmError::ArithmeticError)?;
 
-                let result = match direction {
+                let mut result = match direction {
                     // price * (1 + delta)^trade_count
                     Direction::Up => base.checked_mul(&factor),
                     //same but / instead of *
                     Direction::Down => base.checked_div(&factor),
                 };
 
+                if self.config.curve_type == CurveType::Gda {
+                    // price * (1 - decay)^elapsed_periods
+                    let elapsed = unix_timestamp.saturating_sub(self.updated_at).max(0);
+                    let periods = (elapsed / GDA_DECAY_PERIOD_SEC) as u128;
+
+                    let decay = PreciseNumber::new(
+                        HUNDRED_PCT_BPS
+                            .checked_sub(self.decay_bps.into())
+                            .ok_or(TensorAmmError::ArithmeticError)?
+                            .into(),
+                    )
+                    .ok_or(TensorAmmError::ArithmeticError)?
+                    .checked_div(&hundred_pct)
+                    .ok_or(TensorAmmError::ArithmeticError)?
+                    .checked_pow(periods)
+                    .ok_or(TensorAmmError::ArithmeticError)?;
+
+                    result = result.and_then(|result| result.checked_mul(&decay));
+                }
+
                 let rounded_result = match side {
                     TakerSide::Buy => result.ok_or(TensorAmmError::ArithmeticError)?.ceiling(),
                     TakerSide::Sell => result.ok_or(TensorAmmError::ArithmeticError)?.floor(),
@@ -93,27 +145,138 @@ impl Pool {
                     .ok()
                     .ok_or(TensorAmmError::ArithmeticError)?
             }
+            CurveType::Xyk => self.xyk_price(price_offset, side)?,
+            // Custom curves are priced with `shift_price_with`.
+            CurveType::Custom => return Err(TensorAmmError::MissingPriceLadder),
         };
 
+        // GDA prices stop decaying at the floor.
+        if self.config.curve_type == CurveType::Gda {
+            return Ok(current_price.max(self.floor_price));
+        }
+
         Ok(current_price)
     }
 
+    /// Price of an NFT on a XYK curve, keeping `amount * nfts_held` constant.
+    ///
+    /// With `k = amount * nfts_held` and `n` NFTs in reserve after shifting, buying an NFT costs
+    /// `k / (n - 1) - k / n` and selling one pays `k / n - k / (n + 1)`.
+    fn xyk_price(&self, price_offset: i32, side: TakerSide) -> Result<u64, TensorAmmError> {
+        let steps = i64::from(price_offset)
+            .checked_sub(self.price_offset.into())
+            .ok_or(TensorAmmError::ArithmeticError)?;
+        let nfts = i64::from(self.nfts_held)
+            .checked_sub(steps)
+            .ok_or(TensorAmmError::ArithmeticError)?;
+
+        // Both reserves are needed, and the last NFT of the pool can't be bought.
+        let min_nfts = match side {
+            TakerSide::Buy => 2,
+            TakerSide::Sell => 1,
+        };
+        if self.amount == 0 || nfts < min_nfts {
+            return Err(TensorAmmError::InsufficientReserves);
+        }
+
+        let nfts = nfts as u128;
+        let k = (self.amount as u128)
+            .checked_mul(self.nfts_held.into())
+            .ok_or(TensorAmmError::ArithmeticError)?;
+
+        let price = match side {
+            // Rounded up so buyers never pay less than the curve.
+            TakerSide::Buy => {
+                let denominator = nfts
+                    .checked_mul(nfts - 1)
+                    .ok_or(TensorAmmError::ArithmeticError)?;
+                k.checked_add(denominator - 1)
+                    .ok_or(TensorAmmError::ArithmeticError)?
+                    / denominator
+            }
+            // Rounded down so sellers never receive more than the curve.
+            TakerSide::Sell => {
+                k / nfts
+                    .checked_mul(nfts + 1)
+                    .ok_or(TensorAmmError::ArithmeticError)?
+            }
+        };
+
+        u64::try_from(price).map_err(|_| TensorAmmError::ArithmeticError)
+    }
+
     /// Calculate the price of the pool after shifting it by a certain offset.
     pub fn current_price(&self, side: TakerSide) -> Result<u64, TensorAmmError> {
+        self.current_price_at(side, self.pricing_timestamp())
+    }
+
+    /// Calculate the price of the pool at the given unix timestamp, which only matters for GDA curves.
+    pub fn current_price_at(
+        &self,
+        side: TakerSide,
+        unix_timestamp: i64,
+    ) -> Result<u64, TensorAmmError> {
+        self.shift_price_at(self.current_price_offset(side)?, side, unix_timestamp)
+    }
+
+    /// Calculate the current price of the pool, reading custom curves from their price ladder.
+    pub fn current_price_with(
+        &self,
+        side: TakerSide,
+        price_ladder: Option<&PriceLadder>,
+    ) -> Result<u64, TensorAmmError> {
+        self.shift_price_with(self.current_price_offset(side)?, side, price_ladder)
+    }
+
+    /// Shifts the price of a pool by a certain offset, reading custom curves from their price ladder.
+    pub fn shift_price_with(
+        &self,
+        price_offset: i32,
+        side: TakerSide,
+        price_ladder: Option<&PriceLadder>,
+    ) -> Result<u64, TensorAmmError> {
+        if self.config.curve_type == CurveType::Custom {
+            return price_ladder
+                .ok_or(TensorAmmError::MissingPriceLadder)?
+                .price(price_offset);
+        }
+
+        self.shift_price(price_offset, side)
+    }
+
+    /// The offset the current price of a side is quoted at.
+    fn current_price_offset(&self, side: TakerSide) -> Result<i32, TensorAmmError> {
         match (self.config.pool_type, side) {
             (PoolType::Trade, TakerSide::Buy)
             | (PoolType::Token, TakerSide::Sell)
-            | (PoolType::NFT, TakerSide::Buy) => self.shift_price(self.price_offset, side),
+            | (PoolType::NFT, TakerSide::Buy) => Ok(self.price_offset),
+
+            // XYK curves already quote sells below buys, since buying and selling move the
+            // reserves in opposite directions.
+            (PoolType::Trade, TakerSide::Sell) if self.config.curve_type == CurveType::Xyk => {
+                Ok(self.price_offset)
+            }
 
             // Trade pool sells require the price to be shifted down by 1 to prevent
             // liquidity from being drained by repeated matched buys and sells.
-            (PoolType::Trade, TakerSide::Sell) => self.shift_price(self.price_offset - 1, side),
+            (PoolType::Trade, TakerSide::Sell) => Ok(self.price_offset - 1),
 
             // Invalid combinations of pool type and side.
             _ => Err(TensorAmmError::WrongPoolType),
         }
     }
 
+    /// Timestamp prices are quoted at: the system time for GDA curves, whose price decays
+    /// since the last trade, otherwise the time of the last update which has no effect.
+    fn pricing_timestamp(&self) -> i64 {
+        match self.config.curve_type {
+            CurveType::Gda => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(self.updated_at, |now| now.as_secs() as i64),
+            _ => self.updated_at,
+        }
+    }
+
     /// Calculate the fee the MM receives when providing liquidity to a two-sided pool.
     pub fn calc_mm_fee(&self, current_price: u64) -> Result<u64, TensorAmmError> {
         let fee = match self.config.pool_type {
@@ -144,6 +307,9 @@ impl Display for CurveType {
         match self {
             CurveType::Linear => write!(f, "Linear"),
             CurveType::Exponential => write!(f, "Exponential"),
+            CurveType::Xyk => write!(f, "XYK"),
+            CurveType::Gda => write!(f, "GDA"),
+            CurveType::Custom => write!(f, "Custom"),
         }
     }
 }
@@ -209,7 +375,21 @@ mod tests {
                 amount: 0,
                 shared_escrow: NullableAddress::none(),
                 max_taker_sell_count: 10,
-                reserved: [0; 100],
+                decay_bps: 0,
+                floor_price: 0,
+                manager: NullableAddress::none(),
+                buys_paused: false,
+                sells_paused: false,
+                cosigner_nonce: 0,
+                requires_traits: false,
+                max_taker_buy_count: 0,
+                max_nfts_held: 0,
+                max_total_spend: 0,
+                circuit_breaker: CircuitBreaker::default(),
+                keeper_bounty: 0,
+                cosigner_nonces_used: [0; 8],
+                total_spent: 0,
+                reserved: [0; 71],
             }
         }
     }
@@ -512,4 +692,130 @@ mod tests {
         // This shouldn't oveflow for sell side (1 tick lower).
         assert_eq!(p.current_price(TakerSide::Sell).unwrap(), delta);
     }
+
+    // --------------------------------------- XYK
+
+    fn xyk_test_pool(amount: u64, nfts_held: u32) -> Pool {
+        let mut p = Pool::new_test_pool(
+            PoolType::Trade,
+            CurveType::Xyk,
+            1,
+            0,
+            0,
+            NullableU16::none(),
+        );
+        p.amount = amount;
+        p.nfts_held = nfts_held;
+        p
+    }
+
+    #[test]
+    fn test_xyk_trade_pool() {
+        let p = xyk_test_pool(10 * LAMPORTS_PER_SOL, 10);
+
+        // k = 100 SOL: buying takes the pool from 10 to 9 NFTs, selling from 10 to 11.
+        assert_eq!(p.current_price(TakerSide::Buy).unwrap(), 1_111_111_112);
+        assert_eq!(p.current_price(TakerSide::Sell).unwrap(), 909_090_909);
+
+        // Quoting further NFTs moves along the curve.
+        assert_eq!(p.shift_price(1, TakerSide::Buy).unwrap(), 1_388_888_889);
+        assert_eq!(p.shift_price(-1, TakerSide::Sell).unwrap(), 757_575_757);
+    }
+
+    #[test]
+    #[should_panic(expected = "InsufficientReserves")]
+    fn test_xyk_trade_pool_panic_last_nft() {
+        let p = xyk_test_pool(10 * LAMPORTS_PER_SOL, 1);
+        p.current_price(TakerSide::Buy).unwrap();
+    }
+
+    // --------------------------------------- GDA
+
+    #[test]
+    fn test_gda_nft_pool() {
+        let mut p = Pool::new_test_pool(
+            PoolType::NFT,
+            CurveType::Gda,
+            LAMPORTS_PER_SOL,
+            1_000,
+            0,
+            NullableU16::none(),
+        );
+        p.decay_bps = 1_000;
+        p.floor_price = LAMPORTS_PER_SOL / 2;
+
+        // 10% decay per elapsed period since the last trade.
+        let updated_at = p.updated_at;
+        assert_eq!(
+            p.current_price_at(TakerSide::Buy, updated_at).unwrap(),
+            LAMPORTS_PER_SOL
+        );
+        assert_eq!(
+            p.current_price_at(TakerSide::Buy, updated_at + GDA_DECAY_PERIOD_SEC)
+                .unwrap(),
+            LAMPORTS_PER_SOL * 9 / 10
+        );
+
+        // Stops at the floor.
+        assert_eq!(
+            p.current_price_at(TakerSide::Buy, updated_at + GDA_DECAY_PERIOD_SEC * 7)
+                .unwrap(),
+            LAMPORTS_PER_SOL / 2
+        );
+
+        // Bumped up by delta after a fill.
+        p.price_offset = 1;
+        assert_eq!(
+            p.current_price_at(TakerSide::Buy, updated_at).unwrap(),
+            LAMPORTS_PER_SOL * 11 / 10
+        );
+    }
+
+    // --------------------------------------- Custom
+
+    #[test]
+    fn test_custom_trade_pool() {
+        let ladder = PriceLadder {
+            discriminator: [0; 8],
+            bump: [1],
+            pool: Pubkey::default(),
+            zero_index: 2,
+            prices: vec![90, 95, 100, 120],
+        };
+        let mut p = Pool::new_test_pool(
+            PoolType::Trade,
+            CurveType::Custom,
+            1,
+            0,
+            0,
+            NullableU16::none(),
+        );
+
+        assert_eq!(
+            p.current_price_with(TakerSide::Buy, Some(&ladder)).unwrap(),
+            100
+        );
+        assert_eq!(
+            p.current_price_with(TakerSide::Sell, Some(&ladder))
+                .unwrap(),
+            95
+        );
+
+        p.price_offset = 1;
+        assert_eq!(
+            p.current_price_with(TakerSide::Buy, Some(&ladder)).unwrap(),
+            120
+        );
+        assert_eq!(
+            p.current_price_with(TakerSide::Buy, None).unwrap_err(),
+            TensorAmmError::MissingPriceLadder
+        );
+
+        p.price_offset = 2;
+        assert_eq!(
+            p.current_price_with(TakerSide::Buy, Some(&ladder))
+                .unwrap_err(),
+            TensorAmmError::PriceLadderExhausted
+        );
+    }
 }
@@ -0,0 +1,135 @@
+use borsh::BorshDeserialize;
+
+use crate::accounts::Pool;
+use crate::types::{CircuitBreaker, PoolV1};
+use crate::{NullableAddress, CURRENT_POOL_VERSION};
+
+impl PoolV1 {
+    /// Size of version 1 pool accounts, including the discriminator.
+    pub const LEN: usize = 447;
+
+    /// Decodes version 1 pool account data, which starts with the `Pool` discriminator.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data.get(8..).ok_or(std::io::ErrorKind::UnexpectedEof)?;
+        Self::deserialize(&mut data)
+    }
+
+    /// Converts the pool to the current layout the way `upgrade_pool` does.
+    pub fn upgrade(self, discriminator: [u8; 8]) -> Pool {
+        Pool {
+            discriminator,
+            version: CURRENT_POOL_VERSION,
+            bump: self.bump,
+            pool_id: self.pool_id,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            expiry: self.expiry,
+            owner: self.owner,
+            whitelist: self.whitelist,
+            rent_payer: self.rent_payer,
+            currency: self.currency,
+            amount: self.amount,
+            price_offset: self.price_offset,
+            nfts_held: self.nfts_held,
+            stats: self.stats,
+            shared_escrow: self.shared_escrow,
+            cosigner: self.cosigner,
+            maker_broker: self.maker_broker,
+            max_taker_sell_count: self.max_taker_sell_count,
+            config: self.config,
+            decay_bps: 0,
+            floor_price: 0,
+            manager: NullableAddress::none(),
+            buys_paused: false,
+            sells_paused: false,
+            cosigner_nonce: 0,
+            requires_traits: false,
+            max_taker_buy_count: 0,
+            max_nfts_held: 0,
+            max_total_spend: 0,
+            circuit_breaker: CircuitBreaker::default(),
+            keeper_bounty: 0,
+            cosigner_nonces_used: [0; 8],
+            total_spent: 0,
+            reserved: [0; 71],
+        }
+    }
+}
+
+impl Pool {
+    /// Decodes pool account data of any supported version, converting older versions to the
+    /// current layout the way `upgrade_pool` does.
+    pub fn from_versioned_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        match data.get(8) {
+            Some(1) => {
+                let discriminator = data[..8].try_into().unwrap();
+                PoolV1::from_bytes(data).map(|pool| pool.upgrade(discriminator))
+            }
+            Some(&CURRENT_POOL_VERSION) => Pool::from_bytes(data),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "unsupported pool version",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use borsh::BorshSerialize;
+
+    use crate::types::{CurveType, PoolType};
+    use crate::NullableU16;
+
+    #[test]
+    fn test_decode_pool_versions() {
+        let mut pool = Pool::new_test_pool(
+            PoolType::Trade,
+            CurveType::Linear,
+            1_000,
+            100,
+            3,
+            NullableU16::none(),
+        );
+        pool.version = CURRENT_POOL_VERSION;
+
+        let data = pool.try_to_vec().unwrap();
+        assert_eq!(data.len(), Pool::LEN);
+        assert_eq!(Pool::from_versioned_bytes(&data).unwrap(), pool);
+
+        let v1 = PoolV1 {
+            version: 1,
+            bump: pool.bump,
+            pool_id: pool.pool_id,
+            created_at: pool.created_at,
+            updated_at: pool.updated_at,
+            expiry: pool.expiry,
+            owner: pool.owner,
+            whitelist: pool.whitelist,
+            rent_payer: pool.rent_payer,
+            currency: pool.currency,
+            amount: pool.amount,
+            price_offset: pool.price_offset,
+            nfts_held: pool.nfts_held,
+            stats: pool.stats.clone(),
+            shared_escrow: pool.shared_escrow,
+            cosigner: pool.cosigner,
+            maker_broker: pool.maker_broker,
+            max_taker_sell_count: pool.max_taker_sell_count,
+            config: pool.config.clone(),
+            reserved: [0; 100],
+        };
+
+        let mut data = pool.discriminator.to_vec();
+        v1.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), PoolV1::LEN);
+        assert_eq!(Pool::from_versioned_bytes(&data).unwrap(), pool);
+
+        let mut data = data;
+        data[8] = 0;
+        assert!(Pool::from_versioned_bytes(&data).is_err());
+    }
+}
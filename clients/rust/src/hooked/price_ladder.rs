@@ -0,0 +1,15 @@
+use crate::accounts::PriceLadder;
+use crate::errors::TensorAmmError;
+
+impl PriceLadder {
+    /// Returns the price at the given price offset.
+    pub fn price(&self, price_offset: i32) -> Result<u64, TensorAmmError> {
+        let index = i64::from(self.zero_index) + i64::from(price_offset);
+
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.prices.get(index))
+            .copied()
+            .ok_or(TensorAmmError::PriceLadderExhausted)
+    }
+}
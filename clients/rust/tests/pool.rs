@@ -1,29 +1,31 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
 
-use borsh::BorshDeserialize;
-use setup::ANCHOR_ERROR_ACCOUNT_NOT_SIGNER;
+use borsh::{BorshDeserialize, BorshSerialize};
+use setup::{ANCHOR_ERROR_ACCOUNT_NOT_SIGNER, ANCHOR_ERROR_CONSTRAINT_SEEDS};
 use solana_program_test::{tokio, BanksClientError};
 use solana_sdk::{
+    account::Account,
     clock::Clock,
     instruction::InstructionError,
+    pubkey::Pubkey,
     signature::{Keypair, Signer},
-    system_program,
     transaction::{Transaction, TransactionError},
 };
 use tensor_amm::{
     accounts::Pool,
+    errors::TensorAmmError,
     instructions::{
-        CloseExpiredPool, ClosePool, DepositSol, DepositSolInstructionArgs, EditPool,
-        EditPoolInstructionArgs,
+        CloseExpiredPoolBuilder, ClosePoolBuilder, ConvertPoolBuilder, DepositSolBuilder,
+        EditPoolBuilder, SetPoolStatusBuilder, UpgradePoolBuilder,
     },
-    types::{CurveType, EditPoolConfig},
-    NullableU16,
+    types::{CircuitBreakerArgs, CurveType, EditPoolConfig, PoolType, PoolV1},
+    NullableAddress, NullableU16,
 };
 
 use crate::setup::{
-    airdrop, program_context, setup_default_pool, setup_default_whitelist, TestPool,
-    TestPoolInputs, TestWhitelistV2, TestWhitelistV2Inputs, ONE_SOL_LAMPORTS,
+    airdrop, fetch_pool, program_context, setup_default_pool, setup_default_whitelist, TestPool,
+    TestPoolInputs, TestWhitelistV2, TestWhitelistV2Inputs, ONE_SOL_LAMPORTS, ONE_WEEK,
 };
 
 #[tokio::test]
@@ -127,13 +129,11 @@ async fn close_pool() {
     assert_eq!(pool_data.config, config);
 
     // When the pool is closed
-    let ix = ClosePool {
-        rent_payer: update_authority,
-        pool,
-        owner: update_authority,
-        system_program: system_program::id(),
-    }
-    .instruction();
+    let ix = ClosePoolBuilder::new()
+        .rent_payer(update_authority)
+        .owner(update_authority)
+        .pool(pool)
+        .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -217,13 +217,11 @@ async fn close_expired_pool() {
     // The pool has expired
     assert!(now > pool_data.expiry);
 
-    let ix = CloseExpiredPool {
-        rent_payer: update_authority,
-        pool,
-        owner: update_authority,
-        system_program: system_program::id(),
-    }
-    .instruction();
+    let ix = CloseExpiredPoolBuilder::new()
+        .rent_payer(update_authority)
+        .owner(update_authority)
+        .pool(pool)
+        .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -280,14 +278,11 @@ async fn close_expired_pool_returns_excess_lamports_to_owner() {
     .await;
 
     // And the owner has deposited 1 SOL
-    let deposit_ix = DepositSol {
-        pool,
-        owner: pool_owner,
-        system_program: system_program::id(),
-    }
-    .instruction(DepositSolInstructionArgs {
-        lamports: ONE_SOL_LAMPORTS,
-    });
+    let deposit_ix = DepositSolBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .lamports(ONE_SOL_LAMPORTS)
+        .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[deposit_ix],
@@ -329,13 +324,11 @@ async fn close_expired_pool_returns_excess_lamports_to_owner() {
     // The pool has expired
     assert!(now > pool_data.expiry);
 
-    let ix = CloseExpiredPool {
-        rent_payer,
-        pool,
-        owner: pool_owner,
-        system_program: system_program::id(),
-    }
-    .instruction();
+    let ix = CloseExpiredPoolBuilder::new()
+        .rent_payer(rent_payer)
+        .owner(pool_owner)
+        .pool(pool)
+        .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -420,19 +413,12 @@ async fn edit_pool() {
         mm_fee_bps: NullableU16::none(),
     };
 
-    let ix = EditPool {
-        pool,
-        owner: update_authority,
-        system_program: system_program::id(),
-    }
-    .instruction(EditPoolInstructionArgs {
-        new_config: Some(new_config.clone()),
-        cosigner: None,
-        maker_broker: None,
-        max_taker_sell_count: None,
-        expire_in_sec: None,
-        reset_price_offset: true,
-    });
+    let ix = EditPoolBuilder::new()
+        .owner(update_authority, true)
+        .pool(pool)
+        .new_config(new_config.clone())
+        .reset_price_offset(true)
+        .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -514,20 +500,12 @@ async fn edit_pool_owner_must_sign() {
         mm_fee_bps: config.mm_fee_bps,
     };
 
-    let mut ix = EditPool {
-        pool,
-        owner: update_authority,
-        system_program: system_program::id(),
-    }
-    .instruction(EditPoolInstructionArgs {
-        new_config: Some(new_config.clone()),
-        cosigner: None,
-        maker_broker: None,
-        max_taker_sell_count: None,
-        expire_in_sec: None,
-        reset_price_offset: true,
-    });
-    ix.accounts[0].is_signer = false;
+    let ix = EditPoolBuilder::new()
+        .owner(update_authority, false)
+        .pool(pool)
+        .new_config(new_config.clone())
+        .reset_price_offset(true)
+        .instruction();
 
     let tx = Transaction::new_signed_with_payer(
         &[ix],
@@ -541,3 +519,1002 @@ async fn edit_pool_owner_must_sign() {
     let err = result.err().unwrap();
     assert_custom_error!(err, ANCHOR_ERROR_ACCOUNT_NOT_SIGNER);
 }
+
+#[tokio::test]
+async fn set_pool_status_pauses_and_resumes_trading() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and pool.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // When the owner pauses buys
+    let ix = SetPoolStatusBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .buys_paused(true)
+        .sells_paused(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then only buys are paused.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert!(pool_data.buys_paused);
+    assert!(!pool_data.sells_paused);
+
+    // When the owner resumes buys and pauses sells
+    let ix = SetPoolStatusBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .buys_paused(false)
+        .sells_paused(true)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then only sells are paused.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert!(!pool_data.buys_paused);
+    assert!(pool_data.sells_paused);
+}
+
+#[tokio::test]
+async fn set_pool_status_requires_owner() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let not_owner_signer = Keypair::new();
+
+    airdrop(&mut context, &pool_owner_signer.pubkey(), ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and pool.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // When another signer tries to pause the pool
+    let ix = SetPoolStatusBuilder::new()
+        .owner(not_owner_signer.pubkey())
+        .pool(pool)
+        .buys_paused(true)
+        .sells_paused(true)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &not_owner_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the pool seeds don't match.
+    assert_custom_error!(err, ANCHOR_ERROR_CONSTRAINT_SEEDS);
+
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert!(!pool_data.buys_paused);
+    assert!(!pool_data.sells_paused);
+}
+
+#[tokio::test]
+async fn manager_can_edit_pool_pricing_and_expiry() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+    let manager_signer = Keypair::new();
+    let manager = manager_signer.pubkey();
+
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and a pool with a manager.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, config, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            manager: Some(manager),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.manager, NullableAddress::new(manager));
+
+    // When the manager edits the pricing and expiry without the owner signing
+    let new_config = EditPoolConfig {
+        curve_type: CurveType::Exponential,
+        starting_price: 10,
+        delta: 3,
+        mm_compound_fees: config.mm_compound_fees,
+        mm_fee_bps: config.mm_fee_bps,
+    };
+
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, false)
+        .pool(pool)
+        .manager(Some(manager))
+        .new_config(new_config.clone())
+        .expire_in_sec(2 * ONE_WEEK)
+        .reset_price_offset(true)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the pool was updated.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.config.curve_type, new_config.curve_type);
+    assert_eq!(pool_data.config.starting_price, new_config.starting_price);
+    assert_eq!(pool_data.config.delta, new_config.delta);
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    assert!(pool_data.expiry >= pool_data.created_at + (2 * ONE_WEEK) as i64);
+    assert!(pool_data.expiry <= clock.unix_timestamp + (2 * ONE_WEEK) as i64);
+}
+
+#[tokio::test]
+async fn manager_cannot_make_owner_only_changes() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+    let manager_signer = Keypair::new();
+    let manager = manager_signer.pubkey();
+    let not_manager_signer = Keypair::new();
+
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and a pool with a manager.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, config, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            manager: Some(manager),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // When the manager tries to set a cosigner
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, false)
+        .pool(pool)
+        .manager(Some(manager))
+        .cosigner(Pubkey::new_unique())
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then only the owner is allowed to.
+    assert_custom_error!(err, TensorAmmError::ManagerNotAllowed as u32);
+
+    // When the manager tries to change the MM fee settings
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, false)
+        .pool(pool)
+        .manager(Some(manager))
+        .new_config(EditPoolConfig {
+            curve_type: config.curve_type,
+            starting_price: config.starting_price,
+            delta: config.delta,
+            mm_compound_fees: !config.mm_compound_fees,
+            mm_fee_bps: config.mm_fee_bps,
+        })
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then only the owner is allowed to.
+    assert_custom_error!(err, TensorAmmError::ManagerNotAllowed as u32);
+
+    // When another signer tries to edit the pool as the manager
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, false)
+        .pool(pool)
+        .manager(Some(not_manager_signer.pubkey()))
+        .expire_in_sec(ONE_WEEK)
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &not_manager_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the manager doesn't match the pool.
+    assert_custom_error!(err, TensorAmmError::WrongManager as u32);
+
+    // When the owner removes the manager
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, true)
+        .pool(pool)
+        .new_manager(Pubkey::default())
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.manager, NullableAddress::none());
+
+    // Then the former manager can no longer edit the pool.
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, false)
+        .pool(pool)
+        .manager(Some(manager))
+        .expire_in_sec(ONE_WEEK)
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_error!(err, TensorAmmError::WrongManager as u32);
+}
+
+#[tokio::test]
+async fn edit_gda_pool_validates_decay() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and a GDA pool.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            pool_type: Some(PoolType::NFT),
+            curve_type: Some(CurveType::Gda),
+            starting_price: Some(ONE_SOL_LAMPORTS),
+            delta: Some(100),
+            decay_bps: Some(100),
+            floor_price: Some(ONE_SOL_LAMPORTS / 2),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.decay_bps, 100);
+    assert_eq!(pool_data.floor_price, ONE_SOL_LAMPORTS / 2);
+
+    // When the owner removes the decay
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, true)
+        .pool(pool)
+        .decay_bps(0)
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the decay is invalid.
+    assert_custom_error!(err, TensorAmmError::InvalidDecay as u32);
+
+    // When the owner sets a floor price above the starting price
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, true)
+        .pool(pool)
+        .floor_price(2 * ONE_SOL_LAMPORTS)
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the decay is invalid.
+    assert_custom_error!(err, TensorAmmError::InvalidDecay as u32);
+
+    // When the owner sets a valid decay and floor price
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, true)
+        .pool(pool)
+        .decay_bps(250)
+        .floor_price(ONE_SOL_LAMPORTS / 4)
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the auction was updated.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.decay_bps, 250);
+    assert_eq!(pool_data.floor_price, ONE_SOL_LAMPORTS / 4);
+}
+
+#[tokio::test]
+async fn edit_pool_sets_circuit_breaker() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and pool.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // When the owner sets a window without any limit
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, true)
+        .pool(pool)
+        .circuit_breaker(CircuitBreakerArgs {
+            window_sec: 60,
+            max_trades: 0,
+            max_price_offset_change: 0,
+        })
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the circuit breaker is invalid.
+    assert_custom_error!(err, TensorAmmError::InvalidCircuitBreaker as u32);
+
+    // When the owner sets limits within a window
+    let ix = EditPoolBuilder::new()
+        .owner(pool_owner, true)
+        .pool(pool)
+        .circuit_breaker(CircuitBreakerArgs {
+            window_sec: 60,
+            max_trades: 3,
+            max_price_offset_change: 2,
+        })
+        .reset_price_offset(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the limits were set.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.circuit_breaker.window_sec, 60);
+    assert_eq!(pool_data.circuit_breaker.max_trades, 3);
+    assert_eq!(pool_data.circuit_breaker.max_price_offset_change, 2);
+    assert_eq!(pool_data.circuit_breaker.window_trades, 0);
+}
+
+#[tokio::test]
+async fn close_expired_pool_pays_keeper_bounty() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let rent_payer_signer = Keypair::new();
+    let rent_payer = rent_payer_signer.pubkey();
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+    let keeper_signer = Keypair::new();
+    let keeper = keeper_signer.pubkey();
+
+    airdrop(&mut context, &rent_payer, 2 * ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+    airdrop(&mut context, &keeper, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // When a pool is created with a keeper bounty
+    let keeper_bounty = ONE_SOL_LAMPORTS / 10;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &rent_payer_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            expire_in_sec: Some(1),
+            keeper_bounty: Some(keeper_bounty),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // Then the bounty is escrowed in the pool on top of its rent.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.keeper_bounty, keeper_bounty);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let pool_balance = context.banks_client.get_balance(pool).await.unwrap();
+    assert_eq!(
+        pool_balance,
+        rent.minimum_balance(Pool::LEN) + keeper_bounty
+    );
+
+    // Warp ahead so the pool expires
+    context.warp_to_slot(2000).unwrap();
+
+    let rent_payer_balance_before = context.banks_client.get_balance(rent_payer).await.unwrap();
+    let owner_balance_before = context.banks_client.get_balance(pool_owner).await.unwrap();
+    let keeper_balance_before = context.banks_client.get_balance(keeper).await.unwrap();
+
+    // When a keeper closes the expired pool
+    let ix = CloseExpiredPoolBuilder::new()
+        .rent_payer(rent_payer)
+        .owner(pool_owner)
+        .pool(pool)
+        .keeper(Some(keeper))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &keeper_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account is gone
+    let account = context.banks_client.get_account(pool).await.unwrap();
+    assert!(account.is_none());
+
+    // And the keeper got the bounty while the rent went back to the rent payer.
+    let rent_payer_balance_after = context.banks_client.get_balance(rent_payer).await.unwrap();
+    let owner_balance_after = context.banks_client.get_balance(pool_owner).await.unwrap();
+    let keeper_balance_after = context.banks_client.get_balance(keeper).await.unwrap();
+
+    assert_eq!(keeper_balance_after, keeper_balance_before + keeper_bounty);
+    assert_eq!(
+        rent_payer_balance_after,
+        rent_payer_balance_before + rent.minimum_balance(Pool::LEN)
+    );
+    assert_eq!(owner_balance_after, owner_balance_before);
+}
+
+#[tokio::test]
+async fn close_expired_pool_refunds_keeper_bounty_without_keeper() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let rent_payer_signer = Keypair::new();
+    let rent_payer = rent_payer_signer.pubkey();
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+
+    airdrop(&mut context, &rent_payer, 2 * ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // When a pool is created with a keeper bounty
+    let keeper_bounty = ONE_SOL_LAMPORTS / 10;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &rent_payer_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            expire_in_sec: Some(1),
+            keeper_bounty: Some(keeper_bounty),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // Warp ahead so the pool expires
+    context.warp_to_slot(2000).unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let rent_payer_balance_before = context.banks_client.get_balance(rent_payer).await.unwrap();
+    let owner_balance_before = context.banks_client.get_balance(pool_owner).await.unwrap();
+
+    // When the expired pool is closed without a keeper
+    let ix = CloseExpiredPoolBuilder::new()
+        .rent_payer(rent_payer)
+        .owner(pool_owner)
+        .pool(pool)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // No signers other than tx payer--permissionless close
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the unclaimed bounty goes back to the rent payer along with the rent.
+    let rent_payer_balance_after = context.banks_client.get_balance(rent_payer).await.unwrap();
+    let owner_balance_after = context.banks_client.get_balance(pool_owner).await.unwrap();
+
+    assert_eq!(
+        rent_payer_balance_after,
+        rent_payer_balance_before + rent.minimum_balance(Pool::LEN) + keeper_bounty
+    );
+    assert_eq!(owner_balance_after, owner_balance_before);
+}
+
+#[tokio::test]
+async fn upgrade_pool_converts_v1_pool() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+
+    airdrop(&mut context, &pool_owner, ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and pool.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // Pools on the current version have nothing to upgrade.
+    let ix = UpgradePoolBuilder::new()
+        .payer(pool_owner)
+        .pool(pool)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_error!(err, TensorAmmError::WrongPoolVersion as u32);
+
+    // Replace the pool with its version 1 layout.
+    let current = fetch_pool(&mut context, pool).await;
+    let v1 = PoolV1 {
+        version: 1,
+        bump: current.bump,
+        pool_id: current.pool_id,
+        created_at: current.created_at,
+        updated_at: current.updated_at,
+        expiry: current.expiry,
+        owner: current.owner,
+        whitelist: current.whitelist,
+        rent_payer: current.rent_payer,
+        currency: current.currency,
+        amount: current.amount,
+        price_offset: current.price_offset,
+        nfts_held: current.nfts_held,
+        stats: current.stats.clone(),
+        shared_escrow: current.shared_escrow,
+        cosigner: current.cosigner,
+        maker_broker: current.maker_broker,
+        max_taker_sell_count: current.max_taker_sell_count,
+        config: current.config.clone(),
+        reserved: [0; 100],
+    };
+
+    let mut data = current.discriminator.to_vec();
+    v1.serialize(&mut data).unwrap();
+    assert_eq!(data.len(), PoolV1::LEN);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    context.set_account(
+        &pool,
+        &Account {
+            lamports: rent.minimum_balance(PoolV1::LEN),
+            data,
+            owner: tensor_amm::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    // When anyone upgrades the pool
+    let ix = UpgradePoolBuilder::new()
+        .payer(pool_owner)
+        .pool(pool)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the pool was reallocated and converted to the current layout.
+    let account = context
+        .banks_client
+        .get_account(pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), Pool::LEN);
+    assert_eq!(account.lamports, rent.minimum_balance(Pool::LEN));
+
+    let pool_data = Pool::from_bytes(&account.data).unwrap();
+    assert_eq!(pool_data, v1.upgrade(current.discriminator));
+}
+
+#[tokio::test]
+async fn convert_pool_between_token_trade_and_nft_pools() {
+    // Set up program context with Tamm and WhiteList programs
+    let mut context = program_context().await;
+
+    // Set up signers and identity and fund them.
+    let pool_owner_signer = Keypair::new();
+    let pool_owner = pool_owner_signer.pubkey();
+
+    airdrop(&mut context, &pool_owner, 3 * ONE_SOL_LAMPORTS)
+        .await
+        .unwrap();
+
+    // Set up a basic whitelist and a funded Token pool.
+    let TestWhitelistV2 { whitelist, .. } = setup_default_whitelist(
+        &mut context,
+        TestWhitelistV2Inputs {
+            update_authority_signer: &pool_owner_signer,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let TestPool { pool, .. } = setup_default_pool(
+        &mut context,
+        TestPoolInputs {
+            payer: &pool_owner_signer,
+            owner: &pool_owner_signer,
+            whitelist,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let deposit_ix = DepositSolBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .lamports(ONE_SOL_LAMPORTS)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the owner converts the Token pool straight to an NFT pool
+    let ix = ConvertPoolBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .pool_type(PoolType::NFT)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the conversion isn't supported.
+    assert_custom_error!(err, TensorAmmError::WrongPoolType as u32);
+
+    // When the owner converts the Token pool to a Trade pool
+    let ix = ConvertPoolBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .pool_type(PoolType::Trade)
+        .mm_fee_bps(50)
+        .mm_compound_fees(true)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the pool keeps its funds with the new config.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.config.pool_type, PoolType::Trade);
+    assert_eq!(pool_data.config.mm_fee_bps, NullableU16::new(50));
+    assert!(pool_data.config.mm_compound_fees);
+    assert_eq!(pool_data.amount, ONE_SOL_LAMPORTS);
+
+    let owner_balance_before = context.banks_client.get_balance(pool_owner).await.unwrap();
+
+    // When the owner converts the Trade pool to an NFT pool
+    let ix = ConvertPoolBuilder::new()
+        .owner(pool_owner)
+        .pool(pool)
+        .pool_type(PoolType::NFT)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_owner_signer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the SOL of the pool was sent to the owner.
+    let pool_data = fetch_pool(&mut context, pool).await;
+    assert_eq!(pool_data.config.pool_type, PoolType::NFT);
+    assert_eq!(pool_data.config.mm_fee_bps, NullableU16::none());
+    assert_eq!(pool_data.amount, 0);
+
+    let owner_balance_after = context.banks_client.get_balance(pool_owner).await.unwrap();
+    assert_eq!(owner_balance_after, owner_balance_before + ONE_SOL_LAMPORTS);
+}
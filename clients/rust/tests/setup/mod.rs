@@ -21,6 +21,7 @@ pub const ONE_SOL_LAMPORTS: u64 = 1_000_000_000;
 pub const ONE_WEEK: u64 = 60 * 60 * 24 * 7;
 pub const ONE_YEAR: u64 = ONE_WEEK * 52;
 
+pub const ANCHOR_ERROR_CONSTRAINT_SEEDS: u32 = 2006;
 pub const ANCHOR_ERROR_ACCOUNT_NOT_SIGNER: u32 = 3010;
 
 #[macro_export]
@@ -176,6 +177,10 @@ pub struct TestPoolInputs<'a> {
     pub mm_compound_fees: Option<bool>,
     pub mm_fee_bps: NullableU16,
     pub expire_in_sec: Option<u64>,
+    pub decay_bps: Option<u16>,
+    pub floor_price: Option<u64>,
+    pub manager: Option<Pubkey>,
+    pub keeper_bounty: Option<u64>,
 }
 
 impl<'a> Default for TestPoolInputs<'a> {
@@ -192,6 +197,10 @@ impl<'a> Default for TestPoolInputs<'a> {
             mm_compound_fees: None,
             mm_fee_bps: NullableU16::none(),
             expire_in_sec: None,
+            decay_bps: None,
+            floor_price: None,
+            manager: None,
+            keeper_bounty: None,
         }
     }
 }
@@ -212,6 +221,10 @@ pub async fn setup_default_pool<'a>(
         mm_compound_fees,
         mm_fee_bps,
         expire_in_sec,
+        decay_bps,
+        floor_price,
+        manager,
+        keeper_bounty,
     } = inputs;
 
     let pool = Pool::find_pda(&owner.pubkey(), pool_id.to_bytes()).0;
@@ -231,13 +244,29 @@ pub async fn setup_default_pool<'a>(
         .pool(pool)
         .whitelist(whitelist)
         .pool_id(pool_id.to_bytes())
-        .currency(Pubkey::default())
+        .currency_mint(Pubkey::default())
         .config(config.clone());
 
     if let Some(expire_in_sec) = expire_in_sec {
         builder.expire_in_sec(expire_in_sec);
     }
 
+    if let Some(decay_bps) = decay_bps {
+        builder.decay_bps(decay_bps);
+    }
+
+    if let Some(floor_price) = floor_price {
+        builder.floor_price(floor_price);
+    }
+
+    if let Some(manager) = manager {
+        builder.manager(manager);
+    }
+
+    if let Some(keeper_bounty) = keeper_bounty {
+        builder.keeper_bounty(keeper_bounty);
+    }
+
     let ix = builder.instruction();
 
     // When we create a new account.
@@ -256,3 +285,8 @@ pub async fn setup_default_pool<'a>(
         config,
     }
 }
+
+pub async fn fetch_pool(context: &mut ProgramTestContext, pool: Pubkey) -> Pool {
+    let account = context.banks_client.get_account(pool).await.unwrap();
+    Pool::from_bytes(&account.unwrap().data).unwrap()
+}
@@ -6,7 +6,7 @@ use solana_program::pubkey;
 
 /// Current version of the pool account.
 #[constant]
-pub const CURRENT_POOL_VERSION: u8 = 1;
+pub const CURRENT_POOL_VERSION: u8 = 2;
 
 /// Maximum allowed MM fees in basis points.
 #[constant]
@@ -16,8 +16,31 @@ pub const MAX_MM_FEES_BPS: u16 = 7500; // 75%
 #[constant]
 pub const MAX_DELTA_BPS: u16 = 9999; // 99.99%
 
+/// Period in seconds over which the price of a GDA curve decays by its decay rate.
+#[constant]
+pub const GDA_DECAY_PERIOD_SEC: i64 = 3600; // 1 hour
+
+/// Maximum number of prices in the price ladder of a custom curve.
+pub const MAX_PRICE_LADDER_LEN: usize = 64;
+
+/// Number of nonces below the highest used one that cosigner attestations can still use.
+pub const COSIGNER_NONCE_WINDOW: u64 = 512;
+
+/// Maximum number of traits a pool can require.
+pub const MAX_TRAIT_REQUIREMENTS: usize = 8;
+
+/// Maximum length of the key and value of a required trait.
+pub const MAX_TRAIT_LEN: usize = 64;
+
 /// The pubkey of the Tensor Foundation Fees program.
 pub(crate) const TFEE_PROGRAM_ID: Pubkey = pubkey!("TFEEgwDP6nn1s8mMX2tTNPPz8j2VomkphLUmyxKm17A");
 
+/// The pubkey of the Wen New Standard (WNS) program.
+pub(crate) const WNS_PROGRAM_ID: Pubkey = pubkey!("wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM");
+
+/// The pubkey of the WNS royalty distribution program.
+pub(crate) const WNS_DISTRIBUTION_PROGRAM_ID: Pubkey =
+    pubkey!("diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay");
+
 /// The size of the discriminator for an Anchor account.
 pub(crate) const DISCRIMINATOR_SIZE: usize = 8;
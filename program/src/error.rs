@@ -90,4 +90,109 @@ pub enum ErrorCode {
 
     #[msg("Invalid edition")]
     InvalidEdition,
+
+    #[msg("Invalid currency")]
+    InvalidCurrency,
+
+    #[msg("Missing currency accounts")]
+    MissingCurrencyAccounts,
+
+    #[msg("Invalid currency token account")]
+    InvalidCurrencyTokenAccount,
+
+    #[msg("Invalid compressed NFT")]
+    InvalidCompressedNft,
+
+    #[msg("Invalid WNS accounts")]
+    InvalidWnsAccounts,
+
+    #[msg("Invalid batch accounts")]
+    InvalidBatchAccounts,
+
+    #[msg("Wrong NFT deposit receipt")]
+    WrongNftReceipt,
+
+    #[msg("Pool cannot fund the sale")]
+    InsufficientPoolFunds,
+
+    #[msg("operation not permitted on this curve type")]
+    WrongCurveType,
+
+    #[msg("XYK pools need both currency and NFTs in reserve")]
+    InsufficientReserves,
+
+    #[msg("invalid GDA decay rate or floor price")]
+    InvalidDecay,
+
+    #[msg("price ladder account required for custom curves")]
+    MissingPriceLadder,
+
+    #[msg("price ladder must be non-empty and non-decreasing")]
+    InvalidPriceLadder,
+
+    #[msg("price offset is outside of the price ladder")]
+    PriceLadderExhausted,
+
+    #[msg("Wrong manager")]
+    WrongManager,
+
+    #[msg("only the owner can make this change")]
+    ManagerNotAllowed,
+
+    #[msg("pool is paused for this side of the trade")]
+    PoolPaused,
+
+    #[msg("no cosigner attestation found for the asset")]
+    MissingCosignerAttestation,
+
+    #[msg("cosigner attestation has expired")]
+    ExpiredCosignerAttestation,
+
+    #[msg("cosigner attestation nonce was already used")]
+    StaleCosignerNonce,
+
+    #[msg("price modifier results in an invalid price")]
+    InvalidPriceModifier,
+
+    #[msg("trait requirements must be non-empty and fit the account")]
+    InvalidTraitRequirements,
+
+    #[msg("trait requirements account required for this pool")]
+    MissingTraitRequirements,
+
+    #[msg("asset does not have the traits required by the pool")]
+    TraitRequirementsNotMet,
+
+    #[msg("trait requirements must be cleared first")]
+    PoolHasTraitRequirements,
+
+    #[msg("max taker buy count exceeded, pool cannot sell anymore NFTs")]
+    MaxTakerBuyCountExceeded,
+
+    #[msg("max taker buy count is too small")]
+    MaxTakerBuyCountTooSmall,
+
+    #[msg("pool cannot hold anymore NFTs")]
+    MaxNftsHeldExceeded,
+
+    #[msg("max nfts held is lower than the NFTs held")]
+    MaxNftsHeldTooSmall,
+
+    #[msg("max total spend exceeded, pool cannot buy anymore NFTs")]
+    MaxTotalSpendExceeded,
+
+    #[msg("max total spend is lower than the amount already spent")]
+    MaxTotalSpendTooSmall,
+
+    #[msg("circuit breaker tripped, pool cannot trade until the window ends")]
+    CircuitBreakerTripped,
+
+    #[msg("invalid circuit breaker limits")]
+    InvalidCircuitBreaker,
+
+    #[msg("cannot convert pool with currency in escrow -- withdraw all before converting")]
+    ExistingFunds,
+
+    #[msg("cannot transfer pool holding nfts that are not migrated -- withdraw them before transferring")]
+    NftsNotTransferable,
 }
@@ -0,0 +1,97 @@
+//! Move an existing pool onto a shared escrow.
+use constants::CURRENT_POOL_VERSION;
+use escrow_program::state::MarginAccount;
+use tensor_escrow::instructions::{DepositMarginAccountCpi, DepositMarginAccountInstructionArgs};
+use tensor_toolbox::{escrow, transfer_lamports};
+
+use crate::{error::ErrorCode, program::AmmProgram, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct AttachSharedEscrow<'info> {
+    /// The owner of the pool and the shared escrow--must sign to attach the pool. The pool's
+    /// balance passes through the owner on its way to the shared escrow.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The pool to attach, which must not already be on a shared escrow.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+        constraint = pool.config.can_use_shared_escrow() @ ErrorCode::CannotUseSharedEscrow,
+        constraint = pool.shared_escrow == Pubkey::default() @ ErrorCode::PoolOnSharedEscrow,
+        // Shared escrow accounts only hold SOL.
+        constraint = pool.currency == Pubkey::default() @ ErrorCode::InvalidCurrency,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The shared escrow receiving the pool's SOL balance.
+    #[account(
+        mut,
+        has_one = owner @ ErrorCode::WrongOwner,
+    )]
+    pub shared_escrow: Box<Account<'info, MarginAccount>>,
+
+    /// The TSwap account of the escrow program.
+    /// CHECK: checked by the escrow program on deposit
+    pub tswap: UncheckedAccount<'info>,
+
+    /// The escrow program account, depositing into the shared escrow.
+    /// CHECK: address constraint is checked here
+    #[account(address = escrow::ID)]
+    pub escrow_program: UncheckedAccount<'info>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+}
+
+/// Moves the SOL balance of a Token or Trade pool into a shared escrow owned by the pool owner,
+/// which then provides the pool's liquidity. Stats and pricing are left untouched.
+pub fn process_attach_shared_escrow<'info>(
+    ctx: Context<'_, '_, '_, 'info, AttachSharedEscrow<'info>>,
+) -> Result<()> {
+    let amount = ctx.accounts.pool.amount;
+
+    if amount > 0 {
+        // The escrow program only takes deposits from the owner, so the balance is handed to
+        // the owner first.
+        transfer_lamports(
+            &ctx.accounts.pool.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            amount,
+        )?;
+
+        DepositMarginAccountCpi {
+            __program: &ctx.accounts.escrow_program.to_account_info(),
+            tswap: &ctx.accounts.tswap.to_account_info(),
+            margin_account: &ctx.accounts.shared_escrow.to_account_info(),
+            owner: &ctx.accounts.owner.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            __args: DepositMarginAccountInstructionArgs { lamports: amount },
+        }
+        .invoke()?;
+    }
+
+    // Shared escrow pools don't hold a balance of their own.
+    let pool = &mut ctx.accounts.pool;
+    pool.amount = 0;
+    pool.shared_escrow = ctx.accounts.shared_escrow.key();
+
+    record_event(
+        TAmmEvent::AttachSharedEscrowEvent(AttachSharedEscrowEvent {
+            shared_escrow: ctx.accounts.shared_escrow.key(),
+            amount,
+        }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
+    )
+}
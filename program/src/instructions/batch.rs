@@ -0,0 +1,185 @@
+//! Helpers of the instructions trading or moving several NFTs at once, which pass the accounts
+//! of each NFT in remaining accounts.
+
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Allocate, Assign, CreateAccount, Transfer},
+};
+use anchor_spl::associated_token::{self, Create};
+use tensor_vipers::throw_err;
+
+use crate::{error::ErrorCode, *};
+
+/// Batch instructions follow Anchor's convention for optional accounts in remaining accounts:
+/// passing the program id means the account is not set.
+pub(crate) fn optional_account<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+) -> Option<&'a AccountInfo<'info>> {
+    (*account.key != crate::ID).then_some(account)
+}
+
+/// Splits the `len` accounts of the next NFT of a batch instruction off the remaining accounts.
+pub(crate) fn split_batch_item<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    len: usize,
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    if accounts.len() < len {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    Ok(accounts.split_at(len))
+}
+
+/// Checks that the deposit receipt of an NFT of a batch instruction ties it to the pool.
+pub(crate) fn check_nft_receipt<'info>(
+    receipt_info: &'info AccountInfo<'info>,
+    nft: &Pubkey,
+    pool: &Pubkey,
+) -> Result<()> {
+    let receipt = Account::<NftDepositReceipt>::try_from(receipt_info)?;
+
+    if receipt.mint != *nft || receipt.pool != *pool {
+        throw_err!(ErrorCode::WrongNftReceipt);
+    }
+
+    Ok(())
+}
+
+/// Accounts used to create the token accounts of NFTs traded by a batch instruction, which are
+/// passed in remaining accounts and cannot be initialized by Anchor.
+pub(crate) struct BatchTokenAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> BatchTokenAccounts<'info> {
+    /// Creates the associated token account of `authority` for `mint` if it doesn't exist yet;
+    /// the associated token program checks its address.
+    pub(crate) fn init_ata(
+        &self,
+        ata: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+    ) -> Result<()> {
+        associated_token::create_idempotent(CpiContext::new(
+            self.associated_token_program.clone(),
+            Create {
+                payer: self.payer.clone(),
+                associated_token: ata.clone(),
+                authority: authority.clone(),
+                mint: mint.clone(),
+                system_program: self.system_program.clone(),
+                token_program: self.token_program.clone(),
+            },
+        ))
+    }
+}
+
+impl<'info> TradeShared<'info> {
+    /// Number of accounts following an NFT of a batch instruction to pay its royalties: the
+    /// creator wallets for SOL pools, and each creator wallet followed by its currency token
+    /// account for SPL pools.
+    pub(crate) fn batch_creator_accounts_len(&self, asset: &AmmAsset) -> usize {
+        let creators_len = asset.royalty_creators.as_ref().map_or(0, |c| c.len());
+
+        if self.pool.currency == Pubkey::default() {
+            creators_len
+        } else {
+            creators_len * 2
+        }
+    }
+}
+
+/// Creates the NFT deposit receipt of an NFT sold into a Trade pool by a batch instruction,
+/// where the receipt is passed in remaining accounts and cannot be initialized by Anchor.
+pub(crate) fn init_nft_receipt<'info>(
+    receipt_info: &AccountInfo<'info>,
+    asset: Pubkey,
+    pool: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (receipt_key, bump) = Pubkey::find_program_address(
+        &[b"nft_receipt".as_ref(), asset.as_ref(), pool.as_ref()],
+        &crate::ID,
+    );
+
+    if receipt_key != *receipt_info.key {
+        throw_err!(ErrorCode::WrongNftReceipt);
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"nft_receipt".as_ref(),
+        asset.as_ref(),
+        pool.as_ref(),
+        &[bump],
+    ]];
+    let space = NftDepositReceipt::SIZE as u64;
+    let rent = Rent::get()?.minimum_balance(NftDepositReceipt::SIZE);
+    let current_lamports = receipt_info.lamports();
+
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: receipt_info.clone(),
+                },
+            )
+            .with_signer(signer_seeds),
+            rent,
+            space,
+            &crate::ID,
+        )?;
+    } else {
+        // Someone sent lamports to the receipt address, so it cannot be created in one go.
+        let top_up = rent.saturating_sub(current_lamports);
+
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: receipt_info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        system_program::allocate(
+            CpiContext::new(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: receipt_info.clone(),
+                },
+            )
+            .with_signer(signer_seeds),
+            space,
+        )?;
+
+        system_program::assign(
+            CpiContext::new(
+                system_program.clone(),
+                Assign {
+                    account_to_assign: receipt_info.clone(),
+                },
+            )
+            .with_signer(signer_seeds),
+            &crate::ID,
+        )?;
+    }
+
+    let receipt = NftDepositReceipt {
+        bump,
+        mint: asset,
+        pool,
+    };
+
+    let mut data = receipt_info.try_borrow_mut_data()?;
+    receipt.try_serialize(&mut data.as_mut())
+}
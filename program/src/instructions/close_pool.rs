@@ -1,7 +1,8 @@
 //! Close a pool if it has no NFTs and is not attached to a shared escrow.
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use constants::CURRENT_POOL_VERSION;
 
-use crate::{error::ErrorCode, *};
+use crate::{error::ErrorCode, program::AmmProgram, *};
 
 /// Instruction accounts.
 #[derive(Accounts)]
@@ -32,6 +33,52 @@ pub struct ClosePool<'info> {
 
     /// The Solana system program.
     pub system_program: Program<'info, System>,
+
+    /// The SPL mint the pool is priced in; required to close SPL currency pools.
+    #[account(
+        address = pool.currency @ ErrorCode::InvalidCurrency,
+        mint::token_program = currency_token_program,
+    )]
+    pub currency: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// The pool's currency token account, closed along with the pool.
+    #[account(
+        mut,
+        associated_token::mint = currency,
+        associated_token::authority = pool,
+        associated_token::token_program = currency_token_program,
+    )]
+    pub pool_currency_ta: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The owner's currency token account, receiving any funds left in the pool.
+    /// CHECK: address is checked when funds are sent to it.
+    #[account(mut)]
+    pub owner_currency_ta: Option<UncheckedAccount<'info>>,
+
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// The price ladder of the pool, closed along with the pool; required for custom curves.
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"price_ladder", pool.key().as_ref()],
+        bump = price_ladder.bump[0],
+    )]
+    pub price_ladder: Option<Box<Account<'info, PriceLadder>>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+
+    /// The trait requirements of the pool, closed along with the pool; required if the pool
+    /// has trait requirements.
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"trait_requirements", pool.key().as_ref()],
+        bump = trait_requirements.bump[0],
+    )]
+    pub trait_requirements: Option<Box<Account<'info, TraitRequirements>>>,
 }
 
 /// Allows the owner to close a pool if it has no NFTs.
@@ -41,5 +88,38 @@ pub fn process_close_pool<'info>(ctx: Context<'_, '_, '_, 'info, ClosePool<'info
     let pool = &ctx.accounts.pool;
     let rent_payer_info = ctx.accounts.rent_payer.to_account_info();
 
+    close_currency_ta(
+        pool,
+        ctx.accounts.currency.as_deref(),
+        ctx.accounts.pool_currency_ta.as_deref(),
+        ctx.accounts.owner_currency_ta.as_ref(),
+        ctx.accounts.currency_token_program.as_ref(),
+        ctx.accounts.system_program.to_account_info(),
+        rent_payer_info.clone(),
+    )?;
+
+    // Don't leave the price ladder of custom curves behind.
+    if pool.config.curve_type == CurveType::Custom {
+        require!(
+            ctx.accounts.price_ladder.is_some(),
+            ErrorCode::MissingPriceLadder
+        );
+    }
+
+    if pool.requires_traits {
+        require!(
+            ctx.accounts.trait_requirements.is_some(),
+            ErrorCode::MissingTraitRequirements
+        );
+    }
+
+    record_event(
+        TAmmEvent::ClosePoolEvent(ClosePoolEvent {
+            reason: ClosePoolReason::Owner,
+        }),
+        &ctx.accounts.amm_program,
+        pool,
+    )?;
+
     state::pool::close_pool(pool, rent_payer_info, ctx.accounts.owner.to_account_info())
 }
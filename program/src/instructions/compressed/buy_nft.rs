@@ -0,0 +1,101 @@
+//! Buy a compressed NFT from a NFT or Trade pool.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+#[instruction(args: CompressedArgs)]
+pub struct BuyNftCompressed<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// Bubblegum shared accounts.
+    pub compressed: CompressedShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    #[account(
+        mut,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            get_asset_id(&compressed.merkle_tree.key(), args.nonce).as_ref(),
+            trade.pool.key().as_ref(),
+        ],
+        bump = nft_receipt.bump,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyNftCompressed<'info> {
+    fn pre_process_checks(&self, args: &CompressedArgs) -> Result<AmmAsset> {
+        self.trade.validate_buy()?;
+
+        CompressedAsset {
+            merkle_tree: self.compressed.merkle_tree.key(),
+            args,
+        }
+        .validate_asset()
+    }
+}
+
+/// Buy a compressed NFT from a NFT or Trade pool.
+///
+/// Remaining accounts are the creator accounts, in the order of the metadata creators,
+/// followed by the proof nodes of the merkle tree.
+pub fn process_buy_nft_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNftCompressed<'info>>,
+    args: CompressedArgs,
+    // Max vs exact so we can add slippage later.
+    max_amount: u64,
+    optional_royalty_pct: Option<u16>,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks(&args)?;
+    let asset_id = asset.pubkey;
+    let (creator_accounts, proof_accounts) =
+        split_remaining_accounts(&args, ctx.remaining_accounts)?;
+
+    let fees = ctx.accounts.trade.calculate_fees(
+        &asset,
+        max_amount,
+        TakerSide::Buy,
+        optional_royalty_pct,
+    )?;
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.trade.pool.seeds()];
+
+    // Transfer the NFT from the pool to the buyer.
+    ctx.accounts.compressed.transfer(
+        &args,
+        &ctx.accounts.trade.pool.to_account_info(),
+        &ctx.accounts.trade.taker.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        proof_accounts,
+        Some(signer_seeds),
+    )?;
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_buyer_fees(asset, fees, creator_accounts)?;
+
+    // Close the NFT receipt account.
+    close_account(
+        &mut ctx.accounts.nft_receipt.to_account_info(),
+        &mut ctx.accounts.trade.owner.to_account_info(),
+    )?;
+
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Buy, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Buy, fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
@@ -0,0 +1,89 @@
+//! Deposit a compressed NFT into a NFT or Trade pool.
+
+use super::*;
+
+/// Instruction accounts
+#[derive(Accounts)]
+#[instruction(args: CompressedArgs)]
+pub struct DepositNftCompressed<'info> {
+    /// Transfer shared accounts.
+    pub transfer: TransferShared<'info>,
+
+    /// Bubblegum shared accounts.
+    pub compressed: CompressedShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    /// Compressed NFTs are identified by their asset id.
+    #[account(
+        init,
+        payer = transfer.owner,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            get_asset_id(&compressed.merkle_tree.key(), args.nonce).as_ref(),
+            transfer.pool.key().as_ref(),
+        ],
+        bump,
+        space = NftDepositReceipt::SIZE,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositNftCompressed<'info> {
+    fn pre_process_checks(&self, args: &CompressedArgs) -> Result<AmmAsset> {
+        if self.transfer.pool.expiry < Clock::get()?.unix_timestamp {
+            throw_err!(ErrorCode::ExpiredPool);
+        }
+
+        self.transfer.pool.can_hold_more_nfts()?;
+
+        let asset = CompressedAsset {
+            merkle_tree: self.compressed.merkle_tree.key(),
+            args,
+        }
+        .validate_asset()?;
+
+        self.transfer.verify_whitelist(&asset)?;
+
+        Ok(asset)
+    }
+}
+
+/// Deposit a compressed NFT into a NFT or Trade pool.
+pub fn process_deposit_nft_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositNftCompressed<'info>>,
+    args: CompressedArgs,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks(&args)?;
+
+    // transfer the NFT, all remaining accounts are proof nodes
+    ctx.accounts.compressed.transfer(
+        &args,
+        &ctx.accounts.transfer.owner.to_account_info(),
+        &ctx.accounts.transfer.pool.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        None,
+    )?;
+
+    //update pool
+    let pool = &mut ctx.accounts.transfer.pool;
+    pool.nfts_held = unwrap_int!(pool.nfts_held.checked_add(1));
+
+    //create nft receipt
+    **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
+        bump: ctx.bumps.nft_receipt,
+        mint: asset.pubkey,
+        pool: ctx.accounts.transfer.pool.key(),
+    };
+
+    record_event(
+        TAmmEvent::DepositNftEvent(DepositNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )
+}
@@ -0,0 +1,97 @@
+pub mod buy_nft;
+pub mod deposit_nft;
+pub mod sell_nft_token_pool;
+pub mod sell_nft_trade_pool;
+pub mod withdraw_expired_nft;
+pub mod withdraw_nft;
+
+pub use self::buy_nft::*;
+pub use self::deposit_nft::*;
+pub use self::sell_nft_token_pool::*;
+pub use self::sell_nft_trade_pool::*;
+pub use self::withdraw_expired_nft::*;
+pub use self::withdraw_nft::*;
+
+use crate::{error::ErrorCode, CompressedShared, NftDepositReceipt, *};
+
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{
+    hash::{hash_creators, hash_metadata},
+    instructions::TransferCpiBuilder,
+    types::MetadataArgs,
+    utils::get_asset_id,
+};
+use tensor_toolbox::close_account;
+use tensor_vipers::{throw_err, unwrap_int};
+
+/// Describes the leaf of a compressed NFT in its merkle tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct CompressedArgs {
+    /// The current root of the merkle tree.
+    pub root: [u8; 32],
+    /// The nonce of the leaf, used to derive the asset id.
+    pub nonce: u64,
+    /// The index of the leaf in the merkle tree.
+    pub index: u32,
+    /// The metadata the leaf was minted with.
+    pub metadata: MetadataArgsLocal,
+}
+
+/// Splits the remaining accounts into the creator accounts, used to pay royalties,
+/// and the proof nodes of the merkle tree.
+pub fn split_remaining_accounts<'a, 'info>(
+    args: &CompressedArgs,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    let creators_len = args.metadata.creators.len();
+
+    if remaining_accounts.len() < creators_len {
+        throw_err!(ErrorCode::InvalidCompressedNft);
+    }
+
+    Ok(remaining_accounts.split_at(creators_len))
+}
+
+impl<'info> CompressedShared<'info> {
+    /// Transfers a compressed NFT through Bubblegum, which verifies the leaf and its proof.
+    ///
+    /// The current owner is also passed as the leaf delegate, so delegated compressed NFTs
+    /// must be undelegated before being traded.
+    pub fn transfer(
+        &self,
+        args: &CompressedArgs,
+        leaf_owner: &AccountInfo<'info>,
+        new_leaf_owner: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        proof_accounts: &[AccountInfo<'info>],
+        signer_seeds: Option<&[&[&[u8]]]>,
+    ) -> Result<()> {
+        let metadata = MetadataArgs::from(args.metadata.clone());
+        let data_hash = hash_metadata(&metadata).map_err(|_| ErrorCode::InvalidCompressedNft)?;
+        let creator_hash = hash_creators(&metadata.creators);
+
+        let proof = proof_accounts
+            .iter()
+            .map(|node| (node, false, false))
+            .collect::<Vec<_>>();
+
+        TransferCpiBuilder::new(&self.bubblegum_program)
+            .tree_config(&self.tree_authority)
+            .leaf_owner(leaf_owner, true)
+            .leaf_delegate(leaf_owner, false)
+            .new_leaf_owner(new_leaf_owner)
+            .merkle_tree(&self.merkle_tree)
+            .log_wrapper(&self.log_wrapper)
+            .compression_program(&self.compression_program)
+            .system_program(system_program)
+            .root(args.root)
+            .data_hash(data_hash)
+            .creator_hash(creator_hash)
+            .nonce(args.nonce)
+            .index(args.index)
+            .add_remaining_accounts(&proof)
+            .invoke_signed(signer_seeds.unwrap_or_default())?;
+
+        Ok(())
+    }
+}
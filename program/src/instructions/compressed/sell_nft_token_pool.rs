@@ -0,0 +1,85 @@
+//! Sell a compressed NFT into a Token pool.
+//!
+//! This is separated from Trade pool since the owner will receive the NFT directly.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SellNftTokenPoolCompressed<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// Bubblegum shared accounts.
+    pub compressed: CompressedShared<'info>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SellNftTokenPoolCompressed<'info> {
+    fn pre_process_checks(&self, args: &CompressedArgs) -> Result<AmmAsset> {
+        self.trade.validate_sell(&PoolType::Token)?;
+
+        let asset = CompressedAsset {
+            merkle_tree: self.compressed.merkle_tree.key(),
+            args,
+        }
+        .validate_asset()?;
+
+        self.trade.verify_whitelist(&asset)?;
+
+        Ok(asset)
+    }
+}
+
+/// Sell a compressed NFT into a Token pool.
+///
+/// Remaining accounts are the creator accounts, in the order of the metadata creators,
+/// followed by the proof nodes of the merkle tree.
+pub fn process_sell_nft_token_pool_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftTokenPoolCompressed<'info>>,
+    args: CompressedArgs,
+    min_price: u64,
+    optional_royalty_pct: Option<u16>,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks(&args)?;
+    let asset_id = asset.pubkey;
+    let (creator_accounts, proof_accounts) =
+        split_remaining_accounts(&args, ctx.remaining_accounts)?;
+
+    let fees = ctx.accounts.trade.calculate_fees(
+        &asset,
+        min_price,
+        TakerSide::Sell,
+        optional_royalty_pct,
+    )?;
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+
+    // Transfer the NFT from the seller directly to the pool owner.
+    ctx.accounts.compressed.transfer(
+        &args,
+        &ctx.accounts.trade.taker.to_account_info(),
+        &ctx.accounts.trade.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        proof_accounts,
+        None,
+    )?;
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_seller_fees(asset, fees, creator_accounts)?;
+
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
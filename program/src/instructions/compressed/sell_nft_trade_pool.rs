@@ -0,0 +1,111 @@
+//! Sell a compressed NFT into a two-sided ("Trade") pool, where the pool
+//! is the buyer and ends up as the owner of the NFT.
+//!
+//! The seller is the owner of the NFT and receives the pool's current price in return.
+//! This is separated from Token pool since the NFT will be transferred to the pool and
+//! a deposit receipt is created for it.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+#[instruction(args: CompressedArgs)]
+pub struct SellNftTradePoolCompressed<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// Bubblegum shared accounts.
+    pub compressed: CompressedShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    #[account(
+        init,
+        payer = trade.taker,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            get_asset_id(&compressed.merkle_tree.key(), args.nonce).as_ref(),
+            trade.pool.key().as_ref(),
+        ],
+        bump,
+        space = NftDepositReceipt::SIZE,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SellNftTradePoolCompressed<'info> {
+    fn pre_process_checks(&self, args: &CompressedArgs) -> Result<AmmAsset> {
+        self.trade.validate_sell(&PoolType::Trade)?;
+
+        let asset = CompressedAsset {
+            merkle_tree: self.compressed.merkle_tree.key(),
+            args,
+        }
+        .validate_asset()?;
+
+        self.trade.verify_whitelist(&asset)?;
+
+        Ok(asset)
+    }
+}
+
+/// Sell a compressed NFT into a Trade pool.
+///
+/// Remaining accounts are the creator accounts, in the order of the metadata creators,
+/// followed by the proof nodes of the merkle tree.
+pub fn process_sell_nft_trade_pool_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftTradePoolCompressed<'info>>,
+    args: CompressedArgs,
+    // Min vs exact so we can add slippage later.
+    min_price: u64,
+    optional_royalty_pct: Option<u16>,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks(&args)?;
+    let (creator_accounts, proof_accounts) =
+        split_remaining_accounts(&args, ctx.remaining_accounts)?;
+    let asset_id = asset.pubkey;
+
+    let fees = ctx.accounts.trade.calculate_fees(
+        &asset,
+        min_price,
+        TakerSide::Sell,
+        optional_royalty_pct,
+    )?;
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+
+    // Transfer the NFT from the seller to the pool.
+    ctx.accounts.compressed.transfer(
+        &args,
+        &ctx.accounts.trade.taker.to_account_info(),
+        &ctx.accounts.trade.pool.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        proof_accounts,
+        None,
+    )?;
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_seller_fees(asset, fees, creator_accounts)?;
+
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
+
+    //create nft receipt for trade pool
+    **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
+        bump: ctx.bumps.nft_receipt,
+        mint: asset_id,
+        pool: ctx.accounts.trade.pool.key(),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Withdraw a compressed NFT from a NFT or Trade pool.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+#[instruction(args: CompressedArgs)]
+pub struct WithdrawNftCompressed<'info> {
+    /// Transfer shared accounts.
+    pub transfer: TransferShared<'info>,
+
+    /// Bubblegum shared accounts.
+    pub compressed: CompressedShared<'info>,
+
+    /// The NFT receipt account denoting that an NFT has been deposited into this pool.
+    #[account(
+        mut,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            get_asset_id(&compressed.merkle_tree.key(), args.nonce).as_ref(),
+            transfer.pool.key().as_ref(),
+        ],
+        bump = nft_receipt.bump,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawNftCompressed<'info> {
+    fn pre_process_checks(&self, args: &CompressedArgs) -> Result<AmmAsset> {
+        CompressedAsset {
+            merkle_tree: self.compressed.merkle_tree.key(),
+            args,
+        }
+        .validate_asset()
+    }
+}
+
+/// Withdraw a compressed NFT from a NFT or Trade pool.
+pub fn process_withdraw_nft_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawNftCompressed<'info>>,
+    args: CompressedArgs,
+) -> Result<()> {
+    ctx.accounts.pre_process_checks(&args)?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.transfer.pool.seeds()];
+
+    // transfer the NFT, all remaining accounts are proof nodes
+    ctx.accounts.compressed.transfer(
+        &args,
+        &ctx.accounts.transfer.pool.to_account_info(),
+        &ctx.accounts.transfer.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        Some(signer_seeds),
+    )?;
+
+    //update pool
+    let pool = &mut ctx.accounts.transfer.pool;
+    pool.nfts_held = unwrap_int!(pool.nfts_held.checked_sub(1));
+
+    record_event(
+        TAmmEvent::WithdrawNftEvent(WithdrawNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )?;
+
+    // Close the NFT receipt account.
+    close_account(
+        &mut ctx.accounts.nft_receipt.to_account_info(),
+        &mut ctx.accounts.transfer.owner.to_account_info(),
+    )
+}
@@ -0,0 +1,116 @@
+//! Convert a pool to another pool type in place.
+use constants::CURRENT_POOL_VERSION;
+use tensor_toolbox::transfer_lamports;
+use tensor_vipers::throw_err;
+
+use crate::{error::ErrorCode, program::AmmProgram, *};
+
+/// Convert pool arguments.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct ConvertPoolArgs {
+    /// The pool type to convert to: Token and NFT pools convert to Trade pools and back.
+    pub pool_type: PoolType,
+    /// MM fee of the converted pool; only allowed on Trade pools.
+    pub mm_fee_bps: Option<u16>,
+    /// Whether MM fees are compounded into the converted pool; only used by Trade pools.
+    pub mm_compound_fees: Option<bool>,
+}
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct ConvertPool<'info> {
+    /// The owner of the pool--must sign to convert the pool, and receives the SOL of Trade pools
+    /// converted to NFT pools.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The pool to convert.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+        constraint = pool.expiry >= Clock::get()?.unix_timestamp @ ErrorCode::ExpiredPool,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+}
+
+impl<'info> ConvertPool<'info> {
+    /// Checks the pool can be converted to `pool_type`, returning the converted config.
+    fn validate(&self, args: &ConvertPoolArgs) -> Result<PoolConfig> {
+        let pool = &self.pool;
+
+        match (pool.config.pool_type, args.pool_type) {
+            (PoolType::Token, PoolType::Trade) | (PoolType::NFT, PoolType::Trade) => {}
+            // Token pools can't hold NFTs, which would be left with their receipts.
+            (PoolType::Trade, PoolType::Token) => {
+                require!(pool.nfts_held == 0, ErrorCode::ExistingNfts);
+            }
+            (PoolType::Trade, PoolType::NFT) => {
+                require!(
+                    pool.shared_escrow == Pubkey::default(),
+                    ErrorCode::CannotUseSharedEscrow
+                );
+                // SPL currency has to be withdrawn first, SOL is sent to the owner.
+                require!(
+                    pool.currency == Pubkey::default() || pool.amount == 0,
+                    ErrorCode::ExistingFunds
+                );
+            }
+            _ => {
+                throw_err!(ErrorCode::WrongPoolType);
+            }
+        }
+
+        let new_config = PoolConfig {
+            pool_type: args.pool_type,
+            mm_fee_bps: args.mm_fee_bps.unwrap_or(0),
+            mm_compound_fees: args.mm_compound_fees.unwrap_or(false),
+            ..pool.config
+        };
+
+        new_config.validate()?;
+
+        Ok(new_config)
+    }
+}
+
+/// Converts a Token or NFT pool to a Trade pool, or a Trade pool back to a Token or NFT pool,
+/// keeping its stats and price offset.
+pub fn process_convert_pool<'info>(
+    ctx: Context<'_, '_, '_, 'info, ConvertPool<'info>>,
+    args: ConvertPoolArgs,
+) -> Result<()> {
+    let new_config = ctx.accounts.validate(&args)?;
+
+    // NFT pools send sale proceeds straight to the owner, so they don't hold SOL.
+    if new_config.pool_type == PoolType::NFT && ctx.accounts.pool.amount > 0 {
+        transfer_lamports(
+            &ctx.accounts.pool.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            ctx.accounts.pool.amount,
+        )?;
+        ctx.accounts.pool.amount = 0;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.config = new_config;
+    pool.validate_decay()?;
+
+    record_event(
+        TAmmEvent::EditPoolEvent(EditPoolEvent {
+            config: pool.config,
+            expiry: pool.expiry,
+            price_offset: pool.price_offset,
+        }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
+    )
+}
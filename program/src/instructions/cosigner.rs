@@ -0,0 +1,101 @@
+//! Verification of cosigner attestations signed off-chain.
+//!
+//! Instead of cosigning the trade transaction, a pool cosigner can sign a `CosignerAttestation`
+//! that the taker verifies with an Ed25519 program instruction placed before the trade. The
+//! trade then reads the verified message back through the instructions sysvar.
+use anchor_lang::solana_program::{
+    ed25519_program,
+    pubkey::PUBKEY_BYTES,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use tensor_vipers::throw_err;
+
+use crate::{error::ErrorCode, *};
+
+/// Offset of the first signature offsets entry: signature count and padding come first.
+const SIGNATURE_OFFSETS_START: usize = 2;
+/// Size of a signature offsets entry: seven u16 values.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+/// Instruction index the Ed25519 program uses to refer to its own instruction data.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Finds the attestation signed by `cosigner` approving `taker` to trade `asset` on `taker_side`
+/// of `pool` among the Ed25519 program instructions preceding the current instruction.
+pub fn find_cosigner_attestation(
+    instructions_sysvar: &AccountInfo,
+    cosigner: &Pubkey,
+    pool: &Pubkey,
+    asset: &Pubkey,
+    taker: &Pubkey,
+    taker_side: TakerSide,
+) -> Result<CosignerAttestation> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        for (signer, message) in ed25519_signed_messages(&instruction.data) {
+            if signer != cosigner.as_ref() {
+                continue;
+            }
+
+            if let Some(attestation) = CosignerAttestation::from_message(message) {
+                if attestation.pool == *pool
+                    && attestation.asset == *asset
+                    && attestation.taker == *taker
+                    && attestation.taker_side == taker_side
+                {
+                    return Ok(attestation);
+                }
+            }
+        }
+    }
+
+    throw_err!(ErrorCode::MissingCosignerAttestation);
+}
+
+/// Returns the public key and message of each signature verified by an Ed25519 program
+/// instruction. Entries pointing at data of other instructions are skipped, so only data the
+/// instruction itself carries is trusted.
+fn ed25519_signed_messages(data: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let count = data.first().copied().unwrap_or(0) as usize;
+    let mut messages = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+
+        let offsets = match data.get(start..start + SIGNATURE_OFFSETS_SIZE) {
+            Some(offsets) => offsets,
+            None => break,
+        };
+
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        // [signature_offset, signature_instruction_index, public_key_offset,
+        //  public_key_instruction_index, message_data_offset, message_data_size,
+        //  message_instruction_index]
+        if read_u16(2) != CURRENT_INSTRUCTION
+            || read_u16(6) != CURRENT_INSTRUCTION
+            || read_u16(12) != CURRENT_INSTRUCTION
+        {
+            continue;
+        }
+
+        let public_key_offset = read_u16(4) as usize;
+        let message_offset = read_u16(8) as usize;
+        let message_size = read_u16(10) as usize;
+
+        if let (Some(public_key), Some(message)) = (
+            data.get(public_key_offset..public_key_offset + PUBKEY_BYTES),
+            data.get(message_offset..message_offset + message_size),
+        ) {
+            messages.push((public_key, message));
+        }
+    }
+
+    messages
+}
@@ -1,29 +1,59 @@
 //! Create a new pool.
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 use escrow_program::state::MarginAccount;
-use tensor_vipers::throw_err;
+use tensor_vipers::{throw_err, unwrap_opt};
 use whitelist_program::{self, WhitelistV2};
 
 use crate::{
     constants::CURRENT_POOL_VERSION,
     error::ErrorCode,
-    state::{Pool, PoolConfig},
-    PoolStats, PoolType, MAX_EXPIRY_SEC,
+    program::AmmProgram,
+    state::{
+        CircuitBreaker, CircuitBreakerArgs, CreatePoolEvent, Pool, PoolConfig, PriceLadder,
+        PriceLadderArgs, TAmmEvent,
+    },
+    CurveType, PoolStats, PoolType, MAX_EXPIRY_SEC,
 };
 
-use super::assert_expiry;
+use super::{assert_expiry, record_event};
 
 /// Create pool arguments.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
 pub struct CreatePoolArgs {
     pub pool_id: [u8; 32],
     pub config: PoolConfig,
-    // Here to support future SPL mints, contract enforces this is the native mint currently
+    /// SPL mint the pool is priced in; `None` (or the default pubkey) for SOL.
     pub currency: Option<Pubkey>,
     pub cosigner: Option<Pubkey>,
     pub maker_broker: Option<Pubkey>,
     pub max_taker_sell_count: Option<u32>,
     pub expire_in_sec: Option<u64>,
+    /// Decay rate of GDA curves, in basis points per decay period.
+    pub decay_bps: Option<u16>,
+    /// Price GDA curves stop decaying at.
+    pub floor_price: Option<u64>,
+    /// Prices of custom curves.
+    pub price_ladder: Option<PriceLadderArgs>,
+    /// Key allowed to edit the pricing and expiry of the pool on behalf of the owner.
+    pub manager: Option<Pubkey>,
+    /// Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it.
+    pub max_taker_buy_count: Option<u32>,
+    /// Limit how many NFTs the pool can hold through sells and deposits.
+    pub max_nfts_held: Option<u32>,
+    /// Limit how much the pool can pay for NFTs sold into it over its lifetime.
+    pub max_total_spend: Option<u64>,
+    /// Limits on the trades the pool can make within a time window.
+    pub circuit_breaker: Option<CircuitBreakerArgs>,
+    /// Lamports paid by the rent payer and escrowed in the pool, paid to keepers cleaning up
+    /// the pool once it expires.
+    pub keeper_bounty: Option<u64>,
 }
 
 /// Instruction accounts.
@@ -68,16 +98,78 @@ pub struct CreatePool<'info> {
 
     /// The Solana system program.
     pub system_program: Program<'info, System>,
+
+    /// The SPL mint the pool is priced in; required if `args.currency` is set.
+    #[account(mint::token_program = currency_token_program)]
+    pub currency: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// The pool's currency token account, which escrows the pool's funds for SPL currency pools.
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        associated_token::mint = currency,
+        associated_token::authority = pool,
+        associated_token::token_program = currency_token_program,
+    )]
+    pub pool_currency_ta: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// The SPL associated token program.
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[account(
+        init,
+        payer = rent_payer,
+        space = PriceLadder::SIZE,
+        seeds = [b"price_ladder", pool.key().as_ref()],
+        bump,
+    )]
+    pub price_ladder: Option<Box<Account<'info, PriceLadder>>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
 }
 
 impl<'info> CreatePool<'info> {
-    fn validate(&self, config: PoolConfig) -> Result<()> {
-        config.validate()
+    fn validate(&self, args: &CreatePoolArgs) -> Result<()> {
+        args.config.validate()?;
+
+        if !args.config.can_use_shared_escrow() {
+            require!(self.shared_escrow.is_none(), ErrorCode::PoolOnSharedEscrow);
+        }
+
+        // Custom curves are priced from their price ladder, which is created with the pool.
+        if args.config.curve_type == CurveType::Custom || args.price_ladder.is_some() {
+            require!(
+                args.price_ladder.is_some() && self.price_ladder.is_some(),
+                ErrorCode::MissingPriceLadder
+            );
+        }
+
+        let currency = args.currency.unwrap_or_default();
+
+        if currency != Pubkey::default() {
+            let mint = unwrap_opt!(self.currency.as_ref(), ErrorCode::MissingCurrencyAccounts);
+            require!(mint.key() == currency, ErrorCode::InvalidCurrency);
+
+            require!(
+                self.pool_currency_ta.is_some(),
+                ErrorCode::MissingCurrencyAccounts
+            );
+
+            // Shared escrow accounts only hold SOL.
+            require!(self.shared_escrow.is_none(), ErrorCode::InvalidCurrency);
+        }
+
+        Ok(())
     }
 }
 
 /// Create a new pool.
-#[access_control(ctx.accounts.validate(args.config))]
+#[access_control(ctx.accounts.validate(&args))]
 pub fn process_create_pool(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Result<()> {
     if args.config.starting_price < 1 {
         throw_err!(ErrorCode::StartingPriceTooSmall);
@@ -87,6 +179,11 @@ pub fn process_create_pool(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Re
 
     let expiry = assert_expiry(args.expire_in_sec.unwrap_or(MAX_EXPIRY_SEC as u64))?;
 
+    let mut circuit_breaker = CircuitBreaker::default();
+    if let Some(circuit_breaker_args) = args.circuit_breaker {
+        circuit_breaker.set_limits(circuit_breaker_args)?;
+    }
+
     **ctx.accounts.pool.as_mut() = Pool {
         version: CURRENT_POOL_VERSION,
         bump: [ctx.bumps.pool],
@@ -97,7 +194,7 @@ pub fn process_create_pool(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Re
         owner: ctx.accounts.owner.key(),
         whitelist: ctx.accounts.whitelist.key(),
         rent_payer: ctx.accounts.rent_payer.key(),
-        currency: Pubkey::default(),
+        currency: args.currency.unwrap_or_default(),
         amount: 0,
         price_offset: 0,
         nfts_held: 0,
@@ -112,8 +209,60 @@ pub fn process_create_pool(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Re
         maker_broker: args.maker_broker.unwrap_or_default(),
         max_taker_sell_count: args.max_taker_sell_count.unwrap_or(0),
         config: args.config,
-        _reserved: [0; 100],
+        decay_bps: args.decay_bps.unwrap_or(0),
+        floor_price: args.floor_price.unwrap_or(0),
+        manager: args.manager.unwrap_or_default(),
+        buys_paused: false,
+        sells_paused: false,
+        cosigner_nonce: 0,
+        requires_traits: false,
+        max_taker_buy_count: args.max_taker_buy_count.unwrap_or(0),
+        max_nfts_held: args.max_nfts_held.unwrap_or(0),
+        max_total_spend: args.max_total_spend.unwrap_or(0),
+        circuit_breaker,
+        keeper_bounty: args.keeper_bounty.unwrap_or(0),
+        cosigner_nonces_used: [0; 8],
+        total_spent: 0,
+        _reserved: [0; 71],
     };
 
-    Ok(())
+    ctx.accounts.pool.validate_decay()?;
+
+    // The keeper bounty is escrowed in the pool on top of its rent.
+    let keeper_bounty = ctx.accounts.pool.keeper_bounty;
+    if keeper_bounty > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.rent_payer.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            keeper_bounty,
+        )?;
+    }
+
+    if let Some(price_ladder) = ctx.accounts.price_ladder.as_mut() {
+        price_ladder.bump = [ctx.bumps.price_ladder];
+        price_ladder.pool = ctx.accounts.pool.key();
+        price_ladder.set_prices(unwrap_opt!(
+            args.price_ladder,
+            ErrorCode::MissingPriceLadder
+        ))?;
+    }
+
+    let pool = &ctx.accounts.pool;
+
+    record_event(
+        TAmmEvent::CreatePoolEvent(CreatePoolEvent {
+            owner: pool.owner,
+            whitelist: pool.whitelist,
+            currency: pool.currency,
+            config: pool.config,
+            expiry: pool.expiry,
+        }),
+        &ctx.accounts.amm_program,
+        pool,
+    )
 }
@@ -0,0 +1,227 @@
+//! Helpers for pools whose currency is an SPL token mint instead of SOL.
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id, Create},
+    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use mpl_token_metadata::types::Creator;
+use tensor_vipers::{throw_err, unwrap_checked, unwrap_int, unwrap_opt};
+
+use crate::{error::ErrorCode, *};
+
+/// Accounts required to move an SPL currency around.
+pub struct SplCurrency<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+    /// Pays for any associated token account that needs to be created.
+    pub payer: AccountInfo<'info>,
+}
+
+impl<'a, 'info> SplCurrency<'a, 'info> {
+    /// Derives the associated token account of `wallet` for the currency mint.
+    pub fn ata(&self, wallet: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            wallet,
+            &self.mint.key(),
+            self.token_program.key,
+        )
+    }
+
+    /// Checks that `token_account` is the associated token account of `wallet` and creates it
+    /// if it does not exist yet.
+    pub fn init_ata(
+        &self,
+        token_account: &AccountInfo<'info>,
+        wallet: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if *token_account.key != self.ata(wallet.key) {
+            throw_err!(ErrorCode::InvalidCurrencyTokenAccount);
+        }
+
+        if token_account.data_is_empty() {
+            let associated_token_program = unwrap_opt!(
+                self.associated_token_program.as_ref(),
+                ErrorCode::MissingCurrencyAccounts
+            );
+
+            associated_token::create_idempotent(CpiContext::new(
+                associated_token_program.clone(),
+                Create {
+                    payer: self.payer.clone(),
+                    associated_token: token_account.clone(),
+                    authority: wallet.clone(),
+                    mint: self.mint.to_account_info(),
+                    system_program: self.system_program.clone(),
+                    token_program: self.token_program.clone(),
+                },
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Transfers `amount` of the currency between two token accounts. Zero amounts are skipped.
+    pub fn transfer(
+        &self,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        amount: u64,
+        signer_seeds: Option<&[&[&[u8]]]>,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                self.token_program.clone(),
+                TransferChecked {
+                    from: from.clone(),
+                    to: to.clone(),
+                    authority: authority.clone(),
+                    mint: self.mint.to_account_info(),
+                },
+            )
+            .with_signer(signer_seeds.unwrap_or_default()),
+            amount,
+            self.mint.decimals,
+        )
+    }
+
+    /// Pays royalties in the currency to the creator token accounts found by address in
+    /// `creator_accounts`. Missing token accounts are created, paid by the currency payer, when
+    /// the creator wallet is passed along; otherwise the creator is skipped.
+    ///
+    /// Returns the total amount paid to creators.
+    pub fn transfer_creators_fee(
+        &self,
+        creators: &[Creator],
+        creator_accounts: &[AccountInfo<'info>],
+        creators_fee: u64,
+        from: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        signer_seeds: Option<&[&[&[u8]]]>,
+    ) -> Result<u64> {
+        let mut total = 0u64;
+
+        if creators_fee == 0 {
+            return Ok(total);
+        }
+
+        for creator in creators {
+            let creator_fee = unwrap_checked!({
+                (creator.share as u64)
+                    .checked_mul(creators_fee)?
+                    .checked_div(100)
+            });
+
+            if creator_fee == 0 {
+                continue;
+            }
+
+            let creator_ta = self.ata(&creator.address);
+            let creator_ta_info = match creator_accounts.iter().find(|a| *a.key == creator_ta) {
+                Some(info) => info,
+                None => continue,
+            };
+
+            if creator_ta_info.data_is_empty() {
+                let creator_info = creator_accounts.iter().find(|a| *a.key == creator.address);
+
+                match creator_info {
+                    Some(creator_info) if self.associated_token_program.is_some() => {
+                        self.init_ata(creator_ta_info, creator_info)?
+                    }
+                    _ => continue,
+                }
+            }
+
+            self.transfer(from, creator_ta_info, authority, creator_fee, signer_seeds)?;
+            total = unwrap_int!(total.checked_add(creator_fee));
+        }
+
+        Ok(total)
+    }
+}
+
+/// Reads the balance of a token account from its current data, so it reflects any transfer
+/// made earlier in the same instruction.
+pub fn token_balance(token_account: &AccountInfo) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+    let account = TokenAccount::try_deserialize(&mut data.as_ref())?;
+    Ok(account.amount)
+}
+
+/// Sweeps whatever is left in the pool currency token account to the owner and closes it,
+/// refunding its rent to the pool rent payer.
+pub fn close_pool_currency_ta<'info>(
+    pool: &Account<'info, Pool>,
+    currency: &SplCurrency<'_, 'info>,
+    pool_ta: &AccountInfo<'info>,
+    owner_ta: Option<&AccountInfo<'info>>,
+    rent_payer: AccountInfo<'info>,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&pool.seeds()];
+    let pool_info = pool.to_account_info();
+
+    let balance = token_balance(pool_ta)?;
+
+    if balance > 0 {
+        let owner_ta = unwrap_opt!(owner_ta, ErrorCode::MissingCurrencyAccounts);
+
+        if *owner_ta.key != currency.ata(&pool.owner) {
+            throw_err!(ErrorCode::InvalidCurrencyTokenAccount);
+        }
+
+        currency.transfer(pool_ta, owner_ta, &pool_info, balance, Some(signer_seeds))?;
+    }
+
+    token_interface::close_account(
+        CpiContext::new(
+            currency.token_program.clone(),
+            CloseAccount {
+                account: pool_ta.clone(),
+                destination: rent_payer,
+                authority: pool_info,
+            },
+        )
+        .with_signer(signer_seeds),
+    )
+}
+
+/// Closes the currency token account of SPL currency pools being closed, from the optional
+/// currency accounts of the instruction. Does nothing for SOL pools.
+pub fn close_currency_ta<'info>(
+    pool: &Account<'info, Pool>,
+    currency: Option<&InterfaceAccount<'info, Mint>>,
+    pool_ta: Option<&InterfaceAccount<'info, TokenAccount>>,
+    owner_ta: Option<&UncheckedAccount<'info>>,
+    currency_token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: AccountInfo<'info>,
+    rent_payer: AccountInfo<'info>,
+) -> Result<()> {
+    if pool.currency == Pubkey::default() {
+        return Ok(());
+    }
+
+    let mint = unwrap_opt!(currency, ErrorCode::MissingCurrencyAccounts);
+    let pool_ta = unwrap_opt!(pool_ta, ErrorCode::MissingCurrencyAccounts);
+    let token_program = unwrap_opt!(currency_token_program, ErrorCode::MissingCurrencyAccounts);
+
+    close_pool_currency_ta(
+        pool,
+        &SplCurrency {
+            mint,
+            token_program: token_program.to_account_info(),
+            associated_token_program: None,
+            system_program,
+            payer: rent_payer.clone(),
+        },
+        &pool_ta.to_account_info(),
+        owner_ta.map(|ta| ta.to_account_info()).as_ref(),
+        rent_payer,
+    )
+}
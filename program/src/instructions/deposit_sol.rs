@@ -3,7 +3,7 @@ use constants::CURRENT_POOL_VERSION;
 use tensor_toolbox::transfer_lamports;
 use tensor_vipers::unwrap_int;
 
-use crate::{error::ErrorCode, *};
+use crate::{error::ErrorCode, program::AmmProgram, *};
 
 /// Instruction accounts.
 #[derive(Accounts)]
@@ -25,12 +25,16 @@ pub struct DepositSol<'info> {
         constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
         constraint = pool.config.pool_type == PoolType::Token ||  pool.config.pool_type == PoolType::Trade @ ErrorCode::WrongPoolType,
         constraint = pool.shared_escrow == Pubkey::default() @ ErrorCode::PoolOnSharedEscrow,
+        constraint = pool.currency == Pubkey::default() @ ErrorCode::InvalidCurrency,
         constraint = pool.expiry >= Clock::get()?.unix_timestamp @ ErrorCode::ExpiredPool,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
     /// The Solana system program.
     pub system_program: Program<'info, System>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
 }
 
 /// Allows a pool owner to deposit SOL into a Token or Trade pool.
@@ -49,5 +53,11 @@ pub fn process_deposit_sol<'info>(
         &ctx.accounts.owner.to_account_info(),
         &ctx.accounts.pool.to_account_info(),
         lamports,
+    )?;
+
+    record_event(
+        TAmmEvent::DepositCurrencyEvent(DepositCurrencyEvent { amount: lamports }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
     )
 }
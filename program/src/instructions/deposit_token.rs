@@ -0,0 +1,89 @@
+//! Deposit SPL currency into a Token or Trade pool.
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use constants::CURRENT_POOL_VERSION;
+use tensor_vipers::unwrap_int;
+
+use crate::{error::ErrorCode, program::AmmProgram, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    /// The owner of the pool--must sign to deposit funds.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The pool to deposit the funds into.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+        constraint = pool.config.pool_type == PoolType::Token ||  pool.config.pool_type == PoolType::Trade @ ErrorCode::WrongPoolType,
+        constraint = pool.currency == currency.key() @ ErrorCode::InvalidCurrency,
+        constraint = pool.expiry >= Clock::get()?.unix_timestamp @ ErrorCode::ExpiredPool,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The SPL mint the pool is priced in.
+    #[account(mint::token_program = token_program)]
+    pub currency: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The owner's currency token account, where the funds are deposited from.
+    #[account(
+        mut,
+        token::mint = currency,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The pool's currency token account.
+    #[account(
+        mut,
+        associated_token::mint = currency,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the currency mint.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+}
+
+/// Allows a pool owner to deposit SPL currency into a Token or Trade pool.
+pub fn process_deposit_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositToken<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // Update the pool's currency amount
+    pool.amount = unwrap_int!(pool.amount.checked_add(amount));
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.owner_ta.to_account_info(),
+                to: ctx.accounts.pool_ta.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+                mint: ctx.accounts.currency.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.currency.decimals,
+    )?;
+
+    record_event(
+        TAmmEvent::DepositCurrencyEvent(DepositCurrencyEvent { amount }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
+    )
+}
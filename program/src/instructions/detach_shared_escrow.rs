@@ -0,0 +1,97 @@
+//! Move a pool off its shared escrow, back to liquidity of its own.
+use constants::CURRENT_POOL_VERSION;
+use escrow_program::state::MarginAccount;
+use tensor_escrow::instructions::{
+    WithdrawMarginAccountCpiTammCpi, WithdrawMarginAccountCpiTammInstructionArgs,
+};
+use tensor_toolbox::escrow;
+use tensor_vipers::unwrap_int;
+
+use crate::{error::ErrorCode, program::AmmProgram, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct DetachSharedEscrow<'info> {
+    /// The owner of the pool and the shared escrow--must sign to detach the pool.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The pool to detach from its shared escrow.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+        constraint = pool.shared_escrow == shared_escrow.key() @ ErrorCode::BadSharedEscrow,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The shared escrow the pool is attached to, funding the pool's new balance.
+    #[account(
+        mut,
+        has_one = owner @ ErrorCode::WrongOwner,
+    )]
+    pub shared_escrow: Box<Account<'info, MarginAccount>>,
+
+    /// The escrow program account, withdrawing from the shared escrow.
+    /// CHECK: address constraint is checked here
+    #[account(address = escrow::ID)]
+    pub escrow_program: UncheckedAccount<'info>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+}
+
+/// Moves `lamports` from the shared escrow into the pool, which from then on trades with its own
+/// SOL balance. Stats and pricing are left untouched.
+pub fn process_detach_shared_escrow<'info>(
+    ctx: Context<'_, '_, '_, 'info, DetachSharedEscrow<'info>>,
+    lamports: u64,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let owner_pubkey = ctx.accounts.owner.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool",
+        owner_pubkey.as_ref(),
+        pool.pool_id.as_ref(),
+        &[pool.bump[0]],
+    ]];
+
+    if lamports > 0 {
+        WithdrawMarginAccountCpiTammCpi {
+            __program: &ctx.accounts.escrow_program.to_account_info(),
+            margin_account: &ctx.accounts.shared_escrow.to_account_info(),
+            pool: &ctx.accounts.pool.to_account_info(),
+            owner: &ctx.accounts.owner.to_account_info(),
+            destination: &ctx.accounts.pool.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            __args: WithdrawMarginAccountCpiTammInstructionArgs {
+                bump: pool.bump[0],
+                pool_id: pool.pool_id,
+                lamports,
+            },
+        }
+        .invoke_signed(signer_seeds)?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.amount = unwrap_int!(pool.amount.checked_add(lamports));
+    pool.shared_escrow = Pubkey::default();
+
+    record_event(
+        TAmmEvent::DetachSharedEscrowEvent(DetachSharedEscrowEvent {
+            shared_escrow: ctx.accounts.shared_escrow.key(),
+            amount: lamports,
+        }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
+    )
+}
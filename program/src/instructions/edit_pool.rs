@@ -1,8 +1,9 @@
 //! Edit an existing pool.
-use tensor_vipers::throw_err;
+use tensor_vipers::{throw_err, unwrap_opt};
+use whitelist_program::{self, WhitelistV2};
 
 use self::constants::CURRENT_POOL_VERSION;
-use crate::{error::ErrorCode, *};
+use crate::{error::ErrorCode, program::AmmProgram, *};
 
 /// Edit pool arguments.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -13,6 +14,17 @@ pub struct EditPoolArgs {
     pub expire_in_sec: Option<u64>,
     pub max_taker_sell_count: Option<u32>,
     pub reset_price_offset: bool,
+    pub decay_bps: Option<u16>,
+    pub floor_price: Option<u64>,
+    /// New prices of custom curves.
+    pub price_ladder: Option<PriceLadderArgs>,
+    /// New manager of the pool; the default pubkey removes the manager.
+    pub manager: Option<Pubkey>,
+    pub max_taker_buy_count: Option<u32>,
+    pub max_nfts_held: Option<u32>,
+    pub max_total_spend: Option<u64>,
+    /// New limits of the circuit breaker; a zero window disables it.
+    pub circuit_breaker: Option<CircuitBreakerArgs>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -40,8 +52,9 @@ impl EditPoolConfig {
 /// Instruction accounts.
 #[derive(Accounts)]
 pub struct EditPool<'info> {
-    /// The owner of the pool--must sign to edit the pool.
-    pub owner: Signer<'info>,
+    /// The owner of the pool--must sign to edit the pool, unless the pool manager signs instead.
+    /// CHECK: seeds in pool, signature checked in validate_authority
+    pub owner: UncheckedAccount<'info>,
 
     /// The pool to edit.
     #[account(
@@ -59,9 +72,78 @@ pub struct EditPool<'info> {
 
     /// The Solana system program.
     pub system_program: Program<'info, System>,
+
+    /// The rent payer of the pool, paying for the price ladder if it doesn't exist yet and
+    /// refunded when it is closed.
+    #[account(mut, address = pool.rent_payer @ ErrorCode::WrongRentPayer)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// The price ladder of the pool; required to set prices or switch to or from a custom curve.
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        space = PriceLadder::SIZE,
+        seeds = [b"price_ladder", pool.key().as_ref()],
+        bump,
+    )]
+    pub price_ladder: Option<Box<Account<'info, PriceLadder>>>,
+
+    /// The manager of the pool, which can edit the pricing and expiry of the pool in place of the owner.
+    #[account(
+        constraint = pool.manager != Pubkey::default() && manager.key() == pool.manager @ ErrorCode::WrongManager,
+    )]
+    pub manager: Option<Signer<'info>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+
+    /// The new whitelist of the pool; can only be set while the pool holds no NFTs, since these
+    /// were verified against the current whitelist.
+    #[account(
+        seeds = [b"whitelist", &new_whitelist.namespace.as_ref(), &new_whitelist.uuid],
+        bump,
+        seeds::program = whitelist_program::ID
+    )]
+    pub new_whitelist: Option<Box<Account<'info, WhitelistV2>>>,
 }
 
 impl<'info> EditPool<'info> {
+    /// The owner can make any change, while the manager is limited to pricing and expiry, leaving
+    /// the MM fees of the config unchanged.
+    fn validate_authority(&self, args: &EditPoolArgs) -> Result<()> {
+        if self.owner.is_signer {
+            return Ok(());
+        }
+
+        if self.manager.is_none() {
+            return Err(anchor_lang::error::ErrorCode::AccountNotSigner.into());
+        }
+
+        if args.cosigner.is_some()
+            || args.maker_broker.is_some()
+            || args.max_taker_sell_count.is_some()
+            || args.max_taker_buy_count.is_some()
+            || args.max_nfts_held.is_some()
+            || args.max_total_spend.is_some()
+            || args.circuit_breaker.is_some()
+            || args.manager.is_some()
+            || self.new_whitelist.is_some()
+        {
+            throw_err!(ErrorCode::ManagerNotAllowed);
+        }
+
+        // MM fees are the owner's revenue, so the manager can't change them.
+        if let Some(config) = &args.new_config {
+            if config.mm_fee_bps != self.pool.config.mm_fee_bps
+                || config.mm_compound_fees != self.pool.config.mm_compound_fees
+            {
+                throw_err!(ErrorCode::ManagerNotAllowed);
+            }
+        }
+
+        Ok(())
+    }
+
     fn validate_pool_config(
         &self,
         edit_config: Option<EditPoolConfig>,
@@ -78,15 +160,26 @@ impl<'info> EditPool<'info> {
 
         new_config.validate()?;
 
+        if !new_config.can_use_shared_escrow() {
+            require!(
+                self.pool.shared_escrow == Pubkey::default(),
+                ErrorCode::PoolOnSharedEscrow
+            );
+        }
+
         Ok(Some(new_config))
     }
 }
 
 /// Edit an existing pool.
+#[access_control(ctx.accounts.validate_authority(&args))]
 pub fn process_edit_pool(ctx: Context<EditPool>, args: EditPoolArgs) -> Result<()> {
     let new_config = ctx.accounts.validate_pool_config(args.new_config)?;
+    let config_changed = new_config.is_some();
+    let was_custom = ctx.accounts.pool.config.curve_type == CurveType::Custom;
 
     let pool = &mut ctx.accounts.pool;
+    let auction_params = (pool.config, pool.decay_bps, pool.floor_price);
 
     if let Some(new_config) = new_config {
         pool.config = new_config;
@@ -105,10 +198,90 @@ pub fn process_edit_pool(ctx: Context<EditPool>, args: EditPoolArgs) -> Result<(
         pool.max_taker_sell_count = max_taker_sell_count;
     }
 
+    if let Some(max_taker_buy_count) = args.max_taker_buy_count {
+        pool.valid_max_buy_count(max_taker_buy_count)?;
+        pool.max_taker_buy_count = max_taker_buy_count;
+    }
+
+    if let Some(max_nfts_held) = args.max_nfts_held {
+        pool.valid_max_nfts_held(max_nfts_held)?;
+        pool.max_nfts_held = max_nfts_held;
+    }
+
+    if let Some(max_total_spend) = args.max_total_spend {
+        pool.valid_max_total_spend(max_total_spend)?;
+        pool.max_total_spend = max_total_spend;
+    }
+
+    if let Some(circuit_breaker) = args.circuit_breaker {
+        pool.circuit_breaker.set_limits(circuit_breaker)?;
+    }
+
+    if let Some(manager) = args.manager {
+        pool.manager = manager;
+    }
+
     if args.reset_price_offset {
         pool.price_offset = 0;
     }
 
+    if let Some(decay_bps) = args.decay_bps {
+        pool.decay_bps = decay_bps;
+    }
+
+    if let Some(floor_price) = args.floor_price {
+        pool.floor_price = floor_price;
+    }
+
+    pool.validate_decay()?;
+
+    if let Some(price_ladder_args) = args.price_ladder {
+        let price_ladder = unwrap_opt!(
+            ctx.accounts.price_ladder.as_mut(),
+            ErrorCode::MissingPriceLadder
+        );
+        price_ladder.bump = [ctx.bumps.price_ladder];
+        price_ladder.pool = pool.key();
+        price_ladder.set_prices(price_ladder_args)?;
+    }
+
+    // Custom curves can't be priced without a price ladder.
+    if config_changed && pool.config.curve_type == CurveType::Custom {
+        let price_ladder = unwrap_opt!(
+            ctx.accounts.price_ladder.as_ref(),
+            ErrorCode::MissingPriceLadder
+        );
+        require!(
+            !price_ladder.prices.is_empty(),
+            ErrorCode::MissingPriceLadder
+        );
+    }
+
+    // Close the price ladder when leaving a custom curve, so pools only have one while they
+    // use it and closing the pool doesn't leave it behind.
+    if was_custom && pool.config.curve_type != CurveType::Custom {
+        let price_ladder = unwrap_opt!(
+            ctx.accounts.price_ladder.as_ref(),
+            ErrorCode::MissingPriceLadder
+        );
+        let rent_payer = unwrap_opt!(ctx.accounts.rent_payer.as_ref(), ErrorCode::WrongRentPayer);
+        price_ladder.close(rent_payer.to_account_info())?;
+    }
+
+    // Editing the curve of a GDA pool restarts its auction from the new values.
+    if pool.config.curve_type == CurveType::Gda
+        && (pool.config, pool.decay_bps, pool.floor_price) != auction_params
+    {
+        pool.updated_at = Clock::get()?.unix_timestamp;
+    }
+
+    let new_whitelist = ctx.accounts.new_whitelist.as_ref().map(|w| w.key());
+
+    if let Some(new_whitelist) = new_whitelist {
+        require!(pool.nfts_held == 0, ErrorCode::ExistingNfts);
+        pool.whitelist = new_whitelist;
+    }
+
     // If the user passes in a new expiry value, set it to that.
     // None, in this case means no change, instead of max value like it does in create_pool.
     if let Some(expire_in_sec) = args.expire_in_sec {
@@ -116,5 +289,23 @@ pub fn process_edit_pool(ctx: Context<EditPool>, args: EditPoolArgs) -> Result<(
         pool.expiry = assert_expiry(expire_in_sec)?;
     }
 
+    record_event(
+        TAmmEvent::EditPoolEvent(EditPoolEvent {
+            config: pool.config,
+            expiry: pool.expiry,
+            price_offset: pool.price_offset,
+        }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
+    )?;
+
+    if let Some(whitelist) = new_whitelist {
+        record_event(
+            TAmmEvent::EditPoolWhitelistEvent(EditPoolWhitelistEvent { whitelist }),
+            &ctx.accounts.amm_program,
+            &ctx.accounts.pool,
+        )?;
+    }
+
     Ok(())
 }
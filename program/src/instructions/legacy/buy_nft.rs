@@ -2,10 +2,13 @@
 
 use super::*;
 
+use crate::error::ErrorCode;
+
 /// Instruction accounts
 #[derive(Accounts)]
 pub struct BuyNft<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// Metaplex legacy and pNFT shared accounts.
@@ -70,12 +73,12 @@ pub fn process_buy_nft<'info>(
 ) -> Result<()> {
     // Pre-handler validation checks.
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
     let taker = ctx.accounts.trade.taker.to_account_info();
-    let owner = ctx.accounts.trade.owner.to_account_info();
 
     let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
+        &asset,
         max_amount,
         TakerSide::Buy,
         if asset.royalty_enforced {
@@ -85,7 +88,7 @@ pub fn process_buy_nft<'info>(
         },
     )?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
     let owner_pubkey = ctx.accounts.trade.owner.key();
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -136,7 +139,8 @@ pub fn process_buy_nft<'info>(
             .with_signer(signer_seeds),
     )?;
 
-    ctx.accounts
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_buyer_fees(asset, fees, ctx.remaining_accounts)?;
 
@@ -146,22 +150,14 @@ pub fn process_buy_nft<'info>(
         &mut ctx.accounts.trade.owner.to_account_info(),
     )?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Buy,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Buy, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Buy, fees, creators_fee)?;
 
     // If the pool is an NFT pool, and no remaining NFTs held, we can close it.
-    try_autoclose_pool(
-        &ctx.accounts.trade.pool,
-        ctx.accounts.trade.rent_payer.to_account_info(),
-        owner,
-        ctx.accounts
-            .trade
-            .shared_escrow
-            .as_ref()
-            .map(|escrow| escrow.to_account_info())
-            .as_ref(),
-    )
+    ctx.accounts.trade.try_autoclose_pool()
 }
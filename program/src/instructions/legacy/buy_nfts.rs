@@ -0,0 +1,197 @@
+//! Buy several Metaplex legacy NFTs or pNFTs from a NFT or Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per NFT bought, while the fees of all NFTs
+//! are paid in aggregated transfers and checked against a single maximum total amount.
+//! Royalties are paid to the creators of each NFT.
+
+use super::*;
+
+use crate::error::ErrorCode;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct BuyNfts<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The Token Metadata program account.
+    /// CHECK: address constraint is checked here
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// The sysvar instructions account.
+    /// CHECK: address constraint is checked here
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// The Metaplex Token Authority Rules program account.
+    /// CHECK: address constraint is checked here
+    #[account(address = MPL_TOKEN_AUTH_RULES_ID)]
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// Either the legacy token program or token-2022.
+    pub token_program: Interface<'info, TokenInterface>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each NFT bought: [mint, metadata, edition, nft_receipt, pool_ta, taker_ta,
+    //      pool_token_record, taker_token_record, authorization_rules] (the program id for the
+    //      pNFT accounts a legacy NFT doesn't use), followed by the royalty creator accounts of
+    //      the NFT (each creator followed by its currency token account for SPL pools)
+}
+
+/// Buy several Metaplex legacy NFTs or pNFTs from a NFT or Trade pool.
+pub fn process_buy_nfts<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNfts<'info>>,
+    // Max vs exact so we can add slippage later.
+    max_total_amount: u64,
+    authorization_data: Option<AuthorizationDataLocal>,
+    optional_royalty_pct: Option<u16>,
+) -> Result<()> {
+    ctx.accounts.trade.validate_buy()?;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let owner = ctx.accounts.trade.owner.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+
+    let token_accounts = BatchTokenAccounts {
+        payer: taker.clone(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::new();
+    let mut assets = Vec::new();
+    let mut accounts = ctx.remaining_accounts;
+
+    while !accounts.is_empty() {
+        let (item, rest) = split_batch_item(accounts, 9)?;
+        let (mint_info, pool_ta_info, taker_ta_info) = (&item[0], &item[4], &item[5]);
+
+        // Each purchase counts towards the max taker buy count.
+        ctx.accounts.trade.pool.taker_allowed_to_buy()?;
+
+        let asset = validate_mplx_batch_asset(mint_info, &item[1], &item[2])?;
+        check_nft_receipt(&item[3], &asset.pubkey, &pool_key)?;
+
+        let (creator_accounts, rest) =
+            split_batch_item(rest, ctx.accounts.trade.batch_creator_accounts_len(&asset))?;
+
+        // Fees at the current price, the total is checked against the max amount below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            u64::MAX,
+            TakerSide::Buy,
+            if asset.royalty_enforced {
+                Some(100)
+            } else {
+                optional_royalty_pct
+            },
+        )?;
+
+        token_accounts.init_ata(taker_ta_info, &taker, mint_info)?;
+
+        let mint = Box::new(InterfaceAccount::<Mint>::try_from(mint_info)?);
+        let pool_ta = Box::new(InterfaceAccount::<TokenAccount>::try_from(pool_ta_info)?);
+        let taker_ta = Box::new(InterfaceAccount::<TokenAccount>::try_from(taker_ta_info)?);
+        let metadata = UncheckedAccount::try_from(&item[1]);
+        let edition = UncheckedAccount::try_from(&item[2]);
+        let pool_token_record = optional_account(&item[6]).map(UncheckedAccount::try_from);
+        let taker_token_record = optional_account(&item[7]).map(UncheckedAccount::try_from);
+        let authorization_rules = optional_account(&item[8]).map(UncheckedAccount::try_from);
+
+        let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.trade.pool.seeds()];
+
+        // Transfer the NFT from the pool to the buyer.
+        transfer(
+            TransferArgs {
+                payer: &taker,
+                source: &pool_info,
+                source_ata: &pool_ta,
+                destination: &taker,
+                destination_ata: &taker_ta,
+                mint: &mint,
+                metadata: &metadata,
+                edition: &edition,
+                system_program: &ctx.accounts.system_program,
+                spl_token_program: &ctx.accounts.token_program,
+                spl_ata_program: &ctx.accounts.associated_token_program,
+                token_metadata_program: ctx.accounts.token_metadata_program.as_ref(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.as_ref(),
+                source_token_record: pool_token_record.as_ref(),
+                destination_token_record: taker_token_record.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+                authorization_rules_program: ctx.accounts.authorization_rules_program.as_ref(),
+                authorization_data: authorization_data.clone().map(AuthorizationData::from),
+                delegate: None,
+            },
+            Some(signer_seeds),
+        )?;
+
+        // close nft escrow account
+        token_interface::close_account(
+            ctx.accounts
+                .trade
+                .close_pool_ata_ctx(
+                    ctx.accounts.token_program.to_account_info(),
+                    pool_ta_info.clone(),
+                )
+                .with_signer(signer_seeds),
+        )?;
+
+        // Close the NFT receipt account.
+        close_account(&mut item[3].clone(), &mut owner.clone())?;
+
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+        royalties.push(NftRoyalties::new(&asset, &fees, creator_accounts));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next NFT.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Buy, &fees)?;
+
+        accounts = rest;
+    }
+
+    if assets.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price + royalties + mm_fee doesn't exceed the max amount the user specified.
+    let total_amount = unwrap_checked!({
+        total_fees
+            .price
+            .checked_add(total_fees.mm_fee)?
+            .checked_add(total_fees.creators_fee)
+    });
+
+    if total_amount > max_total_amount {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_batch_buyer_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Buy,
+    )?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(assets, TakerSide::Buy, total_fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
@@ -60,6 +60,8 @@ impl<'info> DepositNft<'info> {
             throw_err!(ErrorCode::ExpiredPool);
         }
 
+        self.transfer.pool.can_hold_more_nfts()?;
+
         let asset = self.mplx.validate_asset()?;
 
         self.transfer.verify_whitelist(&asset)?;
@@ -117,5 +119,11 @@ pub fn process_deposit_nft(
         pool: ctx.accounts.transfer.pool.key(),
     };
 
-    Ok(())
+    record_event(
+        TAmmEvent::DepositNftEvent(DepositNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )
 }
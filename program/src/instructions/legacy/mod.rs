@@ -1,25 +1,48 @@
 pub mod buy_nft;
+pub mod buy_nfts;
 pub mod deposit_nft;
 pub mod sell_nft_token_pool;
 pub mod sell_nft_trade_pool;
+pub mod sell_nfts_token_pool;
+pub mod sell_nfts_trade_pool;
+pub mod withdraw_expired_nft;
 pub mod withdraw_nft;
 
 pub use self::buy_nft::*;
+pub use self::buy_nfts::*;
 pub use self::deposit_nft::*;
 pub use self::sell_nft_token_pool::*;
 pub use self::sell_nft_trade_pool::*;
+pub use self::sell_nfts_token_pool::*;
+pub use self::sell_nfts_trade_pool::*;
+pub use self::withdraw_expired_nft::*;
 pub use self::withdraw_nft::*;
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{self, TokenAccount, TokenInterface},
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
 };
-use mpl_token_metadata::types::AuthorizationData;
+use mpl_token_metadata::{accounts::MasterEdition, types::AuthorizationData};
 use tensor_toolbox::{
     close_account,
     token_metadata::{transfer, TransferArgs},
 };
-use tensor_vipers::{throw_err, unwrap_int};
+use tensor_vipers::{throw_err, unwrap_checked, unwrap_int, unwrap_opt};
 
-use crate::*;
+use crate::{error::ErrorCode, *};
+
+/// Validates a legacy NFT or pNFT passed in remaining accounts by batch instructions, checking
+/// the edition seeds Anchor checks on `MplxShared`; the metadata is checked against the mint
+/// when decoded.
+pub(crate) fn validate_mplx_batch_asset(
+    mint: &AccountInfo,
+    metadata: &AccountInfo,
+    edition: &AccountInfo,
+) -> Result<AmmAsset> {
+    if *edition.key != MasterEdition::find_pda(mint.key).0 {
+        throw_err!(ErrorCode::InvalidEdition);
+    }
+
+    validate_mplx_amm_asset(mint.key, metadata, edition)
+}
@@ -6,10 +6,13 @@
 
 use super::*;
 
+use crate::error::ErrorCode;
+
 /// Instruction accounts.
 #[derive(Accounts)]
 pub struct SellNftTokenPool<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// Metaplex legacy and pNFT shared accounts.
@@ -82,11 +85,10 @@ pub fn process_sell_nft_token_pool<'info>(
 ) -> Result<()> {
     // Runs pre-checks and validates the asset.
     let asset = ctx.accounts.pre_process_checks()?;
-
-    let owner = ctx.accounts.trade.owner.to_account_info();
+    let asset_id = asset.pubkey;
 
     let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
+        &asset,
         min_price,
         TakerSide::Sell,
         if asset.royalty_enforced {
@@ -96,7 +98,7 @@ pub fn process_sell_nft_token_pool<'info>(
         },
     )?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
     let owner_pubkey = ctx.accounts.trade.owner.key();
 
     // --------------------------------------- send pnft
@@ -187,25 +189,18 @@ pub fn process_sell_nft_token_pool<'info>(
     ))?;
     // --------------------------------------- end pnft
 
-    ctx.accounts
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_seller_fees(asset, fees, ctx.remaining_accounts)?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Sell,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
 
-    try_autoclose_pool(
-        &ctx.accounts.trade.pool,
-        ctx.accounts.trade.rent_payer.to_account_info(),
-        owner,
-        ctx.accounts
-            .trade
-            .shared_escrow
-            .as_ref()
-            .map(|escrow| escrow.to_account_info())
-            .as_ref(),
-    )
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
 }
@@ -7,10 +7,13 @@
 
 use super::*;
 
+use crate::error::ErrorCode;
+
 /// Instruction accounts.
 #[derive(Accounts)]
 pub struct SellNftTradePool<'info> {
     // Trade shared accounts
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// Metaplex legacy and pNFT shared accounts.
@@ -78,9 +81,10 @@ pub fn process_sell_nft_trade_pool<'info>(
     optional_royalty_pct: Option<u16>,
 ) -> Result<()> {
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
     let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
+        &asset,
         min_price,
         TakerSide::Sell,
         if asset.royalty_enforced {
@@ -90,7 +94,7 @@ pub fn process_sell_nft_trade_pool<'info>(
         },
     )?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
 
     // transfer nft to pool
     // has to go before any transfer_lamports, o/w we get `sum of account balances before and after instruction do not match`
@@ -129,15 +133,18 @@ pub fn process_sell_nft_trade_pool<'info>(
         ctx.accounts.taker_ta.to_account_info(),
     ))?;
 
-    ctx.accounts
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_seller_fees(asset, fees, ctx.remaining_accounts)?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Sell,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
 
     //create nft receipt for trade pool
     **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
@@ -0,0 +1,207 @@
+//! Sell several Metaplex legacy NFTs or pNFTs into a Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per NFT sold, while the fees of all NFTs
+//! are paid in aggregated transfers and checked against a single minimum total price.
+//! The pool ends up as the owner of the NFTs and a deposit receipt is created for each.
+
+use super::*;
+
+use crate::error::ErrorCode;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SellNftsTradePool<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The Token Metadata program account.
+    /// CHECK: address constraint is checked here
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// The sysvar instructions account.
+    /// CHECK: address constraint is checked here
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// The Metaplex Token Authority Rules program account.
+    /// CHECK: address constraint is checked here
+    #[account(address = MPL_TOKEN_AUTH_RULES_ID)]
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// Either the legacy token program or token-2022.
+    pub token_program: Interface<'info, TokenInterface>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each NFT sold: [mint, metadata, edition, nft_receipt, taker_ta, pool_ta,
+    //      taker_token_record, pool_token_record, authorization_rules, mint_proof] (the program
+    //      id for the accounts the NFT doesn't use), followed by the royalty creator accounts of
+    //      the NFT (each creator followed by its currency token account for SPL pools)
+}
+
+/// Sell several Metaplex legacy NFTs or pNFTs into a Trade pool.
+pub fn process_sell_nfts_trade_pool<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftsTradePool<'info>>,
+    // Min vs exact so we can add slippage later.
+    min_total_price: u64,
+    authorization_data: Option<AuthorizationDataLocal>,
+    optional_royalty_pct: Option<u16>,
+) -> Result<()> {
+    ctx.accounts.trade.validate_sell(&PoolType::Trade)?;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let token_accounts = BatchTokenAccounts {
+        payer: taker.clone(),
+        token_program: token_program.clone(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: system_program.clone(),
+    };
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let funds_available = ctx.accounts.trade.sell_funds_available()?;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::new();
+    let mut assets = Vec::new();
+    let mut accounts = ctx.remaining_accounts;
+
+    while !accounts.is_empty() {
+        let (item, rest) = split_batch_item(accounts, 10)?;
+        let (mint_info, receipt_info, taker_ta_info, pool_ta_info) =
+            (&item[0], &item[3], &item[4], &item[5]);
+
+        // Each sale counts towards the max taker sell count and the max NFTs held.
+        ctx.accounts.trade.pool.taker_allowed_to_sell()?;
+        ctx.accounts.trade.pool.can_hold_more_nfts()?;
+
+        let asset = validate_mplx_batch_asset(mint_info, &item[1], &item[2])?;
+
+        ctx.accounts
+            .trade
+            .verify_whitelist_with_proof(&asset, optional_account(&item[9]))?;
+
+        let (creator_accounts, rest) =
+            split_batch_item(rest, ctx.accounts.trade.batch_creator_accounts_len(&asset))?;
+
+        // Fees at the current price, the total is checked against the min price below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            0,
+            TakerSide::Sell,
+            if asset.royalty_enforced {
+                Some(100)
+            } else {
+                optional_royalty_pct
+            },
+        )?;
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+
+        // The pool or its shared escrow must be able to pay for the whole batch.
+        if total_fees.price > funds_available {
+            throw_err!(ErrorCode::InsufficientPoolFunds);
+        }
+
+        token_accounts.init_ata(pool_ta_info, &pool_info, mint_info)?;
+
+        let mint = Box::new(InterfaceAccount::<Mint>::try_from(mint_info)?);
+        let taker_ta = Box::new(InterfaceAccount::<TokenAccount>::try_from(taker_ta_info)?);
+        let pool_ta = Box::new(InterfaceAccount::<TokenAccount>::try_from(pool_ta_info)?);
+        let metadata = UncheckedAccount::try_from(&item[1]);
+        let edition = UncheckedAccount::try_from(&item[2]);
+        let taker_token_record = optional_account(&item[6]).map(UncheckedAccount::try_from);
+        let pool_token_record = optional_account(&item[7]).map(UncheckedAccount::try_from);
+        let authorization_rules = optional_account(&item[8]).map(UncheckedAccount::try_from);
+
+        // Transfer the NFT from the seller to the pool.
+        transfer(
+            TransferArgs {
+                payer: &taker,
+                source: &taker,
+                source_ata: &taker_ta,
+                destination: &pool_info,
+                destination_ata: &pool_ta,
+                mint: &mint,
+                metadata: &metadata,
+                edition: &edition,
+                system_program: &ctx.accounts.system_program,
+                spl_token_program: &ctx.accounts.token_program,
+                spl_ata_program: &ctx.accounts.associated_token_program,
+                token_metadata_program: ctx.accounts.token_metadata_program.as_ref(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.as_ref(),
+                source_token_record: taker_token_record.as_ref(),
+                destination_token_record: pool_token_record.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+                authorization_rules_program: ctx.accounts.authorization_rules_program.as_ref(),
+                authorization_data: authorization_data.clone().map(AuthorizationData::from),
+                delegate: None,
+            },
+            None,
+        )?;
+
+        // Close seller ATA to return rent to the seller.
+        token_interface::close_account(
+            ctx.accounts
+                .trade
+                .close_taker_ata_ctx(token_program.clone(), taker_ta_info.clone()),
+        )?;
+
+        //create nft receipt for trade pool
+        init_nft_receipt(
+            receipt_info,
+            asset.pubkey,
+            pool_key,
+            &taker,
+            &system_program,
+        )?;
+
+        royalties.push(NftRoyalties::new(&asset, &fees, creator_accounts));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next NFT.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Sell, &fees)?;
+
+        accounts = rest;
+    }
+
+    if assets.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price the seller receives isn't lower than the min price the user specified.
+    let total_price = unwrap_checked!({
+        total_fees
+            .price
+            .checked_sub(total_fees.mm_fee)?
+            .checked_sub(total_fees.creators_fee)
+    });
+
+    if total_price < min_total_price {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_batch_seller_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Sell,
+    )?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(assets, TakerSide::Sell, total_fees, creators_fee)
+}
@@ -113,6 +113,14 @@ pub fn process_withdraw_nft<'info>(
     let pool = &mut ctx.accounts.transfer.pool;
     pool.nfts_held = unwrap_int!(pool.nfts_held.checked_sub(1));
 
+    record_event(
+        TAmmEvent::WithdrawNftEvent(WithdrawNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )?;
+
     // Close the NFT receipt account.
     close_account(
         &mut ctx.accounts.nft_receipt.to_account_info(),
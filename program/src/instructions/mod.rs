@@ -1,28 +1,56 @@
 //! Program instruction handlers.
 pub mod admin;
+pub mod attach_shared_escrow;
+pub mod batch;
 pub mod close_expired_pool;
 pub mod close_pool;
+pub mod compressed;
+pub mod convert_pool;
+pub mod cosigner;
 pub mod create_pool;
+pub mod currency;
 pub mod deposit_sol;
+pub mod deposit_token;
+pub mod detach_shared_escrow;
 pub mod edit_pool;
 pub mod legacy;
 pub mod mplx_core;
+pub mod set_pool_status;
+pub mod set_trait_requirements;
 pub mod shared_accounts;
 pub mod t22;
+pub mod transfer_pool;
+pub mod upgrade_pool;
 pub mod withdraw_sol;
+pub mod withdraw_token;
+pub mod wns;
 
 pub use admin::*;
 use anchor_spl::token::Mint;
+pub use attach_shared_escrow::*;
+pub use batch::*;
 pub use close_expired_pool::*;
 pub use close_pool::*;
+pub use compressed::*;
+pub use convert_pool::*;
+pub use cosigner::*;
 pub use create_pool::*;
+pub use currency::*;
 pub use deposit_sol::*;
+pub use deposit_token::*;
+pub use detach_shared_escrow::*;
 pub use edit_pool::*;
 pub use legacy::*;
 pub use mplx_core::*;
+pub use set_pool_status::*;
+pub use set_trait_requirements::*;
 pub use shared_accounts::*;
 pub use t22::*;
+pub use transfer_pool::*;
+pub use upgrade_pool::*;
 pub use withdraw_sol::*;
+pub use withdraw_token::*;
+pub use wns::*;
 
 use crate::{error::ErrorCode, *};
 use anchor_lang::prelude::*;
@@ -63,7 +91,7 @@ pub fn assert_decode_mint_proof(
 pub fn assert_decode_mint_proof_v2(
     whitelist: &Account<WhitelistV2>,
     nft_mint: &Pubkey,
-    mint_proof: &UncheckedAccount,
+    mint_proof: &AccountInfo,
 ) -> Result<Box<MintProofV2>> {
     let (key, _) = Pubkey::find_program_address(
         &[
@@ -86,8 +114,11 @@ pub fn assert_decode_mint_proof_v2(
     Ok(mint_proof)
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Fees {
+    /// The pool price the fees were calculated from.
+    pub price: u64,
+    pub mm_fee: u64,
     pub taker_fee: u64,
     pub tamm_fee: u64,
     pub maker_broker_fee: u64,
@@ -95,6 +126,21 @@ pub struct Fees {
     pub creators_fee: u64,
 }
 
+impl Fees {
+    /// Adds up the fees of two trades, so trades of several NFTs can be settled at once.
+    pub fn checked_add(&self, other: &Fees) -> Option<Fees> {
+        Some(Fees {
+            price: self.price.checked_add(other.price)?,
+            mm_fee: self.mm_fee.checked_add(other.mm_fee)?,
+            taker_fee: self.taker_fee.checked_add(other.taker_fee)?,
+            tamm_fee: self.tamm_fee.checked_add(other.tamm_fee)?,
+            maker_broker_fee: self.maker_broker_fee.checked_add(other.maker_broker_fee)?,
+            taker_broker_fee: self.taker_broker_fee.checked_add(other.taker_broker_fee)?,
+            creators_fee: self.creators_fee.checked_add(other.creators_fee)?,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct AmmFees {
     pub taker_fee: u64,
@@ -6,6 +6,7 @@ use super::*;
 #[derive(Accounts)]
 pub struct BuyNftCore<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// Metaplex core shared accounts.
@@ -42,19 +43,19 @@ pub fn process_buy_nft_core<'info>(
     max_amount: u64,
 ) -> Result<()> {
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
     let taker = ctx.accounts.trade.taker.to_account_info();
     let pool = ctx.accounts.trade.pool.to_account_info();
-    let owner = ctx.accounts.trade.owner.to_account_info();
 
     let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
+        &asset,
         max_amount,
         TakerSide::Buy,
         Some(100), // royalties enforced
     )?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
     let owner_pubkey = ctx.accounts.trade.owner.key();
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -73,7 +74,8 @@ pub fn process_buy_nft_core<'info>(
         .collection(ctx.accounts.core.collection.as_ref().map(|c| c.as_ref()))
         .invoke_signed(signer_seeds)?;
 
-    ctx.accounts
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_buyer_fees(asset, fees, ctx.remaining_accounts)?;
 
@@ -83,21 +85,13 @@ pub fn process_buy_nft_core<'info>(
         &mut ctx.accounts.trade.owner.to_account_info(),
     )?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Buy,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Buy, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Buy, fees, creators_fee)?;
 
-    try_autoclose_pool(
-        &ctx.accounts.trade.pool,
-        ctx.accounts.trade.rent_payer.to_account_info(),
-        owner,
-        ctx.accounts
-            .trade
-            .shared_escrow
-            .as_ref()
-            .map(|escrow| escrow.to_account_info())
-            .as_ref(),
-    )
+    ctx.accounts.trade.try_autoclose_pool()
 }
@@ -0,0 +1,140 @@
+//! Buy several Metaplex Core assets from a NFT or Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per asset bought, while the fees of all assets
+//! are paid in aggregated transfers and checked against a single maximum total amount.
+//! Royalties are paid to the creators of each asset.
+
+use super::*;
+
+use crate::error::ErrorCode;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct BuyNftsCore<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The MPL Core collection of the assets, if they belong to one.
+    /// CHECK: validated on instruction handler
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    /// The MPL Core program.
+    /// CHECK: address constraint is checked here
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each asset bought: [asset, nft_receipt] (both writable), followed by the royalty
+    //      creator accounts of the asset (each creator followed by its currency token account
+    //      for SPL pools)
+}
+
+/// Buy several MPL Core assets from a NFT or Trade pool.
+pub fn process_buy_nfts_core<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNftsCore<'info>>,
+    // Max vs exact so we can add slippage later.
+    max_total_amount: u64,
+) -> Result<()> {
+    ctx.accounts.trade.validate_buy()?;
+
+    let collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|c| c.to_account_info());
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let owner = ctx.accounts.trade.owner.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::new();
+    let mut assets = Vec::new();
+    let mut accounts = ctx.remaining_accounts;
+
+    while !accounts.is_empty() {
+        let (item, rest) = split_batch_item(accounts, 2)?;
+        let (asset_info, receipt_info) = (&item[0], &item[1]);
+
+        // Each purchase counts towards the max taker buy count.
+        ctx.accounts.trade.pool.taker_allowed_to_buy()?;
+
+        let asset = validate_core_amm_asset(asset_info, collection.as_ref())?;
+        check_nft_receipt(receipt_info, &asset.pubkey, &pool_key)?;
+
+        let (creator_accounts, rest) =
+            split_batch_item(rest, ctx.accounts.trade.batch_creator_accounts_len(&asset))?;
+
+        // Fees at the current price, the total is checked against the max amount below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            u64::MAX,
+            TakerSide::Buy,
+            Some(100), // royalties enforced
+        )?;
+
+        // Transfer the asset from the pool to the buyer.
+        let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.trade.pool.seeds()];
+
+        TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program)
+            .asset(asset_info)
+            .authority(Some(&pool_info))
+            .new_owner(&taker)
+            .payer(&taker)
+            .collection(collection.as_ref())
+            .invoke_signed(signer_seeds)?;
+
+        // Close the NFT receipt account.
+        close_account(&mut receipt_info.clone(), &mut owner.clone())?;
+
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+        royalties.push(NftRoyalties::new(&asset, &fees, creator_accounts));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next asset.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Buy, &fees)?;
+
+        accounts = rest;
+    }
+
+    if assets.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price + royalties + mm_fee doesn't exceed the max amount the user specified.
+    let total_amount = unwrap_checked!({
+        total_fees
+            .price
+            .checked_add(total_fees.mm_fee)?
+            .checked_add(total_fees.creators_fee)
+    });
+
+    if total_amount > max_total_amount {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_batch_buyer_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Buy,
+    )?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(assets, TakerSide::Buy, total_fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
@@ -37,6 +37,8 @@ impl<'info> DepositNftCore<'info> {
             throw_err!(ErrorCode::ExpiredPool);
         }
 
+        self.transfer.pool.can_hold_more_nfts()?;
+
         let asset = self.core.validate_asset()?;
 
         self.transfer.verify_whitelist(&asset)?;
@@ -71,5 +73,11 @@ pub fn process_deposit_nft_core<'info>(
         pool: ctx.accounts.transfer.pool.key(),
     };
 
-    Ok(())
+    record_event(
+        TAmmEvent::DepositNftEvent(DepositNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )
 }
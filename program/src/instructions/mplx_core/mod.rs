@@ -1,18 +1,26 @@
 pub mod buy_nft;
+pub mod buy_nfts;
 pub mod deposit_nft;
 pub mod sell_nft_token_pool;
 pub mod sell_nft_trade_pool;
+pub mod sell_nfts_token_pool;
+pub mod sell_nfts_trade_pool;
+pub mod withdraw_expired_nft;
 pub mod withdraw_nft;
 
 pub use self::buy_nft::*;
+pub use self::buy_nfts::*;
 pub use self::deposit_nft::*;
 pub use self::sell_nft_token_pool::*;
 pub use self::sell_nft_trade_pool::*;
+pub use self::sell_nfts_token_pool::*;
+pub use self::sell_nfts_trade_pool::*;
+pub use self::withdraw_expired_nft::*;
 pub use self::withdraw_nft::*;
 
-use crate::{MplCoreShared, MplCoreSharedBumps, NftDepositReceipt, *};
+use crate::{error::ErrorCode, MplCoreShared, MplCoreSharedBumps, NftDepositReceipt, *};
 
 use anchor_lang::prelude::*;
 use mpl_core::instructions::TransferV1CpiBuilder;
 use tensor_toolbox::close_account;
-use tensor_vipers::{throw_err, unwrap_int};
+use tensor_vipers::{throw_err, unwrap_checked, unwrap_int, unwrap_opt};
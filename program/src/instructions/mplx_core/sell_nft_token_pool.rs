@@ -8,6 +8,7 @@ use super::*;
 #[derive(Accounts)]
 pub struct SellNftTokenPoolCore<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// Metaplex core shared accounts.
@@ -32,18 +33,17 @@ pub fn process_sell_nft_token_pool_core<'info>(
     min_price: u64,
 ) -> Result<()> {
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
     let taker = ctx.accounts.trade.taker.to_account_info();
     let owner = ctx.accounts.trade.owner.to_account_info();
 
-    let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
-        min_price,
-        TakerSide::Sell,
-        Some(100),
-    )?;
+    let fees = ctx
+        .accounts
+        .trade
+        .calculate_fees(&asset, min_price, TakerSide::Sell, Some(100))?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
 
     // Transfer the asset from the seller directly to the pool owner.
     TransferV1CpiBuilder::new(&ctx.accounts.core.mpl_core_program)
@@ -54,25 +54,18 @@ pub fn process_sell_nft_token_pool_core<'info>(
         .collection(ctx.accounts.core.collection.as_ref().map(|c| c.as_ref()))
         .invoke()?;
 
-    ctx.accounts
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_seller_fees(asset, fees, ctx.remaining_accounts)?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Sell,
-    )?;
-
-    try_autoclose_pool(
-        &ctx.accounts.trade.pool,
-        ctx.accounts.trade.rent_payer.to_account_info(),
-        ctx.accounts.trade.owner.to_account_info(),
-        ctx.accounts
-            .trade
-            .shared_escrow
-            .as_ref()
-            .map(|escrow| escrow.to_account_info())
-            .as_ref(),
-    )
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
 }
@@ -11,6 +11,7 @@ use super::*;
 #[derive(Accounts)]
 pub struct SellNftTradePoolCore<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// Metaplex core shared accounts.
@@ -53,37 +54,39 @@ pub fn process_sell_nft_trade_pool_core<'info>(
     min_price: u64,
 ) -> Result<()> {
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
     let taker = ctx.accounts.trade.taker.to_account_info();
-    let pool = &ctx.accounts.trade.pool;
+    let pool = ctx.accounts.trade.pool.to_account_info();
 
-    let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
-        min_price,
-        TakerSide::Sell,
-        Some(100),
-    )?;
+    let fees = ctx
+        .accounts
+        .trade
+        .calculate_fees(&asset, min_price, TakerSide::Sell, Some(100))?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
 
     // Transfer the NFT from the seller to the pool.
     TransferV1CpiBuilder::new(&ctx.accounts.core.mpl_core_program)
         .asset(&ctx.accounts.core.asset)
         .authority(Some(&taker))
-        .new_owner(&pool.to_account_info())
+        .new_owner(&pool)
         .payer(&taker)
         .collection(ctx.accounts.core.collection.as_ref().map(|c| c.as_ref()))
         .invoke()?;
 
-    ctx.accounts
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_seller_fees(asset, fees, ctx.remaining_accounts)?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Sell,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
 
     //create nft receipt for trade pool
     **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
@@ -0,0 +1,153 @@
+//! Sell several Metaplex Core assets into a Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per asset sold, while the fees of all assets
+//! are paid in aggregated transfers and checked against a single minimum total price.
+//! The pool ends up as the owner of the assets and a deposit receipt is created for each.
+
+use super::*;
+
+use crate::error::ErrorCode;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SellNftsTradePoolCore<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The MPL Core collection of the assets, if they belong to one.
+    /// CHECK: validated on instruction handler
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    /// The MPL Core program.
+    /// CHECK: address constraint is checked here
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each asset sold: [asset, nft_receipt, mint_proof] (asset and receipt writable,
+    //      the program id as mint proof when not needed), followed by the royalty creator
+    //      accounts of the asset (each creator followed by its currency token account for SPL
+    //      pools)
+}
+
+/// Sell several MPL Core assets into a Trade pool.
+pub fn process_sell_nfts_trade_pool_core<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftsTradePoolCore<'info>>,
+    // Min vs exact so we can add slippage later.
+    min_total_price: u64,
+) -> Result<()> {
+    ctx.accounts.trade.validate_sell(&PoolType::Trade)?;
+
+    let collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|c| c.to_account_info());
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let funds_available = ctx.accounts.trade.sell_funds_available()?;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::new();
+    let mut assets = Vec::new();
+    let mut accounts = ctx.remaining_accounts;
+
+    while !accounts.is_empty() {
+        let (item, rest) = split_batch_item(accounts, 3)?;
+        let (asset_info, receipt_info, mint_proof_info) = (&item[0], &item[1], &item[2]);
+
+        // Each sale counts towards the max taker sell count and the max NFTs held.
+        ctx.accounts.trade.pool.taker_allowed_to_sell()?;
+        ctx.accounts.trade.pool.can_hold_more_nfts()?;
+
+        let asset = validate_core_amm_asset(asset_info, collection.as_ref())?;
+
+        ctx.accounts
+            .trade
+            .verify_whitelist_with_proof(&asset, optional_account(mint_proof_info))?;
+
+        let (creator_accounts, rest) =
+            split_batch_item(rest, ctx.accounts.trade.batch_creator_accounts_len(&asset))?;
+
+        // Fees at the current price, the total is checked against the min price below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            0,
+            TakerSide::Sell,
+            Some(100), // royalties enforced
+        )?;
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+
+        // The pool or its shared escrow must be able to pay for the whole batch.
+        if total_fees.price > funds_available {
+            throw_err!(ErrorCode::InsufficientPoolFunds);
+        }
+
+        // Transfer the asset from the seller to the pool.
+        TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program)
+            .asset(asset_info)
+            .authority(Some(&taker))
+            .new_owner(&pool_info)
+            .payer(&taker)
+            .collection(collection.as_ref())
+            .invoke()?;
+
+        //create nft receipt for trade pool
+        init_nft_receipt(
+            receipt_info,
+            asset.pubkey,
+            pool_key,
+            &taker,
+            &system_program,
+        )?;
+
+        royalties.push(NftRoyalties::new(&asset, &fees, creator_accounts));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next asset.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Sell, &fees)?;
+
+        accounts = rest;
+    }
+
+    if assets.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price the seller receives isn't lower than the min price the user specified.
+    let total_price = unwrap_checked!({
+        total_fees
+            .price
+            .checked_sub(total_fees.mm_fee)?
+            .checked_sub(total_fees.creators_fee)
+    });
+
+    if total_price < min_total_price {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_batch_seller_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Sell,
+    )?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(assets, TakerSide::Sell, total_fees, creators_fee)
+}
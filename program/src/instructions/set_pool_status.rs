@@ -0,0 +1,39 @@
+//! Pause or resume trading on a pool.
+use constants::CURRENT_POOL_VERSION;
+
+use crate::{error::ErrorCode, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SetPoolStatus<'info> {
+    /// The owner of the pool--must sign to pause or resume the pool.
+    pub owner: Signer<'info>,
+
+    /// The pool to pause or resume.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+/// Allows a pool owner to pause or resume buys and sells on a pool, without closing it.
+/// Deposits and withdrawals are not affected.
+pub fn process_set_pool_status(
+    ctx: Context<SetPoolStatus>,
+    buys_paused: bool,
+    sells_paused: bool,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    pool.buys_paused = buys_paused;
+    pool.sells_paused = sells_paused;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Set or clear the traits a pool requires from the MPL Core assets sold or deposited into it.
+use constants::CURRENT_POOL_VERSION;
+
+use crate::{error::ErrorCode, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SetTraitRequirements<'info> {
+    /// The rent payer of the pool, paying for the trait requirements account if it doesn't
+    /// exist yet and refunded when it is closed.
+    #[account(mut, address = pool.rent_payer @ ErrorCode::WrongRentPayer)]
+    pub rent_payer: Signer<'info>,
+
+    /// The owner of the pool--must sign to change the trait requirements.
+    pub owner: Signer<'info>,
+
+    /// The pool to set the trait requirements of.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The trait requirements of the pool.
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        space = TraitRequirements::SIZE,
+        seeds = [b"trait_requirements", pool.key().as_ref()],
+        bump,
+    )]
+    pub trait_requirements: Box<Account<'info, TraitRequirements>>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the traits required by a pool. Passing no traits removes the requirements and
+/// closes the trait requirements account.
+pub fn process_set_trait_requirements(
+    ctx: Context<SetTraitRequirements>,
+    traits: Vec<TraitRequirement>,
+) -> Result<()> {
+    if traits.is_empty() {
+        ctx.accounts.pool.requires_traits = false;
+
+        return ctx
+            .accounts
+            .trait_requirements
+            .close(ctx.accounts.rent_payer.to_account_info());
+    }
+
+    let trait_requirements = &mut ctx.accounts.trait_requirements;
+    trait_requirements.bump = [ctx.bumps.trait_requirements];
+    trait_requirements.pool = ctx.accounts.pool.key();
+    trait_requirements.set_traits(traits)?;
+
+    ctx.accounts.pool.requires_traits = true;
+
+    Ok(())
+}
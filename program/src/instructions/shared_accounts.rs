@@ -1,7 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{CloseAccount, Mint};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface},
+};
 use constants::CURRENT_POOL_VERSION;
 use escrow_program::instructions::assert_decode_margin_account as assert_decode_escrow_account;
+use mpl_bubblegum::{
+    programs::{MPL_BUBBLEGUM_ID, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    utils::get_asset_id,
+};
+use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
+    types::{Attributes, PluginType},
+};
 use mpl_token_metadata::{
     accounts::{MasterEdition, Metadata},
     types::{Collection, Creator, Key as MplKey},
@@ -15,7 +27,7 @@ use tensor_toolbox::{
     calc_creators_fee, calc_fees, escrow, is_royalty_enforced,
     metaplex_core::validate_core_asset,
     shard_num,
-    token_2022::validate_mint,
+    token_2022::{validate_mint, wns},
     token_metadata::{assert_decode_edition, assert_decode_master_edition, assert_decode_metadata},
     transfer_creators_fee, transfer_lamports, transfer_lamports_checked, CalcFeesArgs,
     CreatorFeeMode, FromAcc, FromExternal, BROKER_FEE_PCT, MAKER_BROKER_PCT, TAKER_FEE_BPS,
@@ -23,7 +35,9 @@ use tensor_toolbox::{
 use tensor_vipers::{throw_err, unwrap_checked, unwrap_int, unwrap_opt};
 use whitelist_program::{FullMerkleProof, WhitelistV2};
 
-use super::constants::TFEE_PROGRAM_ID;
+use super::constants::{
+    DISCRIMINATOR_SIZE, TFEE_PROGRAM_ID, WNS_DISTRIBUTION_PROGRAM_ID, WNS_PROGRAM_ID,
+};
 
 use crate::{error::ErrorCode, *};
 
@@ -72,6 +86,52 @@ pub struct TransferShared<'info> {
     /// merkle proof condition in the whitelist.
     /// CHECK: seeds and ownership are checked in assert_decode_mint_proof_v2.
     pub mint_proof: Option<UncheckedAccount<'info>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+
+    /// The traits required by the pool; required for deposits if the pool has trait requirements.
+    #[account(
+        seeds = [b"trait_requirements", pool.key().as_ref()],
+        bump = trait_requirements.bump[0],
+    )]
+    pub trait_requirements: Option<Box<Account<'info, TraitRequirements>>>,
+}
+
+/// Shared accounts for permissionless withdrawals of NFTs from expired pools, returning them
+/// to the pool owner.
+///
+/// Handlers using this struct must perform the following checks in the `pre_process_checks`
+/// function, as these are not covered by Anchor constraints:
+/// - validate_asset (all)
+#[derive(Accounts)]
+pub struct ExpiredTransferShared<'info> {
+    /// The account cranking the withdrawal, which pays for any account created in the process.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The owner of the pool and the NFT, which does not have to sign for expired pools.
+    /// CHECK: seeds in pool
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// The expired pool the NFT is withdrawn from.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+        constraint = pool.config.pool_type == PoolType::NFT || pool.config.pool_type == PoolType::Trade @ ErrorCode::WrongPoolType,
+        constraint = Clock::get()?.unix_timestamp > pool.expiry @ ErrorCode::PoolNotExpired,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
 }
 
 /// Shared accounts for trade instructions: buy & sell
@@ -84,6 +144,11 @@ pub struct TransferShared<'info> {
 /// - validate_buy (for buys)
 /// - validate_sell (for sells)
 /// - verify_whitelist (for sells)
+///
+/// The pause of the traded side depends on the handler, so handlers constrain their `trade` field
+/// with `Pool::is_paused`.
+///
+/// Pools priced in an SPL token additionally require the currency accounts at the end of the struct.
 #[derive(Accounts)]
 pub struct TradeShared<'info> {
     /// The owner of the pool and the buyer/recipient of the NFT.
@@ -130,7 +195,8 @@ pub struct TradeShared<'info> {
         constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
         constraint = pool.expiry >= Clock::get()?.unix_timestamp @ ErrorCode::ExpiredPool,
         constraint = maker_broker.as_ref().map(|c| c.key()).unwrap_or_default() == pool.maker_broker @ ErrorCode::WrongMakerBroker,
-        constraint = cosigner.as_ref().map(|c| c.key()).unwrap_or_default() == pool.cosigner @ ErrorCode::WrongCosigner,
+        // Without a cosigner signing the transaction, the trade must be checked for a cosigner attestation.
+        constraint = cosigner.as_ref().map(|c| c.key()).or(attestation_sysvar.as_ref().map(|_| pool.cosigner)).unwrap_or_default() == pool.cosigner @ ErrorCode::WrongCosigner,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
@@ -164,7 +230,8 @@ pub struct TradeShared<'info> {
     #[account(mut)]
     pub taker_broker: Option<UncheckedAccount<'info>>,
 
-    /// The optional cosigner account that must be passed in if the pool has a cosigner.
+    /// The optional cosigner account that must be passed in if the pool has a cosigner, unless the
+    /// trade carries an attestation signed by the cosigner.
     /// CHECK: Constraint checked on pool.
     pub cosigner: Option<Signer<'info>>,
 
@@ -177,13 +244,94 @@ pub struct TradeShared<'info> {
     pub escrow_program: Option<UncheckedAccount<'info>>,
 
     pub native_program: Program<'info, System>,
+
+    // --------------------------------------- SPL currency
+    /// The SPL mint the pool is priced in; required if the pool currency is not SOL.
+    #[account(
+        address = pool.currency @ ErrorCode::InvalidCurrency,
+        mint::token_program = currency_token_program,
+    )]
+    pub currency: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// The pool's currency token account, which escrows the pool's funds.
+    #[account(
+        mut,
+        associated_token::mint = currency,
+        associated_token::authority = pool,
+        associated_token::token_program = currency_token_program,
+    )]
+    pub pool_currency_ta: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The taker's currency token account, paying for buys and receiving sale proceeds.
+    #[account(
+        mut,
+        token::mint = currency,
+        token::authority = taker,
+        token::token_program = currency_token_program,
+    )]
+    pub taker_currency_ta: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The owner's currency token account, receiving NFT pool proceeds and non-compounded MM fees.
+    /// CHECK: address is checked and the account is created if needed when funds are sent to it.
+    #[account(mut)]
+    pub owner_currency_ta: Option<UncheckedAccount<'info>>,
+
+    /// The fee vault's currency token account.
+    /// CHECK: address is checked and the account is created if needed when funds are sent to it.
+    #[account(mut)]
+    pub fee_vault_currency_ta: Option<UncheckedAccount<'info>>,
+
+    /// The maker broker's currency token account.
+    /// CHECK: address is checked and the account is created if needed when funds are sent to it.
+    #[account(mut)]
+    pub maker_broker_currency_ta: Option<UncheckedAccount<'info>>,
+
+    /// The taker broker's currency token account.
+    /// CHECK: address is checked and the account is created if needed when funds are sent to it.
+    #[account(mut)]
+    pub taker_broker_currency_ta: Option<UncheckedAccount<'info>>,
+
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// The SPL associated token program, used to create currency token accounts.
+    pub currency_associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // --------------------------------------- Custom curve
+    /// The price ladder of the pool; required if the pool uses a custom curve.
+    #[account(
+        mut,
+        seeds = [b"price_ladder", pool.key().as_ref()],
+        bump = price_ladder.bump[0],
+    )]
+    pub price_ladder: Option<Account<'info, PriceLadder>>,
+
+    // --------------------------------------- Cosigner attestation
+    /// The sysvar instructions account; required to verify cosigner attestations when the
+    /// cosigner does not sign the transaction.
+    /// CHECK: address constraint is checked here
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub attestation_sysvar: Option<UncheckedAccount<'info>>,
+
+    // --------------------------------------- Trait requirements
+    /// The traits required by the pool; required for sells if the pool has trait requirements,
+    /// and closed along with the pool if it is auto-closed.
+    #[account(
+        mut,
+        seeds = [b"trait_requirements", pool.key().as_ref()],
+        bump = trait_requirements.bump[0],
+    )]
+    pub trait_requirements: Option<Box<Account<'info, TraitRequirements>>>,
 }
 
 impl<'info> TradeShared<'info> {
     pub fn validate(&self) -> Result<()> {
-        // If the pool has a cosigner, the cosigner account must be passed in.
+        // If the pool has a cosigner, the cosigner must sign or attestations must be verifiable.
         if self.pool.cosigner != Pubkey::default() {
-            require!(self.cosigner.is_some(), ErrorCode::MissingCosigner);
+            require!(
+                self.cosigner.is_some() || self.attestation_sysvar.is_some(),
+                ErrorCode::MissingCosigner
+            );
         }
 
         // If the pool has a maker broker set, the maker broker account must be passed in.
@@ -191,8 +339,274 @@ impl<'info> TradeShared<'info> {
             require!(self.maker_broker.is_some(), ErrorCode::MissingMakerBroker);
         }
 
+        // SPL currency pools settle through token accounts.
+        if self.pool.currency != Pubkey::default() {
+            require!(
+                self.currency.is_some()
+                    && self.pool_currency_ta.is_some()
+                    && self.taker_currency_ta.is_some(),
+                ErrorCode::MissingCurrencyAccounts
+            );
+        }
+
         Ok(())
     }
+
+    /// The SPL currency accounts of the pool, with the taker paying for any token account creation.
+    fn spl_currency(&self) -> Result<SplCurrency<'_, 'info>> {
+        let mint = unwrap_opt!(self.currency.as_ref(), ErrorCode::MissingCurrencyAccounts);
+        let token_program = unwrap_opt!(
+            self.currency_token_program.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        );
+
+        Ok(SplCurrency {
+            mint,
+            token_program: token_program.to_account_info(),
+            associated_token_program: self
+                .currency_associated_token_program
+                .as_ref()
+                .map(|p| p.to_account_info()),
+            system_program: self.native_program.to_account_info(),
+            payer: self.taker.to_account_info(),
+        })
+    }
+
+    /// Returns the currency token account of `wallet`, creating it if needed.
+    fn currency_ta_for(
+        &self,
+        currency: &SplCurrency<'_, 'info>,
+        token_account: Option<&UncheckedAccount<'info>>,
+        wallet: &AccountInfo<'info>,
+    ) -> Result<AccountInfo<'info>> {
+        let token_account =
+            unwrap_opt!(token_account, ErrorCode::MissingCurrencyAccounts).to_account_info();
+        currency.init_ata(&token_account, wallet)?;
+        Ok(token_account)
+    }
+
+    /// Broker fees go to the broker if specified, otherwise to the fee vault.
+    fn broker_currency_ta(
+        &self,
+        currency: &SplCurrency<'_, 'info>,
+        broker: Option<&UncheckedAccount<'info>>,
+        broker_ta: Option<&UncheckedAccount<'info>>,
+        fee_vault_ta: &AccountInfo<'info>,
+    ) -> Result<AccountInfo<'info>> {
+        match broker {
+            Some(broker) => self.currency_ta_for(currency, broker_ta, &broker.to_account_info()),
+            None => Ok(fee_vault_ta.clone()),
+        }
+    }
+
+    /// The balance the pool trades from: lamports for SOL pools, or the currency token
+    /// account balance for SPL pools.
+    pub fn pool_currency_balance(&self) -> Result<u64> {
+        if self.pool.currency == Pubkey::default() {
+            return Ok(self.pool.get_lamports());
+        }
+
+        let pool_ta = unwrap_opt!(
+            self.pool_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        );
+
+        token_balance(&pool_ta.to_account_info())
+    }
+
+    /// Updates the pool state after a trade settled with `fees`. `pool_initial_balance` must be
+    /// read with `pool_currency_balance` before any funds are moved.
+    pub fn update_pool_accounting(
+        &mut self,
+        pool_initial_balance: u64,
+        taker_side: TakerSide,
+        fees: &Fees,
+    ) -> Result<()> {
+        let pool_final_balance = self.pool_currency_balance()?;
+
+        update_pool_accounting(
+            &mut self.pool,
+            self.price_ladder.as_deref(),
+            pool_initial_balance,
+            pool_final_balance,
+            taker_side,
+            fees.mm_fee,
+        )
+    }
+
+    /// Records one NFT of a batch trade, moving the price one step along the curve for the next
+    /// NFT. Batch trades settle the pool amount once all NFTs are traded, while XYK prices come
+    /// from the pool's currency reserve, so the reserve of XYK pools is also moved by what the
+    /// pool receives or pays for this NFT.
+    pub fn record_batch_trade(&mut self, taker_side: TakerSide, fees: &Fees) -> Result<()> {
+        self.pool
+            .record_trade(taker_side, self.price_ladder.as_deref(), fees.mm_fee)?;
+
+        if self.pool.config.curve_type != CurveType::Xyk {
+            return Ok(());
+        }
+
+        // Compounded MM fees of Trade pools stay in the reserve.
+        let mm_fee_kept =
+            if self.pool.config.pool_type == PoolType::Trade && self.pool.config.mm_compound_fees {
+                fees.mm_fee
+            } else {
+                0
+            };
+
+        self.pool.amount = match (taker_side, self.pool.config.pool_type) {
+            // NFT pools send the proceeds to the owner.
+            (TakerSide::Buy, PoolType::NFT) => self.pool.amount,
+            (TakerSide::Buy, _) => unwrap_checked!({
+                self.pool
+                    .amount
+                    .checked_add(fees.price)?
+                    .checked_add(mm_fee_kept)
+            }),
+            (TakerSide::Sell, _) => unwrap_checked!({
+                self.pool
+                    .amount
+                    .checked_sub(fees.price)?
+                    .checked_add(mm_fee_kept)
+            }),
+        };
+
+        Ok(())
+    }
+
+    /// Updates the currency amount held by the pool after trades that were already recorded
+    /// with `record_batch_trade`, from the `pool_initial_amount` it held before them. Used by
+    /// batch instructions settling several NFTs at once.
+    pub fn update_pool_amount(
+        &mut self,
+        pool_initial_balance: u64,
+        pool_initial_amount: u64,
+        taker_side: TakerSide,
+    ) -> Result<()> {
+        let pool_final_balance = self.pool_currency_balance()?;
+
+        // Drop the reserve moves of XYK pools, the actual balance change is applied below.
+        self.pool.amount = pool_initial_amount;
+
+        update_pool_amount(
+            &mut self.pool,
+            pool_initial_balance,
+            pool_final_balance,
+            taker_side,
+        )
+    }
+
+    /// The funds the pool can pay sellers with: the shared escrow balance for pools on a
+    /// shared escrow, otherwise the currency amount held by the pool.
+    pub fn sell_funds_available(&self) -> Result<u64> {
+        if self.pool.shared_escrow == Pubkey::default() {
+            return Ok(self.pool.amount);
+        }
+
+        let shared_escrow = unwrap_opt!(self.shared_escrow.as_ref(), ErrorCode::BadSharedEscrow);
+
+        if *shared_escrow.key != self.pool.shared_escrow {
+            throw_err!(ErrorCode::BadSharedEscrow);
+        }
+
+        Ok(shared_escrow.lamports())
+    }
+
+    /// Records the settled trade with the resulting pool state. Must be called after the pool
+    /// accounting is updated and before the pool is auto-closed.
+    pub fn record_settlement(
+        &self,
+        assets: Vec<Pubkey>,
+        taker_side: TakerSide,
+        fees: Fees,
+        creators_fee_paid: u64,
+    ) -> Result<()> {
+        let shared_escrow = self.shared_escrow.as_ref().map(|e| e.to_account_info());
+
+        let pool_closed = should_autoclose_pool(
+            &self.pool,
+            shared_escrow.as_ref(),
+            self.price_ladder.as_deref(),
+        )?;
+
+        let event = TAmmEvent::BuySellEventV2(BuySellEventV2 {
+            assets,
+            taker: self.taker.key(),
+            taker_side,
+            price: fees.price,
+            taker_fee: fees.taker_fee,
+            mm_fee: fees.mm_fee,
+            tamm_fee: fees.tamm_fee,
+            maker_broker_fee: fees.maker_broker_fee,
+            taker_broker_fee: fees.taker_broker_fee,
+            creators_fee: creators_fee_paid,
+            price_offset: self.pool.price_offset,
+            nfts_held: self.pool.nfts_held,
+            amount: self.pool.amount,
+            pool_closed,
+        });
+
+        record_event(event, &self.amm_program, &self.pool)
+    }
+
+    /// Closes the pool if it can no longer trade, refunding rent to the rent payer.
+    pub fn try_autoclose_pool(&self) -> Result<()> {
+        let shared_escrow = self.shared_escrow.as_ref().map(|e| e.to_account_info());
+
+        if !should_autoclose_pool(
+            &self.pool,
+            shared_escrow.as_ref(),
+            self.price_ladder.as_deref(),
+        )? {
+            return Ok(());
+        }
+
+        if self.pool.currency != Pubkey::default() {
+            let pool_ta = unwrap_opt!(
+                self.pool_currency_ta.as_ref(),
+                ErrorCode::MissingCurrencyAccounts
+            );
+
+            close_pool_currency_ta(
+                &self.pool,
+                &self.spl_currency()?,
+                &pool_ta.to_account_info(),
+                self.owner_currency_ta
+                    .as_ref()
+                    .map(|ta| ta.to_account_info())
+                    .as_ref(),
+                self.rent_payer.to_account_info(),
+            )?;
+        }
+
+        // The price ladder is only used by this pool.
+        if let Some(price_ladder) = self.price_ladder.as_ref() {
+            price_ladder.close(self.rent_payer.to_account_info())?;
+        }
+
+        // Don't leave the trait requirements of the pool behind.
+        if self.pool.requires_traits {
+            let trait_requirements = unwrap_opt!(
+                self.trait_requirements.as_ref(),
+                ErrorCode::MissingTraitRequirements
+            );
+            trait_requirements.close(self.rent_payer.to_account_info())?;
+        }
+
+        record_event(
+            TAmmEvent::ClosePoolEvent(ClosePoolEvent {
+                reason: ClosePoolReason::AutoClose,
+            }),
+            &self.amm_program,
+            &self.pool,
+        )?;
+
+        close_pool(
+            &self.pool,
+            self.rent_payer.to_account_info(),
+            self.owner.to_account_info(),
+        )
+    }
 }
 
 impl<'info> TradeShared<'info> {
@@ -205,28 +619,52 @@ impl<'info> TradeShared<'info> {
 
         self.pool.taker_allowed_to_sell()?;
 
+        // Token pools pass the NFT through to the owner, only Trade pools hold it.
+        if *pool_type == PoolType::Trade {
+            self.pool.can_hold_more_nfts()?;
+        }
+
         self.validate()
     }
 
     pub fn pay_seller_fees(
-        &self,
+        &mut self,
         amm_asset: AmmAsset,
         fees: Fees,
         remaining_accounts: &[AccountInfo<'info>],
-    ) -> Result<()> {
+    ) -> Result<u64> {
+        self.pay_batch_seller_fees(
+            fees,
+            &[NftRoyalties::new(&amm_asset, &fees, remaining_accounts)],
+        )
+    }
+
+    /// Pays the seller of one or more NFTs, with `fees` adding up the fees of all NFTs and
+    /// the royalties paid to the creators of each NFT. Returns the royalties actually paid.
+    pub fn pay_batch_seller_fees(
+        &mut self,
+        fees: Fees,
+        royalties: &[NftRoyalties<'info>],
+    ) -> Result<u64> {
+        // The spend budget is checked before any funds leave the pool or shared escrow.
+        self.pool.record_spend(fees.price)?;
+
+        if self.pool.currency != Pubkey::default() {
+            return self.pay_seller_fees_spl(fees, royalties);
+        }
+
         let Fees {
+            price: current_price,
+            mm_fee,
             taker_fee: _,
             tamm_fee,
             maker_broker_fee,
             taker_broker_fee,
-            creators_fee,
+            creators_fee: _,
         } = fees;
 
         let pool = &self.pool;
         let owner_pubkey = self.owner.key();
-        let current_price = pool.current_price(TakerSide::Sell)?;
-
-        let mm_fee = pool.calc_mm_fee(current_price)?;
 
         let signer_seeds: &[&[&[u8]]] = &[&[
             b"pool",
@@ -305,23 +743,20 @@ impl<'info> TradeShared<'info> {
         )?;
         left_for_seller = unwrap_int!(left_for_seller.checked_sub(taker_broker_fee));
 
-        let remaining_accounts = &mut remaining_accounts.iter();
-
         // transfer royalties
-        let actual_creators_fee = transfer_creators_fee(
-            &amm_asset
-                .royalty_creators
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(Into::into)
-                .collect(),
-            remaining_accounts,
-            creators_fee,
-            &CreatorFeeMode::Sol {
-                from: &FromAcc::Pda(&self.pool.to_account_info()),
-            },
-        )?;
+        let mut actual_creators_fee = 0u64;
+
+        for nft in royalties {
+            let creators_fee_paid = transfer_creators_fee(
+                &nft.creators.iter().cloned().map(Into::into).collect(),
+                &mut nft.creator_accounts.iter(),
+                nft.creators_fee,
+                &CreatorFeeMode::Sol {
+                    from: &FromAcc::Pda(&self.pool.to_account_info()),
+                },
+            )?;
+            actual_creators_fee = unwrap_int!(actual_creators_fee.checked_add(creators_fee_paid));
+        }
 
         // Deduct royalties from the remaining amount left for the seller.
         left_for_seller = unwrap_int!(left_for_seller.checked_sub(actual_creators_fee));
@@ -338,7 +773,7 @@ impl<'info> TradeShared<'info> {
 
         // No MM fees.
         if pool.config.pool_type == PoolType::Token {
-            return Ok(());
+            return Ok(actual_creators_fee);
         }
 
         // If MM fees are compounded they go to the pool or shared escrow, otherwise to the owner.
@@ -378,7 +813,7 @@ impl<'info> TradeShared<'info> {
             )?;
         }
 
-        Ok(())
+        Ok(actual_creators_fee)
     }
 }
 
@@ -391,6 +826,8 @@ impl<'info> TradeShared<'info> {
             ErrorCode::WrongPoolType
         );
 
+        self.pool.taker_allowed_to_buy()?;
+
         self.validate()
     }
 
@@ -399,19 +836,36 @@ impl<'info> TradeShared<'info> {
         amm_asset: AmmAsset,
         fees: Fees,
         creator_accounts: &[AccountInfo<'info>],
-    ) -> Result<()> {
+    ) -> Result<u64> {
+        self.pay_batch_buyer_fees(
+            fees,
+            &[NftRoyalties::new(&amm_asset, &fees, creator_accounts)],
+        )
+    }
+
+    /// Collects the payment of the buyer of one or more NFTs, with `fees` adding up the fees of
+    /// all NFTs and the royalties paid to the creators of each NFT. Returns the royalties
+    /// actually paid.
+    pub fn pay_batch_buyer_fees(
+        &self,
+        fees: Fees,
+        royalties: &[NftRoyalties<'info>],
+    ) -> Result<u64> {
+        if self.pool.currency != Pubkey::default() {
+            return self.pay_buyer_fees_spl(fees, royalties);
+        }
+
         let Fees {
+            price: current_price,
+            mm_fee,
             taker_fee: _,
             tamm_fee,
             maker_broker_fee,
             taker_broker_fee,
-            creators_fee,
+            creators_fee: _,
         } = fees;
 
         let pool = &self.pool;
-        let current_price = pool.current_price(TakerSide::Buy)?;
-
-        let mm_fee = pool.calc_mm_fee(current_price)?;
 
         /*  **Transfer Fees**
         The buy price is the total price the buyer pays for buying the NFT from the pool.
@@ -483,23 +937,22 @@ impl<'info> TradeShared<'info> {
 
         // transfer royalties (on top of current price)
         // Buyer pays the royalty fee.
-        transfer_creators_fee(
-            &amm_asset
-                .royalty_creators
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(Into::into)
-                .collect(),
-            &mut creator_accounts.iter(),
-            creators_fee,
-            &CreatorFeeMode::Sol {
-                from: &FromAcc::External(&FromExternal {
-                    from: &self.taker,
-                    sys_prog: &self.native_program,
-                }),
-            },
-        )?;
+        let mut actual_creators_fee = 0u64;
+
+        for nft in royalties {
+            let creators_fee_paid = transfer_creators_fee(
+                &nft.creators.iter().cloned().map(Into::into).collect(),
+                &mut nft.creator_accounts.iter(),
+                nft.creators_fee,
+                &CreatorFeeMode::Sol {
+                    from: &FromAcc::External(&FromExternal {
+                        from: &self.taker,
+                        sys_prog: &self.native_program,
+                    }),
+                },
+            )?;
+            actual_creators_fee = unwrap_int!(actual_creators_fee.checked_add(creators_fee_paid));
+        }
 
         // Trade pools need to check compounding fees
         if matches!(pool.config.pool_type, PoolType::Trade) {
@@ -513,7 +966,236 @@ impl<'info> TradeShared<'info> {
             }
         }
 
-        Ok(())
+        Ok(actual_creators_fee)
+    }
+}
+
+impl<'info> TradeShared<'info> {
+    /// Same as `pay_seller_fees`, with the pool paying out of its currency token account.
+    /// Shared escrow pools are always SOL pools.
+    fn pay_seller_fees_spl(&self, fees: Fees, royalties: &[NftRoyalties<'info>]) -> Result<u64> {
+        let Fees {
+            price: current_price,
+            mm_fee,
+            taker_fee: _,
+            tamm_fee,
+            maker_broker_fee,
+            taker_broker_fee,
+            creators_fee: _,
+        } = fees;
+
+        let pool = &self.pool;
+
+        let signer_seeds: &[&[&[u8]]] = &[&pool.seeds()];
+
+        let currency = self.spl_currency()?;
+        let pool_info = pool.to_account_info();
+        let pool_ta = unwrap_opt!(
+            self.pool_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        )
+        .to_account_info();
+        let taker_ta = unwrap_opt!(
+            self.taker_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        )
+        .to_account_info();
+
+        let fee_vault_ta = self.currency_ta_for(
+            &currency,
+            self.fee_vault_currency_ta.as_ref(),
+            &self.fee_vault,
+        )?;
+
+        let mut left_for_seller = current_price;
+
+        // TAmm contract fee.
+        currency.transfer(
+            &pool_ta,
+            &fee_vault_ta,
+            &pool_info,
+            tamm_fee,
+            Some(signer_seeds),
+        )?;
+        left_for_seller = unwrap_int!(left_for_seller.checked_sub(tamm_fee));
+
+        // Broker fees. Transfer if accounts are specified, otherwise the funds go to the fee_vault.
+        let maker_broker_ta = self.broker_currency_ta(
+            &currency,
+            self.maker_broker.as_ref(),
+            self.maker_broker_currency_ta.as_ref(),
+            &fee_vault_ta,
+        )?;
+        currency.transfer(
+            &pool_ta,
+            &maker_broker_ta,
+            &pool_info,
+            maker_broker_fee,
+            Some(signer_seeds),
+        )?;
+        left_for_seller = unwrap_int!(left_for_seller.checked_sub(maker_broker_fee));
+
+        let taker_broker_ta = self.broker_currency_ta(
+            &currency,
+            self.taker_broker.as_ref(),
+            self.taker_broker_currency_ta.as_ref(),
+            &fee_vault_ta,
+        )?;
+        currency.transfer(
+            &pool_ta,
+            &taker_broker_ta,
+            &pool_info,
+            taker_broker_fee,
+            Some(signer_seeds),
+        )?;
+        left_for_seller = unwrap_int!(left_for_seller.checked_sub(taker_broker_fee));
+
+        // transfer royalties
+        let mut actual_creators_fee = 0u64;
+
+        for nft in royalties {
+            let creators_fee_paid = currency.transfer_creators_fee(
+                &nft.creators,
+                &nft.creator_accounts,
+                nft.creators_fee,
+                &pool_ta,
+                &pool_info,
+                Some(signer_seeds),
+            )?;
+            actual_creators_fee = unwrap_int!(actual_creators_fee.checked_add(creators_fee_paid));
+        }
+
+        // Deduct royalties from the remaining amount left for the seller.
+        left_for_seller = unwrap_int!(left_for_seller.checked_sub(actual_creators_fee));
+
+        // Taker pays MM fee, so we subtract it from the amount left for the seller.
+        left_for_seller = unwrap_int!(left_for_seller.checked_sub(mm_fee));
+
+        // Finally, transfer remainder to seller
+        currency.transfer(
+            &pool_ta,
+            &taker_ta,
+            &pool_info,
+            left_for_seller,
+            Some(signer_seeds),
+        )?;
+
+        // No MM fees for token pools; compounded MM fees are already in the pool.
+        if pool.config.pool_type == PoolType::Token || pool.config.mm_compound_fees {
+            return Ok(actual_creators_fee);
+        }
+
+        msg!("Sending mm fees to the owner");
+        let owner_ta = self.currency_ta_for(
+            &currency,
+            self.owner_currency_ta.as_ref(),
+            &self.owner.to_account_info(),
+        )?;
+        currency.transfer(&pool_ta, &owner_ta, &pool_info, mm_fee, Some(signer_seeds))?;
+
+        Ok(actual_creators_fee)
+    }
+
+    /// Same as `pay_buyer_fees`, with the taker paying from their currency token account.
+    fn pay_buyer_fees_spl(&self, fees: Fees, royalties: &[NftRoyalties<'info>]) -> Result<u64> {
+        let Fees {
+            price: current_price,
+            mm_fee,
+            taker_fee: _,
+            tamm_fee,
+            maker_broker_fee,
+            taker_broker_fee,
+            creators_fee: _,
+        } = fees;
+
+        let pool = &self.pool;
+
+        let currency = self.spl_currency()?;
+        let taker = self.taker.to_account_info();
+        let taker_ta = unwrap_opt!(
+            self.taker_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        )
+        .to_account_info();
+        let pool_ta = unwrap_opt!(
+            self.pool_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        )
+        .to_account_info();
+
+        // NFT pool proceeds and non-compounded MM fees go to the owner.
+        let owner_ta = if pool.config.pool_type == PoolType::NFT || !pool.config.mm_compound_fees {
+            Some(self.currency_ta_for(
+                &currency,
+                self.owner_currency_ta.as_ref(),
+                &self.owner.to_account_info(),
+            )?)
+        } else {
+            None
+        };
+
+        // Determine the destination: owner for NFT pools, the pool for Trade pools.
+        let destination = match pool.config.pool_type {
+            PoolType::NFT => unwrap_opt!(owner_ta.clone(), ErrorCode::MissingCurrencyAccounts),
+            PoolType::Trade => pool_ta.clone(),
+            PoolType::Token => unreachable!(),
+        };
+
+        let fee_vault_ta = self.currency_ta_for(
+            &currency,
+            self.fee_vault_currency_ta.as_ref(),
+            &self.fee_vault,
+        )?;
+
+        // Buyer is the taker and pays the taker fee: tamm_fee + maker_broker_fee + taker_broker_fee.
+        currency.transfer(&taker_ta, &fee_vault_ta, &taker, tamm_fee, None)?;
+
+        let maker_broker_ta = self.broker_currency_ta(
+            &currency,
+            self.maker_broker.as_ref(),
+            self.maker_broker_currency_ta.as_ref(),
+            &fee_vault_ta,
+        )?;
+        currency.transfer(&taker_ta, &maker_broker_ta, &taker, maker_broker_fee, None)?;
+
+        let taker_broker_ta = self.broker_currency_ta(
+            &currency,
+            self.taker_broker.as_ref(),
+            self.taker_broker_currency_ta.as_ref(),
+            &fee_vault_ta,
+        )?;
+        currency.transfer(&taker_ta, &taker_broker_ta, &taker, taker_broker_fee, None)?;
+
+        // Price always goes to the destination.
+        currency.transfer(&taker_ta, &destination, &taker, current_price, None)?;
+
+        // transfer royalties (on top of current price)
+        let mut actual_creators_fee = 0u64;
+
+        for nft in royalties {
+            let creators_fee_paid = currency.transfer_creators_fee(
+                &nft.creators,
+                &nft.creator_accounts,
+                nft.creators_fee,
+                &taker_ta,
+                &taker,
+                None,
+            )?;
+            actual_creators_fee = unwrap_int!(actual_creators_fee.checked_add(creators_fee_paid));
+        }
+
+        // Trade pools need to check compounding fees
+        if matches!(pool.config.pool_type, PoolType::Trade) {
+            let mm_fee_destination = match owner_ta {
+                // If MM fees are not compounded they go to the owner.
+                Some(ref owner_ta) => owner_ta,
+                // If MM fees are compounded they remain in the pool.
+                None => &pool_ta,
+            };
+            currency.transfer(&taker_ta, mm_fee_destination, &taker, mm_fee, None)?;
+        }
+
+        Ok(actual_creators_fee)
     }
 }
 
@@ -600,12 +1282,88 @@ pub struct MplCoreShared<'info> {
     pub mpl_core_program: UncheckedAccount<'info>,
 }
 
+#[derive(Accounts)]
+pub struct CompressedShared<'info> {
+    /// The Bubblegum tree config account of the merkle tree.
+    /// CHECK: validated by the Bubblegum program
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// The merkle tree holding the compressed NFT.
+    /// CHECK: validated by the Bubblegum program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// The SPL Noop program.
+    /// CHECK: address constraint is checked here
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// The SPL Account Compression program.
+    /// CHECK: address constraint is checked here
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// The Bubblegum program.
+    /// CHECK: address constraint is checked here
+    #[account(address = MPL_BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+}
+
 #[derive(Accounts)]
 pub struct T22Shared<'info> {
     /// The mint account of the NFT.
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 }
 
+#[derive(Accounts)]
+pub struct WnsShared<'info> {
+    /// The mint account of the NFT.
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The WNS approve account of the mint, which must be set before every transfer.
+    /// CHECK: seeds checked here, initialized by the WNS program
+    #[account(
+        mut,
+        seeds = [b"approve-account", mint.key().as_ref()],
+        bump,
+        seeds::program = wns_program.key(),
+    )]
+    pub approve_account: UncheckedAccount<'info>,
+
+    /// The extra account metas account of the WNS transfer hook.
+    /// CHECK: seeds checked here, initialized by the WNS program
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+        seeds::program = wns_program.key(),
+    )]
+    pub extra_metas: UncheckedAccount<'info>,
+
+    /// The WNS group member account, which ties the mint to its collection.
+    /// CHECK: seeds checked here, decoded in validate_asset
+    #[account(
+        seeds = [b"member", mint.key().as_ref()],
+        bump,
+        seeds::program = wns_program.key(),
+    )]
+    pub group_member: UncheckedAccount<'info>,
+
+    /// The WNS royalty distribution account of the collection.
+    /// CHECK: validated against the collection in validate_asset
+    #[account(mut)]
+    pub distribution: UncheckedAccount<'info>,
+
+    /// The WNS program.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_PROGRAM_ID)]
+    pub wns_program: UncheckedAccount<'info>,
+
+    /// The WNS royalty distribution program.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_DISTRIBUTION_PROGRAM_ID)]
+    pub distribution_program: UncheckedAccount<'info>,
+}
+
 pub struct AmmAsset {
     pub pubkey: Pubkey,
     pub collection: Option<Collection>,
@@ -614,6 +1372,26 @@ pub struct AmmAsset {
     pub royalty_creators: Option<Vec<Creator>>,
     pub seller_fee_basis_points: u16,
     pub royalty_enforced: bool,
+    /// Key/value pairs of the MPL Core Attributes plugin; `None` for other standards.
+    pub attributes: Option<Vec<(String, String)>>,
+}
+
+/// Royalties owed on one NFT of a trade, paid to its royalty creators through the accounts
+/// passed for them.
+pub struct NftRoyalties<'info> {
+    pub creators: Vec<Creator>,
+    pub creators_fee: u64,
+    pub creator_accounts: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> NftRoyalties<'info> {
+    pub fn new(asset: &AmmAsset, fees: &Fees, creator_accounts: &[AccountInfo<'info>]) -> Self {
+        Self {
+            creators: asset.royalty_creators.clone().unwrap_or_default(),
+            creators_fee: fees.creators_fee,
+            creator_accounts: creator_accounts.to_vec(),
+        }
+    }
 }
 
 pub trait ValidateAsset<'info> {
@@ -645,103 +1423,261 @@ impl<'info> ValidateAsset<'info> for T22Shared<'info> {
             royalty_creators: creators,
             seller_fee_basis_points,
             royalty_enforced: true,
+            attributes: None,
         })
     }
 }
 
 impl<'info> ValidateAsset<'info> for MplxShared<'info> {
     fn validate_asset(&self) -> Result<AmmAsset> {
-        let metadata = assert_decode_metadata(&self.mint.key(), &self.metadata)?;
-
-        // Verify edition is valid, this should be a Master Edition or Edition.
-        let key = self.edition.try_borrow_data()?[0];
+        validate_mplx_amm_asset(&self.mint.key(), &self.metadata, &self.edition)
+    }
+}
 
-        match key {
-            k if k == MplKey::MasterEditionV1 as u8 || k == MplKey::MasterEditionV2 as u8 => {
-                assert_decode_master_edition(&self.edition)?;
-            }
-            k if k == MplKey::EditionV1 as u8 => {
-                assert_decode_edition(&self.edition)?;
-            }
-            _ => return Err(ErrorCode::InvalidEdition.into()),
+/// Validates a Metaplex legacy NFT or pNFT from its metadata and edition accounts and maps it
+/// to an `AmmAsset`.
+pub fn validate_mplx_amm_asset(
+    mint: &Pubkey,
+    metadata: &AccountInfo,
+    edition: &AccountInfo,
+) -> Result<AmmAsset> {
+    let metadata = assert_decode_metadata(mint, metadata)?;
+
+    // Verify edition is valid, this should be a Master Edition or Edition.
+    let key = edition.try_borrow_data()?[0];
+
+    match key {
+        k if k == MplKey::MasterEditionV1 as u8 || k == MplKey::MasterEditionV2 as u8 => {
+            assert_decode_master_edition(edition)?;
         }
-
-        let royalty_enforced = is_royalty_enforced(metadata.token_standard);
-        let verified_creators = metadata.creators.clone();
-
-        Ok(AmmAsset {
-            pubkey: self.mint.key(),
-            collection: metadata.collection,
-            whitelist_creators: verified_creators,
-            royalty_creators: metadata.creators,
-            seller_fee_basis_points: metadata.seller_fee_basis_points,
-            royalty_enforced,
-        })
+        k if k == MplKey::EditionV1 as u8 => {
+            assert_decode_edition(edition)?;
+        }
+        _ => return Err(ErrorCode::InvalidEdition.into()),
     }
+
+    let royalty_enforced = is_royalty_enforced(metadata.token_standard);
+    let verified_creators = metadata.creators.clone();
+
+    Ok(AmmAsset {
+        pubkey: *mint,
+        collection: metadata.collection,
+        whitelist_creators: verified_creators,
+        royalty_creators: metadata.creators,
+        seller_fee_basis_points: metadata.seller_fee_basis_points,
+        royalty_enforced,
+        attributes: None,
+    })
 }
 
 impl<'info> ValidateAsset<'info> for MplCoreShared<'info> {
     fn validate_asset(&self) -> Result<AmmAsset> {
-        let asset = validate_core_asset(
+        validate_core_amm_asset(
             &self.asset.to_account_info(),
             self.collection
                 .as_ref()
                 .map(|a| a.to_account_info())
                 .as_ref(),
-        )?;
+        )
+    }
+}
 
-        let collection = asset.collection.map(|address| Collection {
-            key: address,
-            verified: true, // mpl-core collections are always verified
-        });
+/// Validates a MPL Core asset and maps it to an `AmmAsset`.
+pub fn validate_core_amm_asset(
+    asset_info: &AccountInfo,
+    collection: Option<&AccountInfo>,
+) -> Result<AmmAsset> {
+    let asset = validate_core_asset(asset_info, collection)?;
+
+    // Assets without an Attributes plugin have no traits.
+    let attributes = fetch_plugin::<BaseAssetV1, Attributes>(asset_info, PluginType::Attributes)
+        .map(|(_, attributes, _)| {
+            attributes
+                .attribute_list
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value))
+                .collect()
+        })
+        .unwrap_or_default();
 
-        // Map to legacy creators type
-        let whitelist_creators: Option<Vec<Creator>> =
-            asset.whitelist_creators.as_ref().map(|creators| {
-                creators
-                    .iter()
-                    .map(|c| Creator {
-                        address: c.address,
-                        share: 0, // No share on VerifiedCreators on MPL Core assets. This is separate from creators used in royalties.
-                        verified: c.verified,
-                    })
-                    .collect()
-            });
-
-        let royalty_creators = asset.royalty_creators.map(|creators| {
+    let collection = asset.collection.map(|address| Collection {
+        key: address,
+        verified: true, // mpl-core collections are always verified
+    });
+
+    // Map to legacy creators type
+    let whitelist_creators: Option<Vec<Creator>> =
+        asset.whitelist_creators.as_ref().map(|creators| {
             creators
-                .into_iter()
+                .iter()
                 .map(|c| Creator {
                     address: c.address,
-                    share: c.percentage,
-                    verified: false, // mpl-core does not have a concept of "verified" creator for royalties
+                    share: 0, // No share on VerifiedCreators on MPL Core assets. This is separate from creators used in royalties.
+                    verified: c.verified,
                 })
                 .collect()
         });
 
+    let royalty_creators = asset.royalty_creators.map(|creators| {
+        creators
+            .into_iter()
+            .map(|c| Creator {
+                address: c.address,
+                share: c.percentage,
+                verified: false, // mpl-core does not have a concept of "verified" creator for royalties
+            })
+            .collect()
+    });
+
+    Ok(AmmAsset {
+        pubkey: asset_info.key(),
+        collection,
+        whitelist_creators,
+        royalty_creators,
+        seller_fee_basis_points: asset.royalty_fee_bps,
+        royalty_enforced: true,
+        attributes: Some(attributes),
+    })
+}
+
+impl<'info> ValidateAsset<'info> for WnsShared<'info> {
+    fn validate_asset(&self) -> Result<AmmAsset> {
+        // Validate mint account and read the royalties of the collection.
+        let seller_fee_basis_points = wns::validate_mint(&self.mint.to_account_info())?;
+
+        // The group member account stores the collection (group) of the mint:
+        // discriminator (8) + mint (32) + group (32) + member number (4).
+        if *self.group_member.owner != WNS_PROGRAM_ID {
+            throw_err!(ErrorCode::InvalidWnsAccounts);
+        }
+
+        let data = self.group_member.try_borrow_data()?;
+        let group = Pubkey::try_from(
+            data.get(DISCRIMINATOR_SIZE + 32..DISCRIMINATOR_SIZE + 64)
+                .ok_or(ErrorCode::InvalidWnsAccounts)?,
+        )
+        .map_err(|_| ErrorCode::InvalidWnsAccounts)?;
+
+        // Royalties are paid in SOL, so the distribution account is the one for the default payment mint.
+        let (distribution, _) = Pubkey::find_program_address(
+            &[group.as_ref(), Pubkey::default().as_ref()],
+            &WNS_DISTRIBUTION_PROGRAM_ID,
+        );
+
+        if distribution != self.distribution.key() {
+            throw_err!(ErrorCode::InvalidWnsAccounts);
+        }
+
         Ok(AmmAsset {
-            pubkey: self.asset.key(),
-            collection,
-            whitelist_creators,
-            royalty_creators,
-            seller_fee_basis_points: asset.royalty_fee_bps,
+            pubkey: self.mint.key(),
+            collection: Some(Collection {
+                key: group,
+                verified: true, // group membership is managed by the WNS program
+            }),
+            whitelist_creators: None,
+            royalty_creators: None, // royalties are paid to the distribution account
+            seller_fee_basis_points,
             royalty_enforced: true,
+            attributes: None,
+        })
+    }
+}
+
+/// A compressed NFT as described by the instruction arguments.
+///
+/// The metadata is not authenticated on its own: it is hashed into the leaf that the
+/// Bubblegum transfer verifies against the merkle tree, so the transfer fails if it was tampered with.
+pub struct CompressedAsset<'a> {
+    pub merkle_tree: Pubkey,
+    pub args: &'a CompressedArgs,
+}
+
+impl<'a, 'info> ValidateAsset<'info> for CompressedAsset<'a> {
+    fn validate_asset(&self) -> Result<AmmAsset> {
+        let metadata = &self.args.metadata;
+
+        // Bubblegum only supports the original token program.
+        if metadata.token_program_version != TokenProgramVersionLocal::Original {
+            throw_err!(ErrorCode::InvalidCompressedNft);
+        }
+
+        let collection = metadata.collection.map(|c| Collection {
+            key: c.key,
+            verified: c.verified,
+        });
+
+        // Map to legacy creators type
+        let creators: Vec<Creator> = metadata
+            .creators
+            .iter()
+            .map(|c| Creator {
+                address: c.address,
+                verified: c.verified,
+                share: c.share,
+            })
+            .collect();
+
+        Ok(AmmAsset {
+            pubkey: get_asset_id(&self.merkle_tree, self.args.nonce),
+            collection,
+            whitelist_creators: Some(creators.clone()),
+            royalty_creators: Some(creators),
+            seller_fee_basis_points: metadata.seller_fee_basis_points,
+            royalty_enforced: false,
+            attributes: None,
         })
     }
 }
 
 impl<'info> TradeShared<'info> {
+    /// Checks that the cosigner of the pool approved trading `asset` on `taker_side`: either the
+    /// cosigner signed the transaction or an attestation of the cosigner for the taker was
+    /// verified earlier in the transaction.
+    ///
+    /// Returns the price modifier of the attestation, if any.
+    fn verify_cosigner(&mut self, asset: &Pubkey, taker_side: TakerSide) -> Result<PriceModifier> {
+        if self.pool.cosigner == Pubkey::default() || self.cosigner.is_some() {
+            return Ok(PriceModifier::None);
+        }
+
+        let instructions_sysvar = unwrap_opt!(
+            self.attestation_sysvar.as_ref(),
+            ErrorCode::MissingCosigner
+        );
+
+        let attestation = find_cosigner_attestation(
+            instructions_sysvar,
+            &self.pool.cosigner,
+            &self.pool.key(),
+            asset,
+            &self.taker.key(),
+            taker_side,
+        )?;
+
+        require!(
+            attestation.expiry >= Clock::get()?.unix_timestamp,
+            ErrorCode::ExpiredCosignerAttestation
+        );
+
+        self.pool.use_cosigner_nonce(attestation.nonce)?;
+
+        Ok(attestation.price_modifier)
+    }
+
     pub fn calculate_fees(
-        &self,
-        seller_fee_basis_points: u16,
+        &mut self,
+        asset: &AmmAsset,
         user_price: u64,
         taker_side: TakerSide,
         royalty_pct: Option<u16>,
     ) -> Result<Fees> {
+        let price_modifier = self.verify_cosigner(&asset.pubkey, taker_side)?;
+
         let pool = &self.pool;
 
-        // Calculate fees from the current price.
-        let current_price = pool.current_price(taker_side)?;
+        // Calculate fees from the current price, adjusted for the asset by the cosigner.
+        let current_price = price_modifier
+            .apply(pool.current_price_with(taker_side, self.price_ladder.as_deref())?)?;
 
         // This resolves to 0 for Token & NFT pools.
         let mm_fee = pool.calc_mm_fee(current_price)?;
@@ -759,19 +1695,8 @@ impl<'info> TradeShared<'info> {
             tnsr_discount: false,
         })?;
 
-        let creators_fee = calc_creators_fee(seller_fee_basis_points, current_price, royalty_pct)?;
-
-        // for keeping track of current price + fees charged (computed dynamically)
-        // we do this before PriceMismatch for easy debugging eg if there's a lot of slippage
-        let event = TAmmEvent::BuySellEvent(BuySellEvent {
-            current_price,
-            taker_fee,
-            mm_fee,
-            creators_fee,
-        });
-
-        // Self-CPI log the event before price check for easier debugging.
-        record_event(event, &self.amm_program, pool)?;
+        let creators_fee =
+            calc_creators_fee(asset.seller_fee_basis_points, current_price, royalty_pct)?;
 
         match taker_side {
             TakerSide::Buy => {
@@ -797,6 +1722,8 @@ impl<'info> TradeShared<'info> {
         }
 
         Ok(Fees {
+            price: current_price,
+            mm_fee,
             taker_fee,
             tamm_fee,
             maker_broker_fee,
@@ -806,9 +1733,19 @@ impl<'info> TradeShared<'info> {
     }
 
     pub fn verify_whitelist(&self, asset: &AmmAsset) -> Result<()> {
+        self.verify_whitelist_with_proof(asset, self.mint_proof.as_deref())
+    }
+
+    /// Same as `verify_whitelist`, with the mint proof passed in. Used by batch instructions,
+    /// where each NFT comes with its own mint proof.
+    pub fn verify_whitelist_with_proof(
+        &self,
+        asset: &AmmAsset,
+        mint_proof: Option<&AccountInfo<'info>>,
+    ) -> Result<()> {
         let whitelist = unwrap_opt!(self.whitelist.as_ref(), ErrorCode::WrongWhitelist);
 
-        let full_merkle_proof = if let Some(mint_proof) = &self.mint_proof {
+        let full_merkle_proof = if let Some(mint_proof) = mint_proof {
             let mint_proof = assert_decode_mint_proof_v2(whitelist, &asset.pubkey, mint_proof)?;
 
             let leaf = keccak::hash(asset.pubkey.as_ref());
@@ -826,7 +1763,9 @@ impl<'info> TradeShared<'info> {
             &asset.collection,
             &asset.whitelist_creators,
             &full_merkle_proof,
-        )
+        )?;
+
+        verify_traits(&self.pool, self.trait_requirements.as_deref(), asset)
     }
 
     pub fn close_pool_ata_ctx(
@@ -882,7 +1821,9 @@ impl<'info> TransferShared<'info> {
             &asset.collection,
             &asset.whitelist_creators,
             &full_merkle_proof,
-        )
+        )?;
+
+        verify_traits(&self.pool, self.trait_requirements.as_deref(), asset)
     }
 
     pub fn close_pool_ata_ctx(
@@ -916,6 +1857,65 @@ impl<'info> TransferShared<'info> {
     }
 }
 
+impl<'info> ExpiredTransferShared<'info> {
+    /// Pays the keeper its share of the keeper bounty for returning one of the NFTs held.
+    /// Must be called before the NFT is deducted from the pool.
+    pub fn pay_keeper_bounty(&mut self) -> Result<()> {
+        let share = self.pool.keeper_bounty_share();
+
+        if share > 0 {
+            transfer_lamports(
+                &self.pool.to_account_info(),
+                &self.payer.to_account_info(),
+                share,
+            )?;
+            self.pool.keeper_bounty = unwrap_int!(self.pool.keeper_bounty.checked_sub(share));
+        }
+
+        Ok(())
+    }
+
+    pub fn close_pool_ata_ctx(
+        &self,
+        token_program: AccountInfo<'info>,
+        pool_ta: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            token_program,
+            CloseAccount {
+                account: pool_ta,
+                destination: self.owner.to_account_info(),
+                authority: self.pool.to_account_info(),
+            },
+        )
+    }
+}
+
+/// Checks that the asset has the traits required by the pool, if any. Only MPL Core assets
+/// carry on-chain attributes, so other assets can't meet trait requirements.
+fn verify_traits(
+    pool: &Pool,
+    trait_requirements: Option<&TraitRequirements>,
+    asset: &AmmAsset,
+) -> Result<()> {
+    if !pool.requires_traits {
+        return Ok(());
+    }
+
+    let trait_requirements = unwrap_opt!(trait_requirements, ErrorCode::MissingTraitRequirements);
+    let attributes = unwrap_opt!(
+        asset.attributes.as_ref(),
+        ErrorCode::TraitRequirementsNotMet
+    );
+
+    require!(
+        trait_requirements.is_met_by(attributes),
+        ErrorCode::TraitRequirementsNotMet
+    );
+
+    Ok(())
+}
+
 pub enum TransferDirection {
     IntoPool,
     OutOfPool,
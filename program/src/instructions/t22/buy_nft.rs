@@ -2,10 +2,13 @@
 
 use super::*;
 
+use crate::error::ErrorCode;
+
 /// Instruction accounts.
 #[derive(Accounts)]
 pub struct BuyNftT22<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// T22 shared accounts.
@@ -67,15 +70,16 @@ pub fn process_buy_nft_t22<'info>(
     max_amount: u64,
 ) -> Result<()> {
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
     let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
+        &asset,
         max_amount,
         TakerSide::Buy,
         Some(100), // no optional royalties for now
     )?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
     let owner_pubkey = ctx.accounts.trade.owner.key();
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -113,7 +117,14 @@ pub fn process_buy_nft_t22<'info>(
             .with_signer(signer_seeds),
     )?;
 
-    ctx.accounts
+    let creator_accounts = royalty_accounts(
+        &ctx.accounts.trade.pool,
+        creator_accounts,
+        ctx.remaining_accounts,
+    );
+
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_buyer_fees(asset, fees, &creator_accounts)?;
 
@@ -123,21 +134,13 @@ pub fn process_buy_nft_t22<'info>(
         &mut ctx.accounts.trade.owner.to_account_info(),
     )?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Buy,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Buy, &fees)?;
 
-    try_autoclose_pool(
-        &ctx.accounts.trade.pool,
-        ctx.accounts.trade.rent_payer.to_account_info(),
-        ctx.accounts.trade.owner.to_account_info(),
-        ctx.accounts
-            .trade
-            .shared_escrow
-            .as_ref()
-            .map(|escrow| escrow.to_account_info())
-            .as_ref(),
-    )
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Buy, fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
 }
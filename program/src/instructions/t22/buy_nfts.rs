@@ -0,0 +1,161 @@
+//! Buy several Token22 NFTs from a NFT or Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per NFT bought, while the fees of all NFTs
+//! are paid in aggregated transfers and checked against a single maximum total amount.
+//! Royalties are paid to the creators of each NFT.
+
+use super::*;
+
+use crate::error::ErrorCode;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct BuyNftsT22<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The SPL Token program for the Mint and ATAs.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each NFT bought: [mint, nft_receipt, pool_ta, taker_ta], followed by the
+    //      `transfer_hook_accounts` accounts of the NFT for the royalties transfer hook
+}
+
+/// Buy several Token22 NFTs from a NFT or Trade pool.
+pub fn process_buy_nfts_t22<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNftsT22<'info>>,
+    // Max vs exact so we can add slippage later.
+    max_total_amount: u64,
+    // Number of transfer hook accounts of each NFT, in the order of the NFTs.
+    transfer_hook_accounts: Vec<u8>,
+) -> Result<()> {
+    ctx.accounts.trade.validate_buy()?;
+
+    if transfer_hook_accounts.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let owner = ctx.accounts.trade.owner.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    let token_accounts = BatchTokenAccounts {
+        payer: taker.clone(),
+        token_program: token_program.clone(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::with_capacity(transfer_hook_accounts.len());
+    let mut assets = Vec::with_capacity(transfer_hook_accounts.len());
+    let mut accounts = ctx.remaining_accounts;
+
+    for hook_accounts_len in transfer_hook_accounts {
+        let (item, rest) = split_batch_item(accounts, 4)?;
+        let (hook_accounts, rest) = split_batch_item(rest, hook_accounts_len.into())?;
+        let (mint_info, pool_ta_info, taker_ta_info) = (&item[0], &item[2], &item[3]);
+
+        // Each purchase counts towards the max taker buy count.
+        ctx.accounts.trade.pool.taker_allowed_to_buy()?;
+
+        let asset = T22Shared {
+            mint: Box::new(InterfaceAccount::try_from(mint_info)?),
+        }
+        .validate_asset()?;
+        check_nft_receipt(&item[1], &asset.pubkey, &pool_key)?;
+
+        // Fees at the current price, the total is checked against the max amount below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            u64::MAX,
+            TakerSide::Buy,
+            Some(100), // no optional royalties for now
+        )?;
+
+        token_accounts.init_ata(taker_ta_info, &taker, mint_info)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.trade.pool.seeds()];
+
+        // Transfer from the pool to the buyer.
+        let creator_accounts = transfer(
+            &TransferArgs {
+                from: pool_ta_info.clone(),
+                to: taker_ta_info.clone(),
+                authority: pool_info.clone(),
+                mint: mint_info.clone(),
+                token_program: token_program.clone(),
+            },
+            hook_accounts,
+            &asset.royalty_creators,
+            Some(signer_seeds),
+        )?;
+
+        // close nft escrow account
+        token_interface::close_account(
+            ctx.accounts
+                .trade
+                .close_pool_ata_ctx(token_program.clone(), pool_ta_info.clone())
+                .with_signer(signer_seeds),
+        )?;
+
+        // Close the NFT receipt account.
+        close_account(&mut item[1].clone(), &mut owner.clone())?;
+
+        let creator_accounts =
+            royalty_accounts(&ctx.accounts.trade.pool, creator_accounts, hook_accounts);
+
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+        royalties.push(NftRoyalties::new(&asset, &fees, &creator_accounts));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next NFT.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Buy, &fees)?;
+
+        accounts = rest;
+    }
+
+    if !accounts.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price + royalties + mm_fee doesn't exceed the max amount the user specified.
+    let total_amount = unwrap_checked!({
+        total_fees
+            .price
+            .checked_add(total_fees.mm_fee)?
+            .checked_add(total_fees.creators_fee)
+    });
+
+    if total_amount > max_total_amount {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    let creators_fee = ctx
+        .accounts
+        .trade
+        .pay_batch_buyer_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Buy,
+    )?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(assets, TakerSide::Buy, total_fees, creators_fee)?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
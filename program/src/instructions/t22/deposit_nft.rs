@@ -62,6 +62,8 @@ impl<'info> DepositNftT22<'info> {
             throw_err!(ErrorCode::ExpiredPool);
         }
 
+        self.transfer.pool.can_hold_more_nfts()?;
+
         let asset = self.t22.validate_asset()?;
 
         self.transfer.verify_whitelist(&asset)?;
@@ -114,5 +116,11 @@ pub fn process_deposit_nft_t22<'info>(
         pool: ctx.accounts.transfer.pool.key(),
     };
 
-    Ok(())
+    record_event(
+        TAmmEvent::DepositNftEvent(DepositNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )
 }
@@ -1,13 +1,21 @@
 pub mod buy_nft;
+pub mod buy_nfts;
 pub mod deposit_nft;
 pub mod sell_nft_token_pool;
 pub mod sell_nft_trade_pool;
+pub mod sell_nfts_token_pool;
+pub mod sell_nfts_trade_pool;
+pub mod withdraw_expired_nft;
 pub mod withdraw_nft;
 
 pub use self::buy_nft::*;
+pub use self::buy_nfts::*;
 pub use self::deposit_nft::*;
 pub use self::sell_nft_token_pool::*;
 pub use self::sell_nft_trade_pool::*;
+pub use self::sell_nfts_token_pool::*;
+pub use self::sell_nfts_trade_pool::*;
+pub use self::withdraw_expired_nft::*;
 pub use self::withdraw_nft::*;
 
 use crate::*;
@@ -19,17 +27,17 @@ use anchor_spl::{
 };
 use mpl_token_metadata::types::Creator;
 use tensor_toolbox::{close_account, token_2022::transfer::transfer_checked};
-use tensor_vipers::{throw_err, unwrap_int};
+use tensor_vipers::{throw_err, unwrap_checked, unwrap_int, unwrap_opt};
 
-struct TransferArgs<'info> {
-    from: AccountInfo<'info>,
-    to: AccountInfo<'info>,
-    authority: AccountInfo<'info>,
-    mint: AccountInfo<'info>,
-    token_program: AccountInfo<'info>,
+pub(crate) struct TransferArgs<'info> {
+    pub from: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
 }
 
-fn transfer<'info>(
+pub(crate) fn transfer<'info>(
     args: &TransferArgs<'info>,
     remaining_accounts: &[AccountInfo<'info>],
     royalty_creators: &Option<Vec<Creator>>,
@@ -71,3 +79,17 @@ fn transfer<'info>(
 
     Ok(creator_accounts)
 }
+
+/// Returns the accounts royalties are paid to: the creator accounts found by the transfer for SOL
+/// pools, or the remaining accounts (holding the creators' currency token accounts) for SPL pools.
+fn royalty_accounts<'info>(
+    pool: &Pool,
+    creator_accounts: Vec<AccountInfo<'info>>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Vec<AccountInfo<'info>> {
+    if pool.currency == Pubkey::default() {
+        creator_accounts
+    } else {
+        remaining_accounts.to_vec()
+    }
+}
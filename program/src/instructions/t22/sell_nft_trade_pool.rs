@@ -7,10 +7,13 @@
 
 use super::*;
 
+use crate::error::ErrorCode;
+
 /// Instruction accounts
 #[derive(Accounts)]
 pub struct SellNftTradePoolT22<'info> {
     /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
     pub trade: TradeShared<'info>,
 
     /// T22 shared accounts.
@@ -78,15 +81,14 @@ pub fn process_sell_nft_trade_pool_t22<'info>(
     min_price: u64,
 ) -> Result<()> {
     let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
 
-    let fees = ctx.accounts.trade.calculate_fees(
-        asset.seller_fee_basis_points,
-        min_price,
-        TakerSide::Sell,
-        Some(100),
-    )?;
+    let fees = ctx
+        .accounts
+        .trade
+        .calculate_fees(&asset, min_price, TakerSide::Sell, Some(100))?;
 
-    let pool_initial_balance = ctx.accounts.trade.pool.get_lamports();
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
 
     // Transfer to the pool.
     let creator_accounts = transfer(
@@ -111,15 +113,24 @@ pub fn process_sell_nft_trade_pool_t22<'info>(
         ctx.accounts.taker_ta.to_account_info(),
     ))?;
 
-    ctx.accounts
+    let creator_accounts = royalty_accounts(
+        &ctx.accounts.trade.pool,
+        creator_accounts,
+        ctx.remaining_accounts,
+    );
+
+    let creators_fee = ctx
+        .accounts
         .trade
         .pay_seller_fees(asset, fees, &creator_accounts)?;
 
-    update_pool_accounting(
-        &mut ctx.accounts.trade.pool,
-        pool_initial_balance,
-        TakerSide::Sell,
-    )?;
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts
+        .trade
+        .record_settlement(vec![asset_id], TakerSide::Sell, fees, creators_fee)?;
 
     //create nft receipt for trade pool
     **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
@@ -0,0 +1,652 @@
+//! Transfer a pool to a new owner.
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{self, CloseAccount, Mint, Token2022, TokenAccount, TokenInterface},
+};
+use escrow_program::state::MarginAccount;
+use mpl_core::instructions::TransferV1CpiBuilder;
+use mpl_token_metadata::types::AuthorizationData;
+use tensor_toolbox::{
+    close_account,
+    token_metadata::{transfer, TransferArgs},
+    transfer_lamports,
+};
+use tensor_vipers::{throw_err, try_or_err, unwrap_opt};
+
+use self::constants::{CURRENT_POOL_VERSION, WNS_DISTRIBUTION_PROGRAM_ID, WNS_PROGRAM_ID};
+use super::{t22, wns};
+use crate::{error::ErrorCode, program::AmmProgram, *};
+
+/// Standard of an NFT held by a pool being transferred, which determines the remaining accounts
+/// used to migrate it to the new pool.
+///
+/// Compressed NFTs can't be migrated and must be withdrawn before the transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigratedNft {
+    /// `[asset, collection, receipt, new_receipt]`, where the program id is passed as the
+    /// collection of assets without one.
+    Core,
+    /// `[mint, pool_ta, new_pool_ta, receipt, new_receipt, metadata, edition, pool_token_record,
+    /// new_pool_token_record, authorization_rules]`, where the program id is passed for the
+    /// accounts a legacy NFT or pNFT doesn't use.
+    Legacy,
+    /// `[mint, pool_ta, new_pool_ta, receipt, new_receipt]`, followed by the `hook_accounts`
+    /// accounts of the royalty transfer hook.
+    T22 { hook_accounts: u8 },
+    /// `[mint, pool_ta, new_pool_ta, receipt, new_receipt, approve_account, extra_metas,
+    /// group_member, distribution]`
+    Wns,
+}
+
+impl MigratedNft {
+    /// Number of remaining accounts used to migrate the NFT.
+    fn accounts_len(&self) -> usize {
+        match self {
+            MigratedNft::Core => 4,
+            MigratedNft::Legacy => 10,
+            MigratedNft::T22 { hook_accounts } => 5 + usize::from(*hook_accounts),
+            MigratedNft::Wns => 9,
+        }
+    }
+}
+
+/// Transfer pool arguments.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct TransferPoolArgs {
+    /// The NFTs held by the pool, in the order of their accounts in remaining accounts.
+    pub nfts: Vec<MigratedNft>,
+    /// Authorization data of pNFTs with a rule set.
+    pub authorization_data: Option<AuthorizationDataLocal>,
+}
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct TransferPool<'info> {
+    /// The account that pays for the rent of the new pool. This will be stored on the new pool
+    /// so it can be refunded when the pool is closed.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    /// The rent payer of the old pool, refunded when the old pool is closed.
+    /// CHECK: constraint in pool
+    #[account(mut)]
+    pub old_rent_payer: UncheckedAccount<'info>,
+
+    /// The current owner of the pool--must sign to transfer the pool.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The new owner of the pool--must sign to accept the pool.
+    pub new_owner: Signer<'info>,
+
+    /// The pool to transfer, closed at the end of the instruction.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.rent_payer == old_rent_payer.key() @ ErrorCode::WrongRentPayer,
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The new pool, keeping the pool id of the old pool.
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Pool::SIZE,
+        seeds = [
+            b"pool",
+            new_owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub new_pool: Box<Account<'info, Pool>>,
+
+    /// The shared escrow of the new owner; required if the pool is attached to a shared escrow,
+    /// since the old owner's shared escrow cannot fund the new owner's pool.
+    #[account(
+        constraint = new_shared_escrow.owner == new_owner.key() @ ErrorCode::WrongOwner,
+    )]
+    pub new_shared_escrow: Option<Box<Account<'info, MarginAccount>>>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+
+    /// The SPL mint the pool is priced in; required to transfer SPL currency pools.
+    #[account(
+        address = pool.currency @ ErrorCode::InvalidCurrency,
+        mint::token_program = currency_token_program,
+    )]
+    pub currency: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// The currency token account of the old pool, closed once its funds are moved.
+    #[account(
+        mut,
+        associated_token::mint = currency,
+        associated_token::authority = pool,
+        associated_token::token_program = currency_token_program,
+    )]
+    pub pool_currency_ta: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The currency token account of the new pool.
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        associated_token::mint = currency,
+        associated_token::authority = new_pool,
+        associated_token::token_program = currency_token_program,
+    )]
+    pub new_pool_currency_ta: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The token program of the currency mint.
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// The SPL associated token program.
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// The price ladder of the old pool, closed along with it; required for custom curves.
+    #[account(
+        mut,
+        close = old_rent_payer,
+        seeds = [b"price_ladder", pool.key().as_ref()],
+        bump = price_ladder.bump[0],
+    )]
+    pub price_ladder: Option<Box<Account<'info, PriceLadder>>>,
+
+    /// The price ladder of the new pool, copied from the old one; required for custom curves.
+    #[account(
+        init,
+        payer = rent_payer,
+        space = PriceLadder::SIZE,
+        seeds = [b"price_ladder", new_pool.key().as_ref()],
+        bump,
+    )]
+    pub new_price_ladder: Option<Box<Account<'info, PriceLadder>>>,
+
+    /// The MPL Core program; required to migrate MPL Core assets held by the pool.
+    /// CHECK: address constraint is checked here
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+
+    /// The token program of legacy NFTs and pNFTs; required to migrate them.
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// The Token 2022 program; required to migrate T22 and WNS NFTs.
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+
+    /// The Token Metadata program; required to migrate legacy NFTs and pNFTs.
+    /// CHECK: address constraint is checked here
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// The sysvar instructions account; required to migrate pNFTs.
+    /// CHECK: address constraint is checked here
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// The Metaplex Token Authority Rules program; required to migrate pNFTs with a rule set.
+    /// CHECK: address constraint is checked here
+    #[account(address = MPL_TOKEN_AUTH_RULES_ID)]
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// The WNS program; required to migrate WNS NFTs.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_PROGRAM_ID)]
+    pub wns_program: Option<UncheckedAccount<'info>>,
+
+    /// The WNS royalty distribution program; required to migrate WNS NFTs.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_DISTRIBUTION_PROGRAM_ID)]
+    pub distribution_program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> TransferPool<'info> {
+    fn validate(&self, args: &TransferPoolArgs) -> Result<()> {
+        // Every NFT held must be migrated, otherwise it would be stranded in the closed pool.
+        require!(
+            args.nfts.len() == self.pool.nfts_held as usize,
+            ErrorCode::NftsNotTransferable
+        );
+
+        // The pool keeps its funding source: shared escrow pools move to a shared escrow of
+        // the new owner and other pools can't be attached to one here.
+        require!(
+            self.new_shared_escrow.is_some() == (self.pool.shared_escrow != Pubkey::default()),
+            ErrorCode::BadSharedEscrow
+        );
+
+        // Trait requirements are tied to the old pool and must be set again on the new one.
+        require!(
+            !self.pool.requires_traits,
+            ErrorCode::PoolHasTraitRequirements
+        );
+
+        if self.pool.config.curve_type == CurveType::Custom {
+            require!(
+                self.price_ladder.is_some() && self.new_price_ladder.is_some(),
+                ErrorCode::MissingPriceLadder
+            );
+        }
+
+        if self.pool.currency != Pubkey::default() {
+            require!(
+                self.currency.is_some()
+                    && self.pool_currency_ta.is_some()
+                    && self.new_pool_currency_ta.is_some()
+                    && self.currency_token_program.is_some(),
+                ErrorCode::MissingCurrencyAccounts
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Moves the SPL currency of the old pool to the new pool and closes the old pool's
+    /// currency token account.
+    fn transfer_currency(&self) -> Result<()> {
+        let mint = unwrap_opt!(self.currency.as_ref(), ErrorCode::MissingCurrencyAccounts);
+        let pool_ta = unwrap_opt!(
+            self.pool_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        );
+        let new_pool_ta = unwrap_opt!(
+            self.new_pool_currency_ta.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        );
+        let token_program = unwrap_opt!(
+            self.currency_token_program.as_ref(),
+            ErrorCode::MissingCurrencyAccounts
+        );
+
+        let currency = SplCurrency {
+            mint,
+            token_program: token_program.to_account_info(),
+            associated_token_program: None,
+            system_program: self.system_program.to_account_info(),
+            payer: self.rent_payer.to_account_info(),
+        };
+
+        let signer_seeds: &[&[&[u8]]] = &[&self.pool.seeds()];
+        let pool_ta_info = pool_ta.to_account_info();
+
+        currency.transfer(
+            &pool_ta_info,
+            &new_pool_ta.to_account_info(),
+            &self.pool.to_account_info(),
+            token_balance(&pool_ta_info)?,
+            Some(signer_seeds),
+        )?;
+
+        close_pool_currency_ta(
+            &self.pool,
+            &currency,
+            &pool_ta_info,
+            None,
+            self.old_rent_payer.to_account_info(),
+        )
+    }
+
+    /// Moves the NFTs of the old pool to the new pool, replacing their deposit receipts, and
+    /// returns the number of NFTs migrated.
+    fn migrate_nfts(
+        &self,
+        args: &TransferPoolArgs,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<u32> {
+        let mut accounts = remaining_accounts;
+
+        for nft in &args.nfts {
+            if accounts.len() < nft.accounts_len() {
+                throw_err!(ErrorCode::InvalidBatchAccounts);
+            }
+
+            let (item, rest) = accounts.split_at(nft.accounts_len());
+
+            match nft {
+                MigratedNft::Core => self.migrate_core_asset(item)?,
+                MigratedNft::Legacy => {
+                    self.migrate_legacy_nft(item, args.authorization_data.clone())?
+                }
+                MigratedNft::T22 { .. } => self.migrate_t22_nft(item)?,
+                MigratedNft::Wns => self.migrate_wns_nft(item)?,
+            }
+
+            accounts = rest;
+        }
+
+        if !accounts.is_empty() {
+            throw_err!(ErrorCode::InvalidBatchAccounts);
+        }
+
+        Ok(try_or_err!(
+            u32::try_from(args.nfts.len()),
+            ErrorCode::ArithmeticError
+        ))
+    }
+
+    /// Moves an MPL Core asset to the new pool: `[asset, collection, receipt, new_receipt]`.
+    fn migrate_core_asset(&self, item: &'info [AccountInfo<'info>]) -> Result<()> {
+        let (asset_info, collection_info) = (&item[0], &item[1]);
+
+        let mpl_core_program = unwrap_opt!(
+            self.mpl_core_program.as_ref(),
+            ErrorCode::InvalidBatchAccounts
+        );
+
+        if *asset_info.owner != mpl_core::ID {
+            throw_err!(ErrorCode::InvalidBatchAccounts);
+        }
+
+        check_nft_receipt(&item[2], asset_info.key, &self.pool.key())?;
+
+        let pool_info = self.pool.to_account_info();
+        let signer_seeds: &[&[&[u8]]] = &[&self.pool.seeds()];
+
+        TransferV1CpiBuilder::new(mpl_core_program)
+            .asset(asset_info)
+            .authority(Some(&pool_info))
+            .new_owner(&self.new_pool.to_account_info())
+            .payer(&self.owner.to_account_info())
+            .collection(optional_account(collection_info))
+            .invoke_signed(signer_seeds)?;
+
+        self.replace_receipt(asset_info.key, &item[2], &item[3])
+    }
+
+    /// Moves a legacy NFT or pNFT to the new pool: `[mint, pool_ta, new_pool_ta, receipt,
+    /// new_receipt, metadata, edition, pool_token_record, new_pool_token_record,
+    /// authorization_rules]`.
+    fn migrate_legacy_nft(
+        &self,
+        item: &'info [AccountInfo<'info>],
+        authorization_data: Option<AuthorizationDataLocal>,
+    ) -> Result<()> {
+        let token_program =
+            unwrap_opt!(self.token_program.as_ref(), ErrorCode::InvalidBatchAccounts);
+        let associated_token_program = unwrap_opt!(
+            self.associated_token_program.as_ref(),
+            ErrorCode::InvalidBatchAccounts
+        );
+
+        self.prepare_token_nft(item, &token_program.to_account_info())?;
+
+        let mint = Box::new(InterfaceAccount::<Mint>::try_from(&item[0])?);
+        let pool_ta = Box::new(InterfaceAccount::<TokenAccount>::try_from(&item[1])?);
+        let new_pool_ta = Box::new(InterfaceAccount::<TokenAccount>::try_from(&item[2])?);
+        let metadata = UncheckedAccount::try_from(&item[5]);
+        let edition = UncheckedAccount::try_from(&item[6]);
+        let pool_token_record = optional_account(&item[7]).map(UncheckedAccount::try_from);
+        let new_pool_token_record = optional_account(&item[8]).map(UncheckedAccount::try_from);
+        let authorization_rules = optional_account(&item[9]).map(UncheckedAccount::try_from);
+
+        let signer_seeds: &[&[&[u8]]] = &[&self.pool.seeds()];
+
+        transfer(
+            TransferArgs {
+                payer: &self.rent_payer.to_account_info(),
+                source: &self.pool.to_account_info(),
+                source_ata: &pool_ta,
+                destination: &self.new_pool.to_account_info(),
+                destination_ata: &new_pool_ta,
+                mint: &mint,
+                metadata: &metadata,
+                edition: &edition,
+                system_program: &self.system_program,
+                spl_token_program: token_program,
+                spl_ata_program: associated_token_program,
+                token_metadata_program: self.token_metadata_program.as_ref(),
+                sysvar_instructions: self.sysvar_instructions.as_ref(),
+                source_token_record: pool_token_record.as_ref(),
+                destination_token_record: new_pool_token_record.as_ref(),
+                authorization_rules_program: self.authorization_rules_program.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+                authorization_data: authorization_data.map(AuthorizationData::from),
+                delegate: None,
+            },
+            Some(signer_seeds),
+        )?;
+
+        self.finish_token_nft(item, &token_program.to_account_info())
+    }
+
+    /// Moves a T22 NFT to the new pool: `[mint, pool_ta, new_pool_ta, receipt, new_receipt]`,
+    /// followed by the accounts of the royalty transfer hook.
+    fn migrate_t22_nft(&self, item: &'info [AccountInfo<'info>]) -> Result<()> {
+        let token_program = unwrap_opt!(
+            self.token_2022_program.as_ref(),
+            ErrorCode::InvalidBatchAccounts
+        )
+        .to_account_info();
+
+        self.prepare_token_nft(item, &token_program)?;
+
+        let asset = T22Shared {
+            mint: Box::new(InterfaceAccount::try_from(&item[0])?),
+        }
+        .validate_asset()?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&self.pool.seeds()];
+
+        t22::transfer(
+            &t22::TransferArgs {
+                from: item[1].clone(),
+                to: item[2].clone(),
+                authority: self.pool.to_account_info(),
+                mint: item[0].clone(),
+                token_program: token_program.clone(),
+            },
+            &item[5..],
+            &asset.royalty_creators,
+            Some(signer_seeds),
+        )?;
+
+        self.finish_token_nft(item, &token_program)
+    }
+
+    /// Moves a WNS NFT to the new pool: `[mint, pool_ta, new_pool_ta, receipt, new_receipt,
+    /// approve_account, extra_metas, group_member, distribution]`.
+    fn migrate_wns_nft(&self, item: &'info [AccountInfo<'info>]) -> Result<()> {
+        let token_program = unwrap_opt!(
+            self.token_2022_program.as_ref(),
+            ErrorCode::InvalidBatchAccounts
+        )
+        .to_account_info();
+        let wns_program = unwrap_opt!(self.wns_program.as_ref(), ErrorCode::InvalidWnsAccounts);
+        let distribution_program = unwrap_opt!(
+            self.distribution_program.as_ref(),
+            ErrorCode::InvalidWnsAccounts
+        );
+
+        self.prepare_token_nft(item, &token_program)?;
+
+        let wns =
+            WnsShared::from_accounts(&item[0], &item[5..], wns_program, distribution_program)?;
+
+        wns.validate_asset()?;
+
+        // No royalties are paid when moving the NFT between pools.
+        wns.approve(
+            &self.owner.to_account_info(),
+            &token_program,
+            &self.system_program.to_account_info(),
+            0,
+            0,
+        )?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&self.pool.seeds()];
+
+        wns.transfer(
+            &wns::WnsTransferArgs {
+                from: item[1].clone(),
+                to: item[2].clone(),
+                authority: self.pool.to_account_info(),
+                token_program: token_program.clone(),
+            },
+            Some(signer_seeds),
+        )?;
+
+        self.finish_token_nft(item, &token_program)
+    }
+
+    /// Checks the receipt and token account of an NFT held in a token account of the old pool,
+    /// and creates its token account for the new pool.
+    fn prepare_token_nft(
+        &self,
+        item: &'info [AccountInfo<'info>],
+        token_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (mint, pool_ta, new_pool_ta) = (&item[0], &item[1], &item[2]);
+
+        check_nft_receipt(&item[3], mint.key, &self.pool.key())?;
+
+        let pool_ata = get_associated_token_address_with_program_id(
+            &self.pool.key(),
+            mint.key,
+            token_program.key,
+        );
+        if *pool_ta.key != pool_ata {
+            throw_err!(ErrorCode::InvalidBatchAccounts);
+        }
+
+        let associated_token_program = unwrap_opt!(
+            self.associated_token_program.as_ref(),
+            ErrorCode::InvalidBatchAccounts
+        );
+
+        BatchTokenAccounts {
+            payer: self.rent_payer.to_account_info(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+        .init_ata(new_pool_ta, &self.new_pool.to_account_info(), mint)
+    }
+
+    /// Closes the token account of the old pool once its NFT was moved, and replaces the receipt.
+    fn finish_token_nft(
+        &self,
+        item: &'info [AccountInfo<'info>],
+        token_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&self.pool.seeds()];
+
+        token_interface::close_account(
+            CpiContext::new(
+                token_program.clone(),
+                CloseAccount {
+                    account: item[1].clone(),
+                    destination: self.owner.to_account_info(),
+                    authority: self.pool.to_account_info(),
+                },
+            )
+            .with_signer(signer_seeds),
+        )?;
+
+        self.replace_receipt(item[0].key, &item[3], &item[4])
+    }
+
+    /// Closes the receipt of the NFT in the old pool and creates the one in the new pool.
+    fn replace_receipt(
+        &self,
+        nft: &Pubkey,
+        receipt_info: &AccountInfo<'info>,
+        new_receipt_info: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let owner = self.owner.to_account_info();
+
+        close_account(&mut receipt_info.clone(), &mut owner.clone())?;
+
+        init_nft_receipt(
+            new_receipt_info,
+            *nft,
+            self.new_pool.key(),
+            &owner,
+            &self.system_program.to_account_info(),
+        )
+    }
+}
+
+/// Transfer a pool to a new owner.
+///
+/// The pool is recreated under the new owner with the same pool id, config and stats, its funds
+/// and NFTs are moved over and the old pool is closed.
+///
+/// Every NFT held must be listed in the arguments, otherwise the transfer fails with
+/// `NftsNotTransferable`: compressed NFTs can't be migrated and must be withdrawn first. Trait
+/// requirements must also be cleared before the transfer.
+#[access_control(ctx.accounts.validate(&args))]
+pub fn process_transfer_pool<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferPool<'info>>,
+    args: TransferPoolArgs,
+) -> Result<()> {
+    let pool = (**ctx.accounts.pool).clone();
+
+    **ctx.accounts.new_pool = Pool {
+        bump: [ctx.bumps.new_pool],
+        owner: ctx.accounts.new_owner.key(),
+        rent_payer: ctx.accounts.rent_payer.key(),
+        shared_escrow: ctx
+            .accounts
+            .new_shared_escrow
+            .as_ref()
+            .map(|a| a.key())
+            .unwrap_or_default(),
+        // The new owner appoints their own manager.
+        manager: Pubkey::default(),
+        // The bounty is refunded to the old rent payer when the old pool is closed.
+        keeper_bounty: 0,
+        ..pool
+    };
+
+    if let (Some(price_ladder), Some(new_price_ladder)) = (
+        ctx.accounts.price_ladder.as_ref(),
+        ctx.accounts.new_price_ladder.as_mut(),
+    ) {
+        new_price_ladder.bump = [ctx.bumps.new_price_ladder];
+        new_price_ladder.pool = ctx.accounts.new_pool.key();
+        new_price_ladder.set_prices(PriceLadderArgs {
+            prices: price_ladder.prices.clone(),
+            zero_index: price_ladder.zero_index,
+        })?;
+    }
+
+    // Shared escrow pools don't hold funds.
+    if pool.shared_escrow == Pubkey::default() {
+        if pool.currency == Pubkey::default() {
+            transfer_lamports(
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.new_pool.to_account_info(),
+                pool.amount,
+            )?;
+        } else {
+            ctx.accounts.transfer_currency()?;
+        }
+    }
+
+    let migrated = ctx.accounts.migrate_nfts(&args, ctx.remaining_accounts)?;
+
+    record_event(
+        TAmmEvent::TransferPoolEvent(TransferPoolEvent {
+            old_pool: ctx.accounts.pool.key(),
+            old_owner: pool.owner,
+            new_owner: ctx.accounts.new_pool.owner,
+            nfts_migrated: migrated,
+        }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.new_pool,
+    )?;
+
+    state::pool::close_pool(
+        &ctx.accounts.pool,
+        ctx.accounts.old_rent_payer.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+    )
+}
@@ -0,0 +1,67 @@
+//! Upgrade a pool account to the current pool version.
+use anchor_lang::system_program::{self, Transfer};
+use tensor_vipers::unwrap_opt;
+
+use self::constants::{CURRENT_POOL_VERSION, DISCRIMINATOR_SIZE};
+use crate::{error::ErrorCode, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct UpgradePool<'info> {
+    /// Pays for the extra rent. Anyone can upgrade a pool, so keepers can upgrade expired
+    /// pools before cleaning them up.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The pool to upgrade. Older versions don't deserialize as the current `Pool` layout.
+    /// CHECK: discriminator and owner are checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Reallocates a pool created under an older version to the current size and converts its data
+/// to the current layout. The conversion keeps every setting of the pool, so the upgrade is
+/// permissionless.
+pub fn process_upgrade_pool(ctx: Context<UpgradePool>) -> Result<()> {
+    let pool_info = ctx.accounts.pool.to_account_info();
+
+    let pool = {
+        let data = pool_info.try_borrow_data()?;
+
+        // Pools on the current version have nothing to upgrade.
+        let version = unwrap_opt!(data.get(DISCRIMINATOR_SIZE), ErrorCode::WrongPoolVersion);
+        require!(
+            *version != CURRENT_POOL_VERSION,
+            ErrorCode::WrongPoolVersion
+        );
+
+        Pool::try_deserialize_versioned(&data)?
+    };
+
+    // The pool keeps its balance above the minimum rent, so only the difference is added.
+    let rent = Rent::get()?;
+    let top_up = rent
+        .minimum_balance(Pool::SIZE)
+        .saturating_sub(rent.minimum_balance(pool_info.data_len()));
+
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: pool_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    pool_info.realloc(Pool::SIZE, true)?;
+
+    let mut data = pool_info.try_borrow_mut_data()?;
+    pool.try_serialize(&mut data.as_mut())
+}
@@ -3,7 +3,7 @@ use constants::CURRENT_POOL_VERSION;
 use tensor_toolbox::transfer_lamports;
 use tensor_vipers::{throw_err, unwrap_int};
 
-use crate::{error::ErrorCode, *};
+use crate::{error::ErrorCode, program::AmmProgram, *};
 
 /// Instruction accounts.
 #[derive(Accounts)]
@@ -24,11 +24,15 @@ pub struct WithdrawSol<'info> {
         constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
         constraint = pool.config.pool_type == PoolType::Token ||  pool.config.pool_type == PoolType::Trade @ ErrorCode::WrongPoolType,
         constraint = pool.shared_escrow == Pubkey::default() @ ErrorCode::PoolOnSharedEscrow,
+        constraint = pool.currency == Pubkey::default() @ ErrorCode::InvalidCurrency,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
     /// The Solana system program.
     pub system_program: Program<'info, System>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
 }
 
 /// Withdraw SOL from a Token or Trade pool.
@@ -38,17 +42,17 @@ pub fn process_withdraw_sol<'info>(
 ) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
-    let rent = solana_program::rent::Rent::get()?;
-    let pool_min_rent = rent.minimum_balance(Pool::SIZE);
+    // The pool must keep its rent and keeper bounty.
+    let pool_min_balance = pool.min_balance()?;
 
     let current_pool_lamports = pool.to_account_info().get_lamports();
 
-    // The pool must maintain the minimum rent balance. To close the pool, use "close_pool", which
+    // The pool must maintain the minimum balance. To close the pool, use "close_pool", which
     // performs appropriate checks.
     if current_pool_lamports
         .checked_sub(lamports)
         .ok_or(ErrorCode::ArithmeticError)?
-        < pool_min_rent
+        < pool_min_balance
     {
         throw_err!(ErrorCode::PoolInsufficientRent);
     }
@@ -62,5 +66,11 @@ pub fn process_withdraw_sol<'info>(
         &ctx.accounts.pool.to_account_info(),
         &ctx.accounts.owner.to_account_info(),
         lamports,
+    )?;
+
+    record_event(
+        TAmmEvent::WithdrawCurrencyEvent(WithdrawCurrencyEvent { amount: lamports }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
     )
 }
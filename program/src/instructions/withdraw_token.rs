@@ -0,0 +1,99 @@
+//! Withdraw SPL currency from a Trade or Token pool.
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use constants::CURRENT_POOL_VERSION;
+use tensor_vipers::unwrap_int;
+
+use crate::{error::ErrorCode, program::AmmProgram, *};
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    /// The owner of the pool and will receive the funds.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The pool from which the funds will be withdrawn.
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            owner.key().as_ref(),
+            pool.pool_id.as_ref(),
+        ],
+        bump = pool.bump[0],
+        constraint = pool.version == CURRENT_POOL_VERSION @ ErrorCode::WrongPoolVersion,
+        constraint = pool.config.pool_type == PoolType::Token ||  pool.config.pool_type == PoolType::Trade @ ErrorCode::WrongPoolType,
+        constraint = pool.currency == currency.key() @ ErrorCode::InvalidCurrency,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The SPL mint the pool is priced in.
+    #[account(mint::token_program = token_program)]
+    pub currency: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The owner's currency token account, where the funds are withdrawn to.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = currency,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The pool's currency token account.
+    #[account(
+        mut,
+        associated_token::mint = currency,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token program of the currency mint.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+
+    /// The AMM program account, used for self-cpi logging.
+    pub amm_program: Program<'info, AmmProgram>,
+}
+
+/// Withdraw SPL currency from a Token or Trade pool.
+pub fn process_withdraw_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawToken<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // Update the pool's currency amount
+    pool.amount = unwrap_int!(pool.amount.checked_sub(amount));
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.pool_ta.to_account_info(),
+                to: ctx.accounts.owner_ta.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+                mint: ctx.accounts.currency.to_account_info(),
+            },
+        )
+        .with_signer(&[&ctx.accounts.pool.seeds()]),
+        amount,
+        ctx.accounts.currency.decimals,
+    )?;
+
+    record_event(
+        TAmmEvent::WithdrawCurrencyEvent(WithdrawCurrencyEvent { amount }),
+        &ctx.accounts.amm_program,
+        &ctx.accounts.pool,
+    )
+}
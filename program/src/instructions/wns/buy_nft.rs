@@ -0,0 +1,139 @@
+//! Buy a WNS NFT from a NFT or Trade pool.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct BuyNftWns<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// WNS shared accounts.
+    pub wns: WnsShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    #[account(
+        mut,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            wns.mint.key().as_ref(),
+            trade.pool.key().as_ref(),
+        ],
+        bump = nft_receipt.bump,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The TA of the buyer, where the NFT will be transferred.
+    #[account(
+        init_if_needed,
+        payer = trade.taker,
+        associated_token::mint = wns.mint,
+        associated_token::authority = trade.taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The TA of the pool, where the NFT is escrowed.
+    #[account(
+        mut,
+        associated_token::mint = wns.mint,
+        associated_token::authority = trade.pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyNftWns<'info> {
+    fn pre_process_checks(&self) -> Result<AmmAsset> {
+        self.trade.validate_buy()?;
+        self.trade.validate_wns_currency()?;
+
+        self.wns.validate_asset()
+    }
+}
+
+/// Buy a WNS NFT from a NFT or Trade pool.
+pub fn process_buy_nft_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNftWns<'info>>,
+    // Max vs exact so we can add slippage later.
+    max_amount: u64,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
+
+    let fees = ctx.accounts.trade.calculate_fees(
+        &asset,
+        max_amount,
+        TakerSide::Buy,
+        Some(100), // royalties enforced
+    )?;
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let current_price = fees.price;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // The buyer pays royalties into the distribution account when approving the transfer.
+    ctx.accounts.wns.approve(
+        &taker,
+        &token_program,
+        &ctx.accounts.system_program.to_account_info(),
+        current_price,
+        fees.creators_fee,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.trade.pool.seeds()];
+
+    // Transfer from the pool to the buyer.
+    ctx.accounts.wns.transfer(
+        &WnsTransferArgs {
+            from: ctx.accounts.pool_ta.to_account_info(),
+            to: ctx.accounts.taker_ta.to_account_info(),
+            authority: ctx.accounts.trade.pool.to_account_info(),
+            token_program: token_program.clone(),
+        },
+        Some(signer_seeds),
+    )?;
+
+    // Close ATA accounts before fee transfers to avoid unbalanced accounts error. CPIs
+    // don't have the context of manual lamport balance changes so need to come before.
+
+    // close nft escrow account
+    token_interface::close_account(
+        ctx.accounts
+            .trade
+            .close_pool_ata_ctx(token_program, ctx.accounts.pool_ta.to_account_info())
+            .with_signer(signer_seeds),
+    )?;
+
+    // No creator accounts: royalties were already paid to the distribution account.
+    ctx.accounts.trade.pay_buyer_fees(asset, fees, &[])?;
+
+    // Close the NFT receipt account.
+    close_account(
+        &mut ctx.accounts.nft_receipt.to_account_info(),
+        &mut ctx.accounts.trade.owner.to_account_info(),
+    )?;
+
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Buy, &fees)?;
+
+    ctx.accounts.trade.record_settlement(
+        vec![asset_id],
+        TakerSide::Buy,
+        fees,
+        fees.creators_fee,
+    )?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
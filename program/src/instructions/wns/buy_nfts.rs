@@ -0,0 +1,173 @@
+//! Buy several WNS NFTs from a NFT or Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per NFT bought, while the fees of all NFTs
+//! are paid in aggregated transfers and checked against a single maximum total amount.
+//! Royalties are paid into the distribution account of each NFT's collection.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct BuyNftsWns<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Buy) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The WNS program.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_PROGRAM_ID)]
+    pub wns_program: UncheckedAccount<'info>,
+
+    /// The WNS royalty distribution program.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_DISTRIBUTION_PROGRAM_ID)]
+    pub distribution_program: UncheckedAccount<'info>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each NFT bought: [mint, nft_receipt, pool_ta, taker_ta, approve_account,
+    //      extra_metas, group_member, distribution]
+}
+
+/// Buy several WNS NFTs from a NFT or Trade pool.
+pub fn process_buy_nfts_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNftsWns<'info>>,
+    // Max vs exact so we can add slippage later.
+    max_total_amount: u64,
+) -> Result<()> {
+    ctx.accounts.trade.validate_buy()?;
+    ctx.accounts.trade.validate_wns_currency()?;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let owner = ctx.accounts.trade.owner.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let token_accounts = BatchTokenAccounts {
+        payer: taker.clone(),
+        token_program: token_program.clone(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: system_program.clone(),
+    };
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::new();
+    let mut assets = Vec::new();
+    let mut accounts = ctx.remaining_accounts;
+
+    while !accounts.is_empty() {
+        let (item, rest) = split_batch_item(accounts, 8)?;
+        let (mint_info, pool_ta_info, taker_ta_info) = (&item[0], &item[2], &item[3]);
+
+        // Each purchase counts towards the max taker buy count.
+        ctx.accounts.trade.pool.taker_allowed_to_buy()?;
+
+        let wns = WnsShared::from_accounts(
+            mint_info,
+            &item[4..],
+            &ctx.accounts.wns_program,
+            &ctx.accounts.distribution_program,
+        )?;
+        let asset = wns.validate_asset()?;
+        check_nft_receipt(&item[1], &asset.pubkey, &pool_key)?;
+
+        // Fees at the current price, the total is checked against the max amount below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            u64::MAX,
+            TakerSide::Buy,
+            Some(100), // royalties enforced
+        )?;
+
+        // The buyer pays royalties into the distribution account when approving the transfer.
+        wns.approve(
+            &taker,
+            &token_program,
+            &system_program,
+            fees.price,
+            fees.creators_fee,
+        )?;
+
+        token_accounts.init_ata(taker_ta_info, &taker, mint_info)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.trade.pool.seeds()];
+
+        // Transfer from the pool to the buyer.
+        wns.transfer(
+            &WnsTransferArgs {
+                from: pool_ta_info.clone(),
+                to: taker_ta_info.clone(),
+                authority: pool_info.clone(),
+                token_program: token_program.clone(),
+            },
+            Some(signer_seeds),
+        )?;
+
+        // close nft escrow account
+        token_interface::close_account(
+            ctx.accounts
+                .trade
+                .close_pool_ata_ctx(token_program.clone(), pool_ta_info.clone())
+                .with_signer(signer_seeds),
+        )?;
+
+        // Close the NFT receipt account.
+        close_account(&mut item[1].clone(), &mut owner.clone())?;
+
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+        // No creator accounts: royalties were already paid to the distribution account.
+        royalties.push(NftRoyalties::new(&asset, &fees, &[]));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next NFT.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Buy, &fees)?;
+
+        accounts = rest;
+    }
+
+    if assets.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price + royalties + mm_fee doesn't exceed the max amount the user specified.
+    let total_amount = unwrap_checked!({
+        total_fees
+            .price
+            .checked_add(total_fees.mm_fee)?
+            .checked_add(total_fees.creators_fee)
+    });
+
+    if total_amount > max_total_amount {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    ctx.accounts
+        .trade
+        .pay_batch_buyer_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Buy,
+    )?;
+
+    ctx.accounts.trade.record_settlement(
+        assets,
+        TakerSide::Buy,
+        total_fees,
+        total_fees.creators_fee,
+    )?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
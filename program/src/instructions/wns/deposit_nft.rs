@@ -0,0 +1,125 @@
+//! Deposit a WNS NFT into a NFT or Trade pool.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct DepositNftWns<'info> {
+    /// Transfer shared accounts.
+    pub transfer: TransferShared<'info>,
+
+    /// WNS shared accounts.
+    pub wns: WnsShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    #[account(
+        init,
+        payer = transfer.owner,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            wns.mint.key().as_ref(),
+            transfer.pool.key().as_ref(),
+        ],
+        bump,
+        space = NftDepositReceipt::SIZE,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The TA of the owner, where the NFT will be transferred from.
+    #[account(
+        mut,
+        token::mint = wns.mint,
+        token::authority = transfer.owner,
+        token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The TA of the pool, where the NFT will be escrowed.
+    #[account(
+        init_if_needed,
+        payer = transfer.owner,
+        associated_token::mint = wns.mint,
+        associated_token::authority = transfer.pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositNftWns<'info> {
+    fn pre_process_checks(&self) -> Result<AmmAsset> {
+        if self.transfer.pool.expiry < Clock::get()?.unix_timestamp {
+            throw_err!(ErrorCode::ExpiredPool);
+        }
+
+        self.transfer.pool.can_hold_more_nfts()?;
+
+        let asset = self.wns.validate_asset()?;
+
+        self.transfer.verify_whitelist(&asset)?;
+
+        Ok(asset)
+    }
+}
+
+/// Deposit a WNS NFT into a NFT or Trade pool.
+pub fn process_deposit_nft_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositNftWns<'info>>,
+) -> Result<()> {
+    ctx.accounts.pre_process_checks()?;
+
+    let owner = ctx.accounts.transfer.owner.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // No royalties are paid on deposits.
+    ctx.accounts.wns.approve(
+        &owner,
+        &token_program,
+        &ctx.accounts.system_program.to_account_info(),
+        0,
+        0,
+    )?;
+
+    // transfer the NFT
+    ctx.accounts.wns.transfer(
+        &WnsTransferArgs {
+            from: ctx.accounts.owner_ta.to_account_info(),
+            to: ctx.accounts.pool_ta.to_account_info(),
+            authority: owner,
+            token_program: token_program.clone(),
+        },
+        None,
+    )?;
+
+    // Close owner ATA to return rent to the rent payer.
+    token_interface::close_account(
+        ctx.accounts
+            .transfer
+            .close_owner_ata_ctx(token_program, ctx.accounts.owner_ta.to_account_info()),
+    )?;
+
+    //update pool
+    let pool = &mut ctx.accounts.transfer.pool;
+    pool.nfts_held = unwrap_int!(pool.nfts_held.checked_add(1));
+
+    //create nft receipt
+    **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
+        bump: ctx.bumps.nft_receipt,
+        mint: ctx.accounts.wns.mint.key(),
+        pool: ctx.accounts.transfer.pool.key(),
+    };
+
+    record_event(
+        TAmmEvent::DepositNftEvent(DepositNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )
+}
@@ -0,0 +1,151 @@
+pub mod buy_nft;
+pub mod buy_nfts;
+pub mod deposit_nft;
+pub mod sell_nft_token_pool;
+pub mod sell_nft_trade_pool;
+pub mod sell_nfts_token_pool;
+pub mod sell_nfts_trade_pool;
+pub mod withdraw_expired_nft;
+pub mod withdraw_nft;
+
+pub use self::buy_nft::*;
+pub use self::buy_nfts::*;
+pub use self::deposit_nft::*;
+pub use self::sell_nft_token_pool::*;
+pub use self::sell_nft_trade_pool::*;
+pub use self::sell_nfts_token_pool::*;
+pub use self::sell_nfts_trade_pool::*;
+pub use self::withdraw_expired_nft::*;
+pub use self::withdraw_nft::*;
+
+use crate::{
+    constants::{WNS_DISTRIBUTION_PROGRAM_ID, WNS_PROGRAM_ID},
+    error::ErrorCode,
+    *,
+};
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Token2022, TokenAccount, TransferChecked},
+};
+use tensor_toolbox::{
+    close_account,
+    token_2022::{
+        transfer::transfer_checked,
+        wns::{approve, ApproveAccounts},
+    },
+};
+use tensor_vipers::{throw_err, unwrap_checked, unwrap_int, unwrap_opt};
+
+pub(crate) struct WnsTransferArgs<'info> {
+    pub from: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> WnsShared<'info> {
+    /// Builds the WNS accounts of an NFT passed in remaining accounts by batch instructions,
+    /// `[approve_account, extra_metas, group_member, distribution]`, checking the seeds Anchor
+    /// checks on `WnsShared`.
+    pub(crate) fn from_accounts(
+        mint: &'info AccountInfo<'info>,
+        accounts: &'info [AccountInfo<'info>],
+        wns_program: &UncheckedAccount<'info>,
+        distribution_program: &UncheckedAccount<'info>,
+    ) -> Result<Self> {
+        if accounts.len() != 4 {
+            throw_err!(ErrorCode::InvalidWnsAccounts);
+        }
+
+        let seeds: [&[u8]; 3] = [b"approve-account", b"extra-account-metas", b"member"];
+
+        for (seed, account) in seeds.iter().zip(accounts) {
+            let (address, _) =
+                Pubkey::find_program_address(&[seed, mint.key.as_ref()], &WNS_PROGRAM_ID);
+
+            if address != *account.key {
+                throw_err!(ErrorCode::InvalidWnsAccounts);
+            }
+        }
+
+        Ok(Self {
+            mint: Box::new(InterfaceAccount::try_from(mint)?),
+            approve_account: UncheckedAccount::try_from(&accounts[0]),
+            extra_metas: UncheckedAccount::try_from(&accounts[1]),
+            group_member: UncheckedAccount::try_from(&accounts[2]),
+            distribution: UncheckedAccount::try_from(&accounts[3]),
+            wns_program: wns_program.clone(),
+            distribution_program: distribution_program.clone(),
+        })
+    }
+
+    /// Approves the next transfer of the NFT with the WNS program, paying `royalty_fee`
+    /// lamports from `payer` into the collection's distribution account.
+    pub(crate) fn approve(
+        &self,
+        payer: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        amount: u64,
+        royalty_fee: u64,
+    ) -> Result<()> {
+        let approve_accounts = ApproveAccounts {
+            payer: payer.clone(),
+            authority: payer.clone(),
+            mint: self.mint.to_account_info(),
+            approve_account: self.approve_account.to_account_info(),
+            payment_mint: None,
+            distribution_token_account: None,
+            authority_token_account: None,
+            distribution_account: self.distribution.to_account_info(),
+            system_program: system_program.clone(),
+            distribution_program: self.distribution_program.to_account_info(),
+            wns_program: self.wns_program.to_account_info(),
+            token_program: token_program.clone(),
+            payment_token_program: None,
+        };
+
+        approve(approve_accounts, amount, royalty_fee)
+    }
+
+    /// Transfers the NFT, passing the extra accounts required by the WNS transfer hook.
+    pub(crate) fn transfer(
+        &self,
+        args: &WnsTransferArgs<'info>,
+        signer_seeds: Option<&[&[&[u8]]]>,
+    ) -> Result<()> {
+        let transfer_cpi = CpiContext::new(
+            args.token_program.clone(),
+            TransferChecked {
+                from: args.from.clone(),
+                to: args.to.clone(),
+                authority: args.authority.clone(),
+                mint: self.mint.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(vec![
+            self.wns_program.to_account_info(),
+            self.extra_metas.to_account_info(),
+            self.approve_account.to_account_info(),
+        ]);
+
+        transfer_checked(
+            transfer_cpi.with_signer(signer_seeds.unwrap_or_default()),
+            1, // supply = 1
+            0, // decimals = 0
+        )
+    }
+}
+
+impl<'info> TradeShared<'info> {
+    /// WNS royalties are paid in SOL into the collection's distribution account.
+    fn validate_wns_currency(&self) -> Result<()> {
+        if self.pool.currency != Pubkey::default() {
+            throw_err!(ErrorCode::InvalidCurrency);
+        }
+
+        Ok(())
+    }
+}
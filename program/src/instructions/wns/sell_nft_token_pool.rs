@@ -0,0 +1,125 @@
+//! Sell a WNS NFT into a Token pool.
+//!
+//! This is separated from Trade pool since the owner will receive the NFT directly in their ATA.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SellNftTokenPoolWns<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// WNS shared accounts.
+    pub wns: WnsShared<'info>,
+
+    /// The token account of the NFT for the seller's wallet.
+    #[account(
+        mut,
+        token::mint = wns.mint,
+        token::authority = trade.taker,
+        token::token_program = token_program,
+    )]
+    pub taker_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The ATA of the owner, where the NFT will be transferred to as a result of this sale.
+    #[account(
+        init_if_needed,
+        payer = trade.taker,
+        associated_token::mint = wns.mint,
+        associated_token::authority = trade.owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SellNftTokenPoolWns<'info> {
+    fn pre_process_checks(&self) -> Result<AmmAsset> {
+        self.trade.validate_sell(&PoolType::Token)?;
+        self.trade.validate_wns_currency()?;
+
+        let asset = self.wns.validate_asset()?;
+
+        self.trade.verify_whitelist(&asset)?;
+
+        Ok(asset)
+    }
+}
+
+/// Sell a WNS NFT into a Token pool.
+pub fn process_sell_nft_token_pool_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftTokenPoolWns<'info>>,
+    // Min vs exact so we can add slippage later.
+    min_price: u64,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
+
+    let fees = ctx.accounts.trade.calculate_fees(
+        &asset,
+        min_price,
+        TakerSide::Sell,
+        Some(100), // royalties enforced
+    )?;
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let current_price = fees.price;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // The seller pays royalties into the distribution account when approving the transfer,
+    // and is not charged them again when the pool pays out.
+    ctx.accounts.wns.approve(
+        &taker,
+        &token_program,
+        &ctx.accounts.system_program.to_account_info(),
+        current_price,
+        fees.creators_fee,
+    )?;
+
+    // Transfer directly to the owner.
+    ctx.accounts.wns.transfer(
+        &WnsTransferArgs {
+            from: ctx.accounts.taker_ta.to_account_info(),
+            to: ctx.accounts.owner_ta.to_account_info(),
+            authority: taker,
+            token_program: token_program.clone(),
+        },
+        None,
+    )?;
+
+    // Close ATA accounts before fee transfers to avoid unbalanced accounts error. CPIs
+    // don't have the context of manual lamport balance changes so need to come before.
+
+    // Close seller ATA to return rent to the rent payer.
+    token_interface::close_account(
+        ctx.accounts
+            .trade
+            .close_taker_ata_ctx(token_program, ctx.accounts.taker_ta.to_account_info()),
+    )?;
+
+    // No creator accounts: royalties were already paid to the distribution account.
+    ctx.accounts.trade.pay_seller_fees(asset, fees, &[])?;
+
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts.trade.record_settlement(
+        vec![asset_id],
+        TakerSide::Sell,
+        fees,
+        fees.creators_fee,
+    )?;
+
+    ctx.accounts.trade.try_autoclose_pool()
+}
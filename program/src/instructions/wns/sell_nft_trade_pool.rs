@@ -0,0 +1,149 @@
+//! Sell a WNS NFT into a two-sided ("Trade") pool, where the pool is the buyer and ends up as the
+//! owner of the NFT.
+//!
+//! The seller is the owner of the NFT and receives the pool's current price in return.
+//! This is separated from Token pool since the NFT will be transferred to the pool and
+//! a deposit receipt is created for it.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SellNftTradePoolWns<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// WNS shared accounts.
+    pub wns: WnsShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    #[account(
+        init,
+        payer = trade.taker,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            wns.mint.key().as_ref(),
+            trade.pool.key().as_ref(),
+        ],
+        bump,
+        space = NftDepositReceipt::SIZE,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The token account of the NFT for the seller's wallet.
+    #[account(
+        mut,
+        token::mint = wns.mint,
+        token::authority = trade.taker,
+        token::token_program = token_program,
+    )]
+    pub taker_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The ATA of the pool, where the NFT will be transferred to.
+    #[account(
+        init_if_needed,
+        payer = trade.taker,
+        associated_token::mint = wns.mint,
+        associated_token::authority = trade.pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SellNftTradePoolWns<'info> {
+    fn pre_process_checks(&self) -> Result<AmmAsset> {
+        self.trade.validate_sell(&PoolType::Trade)?;
+        self.trade.validate_wns_currency()?;
+
+        let asset = self.wns.validate_asset()?;
+
+        self.trade.verify_whitelist(&asset)?;
+
+        Ok(asset)
+    }
+}
+
+/// Sell a WNS NFT into a Trade pool.
+pub fn process_sell_nft_trade_pool_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftTradePoolWns<'info>>,
+    // Min vs exact so we can add slippage later.
+    min_price: u64,
+) -> Result<()> {
+    let asset = ctx.accounts.pre_process_checks()?;
+    let asset_id = asset.pubkey;
+
+    let fees = ctx.accounts.trade.calculate_fees(
+        &asset,
+        min_price,
+        TakerSide::Sell,
+        Some(100), // royalties enforced
+    )?;
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let current_price = fees.price;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // The seller pays royalties into the distribution account when approving the transfer,
+    // and is not charged them again when the pool pays out.
+    ctx.accounts.wns.approve(
+        &taker,
+        &token_program,
+        &ctx.accounts.system_program.to_account_info(),
+        current_price,
+        fees.creators_fee,
+    )?;
+
+    // Transfer the NFT from the seller to the pool.
+    ctx.accounts.wns.transfer(
+        &WnsTransferArgs {
+            from: ctx.accounts.taker_ta.to_account_info(),
+            to: ctx.accounts.pool_ta.to_account_info(),
+            authority: taker,
+            token_program: token_program.clone(),
+        },
+        None,
+    )?;
+
+    // Close ATA accounts before fee transfers to avoid unbalanced accounts error. CPIs
+    // don't have the context of manual lamport balance changes so need to come before.
+
+    // Close seller ATA to return rent to the rent payer.
+    token_interface::close_account(
+        ctx.accounts
+            .trade
+            .close_taker_ata_ctx(token_program, ctx.accounts.taker_ta.to_account_info()),
+    )?;
+
+    // No creator accounts: royalties were already paid to the distribution account.
+    ctx.accounts.trade.pay_seller_fees(asset, fees, &[])?;
+
+    ctx.accounts
+        .trade
+        .update_pool_accounting(pool_initial_balance, TakerSide::Sell, &fees)?;
+
+    ctx.accounts.trade.record_settlement(
+        vec![asset_id],
+        TakerSide::Sell,
+        fees,
+        fees.creators_fee,
+    )?;
+
+    //create nft receipt for trade pool
+    **ctx.accounts.nft_receipt.as_mut() = NftDepositReceipt {
+        bump: ctx.bumps.nft_receipt,
+        mint: ctx.accounts.wns.mint.key(),
+        pool: ctx.accounts.trade.pool.key(),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//! Sell several WNS NFTs into a Trade pool in a single instruction.
+//!
+//! The price moves along the curve once per NFT sold, while the fees of all NFTs
+//! are paid in aggregated transfers and checked against a single minimum total price.
+//! The pool ends up as the owner of the NFTs and a deposit receipt is created for each.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct SellNftsTradePoolWns<'info> {
+    /// Trade shared accounts.
+    #[account(constraint = !trade.pool.is_paused(TakerSide::Sell) @ ErrorCode::PoolPaused)]
+    pub trade: TradeShared<'info>,
+
+    /// The WNS program.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_PROGRAM_ID)]
+    pub wns_program: UncheckedAccount<'info>,
+
+    /// The WNS royalty distribution program.
+    /// CHECK: address constraint is checked here
+    #[account(address = WNS_DISTRIBUTION_PROGRAM_ID)]
+    pub distribution_program: UncheckedAccount<'info>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+    //
+    // ---- for each NFT sold: [mint, nft_receipt, taker_ta, pool_ta, mint_proof, approve_account,
+    //      extra_metas, group_member, distribution] (the program id as mint proof when not needed)
+}
+
+/// Sell several WNS NFTs into a Trade pool.
+pub fn process_sell_nfts_trade_pool_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNftsTradePoolWns<'info>>,
+    // Min vs exact so we can add slippage later.
+    min_total_price: u64,
+) -> Result<()> {
+    ctx.accounts.trade.validate_sell(&PoolType::Trade)?;
+    ctx.accounts.trade.validate_wns_currency()?;
+
+    let taker = ctx.accounts.trade.taker.to_account_info();
+    let pool_info = ctx.accounts.trade.pool.to_account_info();
+    let pool_key = ctx.accounts.trade.pool.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let token_accounts = BatchTokenAccounts {
+        payer: taker.clone(),
+        token_program: token_program.clone(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: system_program.clone(),
+    };
+
+    let pool_initial_balance = ctx.accounts.trade.pool_currency_balance()?;
+    let pool_initial_amount = ctx.accounts.trade.pool.amount;
+    let funds_available = ctx.accounts.trade.sell_funds_available()?;
+    let mut total_fees = Fees::default();
+    let mut royalties = Vec::new();
+    let mut assets = Vec::new();
+    let mut accounts = ctx.remaining_accounts;
+
+    while !accounts.is_empty() {
+        let (item, rest) = split_batch_item(accounts, 9)?;
+        let (mint_info, receipt_info, taker_ta_info, pool_ta_info) =
+            (&item[0], &item[1], &item[2], &item[3]);
+
+        // Each sale counts towards the max taker sell count and the max NFTs held.
+        ctx.accounts.trade.pool.taker_allowed_to_sell()?;
+        ctx.accounts.trade.pool.can_hold_more_nfts()?;
+
+        let wns = WnsShared::from_accounts(
+            mint_info,
+            &item[5..],
+            &ctx.accounts.wns_program,
+            &ctx.accounts.distribution_program,
+        )?;
+        let asset = wns.validate_asset()?;
+
+        ctx.accounts
+            .trade
+            .verify_whitelist_with_proof(&asset, optional_account(&item[4]))?;
+
+        // Fees at the current price, the total is checked against the min price below.
+        let fees = ctx.accounts.trade.calculate_fees(
+            &asset,
+            0,
+            TakerSide::Sell,
+            Some(100), // royalties enforced
+        )?;
+        total_fees = unwrap_opt!(total_fees.checked_add(&fees), ErrorCode::ArithmeticError);
+
+        // The pool or its shared escrow must be able to pay for the whole batch.
+        if total_fees.price > funds_available {
+            throw_err!(ErrorCode::InsufficientPoolFunds);
+        }
+
+        // The seller pays royalties into the distribution account when approving the transfer,
+        // and is not charged them again when the pool pays out.
+        wns.approve(
+            &taker,
+            &token_program,
+            &system_program,
+            fees.price,
+            fees.creators_fee,
+        )?;
+
+        token_accounts.init_ata(pool_ta_info, &pool_info, mint_info)?;
+
+        // Transfer the NFT from the seller to the pool.
+        wns.transfer(
+            &WnsTransferArgs {
+                from: taker_ta_info.clone(),
+                to: pool_ta_info.clone(),
+                authority: taker.clone(),
+                token_program: token_program.clone(),
+            },
+            None,
+        )?;
+
+        // Close seller ATA to return rent to the seller.
+        token_interface::close_account(
+            ctx.accounts
+                .trade
+                .close_taker_ata_ctx(token_program.clone(), taker_ta_info.clone()),
+        )?;
+
+        //create nft receipt for trade pool
+        init_nft_receipt(
+            receipt_info,
+            asset.pubkey,
+            pool_key,
+            &taker,
+            &system_program,
+        )?;
+
+        // No creator accounts: royalties were already paid to the distribution account.
+        royalties.push(NftRoyalties::new(&asset, &fees, &[]));
+        assets.push(asset.pubkey);
+
+        // Move the price one step along the curve for the next NFT.
+        ctx.accounts
+            .trade
+            .record_batch_trade(TakerSide::Sell, &fees)?;
+
+        accounts = rest;
+    }
+
+    if assets.is_empty() {
+        throw_err!(ErrorCode::InvalidBatchAccounts);
+    }
+
+    // Check that the total price the seller receives isn't lower than the min price the user specified.
+    let total_price = unwrap_checked!({
+        total_fees
+            .price
+            .checked_sub(total_fees.mm_fee)?
+            .checked_sub(total_fees.creators_fee)
+    });
+
+    if total_price < min_total_price {
+        throw_err!(ErrorCode::PriceMismatch);
+    }
+
+    ctx.accounts
+        .trade
+        .pay_batch_seller_fees(total_fees, &royalties)?;
+
+    ctx.accounts.trade.update_pool_amount(
+        pool_initial_balance,
+        pool_initial_amount,
+        TakerSide::Sell,
+    )?;
+
+    ctx.accounts.trade.record_settlement(
+        assets,
+        TakerSide::Sell,
+        total_fees,
+        total_fees.creators_fee,
+    )
+}
@@ -0,0 +1,114 @@
+//! Withdraw a WNS NFT from a NFT or Trade pool.
+
+use super::*;
+
+/// Instruction accounts.
+#[derive(Accounts)]
+pub struct WithdrawNftWns<'info> {
+    /// Transfer shared accounts.
+    pub transfer: TransferShared<'info>,
+
+    /// WNS shared accounts.
+    pub wns: WnsShared<'info>,
+
+    /// The NFT deposit receipt, which ties an NFT to the pool it was deposited to.
+    #[account(
+        mut,
+        seeds=[
+            b"nft_receipt".as_ref(),
+            wns.mint.key().as_ref(),
+            transfer.pool.key().as_ref(),
+        ],
+        bump = nft_receipt.bump,
+    )]
+    pub nft_receipt: Box<Account<'info, NftDepositReceipt>>,
+
+    /// The TA of the owner where the NFT will be withdrawn to.
+    #[account(
+        init_if_needed,
+        payer = transfer.owner,
+        associated_token::mint = wns.mint,
+        associated_token::authority = transfer.owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The TA of the pool, where the NFT token is escrowed.
+    #[account(
+        mut,
+        associated_token::mint = wns.mint,
+        associated_token::authority = transfer.pool,
+        associated_token::token_program = token_program,
+    )]
+    pub pool_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The Token 2022 program.
+    pub token_program: Program<'info, Token2022>,
+    /// The SPL associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The Solana system program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawNftWns<'info> {
+    fn pre_process_checks(&self) -> Result<AmmAsset> {
+        self.wns.validate_asset()
+    }
+}
+
+/// Withdraw a WNS NFT from a NFT or Trade pool.
+pub fn process_withdraw_nft_wns<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawNftWns<'info>>,
+) -> Result<()> {
+    ctx.accounts.pre_process_checks()?;
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // No royalties are paid on withdrawals.
+    ctx.accounts.wns.approve(
+        &ctx.accounts.transfer.owner.to_account_info(),
+        &token_program,
+        &ctx.accounts.system_program.to_account_info(),
+        0,
+        0,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&ctx.accounts.transfer.pool.seeds()];
+
+    // transfer the NFT
+    ctx.accounts.wns.transfer(
+        &WnsTransferArgs {
+            from: ctx.accounts.pool_ta.to_account_info(),
+            to: ctx.accounts.owner_ta.to_account_info(),
+            authority: ctx.accounts.transfer.pool.to_account_info(),
+            token_program: token_program.clone(),
+        },
+        Some(signer_seeds),
+    )?;
+
+    // close pool ATA
+    token_interface::close_account(
+        ctx.accounts
+            .transfer
+            .close_pool_ata_ctx(token_program, ctx.accounts.pool_ta.to_account_info())
+            .with_signer(signer_seeds),
+    )?;
+
+    //update pool
+    let pool = &mut ctx.accounts.transfer.pool;
+    pool.nfts_held = unwrap_int!(pool.nfts_held.checked_sub(1));
+
+    record_event(
+        TAmmEvent::WithdrawNftEvent(WithdrawNftEvent {
+            mint: ctx.accounts.nft_receipt.mint,
+        }),
+        &ctx.accounts.transfer.amm_program,
+        &ctx.accounts.transfer.pool,
+    )?;
+
+    // Close the NFT receipt account.
+    close_account(
+        &mut ctx.accounts.nft_receipt.to_account_info(),
+        &mut ctx.accounts.transfer.owner.to_account_info(),
+    )
+}
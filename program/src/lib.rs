@@ -37,6 +37,45 @@ pub mod amm_program {
         instructions::edit_pool::process_edit_pool(ctx, args)
     }
 
+    /// Convert a Token or NFT pool to a Trade pool, or a Trade pool back to a Token or NFT pool.
+    pub fn convert_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, ConvertPool<'info>>,
+        args: ConvertPoolArgs,
+    ) -> Result<()> {
+        instructions::convert_pool::process_convert_pool(ctx, args)
+    }
+
+    /// Upgrade a pool created under an older version to the current pool layout. Permissionless.
+    pub fn upgrade_pool(ctx: Context<UpgradePool>) -> Result<()> {
+        instructions::upgrade_pool::process_upgrade_pool(ctx)
+    }
+
+    /// Pause or resume buys and sells on a pool.
+    pub fn set_pool_status(
+        ctx: Context<SetPoolStatus>,
+        buys_paused: bool,
+        sells_paused: bool,
+    ) -> Result<()> {
+        instructions::set_pool_status::process_set_pool_status(ctx, buys_paused, sells_paused)
+    }
+
+    /// Set or clear the traits required from MPL Core assets sold or deposited into a pool.
+    pub fn set_trait_requirements(
+        ctx: Context<SetTraitRequirements>,
+        traits: Vec<TraitRequirement>,
+    ) -> Result<()> {
+        instructions::set_trait_requirements::process_set_trait_requirements(ctx, traits)
+    }
+
+    /// Transfer a pool to a new owner, moving its funds and NFTs to a new pool. Pools holding
+    /// compressed NFTs must withdraw them first.
+    pub fn transfer_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferPool<'info>>,
+        args: TransferPoolArgs,
+    ) -> Result<()> {
+        instructions::transfer_pool::process_transfer_pool(ctx, args)
+    }
+
     /// Close a pool if it has no NFTs and is not attached to a shared escrow.
     pub fn close_pool<'info>(ctx: Context<'_, '_, '_, 'info, ClosePool<'info>>) -> Result<()> {
         instructions::close_pool::process_close_pool(ctx)
@@ -65,6 +104,37 @@ pub mod amm_program {
         instructions::withdraw_sol::process_withdraw_sol(ctx, lamports)
     }
 
+    /// Deposit SPL currency tokens into a Token or Trade pool priced in an SPL mint.
+    pub fn deposit_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositToken<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_token::process_deposit_token(ctx, amount)
+    }
+
+    /// Withdraw SPL currency tokens from a Token or Trade pool priced in an SPL mint.
+    pub fn withdraw_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawToken<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_token::process_withdraw_token(ctx, amount)
+    }
+
+    /// Move the SOL balance of a Token or Trade pool into a shared escrow owned by the pool owner.
+    pub fn attach_shared_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, AttachSharedEscrow<'info>>,
+    ) -> Result<()> {
+        instructions::attach_shared_escrow::process_attach_shared_escrow(ctx)
+    }
+
+    /// Move a pool off its shared escrow, funding its own balance from the escrow.
+    pub fn detach_shared_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, DetachSharedEscrow<'info>>,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::detach_shared_escrow::process_detach_shared_escrow(ctx, lamports)
+    }
+
     //-------------------------------//
     // Legacy and PNFT instructions  //
     //-------------------------------//
@@ -85,6 +155,17 @@ pub mod amm_program {
         instructions::legacy::withdraw_nft::process_withdraw_nft(ctx, authorization_data)
     }
 
+    /// Permissionlessly return a Metaplex legacy NFT or pNFT from an expired pool to its owner.
+    pub fn withdraw_expired_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawExpiredNft<'info>>,
+        authorization_data: Option<AuthorizationDataLocal>,
+    ) -> Result<()> {
+        instructions::legacy::withdraw_expired_nft::process_withdraw_expired_nft(
+            ctx,
+            authorization_data,
+        )
+    }
+
     /// Buy a Metaplex legacy NFT or pNFT from a NFT or Trade pool.
     pub fn buy_nft<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyNft<'info>>,
@@ -100,6 +181,21 @@ pub mod amm_program {
         )
     }
 
+    /// Buy several Metaplex legacy NFTs or pNFTs from a NFT or Trade pool in a single instruction.
+    pub fn buy_nfts<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNfts<'info>>,
+        max_total_amount: u64,
+        authorization_data: Option<AuthorizationDataLocal>,
+        optional_royalty_pct: Option<u16>,
+    ) -> Result<()> {
+        instructions::legacy::process_buy_nfts(
+            ctx,
+            max_total_amount,
+            authorization_data,
+            optional_royalty_pct,
+        )
+    }
+
     /// Sell a Metaplex legacy NFT or pNFT into a Token pool.
     pub fn sell_nft_token_pool<'info>(
         ctx: Context<'_, '_, '_, 'info, SellNftTokenPool<'info>>,
@@ -130,6 +226,36 @@ pub mod amm_program {
         )
     }
 
+    /// Sell several Metaplex legacy NFTs or pNFTs into a Token pool in a single instruction.
+    pub fn sell_nfts_token_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTokenPool<'info>>,
+        min_total_price: u64,
+        authorization_data: Option<AuthorizationDataLocal>,
+        optional_royalty_pct: Option<u16>,
+    ) -> Result<()> {
+        instructions::legacy::process_sell_nfts_token_pool(
+            ctx,
+            min_total_price,
+            authorization_data,
+            optional_royalty_pct,
+        )
+    }
+
+    /// Sell several Metaplex legacy NFTs or pNFTs into a Trade pool in a single instruction.
+    pub fn sell_nfts_trade_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTradePool<'info>>,
+        min_total_price: u64,
+        authorization_data: Option<AuthorizationDataLocal>,
+        optional_royalty_pct: Option<u16>,
+    ) -> Result<()> {
+        instructions::legacy::process_sell_nfts_trade_pool(
+            ctx,
+            min_total_price,
+            authorization_data,
+            optional_royalty_pct,
+        )
+    }
+
     //-------------------------------//
     // MPL Core instructions         //
     //-------------------------------//
@@ -148,6 +274,13 @@ pub mod amm_program {
         instructions::mplx_core::process_withdraw_nft_core(ctx)
     }
 
+    /// Permissionlessly return a MPL Core asset from an expired pool to its owner.
+    pub fn withdraw_expired_nft_core<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawExpiredNftCore<'info>>,
+    ) -> Result<()> {
+        instructions::mplx_core::process_withdraw_expired_nft_core(ctx)
+    }
+
     /// Buy a MPL Core asset from a NFT or Trade pool.
     pub fn buy_nft_core<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyNftCore<'info>>,
@@ -156,6 +289,14 @@ pub mod amm_program {
         instructions::mplx_core::process_buy_nft_core(ctx, max_amount)
     }
 
+    /// Buy several MPL Core assets from a NFT or Trade pool in a single instruction.
+    pub fn buy_nfts_core<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNftsCore<'info>>,
+        max_total_amount: u64,
+    ) -> Result<()> {
+        instructions::mplx_core::process_buy_nfts_core(ctx, max_total_amount)
+    }
+
     /// Sell a MPL Core asset into a Token pool.
     pub fn sell_nft_token_pool_core<'info>(
         ctx: Context<'_, '_, '_, 'info, SellNftTokenPoolCore<'info>>,
@@ -174,6 +315,22 @@ pub mod amm_program {
         instructions::mplx_core::process_sell_nft_trade_pool_core(ctx, min_price)
     }
 
+    /// Sell several MPL Core assets into a Token pool in a single instruction.
+    pub fn sell_nfts_token_pool_core<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTokenPoolCore<'info>>,
+        min_total_price: u64,
+    ) -> Result<()> {
+        instructions::mplx_core::process_sell_nfts_token_pool_core(ctx, min_total_price)
+    }
+
+    /// Sell several MPL Core assets into a Trade pool in a single instruction.
+    pub fn sell_nfts_trade_pool_core<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTradePoolCore<'info>>,
+        min_total_price: u64,
+    ) -> Result<()> {
+        instructions::mplx_core::process_sell_nfts_trade_pool_core(ctx, min_total_price)
+    }
+
     //-------------------------------//
     // Token 2022 instructions       //
     //-------------------------------//
@@ -192,6 +349,13 @@ pub mod amm_program {
         instructions::process_withdraw_nft_t22(ctx)
     }
 
+    /// Permissionlessly return a Token22 NFT from an expired pool to its owner.
+    pub fn withdraw_expired_nft_t22<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawExpiredNftT22<'info>>,
+    ) -> Result<()> {
+        instructions::process_withdraw_expired_nft_t22(ctx)
+    }
+
     /// Buy a Token22 NFT from a NFT or Trade pool.
     pub fn buy_nft_t22<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyNftT22<'info>>,
@@ -200,6 +364,15 @@ pub mod amm_program {
         instructions::process_buy_nft_t22(ctx, max_amount)
     }
 
+    /// Buy several Token22 NFTs from a NFT or Trade pool in a single instruction.
+    pub fn buy_nfts_t22<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNftsT22<'info>>,
+        max_total_amount: u64,
+        transfer_hook_accounts: Vec<u8>,
+    ) -> Result<()> {
+        instructions::process_buy_nfts_t22(ctx, max_total_amount, transfer_hook_accounts)
+    }
+
     /// Sell a Token22 NFT into a Token pool.
     pub fn sell_nft_token_pool_t22<'info>(
         ctx: Context<'_, '_, '_, 'info, SellNftTokenPoolT22<'info>>,
@@ -215,4 +388,168 @@ pub mod amm_program {
     ) -> Result<()> {
         instructions::process_sell_nft_trade_pool_t22(ctx, min_price)
     }
+
+    /// Sell several Token22 NFTs into a Token pool in a single instruction.
+    pub fn sell_nfts_token_pool_t22<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTokenPoolT22<'info>>,
+        min_total_price: u64,
+        transfer_hook_accounts: Vec<u8>,
+    ) -> Result<()> {
+        instructions::process_sell_nfts_token_pool_t22(ctx, min_total_price, transfer_hook_accounts)
+    }
+
+    /// Sell several Token22 NFTs into a Trade pool in a single instruction.
+    pub fn sell_nfts_trade_pool_t22<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTradePoolT22<'info>>,
+        min_total_price: u64,
+        transfer_hook_accounts: Vec<u8>,
+    ) -> Result<()> {
+        instructions::process_sell_nfts_trade_pool_t22(ctx, min_total_price, transfer_hook_accounts)
+    }
+
+    //-------------------------------//
+    // WNS instructions              //
+    //-------------------------------//
+
+    /// Deposit a WNS NFT into a NFT or Trade pool.
+    pub fn deposit_nft_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositNftWns<'info>>,
+    ) -> Result<()> {
+        instructions::wns::process_deposit_nft_wns(ctx)
+    }
+
+    /// Withdraw a WNS NFT from a NFT or Trade pool.
+    pub fn withdraw_nft_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawNftWns<'info>>,
+    ) -> Result<()> {
+        instructions::wns::process_withdraw_nft_wns(ctx)
+    }
+
+    /// Permissionlessly return a WNS NFT from an expired pool to its owner.
+    pub fn withdraw_expired_nft_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawExpiredNftWns<'info>>,
+    ) -> Result<()> {
+        instructions::wns::process_withdraw_expired_nft_wns(ctx)
+    }
+
+    /// Buy a WNS NFT from a NFT or Trade pool.
+    pub fn buy_nft_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNftWns<'info>>,
+        max_amount: u64,
+    ) -> Result<()> {
+        instructions::wns::process_buy_nft_wns(ctx, max_amount)
+    }
+
+    /// Buy several WNS NFTs from a NFT or Trade pool in a single instruction.
+    pub fn buy_nfts_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNftsWns<'info>>,
+        max_total_amount: u64,
+    ) -> Result<()> {
+        instructions::wns::process_buy_nfts_wns(ctx, max_total_amount)
+    }
+
+    /// Sell a WNS NFT into a Token pool.
+    pub fn sell_nft_token_pool_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftTokenPoolWns<'info>>,
+        min_price: u64,
+    ) -> Result<()> {
+        instructions::wns::process_sell_nft_token_pool_wns(ctx, min_price)
+    }
+
+    /// Sell a WNS NFT into a Trade pool.
+    pub fn sell_nft_trade_pool_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftTradePoolWns<'info>>,
+        min_price: u64,
+    ) -> Result<()> {
+        instructions::wns::process_sell_nft_trade_pool_wns(ctx, min_price)
+    }
+
+    /// Sell several WNS NFTs into a Token pool in a single instruction.
+    pub fn sell_nfts_token_pool_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTokenPoolWns<'info>>,
+        min_total_price: u64,
+    ) -> Result<()> {
+        instructions::wns::process_sell_nfts_token_pool_wns(ctx, min_total_price)
+    }
+
+    /// Sell several WNS NFTs into a Trade pool in a single instruction.
+    pub fn sell_nfts_trade_pool_wns<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftsTradePoolWns<'info>>,
+        min_total_price: u64,
+    ) -> Result<()> {
+        instructions::wns::process_sell_nfts_trade_pool_wns(ctx, min_total_price)
+    }
+
+    //-------------------------------//
+    // Compressed instructions       //
+    //-------------------------------//
+
+    /// Deposit a compressed NFT into a NFT or Trade pool.
+    pub fn deposit_nft_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositNftCompressed<'info>>,
+        args: CompressedArgs,
+    ) -> Result<()> {
+        instructions::compressed::process_deposit_nft_compressed(ctx, args)
+    }
+
+    /// Withdraw a compressed NFT from a NFT or Trade pool.
+    pub fn withdraw_nft_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawNftCompressed<'info>>,
+        args: CompressedArgs,
+    ) -> Result<()> {
+        instructions::compressed::process_withdraw_nft_compressed(ctx, args)
+    }
+
+    /// Permissionlessly return a compressed NFT from an expired pool to its owner.
+    pub fn withdraw_expired_nft_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawExpiredNftCompressed<'info>>,
+        args: CompressedArgs,
+    ) -> Result<()> {
+        instructions::compressed::process_withdraw_expired_nft_compressed(ctx, args)
+    }
+
+    /// Buy a compressed NFT from a NFT or Trade pool.
+    pub fn buy_nft_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNftCompressed<'info>>,
+        args: CompressedArgs,
+        max_amount: u64,
+        optional_royalty_pct: Option<u16>,
+    ) -> Result<()> {
+        instructions::compressed::process_buy_nft_compressed(
+            ctx,
+            args,
+            max_amount,
+            optional_royalty_pct,
+        )
+    }
+
+    /// Sell a compressed NFT into a Token pool.
+    pub fn sell_nft_token_pool_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftTokenPoolCompressed<'info>>,
+        args: CompressedArgs,
+        min_price: u64,
+        optional_royalty_pct: Option<u16>,
+    ) -> Result<()> {
+        instructions::compressed::process_sell_nft_token_pool_compressed(
+            ctx,
+            args,
+            min_price,
+            optional_royalty_pct,
+        )
+    }
+
+    /// Sell a compressed NFT into a Trade pool.
+    pub fn sell_nft_trade_pool_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellNftTradePoolCompressed<'info>>,
+        args: CompressedArgs,
+        min_price: u64,
+        optional_royalty_pct: Option<u16>,
+    ) -> Result<()> {
+        instructions::compressed::process_sell_nft_trade_pool_compressed(
+            ctx,
+            args,
+            min_price,
+            optional_royalty_pct,
+        )
+    }
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use tensor_toolbox::HUNDRED_PCT_BPS;
+use tensor_vipers::unwrap_opt;
+
+use crate::{error::ErrorCode, TakerSide};
+
+/// Message a pool cosigner signs off-chain to approve trading an asset with the pool, as an
+/// alternative to cosigning the transaction.
+///
+/// The signature is verified by an Ed25519 program instruction preceding the trade, so the
+/// message is the borsh encoding of this struct.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CosignerAttestation {
+    /// The pool the attestation is valid for.
+    pub pool: Pubkey,
+    /// The asset the attestation approves.
+    pub asset: Pubkey,
+    /// The taker allowed to use the attestation, so it can't be front-run by someone else.
+    pub taker: Pubkey,
+    /// The side of the trade the attestation approves.
+    pub taker_side: TakerSide,
+    /// Unix timestamp after which the attestation can no longer be used.
+    pub expiry: i64,
+    /// Unique per attestation of the pool, preventing it from being used more than once. Nonces
+    /// more than `COSIGNER_NONCE_WINDOW` below the highest used one are rejected.
+    pub nonce: u64,
+    /// Adjustment of the pool price for this asset, eg a premium for rare traits.
+    pub price_modifier: PriceModifier,
+}
+
+impl CosignerAttestation {
+    /// Decodes a signed message, which must be exactly an encoded attestation.
+    pub fn from_message(message: &[u8]) -> Option<Self> {
+        Self::try_from_slice(message).ok()
+    }
+}
+
+/// Per-asset adjustment of the pool price, applied on top of the current price of the curve.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum PriceModifier {
+    #[default]
+    None,
+    /// Premium (positive) or discount (negative) in basis points of the price.
+    Bps(i16),
+    /// Premium (positive) or discount (negative) in lamports, or base units of the pool currency.
+    Lamports(i64),
+}
+
+impl PriceModifier {
+    /// Returns the adjusted price, which can't be negative.
+    pub fn apply(&self, price: u64) -> Result<u64> {
+        let adjusted = match *self {
+            PriceModifier::None => Some(price),
+            PriceModifier::Bps(bps) => (price as i128)
+                .checked_mul(HUNDRED_PCT_BPS as i128 + bps as i128)
+                .map(|amount| amount / HUNDRED_PCT_BPS as i128)
+                .and_then(|amount| u64::try_from(amount).ok()),
+            PriceModifier::Lamports(lamports) => price.checked_add_signed(lamports),
+        };
+
+        Ok(unwrap_opt!(adjusted, ErrorCode::InvalidPriceModifier))
+    }
+}
@@ -5,9 +5,24 @@ use super::*;
 #[repr(C)]
 pub enum TAmmEvent {
     BuySellEvent(BuySellEvent),
+    CreatePoolEvent(CreatePoolEvent),
+    EditPoolEvent(EditPoolEvent),
+    DepositNftEvent(DepositNftEvent),
+    WithdrawNftEvent(WithdrawNftEvent),
+    DepositCurrencyEvent(DepositCurrencyEvent),
+    WithdrawCurrencyEvent(WithdrawCurrencyEvent),
+    ClosePoolEvent(ClosePoolEvent),
+    BuySellEventV2(BuySellEventV2),
+    EditPoolWhitelistEvent(EditPoolWhitelistEvent),
+    TransferPoolEvent(TransferPoolEvent),
+    AttachSharedEscrowEvent(AttachSharedEscrowEvent),
+    DetachSharedEscrowEvent(DetachSharedEscrowEvent),
 }
 
 /// Event emitted when a user buys or sells tokens.
+///
+/// No longer emitted: superseded by `BuySellEventV2`, which is only emitted once the trade has
+/// settled. Kept so the indices of the other events don't change.
 #[derive(AnchorDeserialize, AnchorSerialize)]
 #[repr(C)]
 pub struct BuySellEvent {
@@ -16,3 +31,134 @@ pub struct BuySellEvent {
     pub mm_fee: u64,
     pub creators_fee: u64,
 }
+
+/// Event emitted once a buy or sell has settled, with the fees paid and the resulting pool state.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct BuySellEventV2 {
+    /// The assets traded; batch trades settle several at once.
+    pub assets: Vec<Pubkey>,
+    pub taker: Pubkey,
+    pub taker_side: TakerSide,
+    /// The pool price of the trade, before fees.
+    pub price: u64,
+    pub taker_fee: u64,
+    pub mm_fee: u64,
+    pub tamm_fee: u64,
+    pub maker_broker_fee: u64,
+    pub taker_broker_fee: u64,
+    /// The royalties actually paid to creators.
+    pub creators_fee: u64,
+    /// Price offset of the pool after the trade.
+    pub price_offset: i32,
+    /// NFTs held by the pool after the trade.
+    pub nfts_held: u32,
+    /// Currency amount held by the pool after the trade.
+    pub amount: u64,
+    /// Whether the trade left the pool to be auto-closed.
+    pub pool_closed: bool,
+}
+
+/// Event emitted when a pool is created.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct CreatePoolEvent {
+    pub owner: Pubkey,
+    pub whitelist: Pubkey,
+    pub currency: Pubkey,
+    pub config: PoolConfig,
+    pub expiry: i64,
+}
+
+/// Event emitted when a pool is edited, with the resulting pricing and expiry.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct EditPoolEvent {
+    pub config: PoolConfig,
+    pub expiry: i64,
+    pub price_offset: i32,
+}
+
+/// Event emitted when the owner points a pool at a new whitelist.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct EditPoolWhitelistEvent {
+    pub whitelist: Pubkey,
+}
+
+/// Event emitted by the new pool when a pool is transferred to a new owner.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct TransferPoolEvent {
+    /// The closed pool of the old owner.
+    pub old_pool: Pubkey,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    /// The NFTs moved to the new pool.
+    pub nfts_migrated: u32,
+}
+
+/// Event emitted when the owner moves a pool onto a shared escrow.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct AttachSharedEscrowEvent {
+    pub shared_escrow: Pubkey,
+    /// The pool balance deposited into the shared escrow.
+    pub amount: u64,
+}
+
+/// Event emitted when the owner moves a pool off its shared escrow.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct DetachSharedEscrowEvent {
+    pub shared_escrow: Pubkey,
+    /// The balance withdrawn from the shared escrow into the pool.
+    pub amount: u64,
+}
+
+/// Event emitted when the owner deposits an NFT into a pool.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct DepositNftEvent {
+    pub mint: Pubkey,
+}
+
+/// Event emitted when the owner withdraws an NFT from a pool.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct WithdrawNftEvent {
+    pub mint: Pubkey,
+}
+
+/// Event emitted when the owner deposits SOL or the pool's SPL currency into a pool.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct DepositCurrencyEvent {
+    pub amount: u64,
+}
+
+/// Event emitted when the owner withdraws SOL or the pool's SPL currency from a pool.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct WithdrawCurrencyEvent {
+    pub amount: u64,
+}
+
+/// Event emitted when a pool is closed.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct ClosePoolEvent {
+    pub reason: ClosePoolReason,
+}
+
+/// Why a pool was closed.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ClosePoolReason {
+    /// Closed by its owner.
+    Owner,
+    /// Closed permissionlessly after expiring.
+    Expired,
+    /// Closed after a trade left it without funds or NFTs to trade.
+    AutoClose,
+}
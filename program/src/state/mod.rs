@@ -1,15 +1,27 @@
 //! Program state.
 pub mod asset_deposit_receipt;
+pub mod cosigner_attestation;
 pub mod event;
 pub mod nft_deposit_receipt;
 pub mod pool;
+pub mod pool_v1;
+pub mod price_ladder;
+pub mod trait_requirements;
 
 pub use asset_deposit_receipt::*;
+pub use cosigner_attestation::*;
 pub use event::*;
 pub use nft_deposit_receipt::*;
 pub use pool::*;
+pub use pool_v1::*;
+pub use price_ladder::*;
+pub use trait_requirements::*;
 
 use anchor_lang::prelude::*;
+use mpl_bubblegum::types::{
+    Collection as BubblegumCollection, Creator as BubblegumCreator, MetadataArgs,
+    TokenProgramVersion, TokenStandard, UseMethod, Uses,
+};
 use mpl_token_metadata::types::{AuthorizationData, Payload, PayloadType, ProofInfo, SeedsVec};
 use std::collections::HashMap;
 
@@ -97,15 +109,165 @@ impl From<ProofInfoLocal> for ProofInfo {
     }
 }
 
+/// Local version of Bubblegum's `MetadataArgs` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct MetadataArgsLocal {
+    /// The name of the asset.
+    pub name: String,
+    /// The symbol of the asset.
+    pub symbol: String,
+    /// URI pointing to JSON representing the asset.
+    pub uri: String,
+    /// Royalty basis points that go to creators in secondary sales (0-10000).
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    /// Nonce for easy calculation of editions, if present.
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandardLocal>,
+    pub collection: Option<CollectionLocal>,
+    pub uses: Option<UsesLocal>,
+    pub token_program_version: TokenProgramVersionLocal,
+    pub creators: Vec<CreatorLocal>,
+}
+impl From<MetadataArgsLocal> for MetadataArgs {
+    fn from(val: MetadataArgsLocal) -> Self {
+        MetadataArgs {
+            name: val.name,
+            symbol: val.symbol,
+            uri: val.uri,
+            seller_fee_basis_points: val.seller_fee_basis_points,
+            primary_sale_happened: val.primary_sale_happened,
+            is_mutable: val.is_mutable,
+            edition_nonce: val.edition_nonce,
+            token_standard: val.token_standard.map(TokenStandard::from),
+            collection: val.collection.map(BubblegumCollection::from),
+            uses: val.uses.map(Uses::from),
+            token_program_version: TokenProgramVersion::from(val.token_program_version),
+            creators: val
+                .creators
+                .into_iter()
+                .map(BubblegumCreator::from)
+                .collect(),
+        }
+    }
+}
+
+/// Local version of Bubblegum's `TokenStandard` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenStandardLocal {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+impl From<TokenStandardLocal> for TokenStandard {
+    fn from(val: TokenStandardLocal) -> Self {
+        match val {
+            TokenStandardLocal::NonFungible => TokenStandard::NonFungible,
+            TokenStandardLocal::FungibleAsset => TokenStandard::FungibleAsset,
+            TokenStandardLocal::Fungible => TokenStandard::Fungible,
+            TokenStandardLocal::NonFungibleEdition => TokenStandard::NonFungibleEdition,
+        }
+    }
+}
+
+/// Local version of Bubblegum's `Collection` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionLocal {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+impl From<CollectionLocal> for BubblegumCollection {
+    fn from(val: CollectionLocal) -> Self {
+        BubblegumCollection {
+            verified: val.verified,
+            key: val.key,
+        }
+    }
+}
+
+/// Local version of Bubblegum's `Uses` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsesLocal {
+    pub use_method: UseMethodLocal,
+    pub remaining: u64,
+    pub total: u64,
+}
+impl From<UsesLocal> for Uses {
+    fn from(val: UsesLocal) -> Self {
+        Uses {
+            use_method: UseMethod::from(val.use_method),
+            remaining: val.remaining,
+            total: val.total,
+        }
+    }
+}
+
+/// Local version of Bubblegum's `UseMethod` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UseMethodLocal {
+    Burn,
+    Multiple,
+    Single,
+}
+impl From<UseMethodLocal> for UseMethod {
+    fn from(val: UseMethodLocal) -> Self {
+        match val {
+            UseMethodLocal::Burn => UseMethod::Burn,
+            UseMethodLocal::Multiple => UseMethod::Multiple,
+            UseMethodLocal::Single => UseMethod::Single,
+        }
+    }
+}
+
+/// Local version of Bubblegum's `TokenProgramVersion` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenProgramVersionLocal {
+    Original,
+    Token2022,
+}
+impl From<TokenProgramVersionLocal> for TokenProgramVersion {
+    fn from(val: TokenProgramVersionLocal) -> Self {
+        match val {
+            TokenProgramVersionLocal::Original => TokenProgramVersion::Original,
+            TokenProgramVersionLocal::Token2022 => TokenProgramVersion::Token2022,
+        }
+    }
+}
+
+/// Local version of Bubblegum's `Creator` for IDL export.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreatorLocal {
+    pub address: Pubkey,
+    pub verified: bool,
+    /// The percentage share of royalties, in the range 0-100.
+    pub share: u8,
+}
+impl From<CreatorLocal> for BubblegumCreator {
+    fn from(val: CreatorLocal) -> Self {
+        BubblegumCreator {
+            address: val.address,
+            verified: val.verified,
+            share: val.share,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
-    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+    use anchor_lang::{solana_program::native_token::LAMPORTS_PER_SOL, Discriminator};
     use spl_math::precise_number::PreciseNumber;
     use tensor_toolbox::HUNDRED_PCT_BPS;
 
+    use crate::constants::{
+        COSIGNER_NONCE_WINDOW, CURRENT_POOL_VERSION, DISCRIMINATOR_SIZE, GDA_DECAY_PERIOD_SEC,
+        MAX_PRICE_LADDER_LEN, MAX_TRAIT_LEN, MAX_TRAIT_REQUIREMENTS,
+    };
+
     impl Pool {
         pub fn new(
             pool_type: PoolType,
@@ -142,7 +304,21 @@ mod tests {
                 amount: 0,
                 shared_escrow: Pubkey::default(),
                 max_taker_sell_count: 10,
-                _reserved: [0; 100],
+                decay_bps: 0,
+                floor_price: 0,
+                manager: Pubkey::default(),
+                buys_paused: false,
+                sells_paused: false,
+                cosigner_nonce: 0,
+                requires_traits: false,
+                max_taker_buy_count: 0,
+                max_nfts_held: 0,
+                max_total_spend: 0,
+                circuit_breaker: CircuitBreaker::default(),
+                keeper_bounty: 0,
+                cosigner_nonces_used: [0; 8],
+                total_spent: 0,
+                _reserved: [0; 71],
             }
         }
     }
@@ -761,4 +937,630 @@ mod tests {
         let sell_price = p.current_price(TakerSide::Sell).unwrap();
         assert_eq!(sell_price, 926_869_960); // Rounded down from 926,869,960.144591713783...
     }
+
+    // --------------------------------------- xyk
+
+    fn xyk_pool(amount: u64, nfts_held: u32) -> Pool {
+        let mut p = Pool::new(PoolType::Trade, CurveType::Xyk, 1, 0, 0, 0);
+        p.amount = amount;
+        p.nfts_held = nfts_held;
+        p
+    }
+
+    #[test]
+    fn test_xyk_trade_pool() {
+        let p = xyk_pool(10 * LAMPORTS_PER_SOL, 10);
+
+        // k = 100 SOL: buying takes the pool from 10 to 9 NFTs, selling from 10 to 11.
+        assert_eq!(
+            p.current_price(TakerSide::Buy).unwrap(),
+            1_111_111_112 // Rounded up from 1,111,111,111.11...
+        );
+        assert_eq!(
+            p.current_price(TakerSide::Sell).unwrap(),
+            909_090_909 // Rounded down from 909,090,909.09...
+        );
+    }
+
+    #[test]
+    fn test_xyk_trade_pool_follows_reserves() {
+        let mut p = xyk_pool(10 * LAMPORTS_PER_SOL, 10);
+        let buy_price = p.current_price(TakerSide::Buy).unwrap();
+
+        // The taker buys an NFT: the pool holds one NFT less and the price paid.
+        p.amount += buy_price;
+        p.nfts_held -= 1;
+        p.price_offset += 1;
+
+        let next_buy_price = p.current_price(TakerSide::Buy).unwrap();
+        assert!(next_buy_price > buy_price);
+
+        // Selling it back pays what the buyer paid, minus rounding.
+        let sell_price = p.current_price(TakerSide::Sell).unwrap();
+        assert!(sell_price <= buy_price && buy_price - sell_price <= 1);
+    }
+
+    #[test]
+    fn test_xyk_shift_price() {
+        let p = xyk_pool(10 * LAMPORTS_PER_SOL, 10);
+
+        // Quoting the second NFT bought moves along the curve from 9 to 8 NFTs.
+        assert_eq!(
+            p.shift_price(1, TakerSide::Buy).unwrap(),
+            1_388_888_889 // Rounded up from 100 SOL / (9 * 8)
+        );
+        // Quoting the second NFT sold moves along the curve from 11 to 12 NFTs.
+        assert_eq!(
+            p.shift_price(-1, TakerSide::Sell).unwrap(),
+            757_575_757 // Rounded down from 100 SOL / (11 * 12)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "InsufficientReserves")]
+    fn test_xyk_cannot_buy_last_nft() {
+        let p = xyk_pool(10 * LAMPORTS_PER_SOL, 1);
+        p.current_price(TakerSide::Buy).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "InsufficientReserves")]
+    fn test_xyk_cannot_sell_without_nfts() {
+        let p = xyk_pool(10 * LAMPORTS_PER_SOL, 0);
+        p.current_price(TakerSide::Sell).unwrap();
+    }
+
+    #[test]
+    fn test_xyk_config_requires_trade_pool() {
+        let config = PoolConfig {
+            pool_type: PoolType::NFT,
+            curve_type: CurveType::Xyk,
+            starting_price: 1,
+            delta: 0,
+            mm_compound_fees: false,
+            mm_fee_bps: 0,
+        };
+        assert!(config.validate().is_err());
+
+        let config = PoolConfig {
+            pool_type: PoolType::Trade,
+            ..config
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    // --------------------------------------- gda
+
+    fn gda_pool(starting_price: u64, delta: u64, decay_bps: u16, floor_price: u64) -> Pool {
+        let mut p = Pool::new(PoolType::NFT, CurveType::Gda, starting_price, delta, 0, 0);
+        p.decay_bps = decay_bps;
+        p.floor_price = floor_price;
+        p
+    }
+
+    #[test]
+    fn test_gda_nft_pool_decays() {
+        let p = gda_pool(LAMPORTS_PER_SOL, 1_000, 1_000, 0);
+
+        // No decay until a full period has elapsed.
+        assert_eq!(
+            p.shift_price_at(0, TakerSide::Buy, GDA_DECAY_PERIOD_SEC - 1)
+                .unwrap(),
+            LAMPORTS_PER_SOL
+        );
+
+        // 10% decay per period.
+        assert_eq!(
+            p.shift_price_at(0, TakerSide::Buy, GDA_DECAY_PERIOD_SEC)
+                .unwrap(),
+            LAMPORTS_PER_SOL * 9 / 10
+        );
+        assert_eq!(
+            p.shift_price_at(0, TakerSide::Buy, GDA_DECAY_PERIOD_SEC * 2)
+                .unwrap(),
+            LAMPORTS_PER_SOL * 81 / 100
+        );
+    }
+
+    #[test]
+    fn test_gda_nft_pool_floor() {
+        let floor = LAMPORTS_PER_SOL / 2;
+        let p = gda_pool(LAMPORTS_PER_SOL, 1_000, 1_000, floor);
+
+        // 0.9^7 = 0.478 would be below the floor.
+        assert_eq!(
+            p.shift_price_at(0, TakerSide::Buy, GDA_DECAY_PERIOD_SEC * 7)
+                .unwrap(),
+            floor
+        );
+        assert_eq!(
+            p.shift_price_at(0, TakerSide::Buy, i64::MAX).unwrap(),
+            floor
+        );
+    }
+
+    #[test]
+    fn test_gda_nft_pool_bumps_after_fill() {
+        let p = gda_pool(LAMPORTS_PER_SOL, 1_000, 1_000, 0);
+
+        // After a fill, the price is bumped up by delta from the fill price.
+        assert_eq!(
+            p.shift_price_at(1, TakerSide::Buy, 0).unwrap(),
+            LAMPORTS_PER_SOL * 11 / 10
+        );
+    }
+
+    #[test]
+    fn test_gda_validation() {
+        let mut p = gda_pool(LAMPORTS_PER_SOL, 1_000, 1_000, LAMPORTS_PER_SOL / 2);
+        assert!(p.validate_decay().is_ok());
+
+        // Must decay.
+        p.decay_bps = 0;
+        assert!(p.validate_decay().is_err());
+
+        // Floor can't be above the starting price.
+        p.decay_bps = 1_000;
+        p.floor_price = LAMPORTS_PER_SOL + 1;
+        assert!(p.validate_decay().is_err());
+
+        // Only NFT pools.
+        let config = PoolConfig {
+            pool_type: PoolType::Trade,
+            ..p.config
+        };
+        assert!(config.validate().is_err());
+    }
+
+    // --------------------------------------- custom
+
+    fn price_ladder(prices: Vec<u64>, zero_index: u16) -> PriceLadder {
+        PriceLadder {
+            bump: [1],
+            pool: Pubkey::default(),
+            zero_index,
+            prices,
+        }
+    }
+
+    #[test]
+    fn test_custom_token_pool() {
+        // Heavy bids near the floor, thin bids above it.
+        let ladder = price_ladder(vec![90, 95, 96, 97, 150], 4);
+        let mut p = Pool::new(PoolType::Token, CurveType::Custom, 1, 0, 0, 0);
+
+        assert_eq!(
+            p.current_price_with(TakerSide::Sell, Some(&ladder))
+                .unwrap(),
+            150
+        );
+
+        // Walks down the ladder as the pool buys.
+        p.price_offset -= 1;
+        assert_eq!(
+            p.current_price_with(TakerSide::Sell, Some(&ladder))
+                .unwrap(),
+            97
+        );
+        p.price_offset -= 3;
+        assert_eq!(
+            p.current_price_with(TakerSide::Sell, Some(&ladder))
+                .unwrap(),
+            90
+        );
+    }
+
+    #[test]
+    fn test_custom_trade_pool() {
+        let ladder = price_ladder(vec![90, 95, 100, 120], 2);
+        let p = Pool::new(PoolType::Trade, CurveType::Custom, 1, 0, 0, 0);
+
+        // Sells are quoted one step below buys.
+        assert_eq!(
+            p.current_price_with(TakerSide::Buy, Some(&ladder)).unwrap(),
+            100
+        );
+        assert_eq!(
+            p.current_price_with(TakerSide::Sell, Some(&ladder))
+                .unwrap(),
+            95
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "PriceLadderExhausted")]
+    fn test_custom_pool_panic_past_ladder() {
+        let ladder = price_ladder(vec![90, 95], 0);
+        let p = Pool::new(PoolType::Token, CurveType::Custom, 1, 0, -1, 0);
+        p.current_price_with(TakerSide::Sell, Some(&ladder))
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "MissingPriceLadder")]
+    fn test_custom_pool_panic_without_ladder() {
+        let p = Pool::new(PoolType::Token, CurveType::Custom, 1, 0, 0, 0);
+        p.current_price(TakerSide::Sell).unwrap();
+    }
+
+    #[test]
+    fn test_price_ladder_validation() {
+        let args = |prices: Vec<u64>, zero_index| PriceLadderArgs { prices, zero_index };
+
+        assert!(args(vec![1, 2, 2, 3], 3).validate().is_ok());
+        // Empty.
+        assert!(args(vec![], 0).validate().is_err());
+        // Not monotonic.
+        assert!(args(vec![1, 3, 2], 0).validate().is_err());
+        // Zero index outside the ladder.
+        assert!(args(vec![1, 2], 2).validate().is_err());
+        // Too long.
+        assert!(args(vec![1; MAX_PRICE_LADDER_LEN + 1], 0)
+            .validate()
+            .is_err());
+    }
+
+    // --------------------------------------- pause
+
+    #[test]
+    fn test_paused_sides() {
+        let mut p = Pool::new(PoolType::Trade, CurveType::Linear, 1, 0, 0, 0);
+        assert!(!p.is_paused(TakerSide::Buy));
+        assert!(!p.is_paused(TakerSide::Sell));
+
+        p.buys_paused = true;
+        assert!(p.is_paused(TakerSide::Buy));
+        assert!(!p.is_paused(TakerSide::Sell));
+
+        p.buys_paused = false;
+        p.sells_paused = true;
+        assert!(!p.is_paused(TakerSide::Buy));
+        assert!(p.is_paused(TakerSide::Sell));
+    }
+
+    #[test]
+    fn test_max_taker_buy_count() {
+        let mut p = Pool::new(PoolType::NFT, CurveType::Linear, 1, 0, 0, 0);
+        p.max_taker_buy_count = 2;
+        assert!(p.taker_allowed_to_buy().is_ok());
+
+        p.stats.taker_buy_count = 2;
+        assert!(p.taker_allowed_to_buy().is_err());
+        assert!(p.valid_max_buy_count(1).is_err());
+        assert!(p.valid_max_buy_count(2).is_ok());
+
+        // Sells into the pool free up room for more buys.
+        p.stats.taker_sell_count = 1;
+        assert!(p.taker_allowed_to_buy().is_ok());
+
+        p.max_taker_buy_count = 0;
+        p.stats.taker_sell_count = 0;
+        assert!(p.taker_allowed_to_buy().is_ok());
+    }
+
+    #[test]
+    fn test_max_nfts_held() {
+        let mut p = Pool::new(PoolType::Trade, CurveType::Linear, 1, 0, 0, 0);
+        p.max_nfts_held = 2;
+        p.nfts_held = 1;
+        assert!(p.can_hold_more_nfts().is_ok());
+
+        p.nfts_held = 2;
+        assert!(p.can_hold_more_nfts().is_err());
+        assert!(p.valid_max_nfts_held(1).is_err());
+        assert!(p.valid_max_nfts_held(2).is_ok());
+        assert!(p.valid_max_nfts_held(0).is_ok());
+
+        p.max_nfts_held = 0;
+        assert!(p.can_hold_more_nfts().is_ok());
+    }
+
+    #[test]
+    fn test_max_total_spend() {
+        let mut p = Pool::new(
+            PoolType::Token,
+            CurveType::Linear,
+            LAMPORTS_PER_SOL,
+            0,
+            0,
+            0,
+        );
+        p.max_total_spend = 2 * LAMPORTS_PER_SOL;
+
+        p.record_spend(LAMPORTS_PER_SOL).unwrap();
+        p.record_spend(LAMPORTS_PER_SOL).unwrap();
+        assert_eq!(p.total_spent, 2 * LAMPORTS_PER_SOL);
+
+        // A failed spend leaves the stats untouched.
+        assert!(p.record_spend(1).is_err());
+        assert_eq!(p.total_spent, 2 * LAMPORTS_PER_SOL);
+
+        assert!(p.valid_max_total_spend(LAMPORTS_PER_SOL).is_err());
+        assert!(p.valid_max_total_spend(2 * LAMPORTS_PER_SOL).is_ok());
+        assert!(p.valid_max_total_spend(0).is_ok());
+
+        p.max_total_spend = 0;
+        assert!(p.record_spend(LAMPORTS_PER_SOL).is_ok());
+    }
+
+    #[test]
+    fn test_keeper_bounty_share() {
+        let mut p = Pool::new(PoolType::NFT, CurveType::Linear, 1, 0, 0, 0);
+        assert_eq!(p.keeper_bounty_share(), 0);
+
+        // Returning each of the 2 NFTs and closing the pool pay a third of the bounty each.
+        p.keeper_bounty = 900;
+        p.nfts_held = 2;
+        assert_eq!(p.keeper_bounty_share(), 300);
+
+        p.keeper_bounty -= 300;
+        p.nfts_held -= 1;
+        assert_eq!(p.keeper_bounty_share(), 300);
+
+        // Rounding leftovers go to the keeper closing the pool.
+        p.keeper_bounty = 301;
+        p.nfts_held = 0;
+        assert_eq!(p.keeper_bounty_share(), 301);
+    }
+
+    #[test]
+    fn test_use_cosigner_nonce() {
+        let mut p = Pool::new(PoolType::Trade, CurveType::Linear, 1, 0, 0, 0);
+
+        // Nonces can be used in any order, but only once.
+        p.use_cosigner_nonce(10).unwrap();
+        p.use_cosigner_nonce(7).unwrap();
+        p.use_cosigner_nonce(12).unwrap();
+        p.use_cosigner_nonce(11).unwrap();
+        assert_eq!(p.cosigner_nonce, 12);
+
+        assert!(p.use_cosigner_nonce(7).is_err());
+        assert!(p.use_cosigner_nonce(10).is_err());
+        assert!(p.use_cosigner_nonce(12).is_err());
+        p.use_cosigner_nonce(8).unwrap();
+
+        // Used nonces stay used when the window slides up.
+        p.use_cosigner_nonce(100).unwrap();
+        assert!(p.use_cosigner_nonce(10).is_err());
+        p.use_cosigner_nonce(9).unwrap();
+
+        // Nonces that fell out of the window can't be used anymore.
+        p.use_cosigner_nonce(COSIGNER_NONCE_WINDOW + 9).unwrap();
+        assert!(p.use_cosigner_nonce(9).is_err());
+        p.use_cosigner_nonce(13).unwrap();
+        assert!(p.use_cosigner_nonce(13).is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let mut breaker = CircuitBreaker::default();
+        assert!(breaker
+            .set_limits(CircuitBreakerArgs {
+                window_sec: 60,
+                max_trades: 0,
+                max_price_offset_change: 0,
+            })
+            .is_err());
+        assert!(breaker
+            .set_limits(CircuitBreakerArgs {
+                window_sec: 0,
+                max_trades: 3,
+                max_price_offset_change: 0,
+            })
+            .is_err());
+
+        breaker
+            .set_limits(CircuitBreakerArgs {
+                window_sec: 60,
+                max_trades: 3,
+                max_price_offset_change: 2,
+            })
+            .unwrap();
+
+        // Buys and sells cancel out in the price offset, but all count as trades.
+        breaker.record_trade(1_000, 0, 1).unwrap();
+        breaker.record_trade(1_010, 1, 0).unwrap();
+        breaker.record_trade(1_020, 0, -1).unwrap();
+        assert!(breaker.record_trade(1_030, -1, -2).is_err());
+
+        // A new window starts from the offset before its first trade.
+        let mut breaker = CircuitBreaker {
+            window_start: 1_000,
+            window_trades: 3,
+            ..breaker
+        };
+        breaker.record_trade(1_060, -1, -2).unwrap();
+        assert_eq!(breaker.window_start, 1_060);
+        assert_eq!(breaker.window_start_offset, -1);
+        breaker.record_trade(1_061, -2, -3).unwrap();
+        assert!(breaker.record_trade(1_062, -3, -4).is_err());
+
+        // Disabled breakers never trip.
+        let mut breaker = CircuitBreaker::default();
+        for offset in 0..100 {
+            breaker.record_trade(1_000, offset, offset + 1).unwrap();
+        }
+    }
+
+    // --------------------------------------- versions
+
+    #[test]
+    fn test_deserialize_v1_pool() {
+        let mut p = Pool::new(PoolType::Trade, CurveType::Exponential, 1_000, 100, -2, 50);
+        p.amount = LAMPORTS_PER_SOL;
+        p.nfts_held = 3;
+
+        let v1 = PoolV1 {
+            version: 1,
+            bump: p.bump,
+            pool_id: p.pool_id,
+            created_at: p.created_at,
+            updated_at: p.updated_at,
+            expiry: p.expiry,
+            owner: p.owner,
+            whitelist: p.whitelist,
+            rent_payer: p.rent_payer,
+            currency: p.currency,
+            amount: p.amount,
+            price_offset: p.price_offset,
+            nfts_held: p.nfts_held,
+            stats: p.stats,
+            shared_escrow: p.shared_escrow,
+            cosigner: p.cosigner,
+            maker_broker: p.maker_broker,
+            max_taker_sell_count: p.max_taker_sell_count,
+            config: p.config,
+            _reserved: [0; 100],
+        };
+
+        let mut data = Pool::DISCRIMINATOR.to_vec();
+        v1.serialize(&mut data).unwrap();
+        // Version 1 pools were allocated with 447 bytes.
+        assert_eq!(PoolV1::SIZE, 447);
+        assert_eq!(data.len(), PoolV1::SIZE);
+
+        p.version = CURRENT_POOL_VERSION;
+        assert_eq!(Pool::try_deserialize_versioned(&data).unwrap(), p);
+
+        // Current pools decode as they are. Version 2 fields are carved out of the reserved
+        // space, so the account size must never change.
+        let mut data = Vec::new();
+        p.try_serialize(&mut data).unwrap();
+        assert_eq!(Pool::SIZE, 591);
+        assert_eq!(data.len(), Pool::SIZE);
+        assert_eq!(Pool::try_deserialize_versioned(&data).unwrap(), p);
+
+        // Unknown versions are rejected.
+        data[DISCRIMINATOR_SIZE] = CURRENT_POOL_VERSION + 1;
+        assert!(Pool::try_deserialize_versioned(&data).is_err());
+    }
+
+    // --------------------------------------- cosigner attestation
+
+    #[test]
+    fn test_cosigner_attestation_message() {
+        let attestation = CosignerAttestation {
+            pool: Pubkey::new_unique(),
+            asset: Pubkey::new_unique(),
+            taker: Pubkey::new_unique(),
+            taker_side: TakerSide::Buy,
+            expiry: 1_700_000_000,
+            nonce: 42,
+            price_modifier: PriceModifier::Bps(500),
+        };
+
+        let message = attestation.try_to_vec().unwrap();
+        assert_eq!(&message[..32], attestation.pool.as_ref());
+        assert_eq!(&message[32..64], attestation.asset.as_ref());
+        assert_eq!(&message[64..96], attestation.taker.as_ref());
+
+        assert_eq!(
+            CosignerAttestation::from_message(&message),
+            Some(attestation)
+        );
+
+        // Messages with trailing or missing bytes are not attestations.
+        let mut longer = message.clone();
+        longer.push(0);
+        assert_eq!(CosignerAttestation::from_message(&longer), None);
+        assert_eq!(
+            CosignerAttestation::from_message(&message[..message.len() - 1]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_price_modifier() {
+        let price = 2 * LAMPORTS_PER_SOL;
+
+        assert_eq!(PriceModifier::None.apply(price).unwrap(), price);
+
+        // 5% premium and 25% discount.
+        assert_eq!(PriceModifier::Bps(500).apply(price).unwrap(), 2_100_000_000);
+        assert_eq!(
+            PriceModifier::Bps(-2_500).apply(price).unwrap(),
+            1_500_000_000
+        );
+
+        assert_eq!(
+            PriceModifier::Lamports(1_000).apply(price).unwrap(),
+            price + 1_000
+        );
+        assert_eq!(
+            PriceModifier::Lamports(-1_000).apply(price).unwrap(),
+            price - 1_000
+        );
+
+        // Discounts can't make the price negative.
+        assert!(PriceModifier::Bps(-10_001).apply(price).is_err());
+        assert!(PriceModifier::Lamports(-(price as i64) - 1)
+            .apply(price)
+            .is_err());
+        assert!(PriceModifier::Lamports(i64::MAX).apply(u64::MAX).is_err());
+    }
+
+    // --------------------------------------- traits
+
+    fn attribute(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    fn requirement(key: &str, value: &str) -> TraitRequirement {
+        TraitRequirement {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_trait_requirements() {
+        let mut requirements = TraitRequirements {
+            bump: [1],
+            pool: Pubkey::new_unique(),
+            traits: vec![],
+        };
+
+        requirements
+            .set_traits(vec![
+                requirement("Background", "Gold"),
+                requirement("Eyes", "Laser"),
+            ])
+            .unwrap();
+
+        // Every required trait must be present, other attributes are ignored.
+        assert!(requirements.is_met_by(&[
+            attribute("Eyes", "Laser"),
+            attribute("Hat", "None"),
+            attribute("Background", "Gold"),
+        ]));
+        assert!(!requirements.is_met_by(&[attribute("Background", "Gold")]));
+        assert!(
+            !requirements.is_met_by(&[attribute("Background", "Gold"), attribute("Eyes", "Blue"),])
+        );
+        assert!(!requirements.is_met_by(&[]));
+    }
+
+    #[test]
+    fn test_trait_requirements_validation() {
+        let mut requirements = TraitRequirements {
+            bump: [1],
+            pool: Pubkey::new_unique(),
+            traits: vec![],
+        };
+
+        assert!(requirements.set_traits(vec![]).is_err());
+        assert!(requirements
+            .set_traits(vec![
+                requirement("Eyes", "Laser");
+                MAX_TRAIT_REQUIREMENTS + 1
+            ])
+            .is_err());
+        assert!(requirements
+            .set_traits(vec![requirement(&"a".repeat(MAX_TRAIT_LEN + 1), "Laser")])
+            .is_err());
+
+        assert!(requirements
+            .set_traits(vec![requirement("Eyes", "Laser"); MAX_TRAIT_REQUIREMENTS])
+            .is_ok());
+    }
 }
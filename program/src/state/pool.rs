@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 use spl_math::precise_number::PreciseNumber;
 use tensor_toolbox::{transfer_lamports_checked, HUNDRED_PCT_BPS};
-use tensor_vipers::{throw_err, try_or_err, unwrap_checked, unwrap_int};
+use tensor_vipers::{throw_err, try_or_err, unwrap_checked, unwrap_int, unwrap_opt};
 
 use crate::{
-    constants::{DISCRIMINATOR_SIZE, MAX_DELTA_BPS, MAX_MM_FEES_BPS},
+    constants::{
+        COSIGNER_NONCE_WINDOW, CURRENT_POOL_VERSION, DISCRIMINATOR_SIZE, GDA_DECAY_PERIOD_SEC,
+        MAX_DELTA_BPS, MAX_MM_FEES_BPS,
+    },
     error::ErrorCode,
-    MAX_EXPIRY_SEC,
+    PriceLadder, MAX_EXPIRY_SEC,
 };
 
 /// Enum representing the different types of pools.
@@ -32,6 +35,14 @@ pub enum PoolType {
 /// Linear curves have price offsets that increase or decrease linearly.
 ///
 /// Exponential curves have a price offset that increases or decreases exponentially.
+///
+/// XYK curves price NFTs from the pool's reserves (`amount` and `nfts_held`) so that their
+/// product stays constant, and are only available to Trade pools.
+///
+/// GDA curves are gradual Dutch auctions: the price decays towards a floor while the pool doesn't
+/// trade, and bumps back up by `delta` basis points after each fill. Only available to NFT pools.
+///
+/// Custom curves read their prices from the pool's `PriceLadder` account, indexed by the price offset.
 #[repr(u8)]
 #[derive(
     AnchorSerialize, AnchorDeserialize, Debug, Default, Clone, Copy, InitSpace, PartialEq, Eq,
@@ -40,6 +51,9 @@ pub enum CurveType {
     #[default]
     Linear = 0,
     Exponential = 1,
+    Xyk = 2,
+    Gda = 3,
+    Custom = 4,
 }
 
 /// Configuration values for a pool define the type of pool, curve, and other parameters.
@@ -56,6 +70,13 @@ pub struct PoolConfig {
 }
 
 impl PoolConfig {
+    /// Whether pools with this config can trade from a shared escrow: NFT pools don't hold any
+    /// currency, and XYK prices come from the pool's own reserves, which a shared escrow doesn't
+    /// track.
+    pub fn can_use_shared_escrow(&self) -> bool {
+        self.pool_type != PoolType::NFT && self.curve_type != CurveType::Xyk
+    }
+
     pub fn validate(&self) -> Result<()> {
         match self.pool_type {
             PoolType::NFT | PoolType::Token => {
@@ -70,19 +91,39 @@ impl PoolConfig {
             }
         }
 
-        //for exponential pool delta can't be above 99.99% and has to fit into a u16
-        if self.curve_type == CurveType::Exponential {
-            let u16delta = try_or_err!(u16::try_from(self.delta), ErrorCode::ArithmeticError);
-            if u16delta > MAX_DELTA_BPS {
-                throw_err!(ErrorCode::DeltaTooLarge);
+        match self.curve_type {
+            //for exponential pool delta can't be above 99.99% and has to fit into a u16
+            CurveType::Exponential => {
+                let u16delta = try_or_err!(u16::try_from(self.delta), ErrorCode::ArithmeticError);
+                if u16delta > MAX_DELTA_BPS {
+                    throw_err!(ErrorCode::DeltaTooLarge);
+                }
+            }
+            //gda pools bump the price exponentially after each fill and only list NFTs
+            CurveType::Gda => {
+                if self.pool_type != PoolType::NFT {
+                    throw_err!(ErrorCode::WrongPoolType);
+                }
+
+                let u16delta = try_or_err!(u16::try_from(self.delta), ErrorCode::ArithmeticError);
+                if u16delta > MAX_DELTA_BPS {
+                    throw_err!(ErrorCode::DeltaTooLarge);
+                }
+            }
+            //xyk pools need both reserves, so they can only be two-sided
+            CurveType::Xyk => {
+                if self.pool_type != PoolType::Trade {
+                    throw_err!(ErrorCode::WrongPoolType);
+                }
             }
+            CurveType::Linear | CurveType::Custom => {}
         }
 
         Ok(())
     }
 }
 
-/// Stats for a pool include the number of buys and sells, and the accumulated MM profit.
+/// Stats for a pool include the number of buys and sells and the accumulated MM profit.
 #[derive(
     AnchorSerialize, AnchorDeserialize, Debug, Default, Clone, Copy, InitSpace, PartialEq, Eq,
 )]
@@ -92,6 +133,96 @@ pub struct PoolStats {
     pub accumulated_mm_profit: u64,
 }
 
+/// Limits how fast a pool can trade: at most `max_trades` trades, and a price offset moving by at
+/// most `max_price_offset_change`, within each window of `window_sec` seconds.
+///
+/// GDA curves restart the price offset on every fill, so only the trade limit applies to them.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Debug, Default, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct CircuitBreaker {
+    /// Length of a window, in seconds. 0 disables the circuit breaker.
+    pub window_sec: u32,
+    /// Maximum number of trades within a window, 0 for no limit.
+    pub max_trades: u16,
+    /// Maximum absolute change of the price offset within a window, 0 for no limit.
+    pub max_price_offset_change: u32,
+    /// Unix timestamp of the start of the current window, in seconds.
+    pub window_start: i64,
+    /// Number of trades in the current window.
+    pub window_trades: u16,
+    /// Price offset of the pool at the start of the current window.
+    pub window_start_offset: i32,
+}
+
+impl CircuitBreaker {
+    /// Replaces the limits after validating them, starting a new window on the next trade.
+    pub fn set_limits(&mut self, args: CircuitBreakerArgs) -> Result<()> {
+        args.validate()?;
+
+        *self = CircuitBreaker {
+            window_sec: args.window_sec,
+            max_trades: args.max_trades,
+            max_price_offset_change: args.max_price_offset_change,
+            ..Default::default()
+        };
+
+        Ok(())
+    }
+
+    /// Records a trade moving the price offset from `offset_before` to `offset_after`, failing
+    /// if it exceeds the limits of the current window.
+    pub fn record_trade(&mut self, now: i64, offset_before: i32, offset_after: i32) -> Result<()> {
+        if self.window_sec == 0 {
+            return Ok(());
+        }
+
+        // Start a new window once the current one has elapsed.
+        let window_end = unwrap_int!(self.window_start.checked_add(i64::from(self.window_sec)));
+        if now >= window_end {
+            self.window_start = now;
+            self.window_trades = 0;
+            self.window_start_offset = offset_before;
+        }
+
+        self.window_trades = unwrap_int!(self.window_trades.checked_add(1));
+        if self.max_trades != 0 && self.window_trades > self.max_trades {
+            throw_err!(ErrorCode::CircuitBreakerTripped);
+        }
+
+        let offset_change =
+            (i64::from(offset_after) - i64::from(self.window_start_offset)).unsigned_abs();
+        if self.max_price_offset_change != 0
+            && offset_change > u64::from(self.max_price_offset_change)
+        {
+            throw_err!(ErrorCode::CircuitBreakerTripped);
+        }
+
+        Ok(())
+    }
+}
+
+/// Circuit breaker arguments, used to set the limits of a `CircuitBreaker`.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerArgs {
+    pub window_sec: u32,
+    pub max_trades: u16,
+    pub max_price_offset_change: u32,
+}
+
+impl CircuitBreakerArgs {
+    /// An enabled circuit breaker needs at least one limit, and a disabled one none.
+    pub fn validate(&self) -> Result<()> {
+        let has_limits = self.max_trades != 0 || self.max_price_offset_change != 0;
+
+        if (self.window_sec != 0) != has_limits {
+            throw_err!(ErrorCode::InvalidCircuitBreaker);
+        }
+
+        Ok(())
+    }
+}
+
 /// `Pool` is the main state account in the AMM program and represents the AMM pool where trades can happen.
 /// `Pool` accounts are Program Derived Addresses derived  from the seeds: `"pool"`, `owner`, and `identifier`.
 #[account]
@@ -119,9 +250,10 @@ pub struct Pool {
     // without signing when the pool is closed.
     pub rent_payer: Pubkey,
 
-    // Default Pubkey is SOL, otherwise SPL token mint.
+    /// The currency the pool is priced and settled in.
+    /// Default Pubkey is SOL, otherwise SPL token mint.
     pub currency: Pubkey,
-    /// The amount of currency held in the pool.
+    /// The amount of currency held in the pool, in lamports or token base units.
     pub amount: u64,
 
     /// The difference between the number of buys and sells
@@ -151,14 +283,54 @@ pub struct Pool {
     /// Pool configuration values.
     pub config: PoolConfig,
 
+    /// Basis points the price of a GDA curve decays by every `GDA_DECAY_PERIOD_SEC` without a trade.
+    pub decay_bps: u16,
+    /// The price a GDA curve stops decaying at.
+    pub floor_price: u64,
+
+    /// A key the owner delegates pricing to: it can edit the curve and expiry of the pool
+    /// but cannot move funds or NFTs. Default pubkey is interpreted as no value.
+    pub manager: Pubkey,
+
+    /// Whether takers are prevented from buying NFTs from the pool.
+    pub buys_paused: bool,
+    /// Whether takers are prevented from selling NFTs into the pool.
+    pub sells_paused: bool,
+
+    // The version 2 layout ends here: the fields below are carved out of `_reserved`, so new
+    // fields must only be appended after them, keeping the account size fixed.
+    /// The highest nonce of the cosigner attestations used to trade with the pool.
+    pub cosigner_nonce: u64,
+
+    /// Whether NFTs sold or deposited into the pool must have the traits of the pool's
+    /// `TraitRequirements` account.
+    pub requires_traits: bool,
+
+    /// Limit how many NFTs takers can buy from the pool, net of the NFTs they sold into it.
+    pub max_taker_buy_count: u32,
+    /// Limit how many NFTs the pool can hold through sells and deposits.
+    pub max_nfts_held: u32,
+    /// Limit how much the pool can pay for NFTs sold into it over its lifetime.
+    pub max_total_spend: u64,
+    /// Optional limit on the trades the pool can make within a time window.
+    pub circuit_breaker: CircuitBreaker,
+    /// Lamports escrowed in the pool on top of its rent, paid to keepers cleaning up the pool
+    /// once it expires.
+    pub keeper_bounty: u64,
+    /// Bitmap of the used nonces in the window below `cosigner_nonce`, bit `i` standing for
+    /// nonce `cosigner_nonce - i`, so each attestation can only be used once.
+    pub cosigner_nonces_used: [u64; 8],
+    /// Total amount the pool has paid for NFTs sold into it, in lamports or currency tokens.
+    pub total_spent: u64,
+
     /// Reserved space for future upgrades.
-    pub _reserved: [u8; 100],
+    pub _reserved: [u8; 71],
 }
 
 impl Default for Pool {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: CURRENT_POOL_VERSION,
             bump: [1],
             pool_id: [0; 32],
             created_at: 0,
@@ -177,7 +349,21 @@ impl Default for Pool {
             maker_broker: Pubkey::default(),
             max_taker_sell_count: 0,
             config: PoolConfig::default(),
-            _reserved: [0; 100],
+            decay_bps: 0,
+            floor_price: 0,
+            manager: Pubkey::default(),
+            buys_paused: false,
+            sells_paused: false,
+            cosigner_nonce: 0,
+            requires_traits: false,
+            max_taker_buy_count: 0,
+            max_nfts_held: 0,
+            max_total_spend: 0,
+            circuit_breaker: CircuitBreaker::default(),
+            keeper_bounty: 0,
+            cosigner_nonces_used: [0; 8],
+            total_spent: 0,
+            _reserved: [0; 71],
         }
     }
 }
@@ -204,6 +390,91 @@ impl Pool {
         Ok(())
     }
 
+    /// Determines if a taker is able to buy from a pool.
+    pub fn taker_allowed_to_buy(&self) -> Result<()> {
+        //0 indicates no restriction on buy count
+        if self.max_taker_buy_count == 0 {
+            return Ok(());
+        }
+
+        //if takers have sold more than they bought, by definition they can buy more to get to initial state.
+        if self.stats.taker_sell_count > self.stats.taker_buy_count {
+            return Ok(());
+        }
+
+        //<= because equal means taker can no longer buy
+        if self.max_taker_buy_count <= self.stats.taker_buy_count - self.stats.taker_sell_count {
+            throw_err!(ErrorCode::MaxTakerBuyCountExceeded);
+        }
+        Ok(())
+    }
+
+    /// Determines if the pool can take in one more NFT, through a sell or a deposit.
+    pub fn can_hold_more_nfts(&self) -> Result<()> {
+        //0 indicates no cap on the NFTs held
+        if self.max_nfts_held != 0 && self.nfts_held >= self.max_nfts_held {
+            throw_err!(ErrorCode::MaxNftsHeldExceeded);
+        }
+        Ok(())
+    }
+
+    /// Records a payment for an NFT sold into the pool, checking it fits in the spend budget.
+    pub fn record_spend(&mut self, amount: u64) -> Result<()> {
+        let total_spent = unwrap_int!(self.total_spent.checked_add(amount));
+
+        //0 indicates no spend budget
+        if self.max_total_spend != 0 && total_spent > self.max_total_spend {
+            throw_err!(ErrorCode::MaxTotalSpendExceeded);
+        }
+
+        self.total_spent = total_spent;
+        Ok(())
+    }
+
+    /// Marks the nonce of a cosigner attestation as used, failing if it was already used or is
+    /// too far below the highest nonce used to be tracked anymore.
+    ///
+    /// Attestations can be used in any order within the window, so the cosigner can hand out
+    /// several at once without them conflicting.
+    pub fn use_cosigner_nonce(&mut self, nonce: u64) -> Result<()> {
+        let is_used = |bitmap: &[u64; 8], i: u64| bitmap[(i / 64) as usize] & (1 << (i % 64)) != 0;
+        let mark_used = |bitmap: &mut [u64; 8], i: u64| bitmap[(i / 64) as usize] |= 1 << (i % 64);
+
+        if nonce > self.cosigner_nonce {
+            // Slide the window up to the new highest nonce.
+            let shift = nonce - self.cosigner_nonce;
+            let mut shifted = [0; 8];
+            if shift < COSIGNER_NONCE_WINDOW {
+                for i in 0..COSIGNER_NONCE_WINDOW - shift {
+                    if is_used(&self.cosigner_nonces_used, i) {
+                        mark_used(&mut shifted, i + shift);
+                    }
+                }
+            }
+
+            mark_used(&mut shifted, 0);
+            self.cosigner_nonces_used = shifted;
+            self.cosigner_nonce = nonce;
+        } else {
+            let i = self.cosigner_nonce - nonce;
+            if i >= COSIGNER_NONCE_WINDOW || is_used(&self.cosigner_nonces_used, i) {
+                throw_err!(ErrorCode::StaleCosignerNonce);
+            }
+
+            mark_used(&mut self.cosigner_nonces_used, i);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the owner has paused trading on the given side of the pool.
+    pub fn is_paused(&self, taker_side: TakerSide) -> bool {
+        match taker_side {
+            TakerSide::Buy => self.buys_paused,
+            TakerSide::Sell => self.sells_paused,
+        }
+    }
+
     /// Checks that editing pools does not result in setting a new cap that is too low.
     pub fn valid_max_sell_count(&self, new_count: u32) -> Result<()> {
         //0 indicates no restriction
@@ -224,6 +495,60 @@ impl Pool {
         Ok(())
     }
 
+    /// Checks that editing pools does not result in setting a new buy cap that is too low.
+    pub fn valid_max_buy_count(&self, new_count: u32) -> Result<()> {
+        //0 indicates no restriction
+        if new_count == 0 {
+            return Ok(());
+        }
+
+        //if takers have sold more than they bought, by defn we can set any cap (including lowest = 1)
+        if self.stats.taker_sell_count > self.stats.taker_buy_count {
+            return Ok(());
+        }
+
+        //< without = because we should let them edit the cap to stop buys
+        if new_count < self.stats.taker_buy_count - self.stats.taker_sell_count {
+            throw_err!(ErrorCode::MaxTakerBuyCountTooSmall);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that editing pools does not result in an inventory cap below the NFTs held.
+    pub fn valid_max_nfts_held(&self, new_max: u32) -> Result<()> {
+        //0 indicates no cap; < without = because we should let them edit the cap to stop sells and deposits
+        if new_max != 0 && new_max < self.nfts_held {
+            throw_err!(ErrorCode::MaxNftsHeldTooSmall);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that editing pools does not result in a spend budget below the amount already spent.
+    pub fn valid_max_total_spend(&self, new_max: u64) -> Result<()> {
+        //0 indicates no budget; < without = because we should let them edit the budget to stop sells
+        if new_max != 0 && new_max < self.total_spent {
+            throw_err!(ErrorCode::MaxTotalSpendTooSmall);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the decay rate and floor price of GDA curves, which must always decay and
+    /// can't start below their floor.
+    pub fn validate_decay(&self) -> Result<()> {
+        if self.config.curve_type == CurveType::Gda
+            && (self.decay_bps == 0
+                || self.decay_bps > MAX_DELTA_BPS
+                || self.floor_price > self.config.starting_price)
+        {
+            throw_err!(ErrorCode::InvalidDecay);
+        }
+
+        Ok(())
+    }
+
     /// Calculate the fee the MM receives when providing liquidity to a two-sided pool.
     pub fn calc_mm_fee(&self, current_price: u64) -> Result<u64> {
         let fee = match self.config.pool_type {
@@ -242,16 +567,35 @@ impl Pool {
 
     /// Calculate the price of the pool after shifting it by a certain offset.
     pub fn current_price(&self, side: TakerSide) -> Result<u64> {
+        self.current_price_with(side, None)
+    }
+
+    /// Calculate the current price of the pool, reading custom curves from their price ladder.
+    pub fn current_price_with(
+        &self,
+        side: TakerSide,
+        price_ladder: Option<&PriceLadder>,
+    ) -> Result<u64> {
         match (self.config.pool_type, side) {
             (PoolType::Trade, TakerSide::Buy)
             | (PoolType::NFT, TakerSide::Buy)
-            | (PoolType::Token, TakerSide::Sell) => self.shift_price(self.price_offset, side),
+            | (PoolType::Token, TakerSide::Sell) => {
+                self.shift_price_with(self.price_offset, side, price_ladder)
+            }
+
+            // XYK curves already quote sells below buys, since buying and selling move the
+            // reserves in opposite directions.
+            (PoolType::Trade, TakerSide::Sell) if self.config.curve_type == CurveType::Xyk => {
+                self.shift_price_with(self.price_offset, side, price_ladder)
+            }
 
             // Trade pool sells require the price to be shifted down by 1 to prevent
             // liquidity from being drained by repeated matched buys and sells.
-            (PoolType::Trade, TakerSide::Sell) => {
-                self.shift_price(unwrap_int!(self.price_offset.checked_sub(1)), side)
-            }
+            (PoolType::Trade, TakerSide::Sell) => self.shift_price_with(
+                unwrap_int!(self.price_offset.checked_sub(1)),
+                side,
+                price_ladder,
+            ),
 
             // Invalid combinations of pool type and side.
             _ => {
@@ -261,7 +605,41 @@ impl Pool {
     }
 
     /// Shifts the price of a pool by a certain offset.
+    ///
+    /// XYK curves are priced from the pool's reserves, so the offset is relative to the
+    /// current `price_offset`: each step is one NFT bought from the pool.
     pub fn shift_price(&self, price_offset: i32, side: TakerSide) -> Result<u64> {
+        self.shift_price_with(price_offset, side, None)
+    }
+
+    /// Shifts the price of a pool by a certain offset, reading custom curves from their price ladder.
+    pub fn shift_price_with(
+        &self,
+        price_offset: i32,
+        side: TakerSide,
+        price_ladder: Option<&PriceLadder>,
+    ) -> Result<u64> {
+        if self.config.curve_type == CurveType::Custom {
+            let price_ladder = unwrap_opt!(price_ladder, ErrorCode::MissingPriceLadder);
+            return price_ladder.price(price_offset);
+        }
+
+        let unix_timestamp = match self.config.curve_type {
+            // Only GDA prices depend on the time elapsed since the last trade.
+            CurveType::Gda => Clock::get()?.unix_timestamp,
+            _ => self.updated_at,
+        };
+
+        self.shift_price_at(price_offset, side, unix_timestamp)
+    }
+
+    /// Shifts the price of a pool by a certain offset, at the given unix timestamp.
+    pub fn shift_price_at(
+        &self,
+        price_offset: i32,
+        side: TakerSide,
+        unix_timestamp: i64,
+    ) -> Result<u64> {
         let direction = if price_offset > 0 {
             Direction::Up
         } else {
@@ -284,7 +662,7 @@ impl Pool {
                     }
                 }
             }
-            CurveType::Exponential => {
+            CurveType::Exponential | CurveType::Gda => {
                 let hundred_pct = unwrap_int!(PreciseNumber::new(HUNDRED_PCT_BPS.into()));
 
                 let base = unwrap_int!(PreciseNumber::new(self.config.starting_price.into()));
@@ -294,13 +672,29 @@ impl Pool {
                         .checked_pow(offset.into())
                 });
 
-                let result = unwrap_int!(match direction {
+                let mut result = unwrap_int!(match direction {
                     // price * (1 + delta)^trade_count
                     Direction::Up => base.checked_mul(&factor),
                     //same but / instead of *
                     Direction::Down => base.checked_div(&factor),
                 });
 
+                if self.config.curve_type == CurveType::Gda {
+                    // price * (1 - decay)^elapsed_periods
+                    let elapsed = unix_timestamp.saturating_sub(self.updated_at).max(0);
+                    let periods = unwrap_int!(elapsed.checked_div(GDA_DECAY_PERIOD_SEC)) as u128;
+
+                    let decay = unwrap_checked!({
+                        PreciseNumber::new(
+                            HUNDRED_PCT_BPS.checked_sub(self.decay_bps.into())?.into(),
+                        )?
+                        .checked_div(&hundred_pct)?
+                        .checked_pow(periods)
+                    });
+
+                    result = unwrap_int!(result.checked_mul(&decay));
+                }
+
                 let rounded_result = unwrap_int!(match side {
                     TakerSide::Buy => result.ceiling(),
                     TakerSide::Sell => result.floor(),
@@ -308,11 +702,138 @@ impl Pool {
 
                 unwrap_int!(u64::try_from(unwrap_checked!({ rounded_result.to_imprecise() })).ok())
             }
+            CurveType::Xyk => self.xyk_price(price_offset, side)?,
+            // Custom curves are priced with `shift_price_with`.
+            CurveType::Custom => {
+                throw_err!(ErrorCode::MissingPriceLadder);
+            }
         };
 
+        // GDA prices stop decaying at the floor.
+        if self.config.curve_type == CurveType::Gda {
+            return Ok(current_price.max(self.floor_price));
+        }
+
         Ok(current_price)
     }
 
+    /// Price of an NFT on a XYK curve, keeping `amount * nfts_held` constant.
+    ///
+    /// With `k = amount * nfts_held` and `n` NFTs in reserve after shifting, buying an NFT costs
+    /// `k / (n - 1) - k / n` and selling one pays `k / n - k / (n + 1)`.
+    fn xyk_price(&self, price_offset: i32, side: TakerSide) -> Result<u64> {
+        let steps = unwrap_int!(i64::from(price_offset).checked_sub(self.price_offset.into()));
+        let nfts = unwrap_int!(i64::from(self.nfts_held).checked_sub(steps));
+
+        // Both reserves are needed, and the last NFT of the pool can't be bought.
+        let min_nfts = match side {
+            TakerSide::Buy => 2,
+            TakerSide::Sell => 1,
+        };
+        if self.amount == 0 || nfts < min_nfts {
+            throw_err!(ErrorCode::InsufficientReserves);
+        }
+
+        let nfts = nfts as u128;
+        let k = unwrap_checked!({ (self.amount as u128).checked_mul(self.nfts_held.into()) });
+
+        let price = match side {
+            // Rounded up so buyers never pay less than the curve.
+            TakerSide::Buy => unwrap_checked!({
+                let denominator = nfts.checked_mul(nfts.checked_sub(1)?)?;
+                k.checked_add(denominator.checked_sub(1)?)?
+                    .checked_div(denominator)
+            }),
+            // Rounded down so sellers never receive more than the curve.
+            TakerSide::Sell => {
+                unwrap_checked!({ k.checked_div(nfts.checked_mul(nfts.checked_add(1)?)?) })
+            }
+        };
+
+        Ok(unwrap_int!(u64::try_from(price).ok()))
+    }
+
+    /// Records a single trade on the pool: counters, stats and the price offset, which moves the
+    /// price one step along the curve. Must be called once per NFT traded, with the MM fee of the
+    /// trade calculated from the current price, as adjusted by the cosigner. Fails if the trade
+    /// trips the circuit breaker.
+    pub fn record_trade(
+        &mut self,
+        taker_side: TakerSide,
+        price_ladder: Option<&PriceLadder>,
+        mm_fee: u64,
+    ) -> Result<()> {
+        let offset_before = self.price_offset;
+
+        // GDA auctions restart from the curve price, which is then bumped up by the trade below.
+        if self.config.curve_type == CurveType::Gda {
+            self.config.starting_price = self.current_price_with(taker_side, price_ladder)?;
+            self.price_offset = 0;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        self.updated_at = now;
+
+        match taker_side {
+            TakerSide::Buy => {
+                // Taker has bought an NFT from the pool, so we decrement the NFT counter.
+                self.nfts_held = unwrap_int!(self.nfts_held.checked_sub(1));
+
+                // Pool has sold an NFT, so we increment the trade counter.
+                self.price_offset = unwrap_int!(self.price_offset.checked_add(1));
+
+                self.stats.taker_buy_count = unwrap_int!(self.stats.taker_buy_count.checked_add(1));
+
+                if self.config.pool_type == PoolType::Trade {
+                    self.stats.accumulated_mm_profit =
+                        unwrap_checked!({ self.stats.accumulated_mm_profit.checked_add(mm_fee) });
+                }
+            }
+            TakerSide::Sell => {
+                if self.config.pool_type == PoolType::Trade {
+                    self.nfts_held = unwrap_int!(self.nfts_held.checked_add(1));
+
+                    self.stats.accumulated_mm_profit =
+                        unwrap_int!(self.stats.accumulated_mm_profit.checked_add(mm_fee));
+                }
+
+                // Pool has bought an NFT, so we decrement the trade counter.
+                self.price_offset = unwrap_int!(self.price_offset.checked_sub(1));
+                self.stats.taker_sell_count =
+                    unwrap_int!(self.stats.taker_sell_count.checked_add(1));
+            }
+        }
+
+        // Checked here so single trades (through `update_pool_accounting`) and every asset of
+        // batch trades count towards the circuit breaker.
+        self.circuit_breaker
+            .record_trade(now, offset_before, self.price_offset)
+    }
+
+    /// Returns the part of a pool balance that can be traded: SOL pools must keep the
+    /// minimum rent and the keeper bounty, while SPL pools hold their currency in a separate
+    /// token account.
+    pub fn available_balance(&self, balance: u64) -> Result<u64> {
+        if self.currency == Pubkey::default() {
+            let pool_min_balance = self.min_balance()?;
+            Ok(unwrap_int!(balance.checked_sub(pool_min_balance)))
+        } else {
+            Ok(balance)
+        }
+    }
+
+    /// Returns the lamports the pool account must always hold: its rent and the keeper bounty.
+    pub fn min_balance(&self) -> Result<u64> {
+        let pool_min_rent = Rent::get()?.minimum_balance(Pool::SIZE);
+        Ok(unwrap_int!(pool_min_rent.checked_add(self.keeper_bounty)))
+    }
+
+    /// Returns the share of the keeper bounty paid for one cleanup step of an expired pool:
+    /// returning one of the NFTs held, or closing the pool once it is empty.
+    pub fn keeper_bounty_share(&self) -> u64 {
+        self.keeper_bounty / (u64::from(self.nfts_held) + 1)
+    }
+
     /// Returns the seeds for the pool account.
     pub fn seeds(&self) -> [&[u8]; 4] {
         [
@@ -331,45 +852,40 @@ pub enum Direction {
 }
 
 /// Indicates the side of the taker.
-#[derive(PartialEq, Eq, Copy, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Copy, Clone, Debug)]
 pub enum TakerSide {
     Buy,  // Buying from the pool.
     Sell, // Selling into the pool.
 }
 
-/// A utitilty function that tries to autoclose a pool if it is possible.
-pub fn try_autoclose_pool<'info>(
-    pool: &Account<'info, Pool>,
-    rent_payer: AccountInfo<'info>,
-    owner: AccountInfo<'info>,
-    shared_escrow: Option<&AccountInfo<'info>>,
-) -> Result<()> {
-    match pool.config.pool_type {
-        PoolType::Trade => (), // Cannot be auto-closed
+/// Determines if a pool can be auto-closed after a trade.
+pub fn should_autoclose_pool(
+    pool: &Pool,
+    shared_escrow: Option<&AccountInfo>,
+    price_ladder: Option<&PriceLadder>,
+) -> Result<bool> {
+    let should_close = match pool.config.pool_type {
+        PoolType::Trade => false, // Cannot be auto-closed
         PoolType::Token => {
             let amount = if pool.shared_escrow == Pubkey::default() {
                 pool.amount
             } else {
-                shared_escrow.unwrap().lamports()
+                unwrap_opt!(shared_escrow, ErrorCode::BadSharedEscrow).lamports()
             };
 
             // Not enough SOL to purchase another NFT, so we can close the pool.
-            if pool.currency == Pubkey::default() && amount < pool.current_price(TakerSide::Sell)? {
-                close_pool(pool, rent_payer, owner)?;
-            }
-        }
-        PoolType::NFT => {
-            // No more NFTs to sell, so we can close the pool.
-            if pool.nfts_held == 0 {
-                close_pool(pool, rent_payer, owner)?;
-            }
+            pool.currency == Pubkey::default()
+                && amount < pool.current_price_with(TakerSide::Sell, price_ladder)?
         }
-    }
+        // No more NFTs to sell, so we can close the pool.
+        PoolType::NFT => pool.nfts_held == 0,
+    };
 
-    Ok(())
+    Ok(should_close)
 }
 
-/// Closes a pool and returns the rent to the rent payer and any remaining SOL to the owner.
+/// Closes a pool and returns the rent and any unclaimed keeper bounty to the rent payer, and any
+/// remaining SOL to the owner.
 pub fn close_pool<'info>(
     pool: &Account<'info, Pool>,
     rent_payer: AccountInfo<'info>,
@@ -385,92 +901,69 @@ pub fn close_pool<'info>(
         throw_err!(ErrorCode::WrongOwner);
     }
 
-    let pool_min_rent = Rent::get()?.minimum_balance(Pool::SIZE);
+    let pool_min_balance = pool.min_balance()?;
     let pool_lamports = pool.get_lamports();
 
-    // Any SOL above the minimum rent/state bond goes to the owner.
-    if pool_lamports > pool_min_rent {
-        let owner_amount = unwrap_int!(pool_lamports.checked_sub(pool_min_rent));
+    // Any SOL above the minimum rent/state bond and keeper bounty goes to the owner.
+    if pool_lamports > pool_min_balance {
+        let owner_amount = unwrap_int!(pool_lamports.checked_sub(pool_min_balance));
         // If owner is not rent exempt, this skips the transfer and the rent destination will get it instead.
         transfer_lamports_checked(&pool.to_account_info(), &owner, owner_amount)?;
     }
 
-    // Rent goes back to the rent payer.
+    // Rent and the unclaimed keeper bounty go back to the rent payer.
     pool.close(rent_payer)
 }
 
+/// Updates the pool state after a trade: counters, stats and the currency amount held.
+///
+/// The currency amount is tracked from the change in the pool's balance, which is lamports for
+/// SOL pools and the pool currency token account balance for SPL pools.
 pub fn update_pool_accounting(
     pool: &mut Account<'_, Pool>,
+    price_ladder: Option<&PriceLadder>,
     pool_initial_balance: u64,
+    pool_final_balance: u64,
     taker_side: TakerSide,
+    mm_fee: u64,
 ) -> Result<()> {
-    // Calculate fees from the current price.
-    let current_price = pool.current_price(taker_side)?;
-    // This resolves to 0 for Token & NFT pools.
-    let mm_fee = pool.calc_mm_fee(current_price)?;
+    pool.record_trade(taker_side, price_ladder, mm_fee)?;
 
-    pool.updated_at = Clock::get()?.unix_timestamp;
+    update_pool_amount(pool, pool_initial_balance, pool_final_balance, taker_side)
+}
+
+/// Updates the currency amount held by the pool from the change in its balance over one or more trades.
+pub fn update_pool_amount(
+    pool: &mut Account<'_, Pool>,
+    pool_initial_balance: u64,
+    pool_final_balance: u64,
+    taker_side: TakerSide,
+) -> Result<()> {
+    // Shared escrow pools don't have a balance because the shared escrow account holds it.
+    if pool.shared_escrow != Pubkey::default() {
+        return Ok(());
+    }
 
+    // Update the pool's currency balance, by tracking additions and subtractions as a result of the trades.
     match taker_side {
         TakerSide::Buy => {
-            // Taker has bought an NFT from the pool, so we decrement the NFT counter.
-            pool.nfts_held = unwrap_int!(pool.nfts_held.checked_sub(1));
-
-            // Pool has sold an NFT, so we increment the trade counter.
-            pool.price_offset = unwrap_int!(pool.price_offset.checked_add(1));
-
-            pool.stats.taker_buy_count = unwrap_int!(pool.stats.taker_buy_count.checked_add(1));
-
-            if pool.config.pool_type == PoolType::Trade {
-                pool.stats.accumulated_mm_profit =
-                    unwrap_checked!({ pool.stats.accumulated_mm_profit.checked_add(mm_fee) });
-            }
-
-            // Update the pool's currency balance, by tracking additions and subtractions as a result of this trade.
-            // Shared escrow pools don't have a SOL balance because the shared escrow account holds it.
-            if pool.currency == Pubkey::default() && pool.shared_escrow == Pubkey::default() {
-                let pool_min_rent = Rent::get()?.minimum_balance(Pool::SIZE);
-                let pool_final_balance = pool.get_lamports();
-                let lamports_added =
-                    unwrap_checked!({ pool_final_balance.checked_sub(pool_initial_balance) });
-                pool.amount = unwrap_checked!({ pool.amount.checked_add(lamports_added) });
-
-                // Sanity check to avoid edge cases:
-                require!(
-                    pool.amount <= unwrap_int!(pool_final_balance.checked_sub(pool_min_rent)),
-                    ErrorCode::InvalidPoolAmount
-                );
-            }
+            let amount_added =
+                unwrap_checked!({ pool_final_balance.checked_sub(pool_initial_balance) });
+            pool.amount = unwrap_checked!({ pool.amount.checked_add(amount_added) });
         }
         TakerSide::Sell => {
-            if pool.config.pool_type == PoolType::Trade {
-                pool.nfts_held = unwrap_int!(pool.nfts_held.checked_add(1));
-
-                pool.stats.accumulated_mm_profit =
-                    unwrap_int!(pool.stats.accumulated_mm_profit.checked_add(mm_fee));
-            }
-
-            // Pool has bought an NFT, so we decrement the trade counter.
-            pool.price_offset = unwrap_int!(pool.price_offset.checked_sub(1));
-            pool.stats.taker_sell_count = unwrap_int!(pool.stats.taker_sell_count.checked_add(1));
-
-            // Update the pool's currency balance, by tracking additions and subtractions as a result of this trade.
-            if pool.currency == Pubkey::default() && pool.shared_escrow == Pubkey::default() {
-                let pool_min_rent = Rent::get()?.minimum_balance(Pool::SIZE);
-                let pool_final_balance = pool.get_lamports();
-                let lamports_taken =
-                    unwrap_checked!({ pool_initial_balance.checked_sub(pool_final_balance) });
-                pool.amount = unwrap_checked!({ pool.amount.checked_sub(lamports_taken) });
-
-                // Sanity check to avoid edge cases:
-                require!(
-                    pool.amount <= unwrap_int!(pool_final_balance.checked_sub(pool_min_rent)),
-                    ErrorCode::InvalidPoolAmount
-                );
-            }
+            let amount_taken =
+                unwrap_checked!({ pool_initial_balance.checked_sub(pool_final_balance) });
+            pool.amount = unwrap_checked!({ pool.amount.checked_sub(amount_taken) });
         }
     }
 
+    // Sanity check to avoid edge cases:
+    require!(
+        pool.amount <= pool.available_balance(pool_final_balance)?,
+        ErrorCode::InvalidPoolAmount
+    );
+
     Ok(())
 }
 
@@ -0,0 +1,100 @@
+use anchor_lang::{prelude::*, Discriminator};
+use tensor_vipers::throw_err;
+
+use crate::{
+    constants::{CURRENT_POOL_VERSION, DISCRIMINATOR_SIZE},
+    error::ErrorCode,
+    CircuitBreaker, Pool, PoolConfig, PoolStats,
+};
+
+/// Layout of version 1 pool accounts, kept to read them when upgrading to the current layout.
+///
+/// Version 1 accounts share the `Pool` discriminator, so `PoolV1` is not an account type.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, InitSpace, Eq, PartialEq)]
+pub struct PoolV1 {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub pool_id: [u8; 32],
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub expiry: i64,
+    pub owner: Pubkey,
+    pub whitelist: Pubkey,
+    pub rent_payer: Pubkey,
+    pub currency: Pubkey,
+    pub amount: u64,
+    pub price_offset: i32,
+    pub nfts_held: u32,
+    pub stats: PoolStats,
+    pub shared_escrow: Pubkey,
+    pub cosigner: Pubkey,
+    pub maker_broker: Pubkey,
+    pub max_taker_sell_count: u32,
+    pub config: PoolConfig,
+    pub _reserved: [u8; 100],
+}
+
+impl PoolV1 {
+    /// Size of a version 1 pool account, inclusive of the 8-byte discriminator.
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + Self::INIT_SPACE;
+}
+
+impl From<PoolV1> for Pool {
+    fn from(pool: PoolV1) -> Self {
+        Pool {
+            version: CURRENT_POOL_VERSION,
+            bump: pool.bump,
+            pool_id: pool.pool_id,
+            created_at: pool.created_at,
+            updated_at: pool.updated_at,
+            expiry: pool.expiry,
+            owner: pool.owner,
+            whitelist: pool.whitelist,
+            rent_payer: pool.rent_payer,
+            currency: pool.currency,
+            amount: pool.amount,
+            price_offset: pool.price_offset,
+            nfts_held: pool.nfts_held,
+            stats: pool.stats,
+            shared_escrow: pool.shared_escrow,
+            cosigner: pool.cosigner,
+            maker_broker: pool.maker_broker,
+            max_taker_sell_count: pool.max_taker_sell_count,
+            config: pool.config,
+            decay_bps: 0,
+            floor_price: 0,
+            manager: Pubkey::default(),
+            buys_paused: false,
+            sells_paused: false,
+            cosigner_nonce: 0,
+            requires_traits: false,
+            max_taker_buy_count: 0,
+            max_nfts_held: 0,
+            max_total_spend: 0,
+            circuit_breaker: CircuitBreaker::default(),
+            keeper_bounty: 0,
+            cosigner_nonces_used: [0; 8],
+            total_spent: 0,
+            _reserved: [0; 71],
+        }
+    }
+}
+
+impl Pool {
+    /// Decodes pool account data of any supported version, converting it to the current layout.
+    pub fn try_deserialize_versioned(data: &[u8]) -> Result<Pool> {
+        if data.len() <= DISCRIMINATOR_SIZE || data[..DISCRIMINATOR_SIZE] != Pool::DISCRIMINATOR {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        let mut body = &data[DISCRIMINATOR_SIZE..];
+
+        match body[0] {
+            1 => Ok(PoolV1::deserialize(&mut body)?.into()),
+            CURRENT_POOL_VERSION => Ok(Pool::deserialize(&mut body)?),
+            _ => {
+                throw_err!(ErrorCode::WrongPoolVersion);
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, unwrap_int, unwrap_opt};
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, MAX_PRICE_LADDER_LEN},
+    error::ErrorCode,
+};
+
+/// Explicit price points of a pool using a `Custom` curve, indexed by the pool's `price_offset`.
+/// Seeds: "price_ladder", pool
+#[account]
+#[derive(Debug, InitSpace, Eq, PartialEq)]
+pub struct PriceLadder {
+    pub bump: [u8; 1],
+    pub pool: Pubkey,
+    /// Index of the price used when the pool's `price_offset` is zero.
+    pub zero_index: u16,
+    /// Prices in ascending order: selling into the pool walks down the ladder,
+    /// buying from it walks up.
+    #[max_len(MAX_PRICE_LADDER_LEN)]
+    pub prices: Vec<u64>,
+}
+
+impl PriceLadder {
+    /// Size of the `PriceLadder` account, inclusive of the 8-byte discriminator.
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + Self::INIT_SPACE;
+
+    /// Returns the price at the given price offset.
+    pub fn price(&self, price_offset: i32) -> Result<u64> {
+        let index = unwrap_int!(i64::from(self.zero_index).checked_add(price_offset.into()));
+
+        let price = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.prices.get(index));
+
+        Ok(*unwrap_opt!(price, ErrorCode::PriceLadderExhausted))
+    }
+
+    /// Replaces the prices of the ladder after validating them.
+    pub fn set_prices(&mut self, args: PriceLadderArgs) -> Result<()> {
+        args.validate()?;
+
+        self.zero_index = args.zero_index;
+        self.prices = args.prices;
+
+        Ok(())
+    }
+}
+
+/// Price ladder arguments, used to set the prices of a `Custom` curve.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PriceLadderArgs {
+    pub prices: Vec<u64>,
+    pub zero_index: u16,
+}
+
+impl PriceLadderArgs {
+    /// Prices must be non-empty, fit the account and never decrease,
+    /// and the zero index must point at one of them.
+    pub fn validate(&self) -> Result<()> {
+        if self.prices.is_empty()
+            || self.prices.len() > MAX_PRICE_LADDER_LEN
+            || usize::from(self.zero_index) >= self.prices.len()
+            || self.prices.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            throw_err!(ErrorCode::InvalidPriceLadder);
+        }
+
+        Ok(())
+    }
+}
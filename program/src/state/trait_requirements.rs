@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use tensor_vipers::throw_err;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, MAX_TRAIT_LEN, MAX_TRAIT_REQUIREMENTS},
+    error::ErrorCode,
+};
+
+/// Attributes MPL Core assets must have to be sold or deposited into a pool, read from the
+/// asset's Attributes plugin. Seeds: "trait_requirements", pool
+#[account]
+#[derive(Debug, InitSpace, Eq, PartialEq)]
+pub struct TraitRequirements {
+    pub bump: [u8; 1],
+    pub pool: Pubkey,
+    /// Key/value pairs the asset must all have.
+    #[max_len(MAX_TRAIT_REQUIREMENTS)]
+    pub traits: Vec<TraitRequirement>,
+}
+
+/// A key/value pair of the MPL Core Attributes plugin.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, InitSpace, PartialEq, Eq)]
+pub struct TraitRequirement {
+    #[max_len(MAX_TRAIT_LEN)]
+    pub key: String,
+    #[max_len(MAX_TRAIT_LEN)]
+    pub value: String,
+}
+
+impl TraitRequirements {
+    /// Size of the `TraitRequirements` account, inclusive of the 8-byte discriminator.
+    pub const SIZE: usize = DISCRIMINATOR_SIZE + Self::INIT_SPACE;
+
+    /// Replaces the required traits after validating them.
+    pub fn set_traits(&mut self, traits: Vec<TraitRequirement>) -> Result<()> {
+        if traits.is_empty()
+            || traits.len() > MAX_TRAIT_REQUIREMENTS
+            || traits
+                .iter()
+                .any(|t| t.key.len() > MAX_TRAIT_LEN || t.value.len() > MAX_TRAIT_LEN)
+        {
+            throw_err!(ErrorCode::InvalidTraitRequirements);
+        }
+
+        self.traits = traits;
+
+        Ok(())
+    }
+
+    /// Whether the attributes include every required key/value pair.
+    pub fn is_met_by(&self, attributes: &[(String, String)]) -> bool {
+        self.traits.iter().all(|required| {
+            attributes
+                .iter()
+                .any(|(key, value)| *key == required.key && *value == required.value)
+        })
+    }
+}
@@ -0,0 +1,259 @@
+import * as c from "codama";
+
+export default function visitor(options) {
+  return c.rootNodeVisitor((currentRoot) => {
+    let root = currentRoot;
+    const updateRoot = (visitor) => {
+      const newRoot = c.visit(root, visitor);
+      c.assertIsNode(newRoot, "rootNode");
+      root = newRoot;
+    };
+
+    updateRoot(
+      c.updateInstructionsVisitor({
+        buyNftCompressed: {
+          accounts: {
+            logWrapper: {
+              defaultValue: c.publicKeyValueNode(
+                "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV",
+                "splNoop"
+              )
+            },
+            compressionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                "splAccountCompression"
+              )
+            },
+            bubblegumProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY",
+                "mplBubblegum"
+              )
+            },
+            nftReceipt: {
+              defaultValue: null
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("creators"),
+              {
+                isOptional: true,
+                isSigner: false,
+                isWritable: true
+              }
+            ),
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("proof"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        },
+        depositNftCompressed: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            logWrapper: {
+              defaultValue: c.publicKeyValueNode(
+                "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV",
+                "splNoop"
+              )
+            },
+            compressionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                "splAccountCompression"
+              )
+            },
+            bubblegumProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY",
+                "mplBubblegum"
+              )
+            },
+            nftReceipt: {
+              defaultValue: null
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("proof"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        },
+        sellNftTokenPoolCompressed: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            logWrapper: {
+              defaultValue: c.publicKeyValueNode(
+                "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV",
+                "splNoop"
+              )
+            },
+            compressionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                "splAccountCompression"
+              )
+            },
+            bubblegumProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY",
+                "mplBubblegum"
+              )
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("creators"),
+              {
+                isOptional: true,
+                isSigner: false,
+                isWritable: true
+              }
+            ),
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("proof"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        },
+        sellNftTradePoolCompressed: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            logWrapper: {
+              defaultValue: c.publicKeyValueNode(
+                "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV",
+                "splNoop"
+              )
+            },
+            compressionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                "splAccountCompression"
+              )
+            },
+            bubblegumProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY",
+                "mplBubblegum"
+              )
+            },
+            nftReceipt: {
+              defaultValue: null
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("creators"),
+              {
+                isOptional: true,
+                isSigner: false,
+                isWritable: true
+              }
+            ),
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("proof"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        },
+        withdrawExpiredNftCompressed: {
+          accounts: {
+            logWrapper: {
+              defaultValue: c.publicKeyValueNode(
+                "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV",
+                "splNoop"
+              )
+            },
+            compressionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                "splAccountCompression"
+              )
+            },
+            bubblegumProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY",
+                "mplBubblegum"
+              )
+            },
+            nftReceipt: {
+              defaultValue: null
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("proof"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        },
+        withdrawNftCompressed: {
+          accounts: {
+            logWrapper: {
+              defaultValue: c.publicKeyValueNode(
+                "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV",
+                "splNoop"
+              )
+            },
+            compressionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK",
+                "splAccountCompression"
+              )
+            },
+            bubblegumProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY",
+                "mplBubblegum"
+              )
+            },
+            nftReceipt: {
+              defaultValue: null
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("proof"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        }
+      })
+    );
+
+    return root;
+  });
+}
@@ -36,6 +36,18 @@ export default function visitor(options) {
             )
           ]
         },
+        buyNftsCore: {
+          accounts: {
+            feeVault: {
+              defaultValue: c.resolverValueNode("resolveFeeVaultPdaFromPool", {
+                dependsOn: [c.accountValueNode("pool")]
+              })
+            },
+            rentPayer: {
+              defaultValue: c.accountValueNode("owner")
+            }
+          }
+        },
         depositNftCore: {
           accounts: {
             whitelist: {
@@ -46,6 +58,13 @@ export default function visitor(options) {
             }
           }
         },
+        withdrawExpiredNftCore: {
+          accounts: {
+            nftReceipt: {
+              defaultValue: c.pdaValueNode("assetDepositReceipt")
+            }
+          }
+        },
         withdrawNftCore: {
           accounts: {
             nftReceipt: {
@@ -53,6 +72,36 @@ export default function visitor(options) {
             }
           }
         },
+        sellNftsTokenPoolCore: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            feeVault: {
+              defaultValue: c.resolverValueNode("resolveFeeVaultPdaFromPool", {
+                dependsOn: [c.accountValueNode("pool")]
+              })
+            },
+            rentPayer: {
+              defaultValue: c.accountValueNode("owner")
+            }
+          }
+        },
+        sellNftsTradePoolCore: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            feeVault: {
+              defaultValue: c.resolverValueNode("resolveFeeVaultPdaFromPool", {
+                dependsOn: [c.accountValueNode("pool")]
+              })
+            },
+            rentPayer: {
+              defaultValue: c.accountValueNode("owner")
+            }
+          }
+        },
         sellNftTokenPoolCore: {
           accounts: {
             whitelist: {
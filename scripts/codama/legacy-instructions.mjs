@@ -46,6 +46,17 @@ export default function visitor(options) {
             )
           ]
         },
+        buyNfts: {
+          arguments: {
+            tokenStandard: {
+              type: c.definedTypeLinkNode("TokenStandard", "resolvers"),
+              defaultValue: c.enumValueNode(
+                c.definedTypeLinkNode("TokenStandard", "resolvers"),
+                "ProgrammableNonFungible"
+              )
+            }
+          }
+        },
         depositNft: {
           accounts: {
             whitelist: {
@@ -150,6 +161,63 @@ export default function visitor(options) {
             )
           ]
         },
+        sellNftsTokenPool: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            }
+          },
+          arguments: {
+            tokenStandard: {
+              type: c.definedTypeLinkNode("TokenStandard", "resolvers"),
+              defaultValue: c.enumValueNode(
+                c.definedTypeLinkNode("TokenStandard", "resolvers"),
+                "ProgrammableNonFungible"
+              )
+            }
+          }
+        },
+        sellNftsTradePool: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            }
+          },
+          arguments: {
+            tokenStandard: {
+              type: c.definedTypeLinkNode("TokenStandard", "resolvers"),
+              defaultValue: c.enumValueNode(
+                c.definedTypeLinkNode("TokenStandard", "resolvers"),
+                "ProgrammableNonFungible"
+              )
+            }
+          }
+        },
+        withdrawExpiredNft: {
+          accounts: {
+            userTokenRecord: {
+              isOptional: true,
+              defaultValue: c.resolverValueNode(
+                "resolveUserTokenRecordFromTokenStandard",
+                {
+                  dependsOn: [
+                    c.accountValueNode("mint"),
+                    c.accountValueNode("ownerTa")
+                  ]
+                }
+              )
+            }
+          },
+          arguments: {
+            tokenStandard: {
+              type: c.definedTypeLinkNode("TokenStandard", "resolvers"),
+              defaultValue: c.enumValueNode(
+                c.definedTypeLinkNode("TokenStandard", "resolvers"),
+                "ProgrammableNonFungible"
+              )
+            }
+          }
+        },
         withdrawNft: {
           accounts: {
             userTokenRecord: {
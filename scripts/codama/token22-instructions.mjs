@@ -39,6 +39,16 @@ export default function visitor(options) {
             )
           ]
         },
+        buyNftsT22: {
+          accounts: {
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            }
+          }
+        },
         depositNftT22: {
           accounts: {
             whitelist: {
@@ -124,6 +134,52 @@ export default function visitor(options) {
             )
           ]
         },
+        sellNftsTokenPoolT22: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            }
+          }
+        },
+        sellNftsTradePoolT22: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            }
+          }
+        },
+        withdrawExpiredNftT22: {
+          accounts: {
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            }
+          },
+          remainingAccounts: [
+            c.instructionRemainingAccountsNode(
+              c.argumentValueNode("transferHookAccounts"),
+              {
+                isOptional: false,
+                isSigner: false,
+                isWritable: false
+              }
+            )
+          ]
+        },
         withdrawNftT22: {
           accounts: {
             tokenProgram: {
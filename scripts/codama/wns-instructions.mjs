@@ -0,0 +1,232 @@
+import * as c from "codama";
+
+export default function visitor(options) {
+  return c.rootNodeVisitor((currentRoot) => {
+    let root = currentRoot;
+    const updateRoot = (visitor) => {
+      const newRoot = c.visit(root, visitor);
+      c.assertIsNode(newRoot, "rootNode");
+      root = newRoot;
+    };
+
+    updateRoot(
+      c.updateInstructionsVisitor({
+        buyNftWns: {
+          accounts: {
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        buyNftsWns: {
+          accounts: {
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        depositNftWns: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        sellNftTokenPoolWns: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        sellNftTradePoolWns: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        sellNftsTokenPoolWns: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        sellNftsTradePoolWns: {
+          accounts: {
+            whitelist: {
+              isOptional: false
+            },
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        withdrawExpiredNftWns: {
+          accounts: {
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        },
+        withdrawNftWns: {
+          accounts: {
+            tokenProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+                "tokenProgram"
+              )
+            },
+            wnsProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM",
+                "wnsProgram"
+              )
+            },
+            distributionProgram: {
+              defaultValue: c.publicKeyValueNode(
+                "diste3nXmK7ddDTs1zb6uday6j4etCa9RChD8fJ1xay",
+                "distributionProgram"
+              )
+            }
+          }
+        }
+      })
+    );
+
+    return root;
+  });
+}
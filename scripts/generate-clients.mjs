@@ -6,9 +6,11 @@ import * as c from "codama";
 import "zx/globals";
 import { getAllProgramIdls } from "./utils.mjs";
 
+import compressedInstructions from "./codama/compressed-instructions.mjs";
 import coreInstructions from "./codama/core-instructions.mjs";
 import legacyInstructions from "./codama/legacy-instructions.mjs";
 import token22Instructions from "./codama/token22-instructions.mjs";
+import wnsInstructions from "./codama/wns-instructions.mjs";
 
 // Instanciate codama.
 const [idl, ...additionalIdls] = getAllProgramIdls().map((idl) =>
@@ -62,6 +64,18 @@ codama.update(
         ),
         c.variablePdaSeedNode("pool", c.publicKeyTypeNode(), "The pool address")
       ]
+    },
+    priceLadder: {
+      seeds: [
+        c.constantPdaSeedNodeFromString("utf8", "price_ladder"),
+        c.variablePdaSeedNode("pool", c.publicKeyTypeNode(), "The pool address")
+      ]
+    },
+    traitRequirements: {
+      seeds: [
+        c.constantPdaSeedNodeFromString("utf8", "trait_requirements"),
+        c.variablePdaSeedNode("pool", c.publicKeyTypeNode(), "The pool address")
+      ]
     }
   })
 );
@@ -84,13 +98,30 @@ codama.update(
         },
         maxTakerSellCount: {
           defaultValue: c.noneValueNode()
+        },
+        currency: {
+          name: "currencyMint"
+        },
+        priceLadder: {
+          name: "priceLadderArgs"
         }
       }
     },
     editPool: {
+      accounts: {
+        owner: {
+          isSigner: "either"
+        }
+      },
       arguments: {
         maxTakerSellCount: {
           defaultValue: c.noneValueNode()
+        },
+        priceLadder: {
+          name: "priceLadderArgs"
+        },
+        manager: {
+          name: "newManager"
         }
       }
     }
@@ -155,6 +186,61 @@ codama.update(
         };
       }
     },
+    {
+      select:
+        "[accountNode]pool.[structTypeNode].[structFieldTypeNode]manager",
+      transform: (node) => {
+        c.assertIsNode(node, "structFieldTypeNode");
+        return {
+          ...node,
+          type: c.definedTypeLinkNode("nullableAddress")
+        };
+      }
+    },
+    {
+      select:
+        "[definedTypeNode]poolV1.[structTypeNode].[structFieldTypeNode]sharedEscrow",
+      transform: (node) => {
+        c.assertIsNode(node, "structFieldTypeNode");
+        return {
+          ...node,
+          type: c.definedTypeLinkNode("nullableAddress")
+        };
+      }
+    },
+    {
+      select:
+        "[definedTypeNode]poolV1.[structTypeNode].[structFieldTypeNode]cosigner",
+      transform: (node) => {
+        c.assertIsNode(node, "structFieldTypeNode");
+        return {
+          ...node,
+          type: c.definedTypeLinkNode("nullableAddress")
+        };
+      }
+    },
+    {
+      select:
+        "[definedTypeNode]poolV1.[structTypeNode].[structFieldTypeNode]makerBroker",
+      transform: (node) => {
+        c.assertIsNode(node, "structFieldTypeNode");
+        return {
+          ...node,
+          type: c.definedTypeLinkNode("nullableAddress")
+        };
+      }
+    },
+    {
+      select:
+        "[definedTypeNode]poolV1.[structTypeNode].[structFieldTypeNode]currency",
+      transform: (node) => {
+        c.assertIsNode(node, "structFieldTypeNode");
+        return {
+          ...node,
+          type: c.definedTypeLinkNode("currency")
+        };
+      }
+    },
     {
       select:
         "[definedTypeNode]poolConfig.[structTypeNode].[structFieldTypeNode]mmFeeBps",
@@ -430,6 +516,16 @@ codama.update(
       ignoreIfOptional: true,
       defaultValue: c.pdaValueNode("nftDepositReceipt")
     },
+    {
+      account: "priceLadder",
+      ignoreIfOptional: true,
+      defaultValue: c.pdaValueNode("priceLadder")
+    },
+    {
+      account: "traitRequirements",
+      ignoreIfOptional: true,
+      defaultValue: c.pdaValueNode("traitRequirements")
+    },
     {
       account: "metadata",
       ignoreIfOptional: true,
@@ -452,6 +548,31 @@ codama.update(
   ])
 );
 
+// Remove defaults that do not apply to the currency and pool transfer instructions.
+codama.update(
+  c.updateInstructionsVisitor({
+    depositToken: {
+      accounts: {
+        ownerTa: { defaultValue: null },
+        poolTa: { defaultValue: null }
+      }
+    },
+    withdrawToken: {
+      accounts: {
+        ownerTa: { defaultValue: null },
+        poolTa: { defaultValue: null }
+      }
+    },
+    transferPool: {
+      accounts: {
+        tokenMetadataProgram: { defaultValue: null },
+        sysvarInstructions: { defaultValue: null },
+        authorizationRulesProgram: { defaultValue: null }
+      }
+    }
+  })
+);
+
 // Set more struct default values dynamically.
 codama.update(
   c.bottomUpTransformerVisitor([
@@ -465,7 +586,20 @@ codama.update(
           "max_taker_sell_count",
           "currency",
           "authorizationData",
-          "optionalRoyaltyPct"
+          "optionalRoyaltyPct",
+          "currencyMint",
+          "decayBps",
+          "floorPrice",
+          "priceLadderArgs",
+          "manager",
+          "newManager",
+          "maxTakerBuyCount",
+          "maxNftsHeld",
+          "maxTotalSpend",
+          "circuitBreaker",
+          "keeperBounty",
+          "mmFeeBps",
+          "mmCompoundFees"
         ];
         return (
           c.isNode(node, ["instructionNode", "instructionArgumentNode"]) &&
@@ -489,6 +623,8 @@ codama.update(
 codama.update(legacyInstructions());
 codama.update(token22Instructions());
 codama.update(coreInstructions());
+codama.update(wnsInstructions());
+codama.update(compressedInstructions());
 
 // Debug: print the AST.
 // codama.accept(c.consoleLogVisitor(c.getDebugStringVisitor({ indent: true })));